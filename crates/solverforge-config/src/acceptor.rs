@@ -65,6 +65,10 @@ pub struct SimulatedAnnealingConfig {
     // Decay rate.
     pub decay_rate: Option<f64>,
 
+    // Temperature reached when the phase's time or step budget is spent; replaces
+    // per-step decay with linear cooling over that budget.
+    pub ending_temperature: Option<f64>,
+
     // Temperature at or below which worsening moves are rejected deterministically.
     pub hill_climbing_temperature: Option<f64>,
 
@@ -114,6 +118,13 @@ pub struct DiversifiedLateAcceptanceConfig {
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct GreatDelugeConfig {
-    // Water level increase ratio.
+    // Water level increase ratio; the starting ratio in auto mode.
     pub water_level_increase_ratio: Option<f64>,
+
+    // Adapt the increase ratio to the observed acceptance rate.
+    #[serde(default)]
+    pub auto: bool,
+
+    // Fraction of non-improving moves auto mode aims to accept (default 0.2).
+    pub target_acceptance_rate: Option<f64>,
 }
@@ -0,0 +1,71 @@
+/* Constraint weight overrides loaded from the `[constraint_weights]` table.
+
+Weights are kept as score strings in the configuration and parsed once the
+score type is known, so the config schema stays independent of the score
+type a model uses.
+*/
+
+use solverforge_core::score::ParseableScore;
+use solverforge_scoring::ConstraintWeightOverrides;
+
+use crate::error::ConfigError;
+use crate::solver_config::SolverConfig;
+
+impl SolverConfig {
+    /// Parses `[constraint_weights]` into overrides for the score type `Sc`.
+    ///
+    /// `known_constraints` lists the constraint names of the model, usually
+    /// taken from the constraint set's metadata. Constraints then read their
+    /// weight through [`solverforge_scoring::WeightProvider`] when they are
+    /// built.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Invalid`] listing every configured name missing
+    /// from `known_constraints`, or naming the first weight that does not
+    /// parse as `Sc`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solverforge_config::SolverConfig;
+    /// use solverforge_core::score::HardSoftScore;
+    ///
+    /// let config = SolverConfig::from_toml_str(r#"
+    /// [constraint_weights]
+    /// "Room conflict" = "-2hard/0soft"
+    /// "#).unwrap();
+    ///
+    /// let overrides = config
+    ///     .constraint_weight_overrides::<HardSoftScore>(&["Room conflict", "Teacher preference"])
+    ///     .unwrap();
+    /// assert_eq!(overrides.get("Room conflict"), Some(&HardSoftScore::of(-2, 0)));
+    /// assert!(!overrides.contains("Teacher preference"));
+    /// ```
+    pub fn constraint_weight_overrides<Sc: ParseableScore>(
+        &self,
+        known_constraints: &[&str],
+    ) -> Result<ConstraintWeightOverrides<Sc>, ConfigError> {
+        let unknown: Vec<&str> = self
+            .constraint_weights
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !known_constraints.contains(name))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(ConfigError::Invalid(format!(
+                "constraint_weights names unknown constraints: {}",
+                unknown.join(", ")
+            )));
+        }
+
+        let mut overrides = ConstraintWeightOverrides::new();
+        for (name, weight) in &self.constraint_weights {
+            let weight = Sc::parse(weight).map_err(|error| {
+                ConfigError::Invalid(format!("constraint_weights.{name}: {error}"))
+            })?;
+            overrides.put(name.as_str(), weight);
+        }
+        Ok(overrides)
+    }
+}
@@ -7,7 +7,7 @@ pub struct DirectorConfig {
     // Fully qualified name of the constraint provider type.
     pub constraint_provider: Option<String>,
 
-    // Whether to enable constraint matching assertions.
+    // Whether the score director collects detailed constraint matches.
     #[serde(default)]
     pub constraint_match_enabled: bool,
 }
@@ -12,6 +12,9 @@ pub enum ConfigError {
     #[error("YAML parse error: {0}")]
     Yaml(#[from] serde_yaml::Error),
 
+    #[error("JSON parse error: {0}")]
+    Json(#[from] serde_json::Error),
+
     #[error("Invalid configuration: {0}")]
     Invalid(String),
 }
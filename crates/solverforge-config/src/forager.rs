@@ -25,6 +25,21 @@ pub enum ForagerConfig {
     FirstBestScoreImproving,
 
     // Stop on the first move improving the previous step score.
+    FirstLastStepScoreImproving(FirstLastStepScoreImprovingForagerConfig),
+}
+
+// Early-pick policy for accepted-count foraging.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PickEarlyType {
+    // Always collect up to the accepted-count limit.
+    #[default]
+    Never,
+
+    // End the step on the first accepted move improving the phase-best score.
+    FirstBestScoreImproving,
+
+    // End the step on the first accepted move improving the previous step score.
     FirstLastStepScoreImproving,
 }
 
@@ -33,4 +48,14 @@ pub enum ForagerConfig {
 #[serde(rename_all = "snake_case")]
 pub struct AcceptedCountForagerConfig {
     pub limit: Option<usize>,
+    #[serde(default)]
+    pub pick_early_type: PickEarlyType,
+}
+
+// Last-step-improving forager configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FirstLastStepScoreImprovingForagerConfig {
+    // Also stop after this many accepted moves when none improves the last step.
+    pub accepted_count_limit: Option<usize>,
 }
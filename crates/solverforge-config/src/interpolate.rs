@@ -0,0 +1,200 @@
+/* Environment variable interpolation for raw configuration text.
+
+Expansion runs on the document string before it is parsed, so tokens work
+in any position, including numeric fields:
+
+- `${NAME}` expands to the value of `NAME`; an unset variable is an error.
+- `${NAME:-default}` falls back to `default` when `NAME` is unset or empty.
+- `$$` produces a literal `$`.
+
+Any other `$` is copied through unchanged. Comments are copied verbatim, so a
+commented-out `${NAME}` neither expands nor fails on an unset variable.
+Outside quoted strings, TOML starts a comment at any `#`; YAML only at a `#`
+that begins a line or follows whitespace.
+*/
+
+use crate::error::ConfigError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Syntax {
+    Toml,
+    Yaml,
+}
+
+pub(crate) fn interpolate_env(raw: &str, syntax: Syntax) -> Result<String, ConfigError> {
+    interpolate(raw, syntax, |name| std::env::var(name).ok())
+}
+
+pub(crate) fn interpolate(
+    raw: &str,
+    syntax: Syntax,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, ConfigError> {
+    let mut expanded = String::with_capacity(raw.len());
+    let mut code_start = 0;
+
+    for (comment_start, comment_end) in comment_spans(raw, syntax) {
+        expand_into(&mut expanded, &raw[code_start..comment_start], &lookup)?;
+        expanded.push_str(&raw[comment_start..comment_end]);
+        code_start = comment_end;
+    }
+
+    expand_into(&mut expanded, &raw[code_start..], &lookup)?;
+    Ok(expanded)
+}
+
+fn expand_into(
+    expanded: &mut String,
+    code: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), ConfigError> {
+    let mut rest = code;
+
+    while let Some(dollar) = rest.find('$') {
+        expanded.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+
+        if let Some(tail) = after.strip_prefix('$') {
+            expanded.push('$');
+            rest = tail;
+        } else if let Some(body) = after.strip_prefix('{') {
+            let close = body.find('}').ok_or_else(|| {
+                ConfigError::Invalid(format!(
+                    "unterminated environment variable reference `${{{body}`"
+                ))
+            })?;
+            expanded.push_str(&resolve(&body[..close], lookup)?);
+            rest = &body[close + 1..];
+        } else {
+            expanded.push('$');
+            rest = after;
+        }
+    }
+
+    expanded.push_str(rest);
+    Ok(())
+}
+
+fn resolve(token: &str, lookup: &impl Fn(&str) -> Option<String>) -> Result<String, ConfigError> {
+    let (name, default) = match token.split_once(":-") {
+        Some((name, default)) => (name, Some(default)),
+        None => (token, None),
+    };
+    if name.is_empty() {
+        return Err(ConfigError::Invalid(format!(
+            "environment variable reference `${{{token}}}` has no name"
+        )));
+    }
+
+    match (lookup(name), default) {
+        (Some(value), Some(default)) if value.is_empty() => Ok(default.to_string()),
+        (Some(value), _) => Ok(value),
+        (None, Some(default)) => Ok(default.to_string()),
+        (None, None) => Err(ConfigError::Invalid(format!(
+            "environment variable `{name}` is not set and has no default"
+        ))),
+    }
+}
+
+/* Byte spans of the comments in `raw`, each running from its `#` up to, but
+not including, the end of its line.
+
+Quoted strings are skipped so a `#` inside a value is not a comment, and `$$`
+and `${...}` tokens are skipped whole so a default such as `${A:-#}` stays one
+token. Every delimiter is ASCII, so span boundaries are char boundaries.
+*/
+fn comment_spans(raw: &str, syntax: Syntax) -> Vec<(usize, usize)> {
+    let bytes = raw.as_bytes();
+    let mut spans = Vec::new();
+    let mut at = 0;
+
+    while at < bytes.len() {
+        match bytes[at] {
+            b'$' => at = skip_token(raw, at),
+            b'#' if starts_comment(bytes, at, syntax) => {
+                let end = raw[at..].find('\n').map_or(raw.len(), |offset| at + offset);
+                spans.push((at, end));
+                at = end;
+            }
+            quote @ (b'"' | b'\'') if opens_string(bytes, at, syntax) => {
+                at = skip_string(raw, at, quote, syntax);
+            }
+            _ => at += 1,
+        }
+    }
+
+    spans
+}
+
+fn skip_token(raw: &str, dollar: usize) -> usize {
+    let after = &raw[dollar + 1..];
+    if after.starts_with('$') {
+        dollar + 2
+    } else if after.starts_with('{') {
+        after
+            .find('}')
+            .map_or(dollar + 1, |close| dollar + close + 2)
+    } else {
+        dollar + 1
+    }
+}
+
+fn starts_comment(bytes: &[u8], at: usize, syntax: Syntax) -> bool {
+    match syntax {
+        Syntax::Toml => true,
+        Syntax::Yaml => at == 0 || bytes[at - 1].is_ascii_whitespace(),
+    }
+}
+
+// YAML quotes only delimit a scalar that starts with them; the apostrophe in a
+// plain scalar such as `name: don't` is ordinary text.
+fn opens_string(bytes: &[u8], at: usize, syntax: Syntax) -> bool {
+    match syntax {
+        Syntax::Toml => true,
+        Syntax::Yaml => bytes[..at]
+            .iter()
+            .rev()
+            .find(|&&byte| byte != b' ' && byte != b'\t')
+            .is_none_or(|byte| matches!(byte, b'\n' | b':' | b'-' | b'[' | b'{' | b',' | b'?')),
+    }
+}
+
+// Returns the index just past the closing quote, or the end of the text for an
+// unterminated string. TOML triple-quoted strings may span lines, while its
+// single-line strings end at the newline. Double-quoted strings honour
+// backslash escapes; YAML single-quoted strings escape a quote by doubling it.
+fn skip_string(raw: &str, open: usize, quote: u8, syntax: Syntax) -> usize {
+    let bytes = raw.as_bytes();
+    let multiline = syntax == Syntax::Toml && bytes[open..].starts_with(&[quote; 3]);
+    let mut at = open + if multiline { 3 } else { 1 };
+
+    while at < bytes.len() {
+        match bytes[at] {
+            b'$' => at = skip_token(raw, at),
+            b'\\' if quote == b'"' => at += 2,
+            b'\n' if syntax == Syntax::Toml && !multiline => return at,
+            byte if byte == quote && multiline => {
+                if bytes[at..].starts_with(&[quote; 3]) {
+                    // Up to two content quotes may directly precede the delimiter.
+                    let mut close = at + 3;
+                    while close < at + 5 && bytes.get(close) == Some(&quote) {
+                        close += 1;
+                    }
+                    return close;
+                }
+                at += 1;
+            }
+            b'\''
+                if syntax == Syntax::Yaml
+                    && quote == b'\''
+                    && bytes.get(at + 1) == Some(&quote) =>
+            {
+                at += 2;
+            }
+            byte if byte == quote => return at + 1,
+            _ => at += 1,
+        }
+    }
+
+    bytes.len()
+}
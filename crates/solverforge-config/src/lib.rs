@@ -42,13 +42,16 @@ let config = SolverConfig::load("solver.toml").unwrap_or_default();
 */
 
 mod acceptor;
+mod constraint_weights;
 mod director;
 mod error;
 mod forager;
+mod interpolate;
 mod move_selector;
 mod phase;
 mod solver_config;
 mod termination;
+mod validate;
 
 pub use acceptor::{
     AcceptorConfig, DiversifiedLateAcceptanceConfig, GreatDelugeConfig, HardRegressionPolicyConfig,
@@ -57,7 +60,10 @@ pub use acceptor::{
 };
 pub use director::DirectorConfig;
 pub use error::ConfigError;
-pub use forager::{AcceptedCountForagerConfig, ForagerConfig, ScoreTieBreak};
+pub use forager::{
+    AcceptedCountForagerConfig, FirstLastStepScoreImprovingForagerConfig, ForagerConfig,
+    PickEarlyType, ScoreTieBreak,
+};
 pub use move_selector::{
     CartesianProductConfig, ChangeMoveConfig, CompoundConflictRepairMoveSelectorConfig,
     ConflictRepairMoveSelectorConfig, GroupedScalarMoveSelectorConfig, KOptMoveSelectorConfig,
@@ -71,7 +77,8 @@ pub use move_selector::{
 };
 pub use phase::{
     ConstructionHeuristicConfig, ConstructionHeuristicType, ConstructionObligation,
-    CustomPhaseConfig, LocalSearchConfig, LocalSearchType, PartitionedSearchConfig, PhaseConfig,
+    CustomPhaseConfig, LocalSearchConfig, LocalSearchRestartConfig, LocalSearchType,
+    PartitionCountMode, PartitionedSearchConfig, PhaseConfig,
 };
 pub use solver_config::{
     CandidateTraceConfig, EnvironmentMode, MoveThreadCount, SolverConfig, SolverConfigOverride,
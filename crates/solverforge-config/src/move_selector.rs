@@ -618,6 +618,10 @@ pub struct CartesianProductConfig {
     #[serde(default)]
     pub require_hard_improvement: bool,
 
+    // Upper bound on composed pairs yielded per step; None means unbounded.
+    #[serde(default)]
+    pub max_moves: Option<usize>,
+
     // Child selectors.
     pub selectors: Vec<MoveSelectorConfig>,
 }
@@ -9,6 +9,10 @@ use crate::termination::TerminationConfig;
 // Phase configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "phase configs are parsed once and kept by value in SolverConfig"
+)]
 pub enum PhaseConfig {
     // Construction heuristic phase.
     ConstructionHeuristic(ConstructionHeuristicConfig),
@@ -179,10 +183,27 @@ pub struct LocalSearchConfig {
     #[serde(default)]
     pub neighborhoods: Vec<MoveSelectorConfig>,
 
+    // Perturbation restarts when acceptor/forager search stops applying moves.
+    pub restart: Option<LocalSearchRestartConfig>,
+
     // Phase termination configuration.
     pub termination: Option<TerminationConfig>,
 }
 
+// Local search restart configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LocalSearchRestartConfig {
+    // Maximum restarts per phase run.
+    pub max_restarts: u64,
+
+    // Consecutive steps without an applied move that count as stuck (default 1).
+    pub stuck_step_count: Option<u64>,
+
+    // Random moves applied from the phase's move selector per restart (default 1).
+    pub perturbation_size: Option<usize>,
+}
+
 // Partitioned search configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -194,6 +215,10 @@ pub struct PartitionedSearchConfig {
     #[serde(default)]
     pub thread_count: MoveThreadCount,
 
+    // How many partitions to request from the partitioner.
+    #[serde(default)]
+    pub partition_count: PartitionCountMode,
+
     // Whether to log partition progress.
     #[serde(default)]
     pub log_progress: bool,
@@ -206,6 +231,21 @@ pub struct PartitionedSearchConfig {
     pub termination: Option<TerminationConfig>,
 }
 
+// Partition count configuration for partitioned search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionCountMode {
+    // The partitioner chooses its own partition count.
+    #[default]
+    Partitioner,
+
+    // One partition per resolved partition thread.
+    Auto,
+
+    // Specific number of partitions.
+    Count(usize),
+}
+
 // Custom phase configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
 use std::path::Path;
 use std::time::Duration;
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::director::DirectorConfig;
 use crate::error::ConfigError;
+use crate::interpolate::{interpolate_env, Syntax};
 use crate::phase::PhaseConfig;
 use crate::termination::TerminationConfig;
 
@@ -44,6 +46,13 @@ pub struct SolverConfig {
     /// diagnostic runs cannot accidentally retain an unbounded neighborhood.
     #[serde(default)]
     pub candidate_trace: Option<CandidateTraceConfig>,
+
+    /// Constraint weight overrides keyed by constraint name.
+    ///
+    /// Values are score strings such as `"-2hard/0soft"`; see
+    /// [`SolverConfig::constraint_weight_overrides`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub constraint_weights: BTreeMap<String, String>,
 }
 
 impl SolverConfig {
@@ -67,10 +76,45 @@ impl SolverConfig {
     }
 
     /// Parses configuration from a TOML string.
+    ///
+    /// Only the shape is checked; call [`SolverConfig::validate`] to reject
+    /// values the solver cannot run with.
     pub fn from_toml_str(s: &str) -> Result<Self, ConfigError> {
         Ok(toml::from_str(s)?)
     }
 
+    /// Parses a TOML string after expanding environment variable references.
+    ///
+    /// `${NAME}` and `${NAME:-default}` are substituted in the raw text before
+    /// parsing, so they may stand in for numeric values; `$$` yields a literal
+    /// `$`. References inside `#` comments are left as written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Invalid`] naming the variable when a reference
+    /// without a default is unset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solverforge_config::SolverConfig;
+    ///
+    /// let config = SolverConfig::from_toml_str_with_env(r#"
+    /// random_seed = ${SOLVERFORGE_DOC_SEED:-7}
+    /// "#).unwrap();
+    /// assert_eq!(config.random_seed, Some(7));
+    /// ```
+    pub fn from_toml_str_with_env(s: &str) -> Result<Self, ConfigError> {
+        Self::from_toml_str(&interpolate_env(s, Syntax::Toml)?)
+    }
+
+    /// Parses a YAML string after expanding environment variable references.
+    ///
+    /// Uses the same syntax as [`SolverConfig::from_toml_str_with_env`].
+    pub fn from_yaml_str_with_env(s: &str) -> Result<Self, ConfigError> {
+        Self::from_yaml_str(&interpolate_env(s, Syntax::Yaml)?)
+    }
+
     /// Loads configuration from a YAML file.
     pub fn from_yaml_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
         let contents = std::fs::read_to_string(path)?;
@@ -82,6 +126,17 @@ impl SolverConfig {
         Ok(serde_yaml::from_str(s)?)
     }
 
+    /// Loads configuration from a JSON file.
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::from_json_str(&contents)
+    }
+
+    /// Parses configuration from a JSON string.
+    pub fn from_json_str(s: &str) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_str(s)?)
+    }
+
     pub fn with_termination_seconds(mut self, seconds: u64) -> Self {
         self.termination = Some(TerminationConfig {
             seconds_spent_limit: Some(seconds),
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 
@@ -18,11 +18,37 @@ pub struct TerminationConfig {
     // Maximum number of steps.
     pub step_count_limit: Option<u64>,
 
+    // Maximum number of evaluated moves.
+    pub move_count_limit: Option<u64>,
+
+    // Maximum number of score calculations.
+    pub score_calculation_count_limit: Option<u64>,
+
     // Maximum unimproved steps before terminating.
     pub unimproved_step_count_limit: Option<u64>,
 
     // Maximum seconds without improvement.
     pub unimproved_seconds_spent_limit: Option<u64>,
+
+    // Absolute wall-clock stop time in milliseconds since the Unix epoch.
+    pub unix_deadline_millis: Option<u64>,
+
+    // Trailing window, in seconds, over which diminished returns are measured.
+    pub diminished_returns_window_seconds: Option<u64>,
+
+    // Minimum best-score improvement per second over the trailing window.
+    pub diminished_returns_min_improvement_ratio: Option<f64>,
+
+    // Seconds to keep optimizing after the best score first becomes feasible.
+    pub feasible_then_seconds: Option<u64>,
+
+    // Nested terminations, any one of which stops solving.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<TerminationConfig>,
+
+    // Nested terminations that must all be reached before solving stops.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all_of: Vec<TerminationConfig>,
 }
 
 impl TerminationConfig {
@@ -36,7 +62,38 @@ impl TerminationConfig {
         }
     }
 
+    pub fn deadline(&self) -> Option<SystemTime> {
+        self.unix_deadline_millis
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    // Time left until the deadline as seen from `now`; zero once it has passed.
+    pub fn deadline_remaining(&self, now: SystemTime) -> Option<Duration> {
+        self.deadline()
+            .map(|deadline| deadline.duration_since(now).unwrap_or(Duration::ZERO))
+    }
+
+    // Whether this block nests `any_of` or `all_of` terminations.
+    pub fn is_nested(&self) -> bool {
+        !self.any_of.is_empty() || !self.all_of.is_empty()
+    }
+
     pub fn unimproved_time_limit(&self) -> Option<Duration> {
         self.unimproved_seconds_spent_limit.map(Duration::from_secs)
     }
+
+    pub fn feasible_then_limit(&self) -> Option<Duration> {
+        self.feasible_then_seconds.map(Duration::from_secs)
+    }
+
+    /* Trailing window and minimum improvement ratio for diminished returns.
+
+    Solving stops once the last score level improved by less than the ratio
+    per second over the window. Both fields must be set.
+    */
+    pub fn diminished_returns(&self) -> Option<(Duration, f64)> {
+        let window = self.diminished_returns_window_seconds?;
+        let ratio = self.diminished_returns_min_improvement_ratio?;
+        Some((Duration::from_secs(window), ratio))
+    }
 }
@@ -1,5 +1,8 @@
 pub(super) use super::*;
 
+mod constraint_weights;
+mod interpolation;
 mod parsing;
 mod roundtrip;
 mod selectors;
+mod validation;
@@ -0,0 +1,84 @@
+// Tests for constraint weight overrides.
+
+use super::*;
+use solverforge_core::score::{HardMediumSoftScore, HardSoftScore};
+
+const KNOWN: &[&str] = &["Room conflict", "Teacher preference"];
+
+#[test]
+fn test_constraint_weights_parse_into_overrides() {
+    let config = SolverConfig::from_toml_str(
+        r#"
+        [constraint_weights]
+        "Room conflict" = "-2hard/0soft"
+        "Teacher preference" = "0hard/-3soft"
+        "#,
+    )
+    .unwrap();
+
+    let overrides = config
+        .constraint_weight_overrides::<HardSoftScore>(KNOWN)
+        .unwrap();
+    assert_eq!(overrides.len(), 2);
+    assert_eq!(
+        overrides.get("Room conflict"),
+        Some(&HardSoftScore::of(-2, 0))
+    );
+    assert_eq!(
+        overrides.get("Teacher preference"),
+        Some(&HardSoftScore::of(0, -3))
+    );
+}
+
+#[test]
+fn test_constraint_weights_default_to_empty() {
+    let config = SolverConfig::from_toml_str("random_seed = 1").unwrap();
+
+    assert!(config.constraint_weights.is_empty());
+    let overrides = config
+        .constraint_weight_overrides::<HardSoftScore>(KNOWN)
+        .unwrap();
+    assert!(overrides.is_empty());
+    assert!(!toml::to_string(&config)
+        .unwrap()
+        .contains("constraint_weights"));
+}
+
+#[test]
+fn test_constraint_weights_reject_unknown_names() {
+    let config = SolverConfig::from_yaml_str(
+        r#"
+constraint_weights:
+  Room conflict: "-1hard/0soft"
+  Lunch break: "0hard/-1soft"
+  Overtime: "0hard/-1soft"
+"#,
+    )
+    .unwrap();
+
+    let error = config
+        .constraint_weight_overrides::<HardSoftScore>(KNOWN)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid configuration: constraint_weights names unknown constraints: Lunch break, Overtime"
+    );
+}
+
+#[test]
+fn test_constraint_weights_reject_malformed_scores() {
+    let config = SolverConfig::from_toml_str(
+        r#"
+        [constraint_weights]
+        "Room conflict" = "-1hard/0soft"
+        "#,
+    )
+    .unwrap();
+
+    let error = config
+        .constraint_weight_overrides::<HardMediumSoftScore>(KNOWN)
+        .unwrap_err();
+    assert!(error.to_string().starts_with(
+        "Invalid configuration: constraint_weights.Room conflict: Score parse error:"
+    ));
+}
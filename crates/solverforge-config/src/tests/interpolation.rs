@@ -0,0 +1,131 @@
+// Tests for environment variable interpolation.
+
+use super::*;
+use crate::interpolate::{interpolate, Syntax};
+
+fn lookup(name: &str) -> Option<String> {
+    match name {
+        "SEED" => Some("42".to_string()),
+        "LIMIT" => Some("30".to_string()),
+        "EMPTY" => Some(String::new()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_interpolate_expands_defined_variables() {
+    assert_eq!(
+        interpolate("random_seed = ${SEED}", Syntax::Toml, lookup).unwrap(),
+        "random_seed = 42"
+    );
+}
+
+#[test]
+fn test_interpolate_uses_default_when_unset_or_empty() {
+    assert_eq!(
+        interpolate("${MISSING:-5}", Syntax::Toml, lookup).unwrap(),
+        "5"
+    );
+    assert_eq!(
+        interpolate("${EMPTY:-5}", Syntax::Toml, lookup).unwrap(),
+        "5"
+    );
+    assert_eq!(
+        interpolate("${SEED:-5}", Syntax::Toml, lookup).unwrap(),
+        "42"
+    );
+}
+
+#[test]
+fn test_interpolate_passes_escaped_dollar_through() {
+    assert_eq!(
+        interpolate("name = \"$${SEED} costs $5\"", Syntax::Toml, lookup).unwrap(),
+        "name = \"${SEED} costs $5\""
+    );
+}
+
+#[test]
+fn test_interpolate_rejects_unset_variable_without_default() {
+    let error = interpolate("random_seed = ${MISSING}", Syntax::Toml, lookup).unwrap_err();
+    match error {
+        ConfigError::Invalid(message) => assert!(message.contains("`MISSING`"), "{message}"),
+        other => panic!("expected invalid config error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_interpolate_rejects_unterminated_reference() {
+    assert!(matches!(
+        interpolate("random_seed = ${SEED", Syntax::Toml, lookup),
+        Err(ConfigError::Invalid(_))
+    ));
+}
+
+#[test]
+fn test_toml_with_env_expands_numeric_fields() {
+    let toml = r#"
+        random_seed = ${SOLVERFORGE_TEST_UNSET_SEED:-11}
+
+        [termination]
+        seconds_spent_limit = ${SOLVERFORGE_TEST_UNSET_LIMIT:-30}
+    "#;
+
+    let config = SolverConfig::from_toml_str_with_env(toml).unwrap();
+    assert_eq!(config.random_seed, Some(11));
+    assert_eq!(
+        config.time_limit(),
+        Some(std::time::Duration::from_secs(30))
+    );
+}
+
+#[test]
+fn test_yaml_with_env_reports_missing_variable() {
+    let yaml = "random_seed: ${SOLVERFORGE_TEST_UNSET_SEED}\n";
+
+    assert!(matches!(
+        SolverConfig::from_yaml_str_with_env(yaml),
+        Err(ConfigError::Invalid(message)) if message.contains("SOLVERFORGE_TEST_UNSET_SEED")
+    ));
+}
+
+#[test]
+fn test_interpolate_leaves_toml_comments_untouched() {
+    let toml = "# seed = ${MISSING}\nrandom_seed = ${SEED} # was ${MISSING}\n";
+
+    assert_eq!(
+        interpolate(toml, Syntax::Toml, lookup).unwrap(),
+        "# seed = ${MISSING}\nrandom_seed = 42 # was ${MISSING}\n"
+    );
+}
+
+#[test]
+fn test_interpolate_expands_inside_toml_strings_containing_hashes() {
+    let toml = "name = \"#${SEED}\" # ${MISSING}\ntag = '#' # ${MISSING}\nnote = \"\"\"\n# ${LIMIT}\n\"\"\"\nlimit = ${LIMIT:-#}\n";
+
+    assert_eq!(
+        interpolate(toml, Syntax::Toml, lookup).unwrap(),
+        "name = \"#42\" # ${MISSING}\ntag = '#' # ${MISSING}\nnote = \"\"\"\n# 30\n\"\"\"\nlimit = 30\n"
+    );
+}
+
+#[test]
+fn test_interpolate_follows_yaml_comment_rules() {
+    let yaml =
+        "# ${MISSING}\nname: don't#${SEED} # ${MISSING}\nquoted: 'it''s # ${LIMIT}' # ${MISSING}\n";
+
+    assert_eq!(
+        interpolate(yaml, Syntax::Yaml, lookup).unwrap(),
+        "# ${MISSING}\nname: don't#42 # ${MISSING}\nquoted: 'it''s # 30' # ${MISSING}\n"
+    );
+}
+
+#[test]
+fn test_toml_with_env_ignores_commented_out_references() {
+    let toml = r#"
+        # random_seed = ${SOLVERFORGE_TEST_UNSET_SEED}
+        random_seed = 3
+    "#;
+
+    let config = SolverConfig::from_toml_str_with_env(toml).unwrap();
+    assert_eq!(config.random_seed, Some(3));
+}
@@ -50,6 +50,47 @@ fn test_yaml_parsing() {
     assert_eq!(config.random_seed, Some(42));
 }
 
+#[test]
+fn test_json_parsing() {
+    let json = r#"{
+        "environment_mode": "reproducible",
+        "random_seed": 42,
+        "termination": { "seconds_spent_limit": 30 },
+        "phases": [
+            {
+                "type": "construction_heuristic",
+                "construction_heuristic_type": "first_fit_decreasing"
+            },
+            {
+                "type": "local_search",
+                "acceptor": { "type": "late_acceptance", "late_acceptance_size": 400 }
+            }
+        ]
+    }"#;
+
+    let config = SolverConfig::from_json_str(json).unwrap();
+    assert_eq!(config.environment_mode, EnvironmentMode::Reproducible);
+    assert_eq!(config.random_seed, Some(42));
+    assert_eq!(config.termination.unwrap().seconds_spent_limit, Some(30));
+    assert!(matches!(
+        config.phases[0],
+        PhaseConfig::ConstructionHeuristic(_)
+    ));
+    let PhaseConfig::LocalSearch(local_search) = &config.phases[1] else {
+        panic!("phase should be local_search");
+    };
+    assert!(matches!(
+        local_search.acceptor,
+        Some(AcceptorConfig::LateAcceptance(_))
+    ));
+}
+
+#[test]
+fn test_json_parse_error_is_reported_as_json() {
+    let error = SolverConfig::from_json_str(r#"{ "phases": [ { "type": "nope" } ] }"#).unwrap_err();
+    assert!(matches!(error, ConfigError::Json(_)));
+}
+
 #[test]
 fn test_builder() {
     let config = SolverConfig::new()
@@ -106,9 +147,62 @@ fn search_mode_phase_configs_parse_current_fields() {
     };
     assert_eq!(partitioned.partitioner.as_deref(), Some("by_vehicle"));
     assert_eq!(partitioned.thread_count, MoveThreadCount::None);
+    assert_eq!(partitioned.partition_count, PartitionCountMode::Partitioner);
     assert!(partitioned.log_progress);
 }
 
+#[test]
+fn partitioned_search_partition_count_parsing() {
+    let toml = r#"
+        [[phases]]
+        type = "partitioned_search"
+        partition_count = "auto"
+
+        [[phases]]
+        type = "partitioned_search"
+        partition_count = { count = 6 }
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    let counts: Vec<_> = config
+        .phases
+        .iter()
+        .map(|phase| match phase {
+            PhaseConfig::PartitionedSearch(partitioned) => partitioned.partition_count,
+            _ => panic!("phase should be partitioned_search"),
+        })
+        .collect();
+    assert_eq!(
+        counts,
+        vec![PartitionCountMode::Auto, PartitionCountMode::Count(6)]
+    );
+}
+
+#[test]
+fn local_search_restart_parsing() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.restart]
+        max_restarts = 5
+        perturbation_size = 3
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    config.validate().unwrap();
+    let PhaseConfig::LocalSearch(local_search) = &config.phases[0] else {
+        panic!("phase should be local search");
+    };
+    assert_eq!(
+        local_search.restart,
+        Some(LocalSearchRestartConfig {
+            max_restarts: 5,
+            stuck_step_count: None,
+            perturbation_size: Some(3),
+        })
+    );
+}
+
 #[test]
 fn local_search_variable_neighborhood_descent_parsing() {
     let toml = r#"
@@ -248,3 +342,132 @@ fn grouped_scalar_move_selector_parses_and_roundtrips() {
     assert_eq!(selector.max_moves_per_step, Some(64));
     assert!(selector.require_hard_improvement);
 }
+
+#[test]
+fn k_opt_move_selector_parses_four_opt() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+
+        [phases.move_selector]
+        type = "k_opt_move_selector"
+        k = 4
+        min_segment_len = 1
+        max_nearby = 0
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    let PhaseConfig::LocalSearch(local_search) = &config.phases[0] else {
+        panic!("phase should be local_search");
+    };
+    let Some(MoveSelectorConfig::KOptMoveSelector(selector)) = &local_search.move_selector else {
+        panic!("local search should have k-opt selector");
+    };
+
+    assert_eq!(selector.k, 4);
+    assert_eq!(selector.min_segment_len, 1);
+}
+
+#[test]
+fn test_termination_deadline_parsing() {
+    let toml = r#"
+        [termination]
+        unix_deadline_millis = 1500
+    "#;
+
+    let termination = SolverConfig::from_toml_str(toml)
+        .unwrap()
+        .termination
+        .unwrap();
+    let deadline = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1500);
+    assert_eq!(termination.deadline(), Some(deadline));
+    assert_eq!(
+        termination.deadline_remaining(deadline - std::time::Duration::from_millis(500)),
+        Some(std::time::Duration::from_millis(500))
+    );
+    assert_eq!(
+        termination.deadline_remaining(deadline + std::time::Duration::from_secs(1)),
+        Some(std::time::Duration::ZERO)
+    );
+    assert_eq!(termination.time_limit(), None);
+}
+
+#[test]
+fn test_termination_diminished_returns_parsing() {
+    let toml = r#"
+        [termination]
+        diminished_returns_window_seconds = 30
+        diminished_returns_min_improvement_ratio = 0.5
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    config.validate().unwrap();
+    let termination = config.termination.unwrap();
+    assert_eq!(
+        termination.diminished_returns(),
+        Some((std::time::Duration::from_secs(30), 0.5))
+    );
+    assert_eq!(termination.time_limit(), None);
+}
+
+#[test]
+fn test_termination_move_and_score_calculation_count_parsing() {
+    let toml = r#"
+        [termination]
+        move_count_limit = 5000
+        score_calculation_count_limit = 20000
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    config.validate().unwrap();
+    let termination = config.termination.unwrap();
+    assert_eq!(termination.move_count_limit, Some(5000));
+    assert_eq!(termination.score_calculation_count_limit, Some(20000));
+}
+
+#[test]
+fn test_termination_nested_parsing() {
+    let toml = r#"
+        [[termination.all_of]]
+        [[termination.all_of.any_of]]
+        seconds_spent_limit = 60
+        [[termination.all_of.any_of]]
+        best_score_limit = "0hard/0soft"
+
+        [[termination.all_of]]
+        step_count_limit = 100
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    config.validate().unwrap();
+    let termination = config.termination.unwrap();
+    assert!(termination.is_nested());
+    assert!(termination.any_of.is_empty());
+    assert_eq!(termination.all_of.len(), 2);
+    let either = &termination.all_of[0];
+    assert_eq!(either.any_of.len(), 2);
+    assert_eq!(either.any_of[0].seconds_spent_limit, Some(60));
+    assert_eq!(
+        either.any_of[1].best_score_limit.as_deref(),
+        Some("0hard/0soft")
+    );
+    assert_eq!(termination.all_of[1].step_count_limit, Some(100));
+    assert!(!termination.all_of[1].is_nested());
+}
+
+#[test]
+fn test_termination_feasible_then_parsing() {
+    let toml = r#"
+        [termination]
+        feasible_then_seconds = 15
+    "#;
+
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    config.validate().unwrap();
+    let termination = config.termination.unwrap();
+    assert_eq!(
+        termination.feasible_then_limit(),
+        Some(std::time::Duration::from_secs(15))
+    );
+    assert_eq!(termination.time_limit(), None);
+}
@@ -136,6 +136,7 @@ fn test_new_scalar_selector_variants_and_cartesian_roundtrip() {
         [[phases.move_selector.selectors]]
         type = "cartesian_product_move_selector"
         require_hard_improvement = true
+        max_moves = 64
 
         [[phases.move_selector.selectors.selectors]]
         type = "change_move_selector"
@@ -195,6 +196,7 @@ fn test_new_scalar_selector_variants_and_cartesian_roundtrip() {
         panic!("sixth selector should be cartesian_product");
     };
     assert!(cartesian.require_hard_improvement);
+    assert_eq!(cartesian.max_moves, Some(64));
     assert_eq!(cartesian.selectors.len(), 2);
 }
 
@@ -207,6 +209,7 @@ fn test_tagged_forager_variants_roundtrip() {
         [phases.forager]
         type = "accepted_count"
         limit = 9
+        pick_early_type = "first_last_step_score_improving"
     "#;
 
     let config = SolverConfig::from_toml_str(toml).unwrap();
@@ -217,6 +220,10 @@ fn test_tagged_forager_variants_roundtrip() {
         panic!("forager should be accepted_count");
     };
     assert_eq!(accepted_count.limit, Some(9));
+    assert_eq!(
+        accepted_count.pick_early_type,
+        PickEarlyType::FirstLastStepScoreImproving
+    );
 
     let improving_toml = r#"
         [[phases]]
@@ -235,3 +242,43 @@ fn test_tagged_forager_variants_roundtrip() {
         Some(ForagerConfig::FirstBestScoreImproving)
     ));
 }
+
+#[test]
+fn test_json_roundtrip_matches_toml() {
+    let toml = r#"
+        environment_mode = "fast_assert"
+        random_seed = 7
+
+        [termination]
+        seconds_spent_limit = 30
+        unimproved_step_count_limit = 500
+
+        [[phases]]
+        type = "construction_heuristic"
+        construction_heuristic_type = "cheapest_insertion"
+
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "late_acceptance"
+        late_acceptance_size = 400
+
+        [phases.move_selector]
+        type = "conflict_repair_move_selector"
+        constraints = ["minimumRest"]
+        max_matches_per_step = 4
+        max_repairs_per_match = 8
+        max_moves_per_step = 32
+
+        [[phases]]
+        type = "custom"
+        name = "polish"
+    "#;
+
+    let from_toml = SolverConfig::from_toml_str(toml).unwrap();
+    let encoded = serde_json::to_string(&from_toml).unwrap();
+    let from_json = SolverConfig::from_json_str(&encoded).unwrap();
+
+    assert_eq!(format!("{from_json:?}"), format!("{from_toml:?}"));
+    assert_eq!(serde_json::to_string(&from_json).unwrap(), encoded);
+}
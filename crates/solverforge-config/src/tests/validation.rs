@@ -0,0 +1,404 @@
+// Tests for semantic configuration validation.
+
+use super::*;
+
+fn validation_error(toml: &str) -> String {
+    let config = SolverConfig::from_toml_str(toml).unwrap();
+    match config.validate() {
+        Err(ConfigError::Invalid(message)) => message,
+        other => panic!("expected an invalid configuration, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_validate_accepts_a_complete_config() {
+    let toml = r#"
+        [termination]
+        seconds_spent_limit = 30
+
+        [[phases]]
+        type = "construction_heuristic"
+
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "late_acceptance"
+        late_acceptance_size = 400
+        [phases.move_selector]
+        type = "union_move_selector"
+        [[phases.move_selector.selectors]]
+        type = "change_move_selector"
+    "#;
+
+    SolverConfig::from_toml_str(toml)
+        .unwrap()
+        .validate()
+        .unwrap();
+    SolverConfig::default().validate().unwrap();
+}
+
+#[test]
+fn test_validate_rejects_empty_termination() {
+    assert_eq!(
+        validation_error("[termination]"),
+        "termination must set at least one limit"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "construction_heuristic"
+
+        [[phases]]
+        type = "local_search"
+        [phases.termination]
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[1].termination must set at least one limit"
+    );
+}
+
+#[test]
+fn test_validate_rejects_zero_late_acceptance_size() {
+    let toml = r#"
+        [[phases]]
+        type = "construction_heuristic"
+
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "late_acceptance"
+        late_acceptance_size = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[1].acceptor.late_acceptance_size must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_zero_tabu_size() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "tabu_search"
+        entity_tabu_size = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.entity_tabu_size must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_out_of_range_simulated_annealing() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "simulated_annealing"
+        decay_rate = 1.5
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.decay_rate must be finite and in (0, 1]"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "simulated_annealing"
+        level_temperatures = [2.0, -1.0]
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.level_temperatures[1] must be finite and non-negative"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "simulated_annealing"
+        ending_temperature = -0.5
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.ending_temperature must be finite and non-negative"
+    );
+}
+
+#[test]
+fn test_validate_rejects_unusable_great_deluge_target() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "great_deluge"
+        target_acceptance_rate = 0.3
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.target_acceptance_rate requires auto = true"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.acceptor]
+        type = "great_deluge"
+        auto = true
+        target_acceptance_rate = 1.0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].acceptor.target_acceptance_rate must be in (0, 1)"
+    );
+}
+
+#[test]
+fn test_validate_rejects_zero_accepted_count_limit() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.forager]
+        type = "accepted_count"
+        limit = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].forager.limit must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_unusable_local_search_restart() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.restart]
+        max_restarts = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].restart.max_restarts must be >= 1"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.restart]
+        max_restarts = 2
+        perturbation_size = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].restart.perturbation_size must be >= 1"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        local_search_type = "variable_neighborhood_descent"
+        [phases.restart]
+        max_restarts = 2
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].restart is only supported for acceptor_forager local search"
+    );
+}
+
+#[test]
+fn test_validate_rejects_empty_union_selectors() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.move_selector]
+        type = "union_move_selector"
+        selectors = []
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].move_selector.selectors must not be empty"
+    );
+}
+
+#[test]
+fn test_validate_reports_nested_selector_paths() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.move_selector]
+        type = "union_move_selector"
+        [[phases.move_selector.selectors]]
+        type = "change_move_selector"
+        [[phases.move_selector.selectors]]
+        type = "limited_neighborhood"
+        selected_count_limit = 0
+        [phases.move_selector.selectors.selector]
+        type = "swap_move_selector"
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].move_selector.selectors[1].selected_count_limit must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_k_opt_selector_outside_supported_k() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.move_selector]
+        type = "k_opt_move_selector"
+        k = 6
+        min_segment_len = 1
+        max_nearby = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].move_selector.k must be between 2 and 5"
+    );
+}
+
+#[test]
+fn test_validate_walks_partitioned_child_phases() {
+    let toml = r#"
+        [[phases]]
+        type = "partitioned_search"
+        [[phases.child_phases]]
+        type = "construction_heuristic"
+        [[phases.child_phases]]
+        type = "local_search"
+        [phases.child_phases.acceptor]
+        type = "step_counting_hill_climbing"
+        step_count_limit = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].child_phases[1].acceptor.step_count_limit must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_zero_construction_candidate_limit() {
+    let toml = r#"
+        [[phases]]
+        type = "construction_heuristic"
+        value_candidate_limit = 0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].value_candidate_limit must be >= 1"
+    );
+}
+
+#[test]
+fn test_validate_rejects_incomplete_diminished_returns() {
+    let toml = r#"
+        [termination]
+        diminished_returns_window_seconds = 10
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "termination.diminished_returns_min_improvement_ratio must be set with diminished_returns_window_seconds"
+    );
+
+    let toml = r#"
+        [termination]
+        diminished_returns_window_seconds = 0
+        diminished_returns_min_improvement_ratio = 1.0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "termination.diminished_returns_window_seconds must be >= 1"
+    );
+
+    let toml = r#"
+        [termination]
+        diminished_returns_window_seconds = 10
+        diminished_returns_min_improvement_ratio = -1.0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "termination.diminished_returns_min_improvement_ratio must be finite and non-negative"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.termination]
+        diminished_returns_window_seconds = 10
+        diminished_returns_min_improvement_ratio = 1.0
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].termination.diminished_returns_window_seconds is only supported on the solver termination"
+    );
+}
+
+#[test]
+fn test_validate_rejects_phase_level_work_count_limits() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.termination]
+        move_count_limit = 100
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].termination.move_count_limit is only supported on the solver termination"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.termination]
+        score_calculation_count_limit = 100
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].termination.score_calculation_count_limit is only supported on the solver termination"
+    );
+}
+
+#[test]
+fn test_validate_walks_nested_terminations() {
+    let toml = r#"
+        [[termination.any_of]]
+        seconds_spent_limit = 60
+        [[termination.any_of]]
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "termination.any_of[1] must set at least one limit"
+    );
+
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [[phases.termination.all_of]]
+        step_count_limit = 10
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].termination.all_of is only supported on the solver termination"
+    );
+}
+
+#[test]
+fn test_validate_rejects_phase_level_feasible_then() {
+    let toml = r#"
+        [[phases]]
+        type = "local_search"
+        [phases.termination]
+        feasible_then_seconds = 5
+    "#;
+    assert_eq!(
+        validation_error(toml),
+        "phases[0].termination.feasible_then_seconds is only supported on the solver termination"
+    );
+}
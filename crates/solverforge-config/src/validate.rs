@@ -0,0 +1,381 @@
+/* Semantic validation for parsed solver configuration.
+
+Parsing only checks shape; these rules reject values that deserialize fine
+but cannot drive a solver. Every error names the offending field with a
+path such as `phases[1].acceptor.late_acceptance_size`.
+*/
+
+use crate::acceptor::AcceptorConfig;
+use crate::error::ConfigError;
+use crate::forager::ForagerConfig;
+use crate::move_selector::MoveSelectorConfig;
+use crate::phase::{
+    ConstructionHeuristicConfig, ConstructionHeuristicType, LocalSearchRestartConfig,
+    LocalSearchType, PhaseConfig,
+};
+use crate::solver_config::SolverConfig;
+use crate::termination::TerminationConfig;
+
+impl SolverConfig {
+    /// Checks the configuration for values the solver cannot run with.
+    ///
+    /// Parsing does not call this; run it after loading a configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Invalid`] describing the first offending field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use solverforge_config::SolverConfig;
+    ///
+    /// let config = SolverConfig::from_toml_str(r#"
+    /// [[phases]]
+    /// type = "local_search"
+    /// [phases.acceptor]
+    /// type = "late_acceptance"
+    /// late_acceptance_size = 0
+    /// "#).unwrap();
+    ///
+    /// let error = config.validate().unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Invalid configuration: phases[0].acceptor.late_acceptance_size must be >= 1"
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(termination) = &self.termination {
+            validate_termination(termination, "termination")?;
+        }
+        validate_phases(&self.phases, "phases")
+    }
+}
+
+fn invalid(path: &str, rule: &str) -> ConfigError {
+    ConfigError::Invalid(format!("{path} {rule}"))
+}
+
+fn require_positive(value: Option<usize>, path: &str) -> Result<(), ConfigError> {
+    match value {
+        Some(0) => Err(invalid(path, "must be >= 1")),
+        _ => Ok(()),
+    }
+}
+
+fn require_non_negative(value: Option<f64>, path: &str) -> Result<(), ConfigError> {
+    match value {
+        Some(value) if !value.is_finite() || value < 0.0 => {
+            Err(invalid(path, "must be finite and non-negative"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_phases(phases: &[PhaseConfig], path: &str) -> Result<(), ConfigError> {
+    for (index, phase) in phases.iter().enumerate() {
+        validate_phase(phase, &format!("{path}[{index}]"))?;
+    }
+    Ok(())
+}
+
+fn validate_phase(phase: &PhaseConfig, path: &str) -> Result<(), ConfigError> {
+    let termination = match phase {
+        PhaseConfig::ConstructionHeuristic(config) => {
+            validate_construction(config, path)?;
+            &config.termination
+        }
+        PhaseConfig::LocalSearch(config) => {
+            if let Some(acceptor) = &config.acceptor {
+                validate_acceptor(acceptor, &format!("{path}.acceptor"))?;
+            }
+            match &config.forager {
+                Some(ForagerConfig::AcceptedCount(forager)) => {
+                    require_positive(forager.limit, &format!("{path}.forager.limit"))?;
+                }
+                Some(ForagerConfig::FirstLastStepScoreImproving(forager)) => {
+                    require_positive(
+                        forager.accepted_count_limit,
+                        &format!("{path}.forager.accepted_count_limit"),
+                    )?;
+                }
+                _ => {}
+            }
+            if let Some(selector) = &config.move_selector {
+                validate_move_selector(selector, &format!("{path}.move_selector"))?;
+            }
+            for (index, selector) in config.neighborhoods.iter().enumerate() {
+                validate_move_selector(selector, &format!("{path}.neighborhoods[{index}]"))?;
+            }
+            if let Some(restart) = &config.restart {
+                validate_restart(
+                    restart,
+                    config.local_search_type,
+                    &format!("{path}.restart"),
+                )?;
+            }
+            &config.termination
+        }
+        PhaseConfig::PartitionedSearch(config) => {
+            validate_phases(&config.child_phases, &format!("{path}.child_phases"))?;
+            &config.termination
+        }
+        PhaseConfig::Custom(config) => {
+            if config.name.trim().is_empty() {
+                return Err(invalid(&format!("{path}.name"), "must not be empty"));
+            }
+            &None
+        }
+    };
+    match termination {
+        Some(termination) => {
+            let path = format!("{path}.termination");
+            reject_solver_only_termination(termination, &path)?;
+            validate_termination(termination, &path)
+        }
+        None => Ok(()),
+    }
+}
+
+// Phase-scoped termination only tracks flat time, step, score and
+// unimproved limits.
+fn reject_solver_only_termination(
+    config: &TerminationConfig,
+    path: &str,
+) -> Result<(), ConfigError> {
+    let solver_only = [
+        ("move_count_limit", config.move_count_limit.is_some()),
+        (
+            "score_calculation_count_limit",
+            config.score_calculation_count_limit.is_some(),
+        ),
+        (
+            "diminished_returns_window_seconds",
+            config.diminished_returns_window_seconds.is_some()
+                || config.diminished_returns_min_improvement_ratio.is_some(),
+        ),
+        (
+            "feasible_then_seconds",
+            config.feasible_then_seconds.is_some(),
+        ),
+        ("any_of", !config.any_of.is_empty()),
+        ("all_of", !config.all_of.is_empty()),
+    ];
+    match solver_only.iter().find(|(_, set)| *set) {
+        Some((field, _)) => Err(invalid(
+            &format!("{path}.{field}"),
+            "is only supported on the solver termination",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn validate_construction(
+    config: &ConstructionHeuristicConfig,
+    path: &str,
+) -> Result<(), ConfigError> {
+    if config.construction_heuristic_type == ConstructionHeuristicType::ListKOpt && config.k < 2 {
+        return Err(invalid(&format!("{path}.k"), "must be >= 2"));
+    }
+    require_positive(
+        config.value_candidate_limit,
+        &format!("{path}.value_candidate_limit"),
+    )?;
+    require_positive(
+        config.group_candidate_limit,
+        &format!("{path}.group_candidate_limit"),
+    )
+}
+
+fn validate_termination(config: &TerminationConfig, path: &str) -> Result<(), ConfigError> {
+    let TerminationConfig {
+        seconds_spent_limit,
+        minutes_spent_limit,
+        best_score_limit,
+        step_count_limit,
+        move_count_limit,
+        score_calculation_count_limit,
+        unimproved_step_count_limit,
+        unimproved_seconds_spent_limit,
+        unix_deadline_millis,
+        diminished_returns_window_seconds,
+        diminished_returns_min_improvement_ratio,
+        feasible_then_seconds,
+        any_of,
+        all_of,
+    } = config;
+    for (index, nested) in any_of.iter().enumerate() {
+        validate_termination(nested, &format!("{path}.any_of[{index}]"))?;
+    }
+    for (index, nested) in all_of.iter().enumerate() {
+        validate_termination(nested, &format!("{path}.all_of[{index}]"))?;
+    }
+    match (
+        diminished_returns_window_seconds,
+        diminished_returns_min_improvement_ratio,
+    ) {
+        (Some(0), _) => {
+            return Err(invalid(
+                &format!("{path}.diminished_returns_window_seconds"),
+                "must be >= 1",
+            ));
+        }
+        (Some(_), None) => {
+            return Err(invalid(
+                &format!("{path}.diminished_returns_min_improvement_ratio"),
+                "must be set with diminished_returns_window_seconds",
+            ));
+        }
+        (None, Some(_)) => {
+            return Err(invalid(
+                &format!("{path}.diminished_returns_window_seconds"),
+                "must be set with diminished_returns_min_improvement_ratio",
+            ));
+        }
+        _ => {}
+    }
+    require_non_negative(
+        *diminished_returns_min_improvement_ratio,
+        &format!("{path}.diminished_returns_min_improvement_ratio"),
+    )?;
+    let has_limit = seconds_spent_limit.is_some()
+        || minutes_spent_limit.is_some()
+        || best_score_limit.is_some()
+        || step_count_limit.is_some()
+        || move_count_limit.is_some()
+        || score_calculation_count_limit.is_some()
+        || unimproved_step_count_limit.is_some()
+        || unimproved_seconds_spent_limit.is_some()
+        || unix_deadline_millis.is_some()
+        || diminished_returns_window_seconds.is_some()
+        || feasible_then_seconds.is_some()
+        || !any_of.is_empty()
+        || !all_of.is_empty();
+    if has_limit {
+        Ok(())
+    } else {
+        Err(invalid(path, "must set at least one limit"))
+    }
+}
+
+fn validate_restart(
+    config: &LocalSearchRestartConfig,
+    local_search_type: LocalSearchType,
+    path: &str,
+) -> Result<(), ConfigError> {
+    if local_search_type != LocalSearchType::AcceptorForager {
+        return Err(invalid(
+            path,
+            "is only supported for acceptor_forager local search",
+        ));
+    }
+    if config.max_restarts == 0 {
+        return Err(invalid(&format!("{path}.max_restarts"), "must be >= 1"));
+    }
+    if config.stuck_step_count == Some(0) {
+        return Err(invalid(&format!("{path}.stuck_step_count"), "must be >= 1"));
+    }
+    require_positive(
+        config.perturbation_size,
+        &format!("{path}.perturbation_size"),
+    )
+}
+
+fn validate_acceptor(config: &AcceptorConfig, path: &str) -> Result<(), ConfigError> {
+    match config {
+        AcceptorConfig::HillClimbing => Ok(()),
+        AcceptorConfig::StepCountingHillClimbing(config) => {
+            if config.step_count_limit == Some(0) {
+                return Err(invalid(&format!("{path}.step_count_limit"), "must be >= 1"));
+            }
+            Ok(())
+        }
+        AcceptorConfig::TabuSearch(config) => {
+            require_positive(config.entity_tabu_size, &format!("{path}.entity_tabu_size"))?;
+            require_positive(config.value_tabu_size, &format!("{path}.value_tabu_size"))?;
+            require_positive(config.move_tabu_size, &format!("{path}.move_tabu_size"))?;
+            require_positive(
+                config.undo_move_tabu_size,
+                &format!("{path}.undo_move_tabu_size"),
+            )
+        }
+        AcceptorConfig::SimulatedAnnealing(config) => {
+            if let Some(decay_rate) = config.decay_rate {
+                if !decay_rate.is_finite() || decay_rate <= 0.0 || decay_rate > 1.0 {
+                    return Err(invalid(
+                        &format!("{path}.decay_rate"),
+                        "must be finite and in (0, 1]",
+                    ));
+                }
+            }
+            for (index, temperature) in config.level_temperatures.iter().flatten().enumerate() {
+                require_non_negative(
+                    Some(*temperature),
+                    &format!("{path}.level_temperatures[{index}]"),
+                )?;
+            }
+            require_non_negative(
+                config.ending_temperature,
+                &format!("{path}.ending_temperature"),
+            )?;
+            require_non_negative(
+                config.hill_climbing_temperature,
+                &format!("{path}.hill_climbing_temperature"),
+            )
+        }
+        AcceptorConfig::LateAcceptance(config) => require_positive(
+            config.late_acceptance_size,
+            &format!("{path}.late_acceptance_size"),
+        ),
+        AcceptorConfig::DiversifiedLateAcceptance(config) => {
+            require_positive(
+                config.late_acceptance_size,
+                &format!("{path}.late_acceptance_size"),
+            )?;
+            require_non_negative(config.tolerance, &format!("{path}.tolerance"))
+        }
+        AcceptorConfig::GreatDeluge(config) => {
+            let Some(rate) = config.target_acceptance_rate else {
+                return Ok(());
+            };
+            let path = format!("{path}.target_acceptance_rate");
+            if !config.auto {
+                return Err(invalid(&path, "requires auto = true"));
+            }
+            if !rate.is_finite() || rate <= 0.0 || rate >= 1.0 {
+                return Err(invalid(&path, "must be in (0, 1)"));
+            }
+            Ok(())
+        }
+    }
+}
+
+fn validate_move_selector(config: &MoveSelectorConfig, path: &str) -> Result<(), ConfigError> {
+    let children = match config {
+        MoveSelectorConfig::UnionMoveSelector(config) => &config.selectors,
+        MoveSelectorConfig::CartesianProductMoveSelector(config) => &config.selectors,
+        MoveSelectorConfig::LimitedNeighborhood(config) => {
+            if config.selected_count_limit == 0 {
+                return Err(invalid(
+                    &format!("{path}.selected_count_limit"),
+                    "must be >= 1",
+                ));
+            }
+            return validate_move_selector(&config.selector, &format!("{path}.selector"));
+        }
+        MoveSelectorConfig::KOptMoveSelector(config) => {
+            if !(2..=5).contains(&config.k) {
+                return Err(invalid(&format!("{path}.k"), "must be between 2 and 5"));
+            }
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+    if children.is_empty() {
+        return Err(invalid(&format!("{path}.selectors"), "must not be empty"));
+    }
+    for (index, child) in children.iter().enumerate() {
+        validate_move_selector(child, &format!("{path}.selectors[{index}]"))?;
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 // Banner and version display for the solver console.
 
+use crate::color::strip_ansi;
 use owo_colors::OwoColorize;
 use std::io::{self, Write};
 
@@ -7,7 +8,7 @@ use std::io::{self, Write};
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const EMERALD: (u8, u8, u8) = (16, 185, 129);
 
-pub(crate) fn print_banner() {
+pub(crate) fn print_banner(color: bool) {
     let banner = r#"
  ____        _                 _____
 / ___|  ___ | |_   _____ _ __ |  ___|__  _ __ __ _  ___
@@ -22,18 +23,18 @@ pub(crate) fn print_banner() {
         VERSION
     );
 
-    let mut stdout = io::stdout().lock();
-    let _ = writeln!(
-        stdout,
-        "{}",
-        banner.truecolor(EMERALD.0, EMERALD.1, EMERALD.2)
-    );
-    let _ = writeln!(
-        stdout,
-        "{}",
+    let mut output = format!(
+        "{}\n{}",
+        banner.truecolor(EMERALD.0, EMERALD.1, EMERALD.2),
         version_line
             .truecolor(EMERALD.0, EMERALD.1, EMERALD.2)
             .bold()
     );
+    if !color {
+        output = strip_ansi(&output);
+    }
+
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{}", output);
     let _ = stdout.flush();
 }
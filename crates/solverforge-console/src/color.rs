@@ -0,0 +1,41 @@
+// Color detection and ANSI stripping for plain-text console output.
+
+use std::io::IsTerminal;
+
+// Whether colored output suits stdout: a terminal, with `NO_COLOR` unset or empty.
+pub(crate) fn stdout_supports_color() -> bool {
+    color_allowed(
+        std::io::stdout().is_terminal(),
+        std::env::var_os("NO_COLOR").as_deref(),
+    )
+}
+
+pub(crate) fn color_allowed(is_terminal: bool, no_color: Option<&std::ffi::OsStr>) -> bool {
+    is_terminal && no_color.is_none_or(|value| value.is_empty())
+}
+
+// Removes ANSI escape sequences, leaving the plain text and box-drawing characters.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            plain.push(ch);
+            continue;
+        }
+        if chars.next() != Some('[') {
+            continue;
+        }
+        // CSI sequences end at the first byte in the `@`..=`~` range.
+        for terminator in chars.by_ref() {
+            if ('@'..='~').contains(&terminator) {
+                break;
+            }
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+#[path = "color_tests.rs"]
+mod tests;
@@ -0,0 +1,43 @@
+use super::*;
+use crate::format::format_event;
+use crate::visitor::EventVisitor;
+use std::ffi::OsStr;
+use tracing::Level;
+
+#[test]
+fn color_needs_a_terminal_and_no_no_color() {
+    assert!(color_allowed(true, None));
+    assert!(color_allowed(true, Some(OsStr::new(""))));
+    assert!(!color_allowed(true, Some(OsStr::new("1"))));
+    assert!(!color_allowed(false, None));
+}
+
+#[test]
+fn strip_ansi_removes_escape_sequences_only() {
+    assert_eq!(
+        strip_ansi("\u{1b}[1;32m▶\u{1b}[0m Solving │ \u{1b}[38;2;16;185;129mok\u{1b}[0m"),
+        "▶ Solving │ ok"
+    );
+    assert_eq!(strip_ansi("plain"), "plain");
+}
+
+#[test]
+fn stripped_events_render_as_plain_text() {
+    let visitor = EventVisitor {
+        event: Some("phase_end".to_string()),
+        phase: Some("Local Search".to_string()),
+        steps: Some(1_234),
+        moves_speed: Some(5_000),
+        duration: Some("2s".to_string()),
+        score: Some("0hard/-7soft".to_string()),
+        ..EventVisitor::default()
+    };
+
+    let colored = format_event(&visitor, Level::INFO);
+    let plain = strip_ansi(&colored);
+
+    assert!(colored.contains('\u{1b}'));
+    assert!(!plain.contains('\u{1b}'));
+    assert!(plain.contains("Local Search ended │ 2s │ 1,234 steps │ 5,000 moves/s"));
+    assert!(plain.ends_with("│ 0hard/-7soft"));
+}
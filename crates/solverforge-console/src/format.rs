@@ -458,14 +458,21 @@ fn format_step(v: &EventVisitor, level: Level) -> String {
         "✗".bright_red().to_string()
     };
 
-    format!(
+    let mut output = format!(
         "{} {} Step {:>10} │ Move {:>6} │ {}",
         format_elapsed(),
         icon,
         step.to_formatted_string(&Locale::en).bright_black(),
         move_index.to_formatted_string(&Locale::en).bright_black(),
         format_score(score).bright_black()
-    )
+    );
+    if let Some(temperature) = v.temperature {
+        output.push_str(&format!(
+            " │ T {}",
+            format!("{temperature:.4e}").bright_black()
+        ));
+    }
+    output
 }
 
 fn format_score(score: &str) -> String {
@@ -512,11 +519,20 @@ fn calculate_problem_scale(entity_count: usize, scale_count: usize) -> String {
         return "0".to_string();
     }
 
+    // Work in log space: `scale_count^entity_count` overflows f64 long before
+    // the exponent does, and the exponent itself can exceed i32.
     let log_scale = (entity_count as f64) * (scale_count as f64).log10();
-    let exponent = log_scale.floor() as i32;
-    let mantissa = 10f64.powf(log_scale - exponent as f64);
+    if !log_scale.is_finite() {
+        return "unknown".to_string();
+    }
+    let mut exponent = log_scale.floor();
+    let mut mantissa = (10f64.powf(log_scale - exponent) * 1000.0).round() / 1000.0;
+    if mantissa >= 10.0 {
+        mantissa /= 10.0;
+        exponent += 1.0;
+    }
 
-    format!("{:.3} x 10^{}", mantissa, exponent)
+    format!("{:.3} x 10^{:.0}", mantissa, exponent)
 }
 
 #[cfg(test)]
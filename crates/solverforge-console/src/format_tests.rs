@@ -39,6 +39,22 @@ fn calculate_problem_scale_handles_zero_and_nonzero_inputs() {
     assert_eq!(calculate_problem_scale(10, 100), "1.000 x 10^20");
 }
 
+#[test]
+fn calculate_problem_scale_reports_power_in_scientific_notation() {
+    assert_eq!(calculate_problem_scale(8, 8), "1.678 x 10^7");
+    assert_eq!(calculate_problem_scale(1000, 1), "1.000 x 10^0");
+    assert_eq!(calculate_problem_scale(0, 5), "0");
+}
+
+#[test]
+fn calculate_problem_scale_keeps_exponents_beyond_i32() {
+    assert_eq!(
+        calculate_problem_scale(usize::MAX, 10),
+        format!("1.000 x 10^{:.0}", usize::MAX as f64)
+    );
+    assert_eq!(calculate_problem_scale(3, 10), "1.000 x 10^3");
+}
+
 #[test]
 fn format_elapsed_duration_uses_exact_integer_units() {
     assert_eq!(format_elapsed_duration(Duration::from_millis(750)), "750ms");
@@ -98,6 +114,27 @@ fn format_event_renders_progress_and_trace_steps() {
     assert!(step_output.contains("Move"));
     assert!(step_output.contains("3"));
     assert!(step_output.contains("-1hard"));
+    assert!(!step_output.contains(" T "));
+
+    let outputs = capture_events(|| {
+        tracing::trace!(
+            target: "solverforge_solver::test",
+            event = "step",
+            step = 43u64,
+            move_index = 4u64,
+            score = "-1hard/0soft",
+            accepted = false,
+            temperature = 12.5f64,
+        );
+    });
+
+    let annealing_output = outputs
+        .iter()
+        .find(|output| output.contains("Step"))
+        .cloned()
+        .expect("expected trace step output");
+    assert!(annealing_output.contains("T "));
+    assert!(annealing_output.contains("1.2500e1"));
 }
 
 #[test]
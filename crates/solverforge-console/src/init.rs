@@ -1,10 +1,15 @@
 use std::sync::OnceLock;
 
+use tracing::subscriber::DefaultGuard;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
 use crate::banner;
+use crate::color::stdout_supports_color;
+use crate::layer::{OutputFormat, SolverConsoleWriterLayer};
 use crate::SolverConsoleLayer;
 
 static INIT: OnceLock<()> = OnceLock::new();
@@ -12,10 +17,14 @@ static INIT: OnceLock<()> = OnceLock::new();
 /// Initializes the solver console output.
 ///
 /// Safe to call multiple times - only the first call has effect.
-/// Prints the SolverForge banner and sets up tracing.
+/// Prints the SolverForge banner and sets up tracing. Setting
+/// `SOLVERFORGE_LOG_FORMAT=json` switches to JSON lines and skips the banner.
 pub fn init() {
     INIT.get_or_init(|| {
-        banner::print_banner();
+        let format = output_format_from(std::env::var("SOLVERFORGE_LOG_FORMAT").ok().as_deref());
+        if format == OutputFormat::Pretty {
+            banner::print_banner(stdout_supports_color());
+        }
 
         #[cfg(feature = "verbose-logging")]
         let solver_level = "solverforge_solver=debug";
@@ -43,11 +52,42 @@ pub fn init() {
 
         let _ = tracing_subscriber::registry()
             .with(filter)
-            .with(SolverConsoleLayer)
+            .with(SolverConsoleLayer::with_format(format))
             .try_init();
     });
 }
 
+fn output_format_from(value: Option<&str>) -> OutputFormat {
+    match value.map(str::trim) {
+        Some(value) if value.eq_ignore_ascii_case("json") => OutputFormat::Json,
+        _ => OutputFormat::Pretty,
+    }
+}
+
+/// Installs the console layer for the current thread only, writing to `make_writer`.
+///
+/// Intended for tests: no banner is printed, `RUST_LOG` is ignored, and no
+/// process-wide subscriber is set. Output stops when the returned guard drops,
+/// so each test can capture its own solver output independently.
+pub fn init_for_tests<W>(make_writer: W) -> DefaultGuard
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    #[cfg(feature = "verbose-logging")]
+    let solver_level = tracing::Level::DEBUG;
+    #[cfg(not(feature = "verbose-logging"))]
+    let solver_level = tracing::Level::INFO;
+
+    let filter = Targets::new()
+        .with_target("solverforge_solver", solver_level)
+        .with_target("solverforge_dynamic", tracing::Level::INFO);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(SolverConsoleWriterLayer::new(make_writer))
+        .set_default()
+}
+
 fn rust_log_has_directive_for(rust_log: Option<&str>, target: &str) -> bool {
     rust_log
         .into_iter()
@@ -73,7 +113,76 @@ fn rust_log_has_global_trace(rust_log: Option<&str>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{rust_log_has_directive_for, rust_log_has_global_trace};
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    use super::{
+        init_for_tests, output_format_from, rust_log_has_directive_for, rust_log_has_global_trace,
+    };
+    use crate::layer::OutputFormat;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn emit_phase_start(phase: &str) {
+        tracing::info!(
+            target: "solverforge_solver::test",
+            event = "phase_start",
+            phase = phase,
+            phase_index = 0u64,
+        );
+    }
+
+    #[test]
+    fn test_guards_capture_independent_output() {
+        let first = SharedBuffer::default();
+        let second = SharedBuffer::default();
+
+        {
+            let writer = first.clone();
+            let _guard = init_for_tests(move || writer.clone());
+            emit_phase_start("First Phase");
+        }
+        {
+            let writer = second.clone();
+            let _guard = init_for_tests(move || writer.clone());
+            emit_phase_start("Second Phase");
+        }
+        emit_phase_start("Unguarded Phase");
+
+        let first = first.contents();
+        let second = second.contents();
+        assert!(first.contains("First Phase"));
+        assert!(!first.contains("Second Phase"));
+        assert!(second.contains("Second Phase"));
+        assert!(!second.contains("First Phase"));
+        assert!(!first.contains("Unguarded") && !second.contains("Unguarded"));
+    }
+
+    #[test]
+    fn log_format_env_selects_json_only_when_requested() {
+        assert_eq!(output_format_from(Some("json")), OutputFormat::Json);
+        assert_eq!(output_format_from(Some(" JSON ")), OutputFormat::Json);
+        assert_eq!(output_format_from(Some("pretty")), OutputFormat::Pretty);
+        assert_eq!(output_format_from(None), OutputFormat::Pretty);
+    }
 
     #[test]
     fn unrelated_rust_log_does_not_disable_solver_console_defaults() {
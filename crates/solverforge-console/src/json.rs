@@ -0,0 +1,68 @@
+// JSON-lines formatting for solver console output.
+
+use serde_json::{Map, Value};
+use tracing::Level;
+
+use crate::time::{elapsed, mark_solve_start};
+use crate::visitor::EventVisitor;
+
+/* Renders one solver event as a single-line JSON object.
+
+Every object carries the stable keys `event`, `elapsed_secs`, `score`,
+`steps`, and `speed` (null when the event has no such value); phase events
+add `phase` and `phase_index`, and progress events add `best_score`. Counts
+stay JSON numbers. Returns an empty string for events the pretty formatter
+would also skip.
+*/
+pub(crate) fn format_json_event(v: &EventVisitor, level: Level) -> String {
+    let event = v.event.as_deref().unwrap_or("");
+    match event {
+        "solve_start" => mark_solve_start(),
+        "solve_end" | "phase_start" | "phase_end" | "progress" => {}
+        "step" if level == Level::TRACE => {}
+        _ => return String::new(),
+    }
+
+    let mut object = Map::new();
+    object.insert("event".to_string(), Value::from(event));
+    object.insert(
+        "elapsed_secs".to_string(),
+        Value::from(elapsed().as_secs_f64()),
+    );
+    object.insert(
+        "score".to_string(),
+        optional(v.score.as_deref().or(v.current_score.as_deref())),
+    );
+    object.insert("steps".to_string(), optional(v.steps.or(v.step)));
+    object.insert(
+        "speed".to_string(),
+        optional(v.speed.or(v.moves_speed).map(saturating_u64)),
+    );
+
+    if let Some(phase) = v.phase.as_deref() {
+        object.insert("phase".to_string(), Value::from(phase));
+    }
+    if let Some(phase_index) = v.phase_index {
+        object.insert("phase_index".to_string(), Value::from(phase_index));
+    }
+    if let Some(best_score) = v.best_score.as_deref() {
+        object.insert("best_score".to_string(), Value::from(best_score));
+    }
+    if let Some(feasible) = v.feasible {
+        object.insert("feasible".to_string(), Value::from(feasible));
+    }
+
+    Value::Object(object).to_string()
+}
+
+fn optional<T: Into<Value>>(value: Option<T>) -> Value {
+    value.map_or(Value::Null, Into::into)
+}
+
+fn saturating_u64(value: u128) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+#[path = "json_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::{Layer, Registry};
+
+#[derive(Clone)]
+struct CaptureLayer {
+    outputs: Arc<Mutex<Vec<String>>>,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+
+        let output = format_json_event(&visitor, *event.metadata().level());
+        if !output.is_empty() {
+            self.outputs.lock().unwrap().push(output);
+        }
+    }
+}
+
+fn capture_json(f: impl FnOnce()) -> Vec<Value> {
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = Registry::default().with(CaptureLayer {
+        outputs: outputs.clone(),
+    });
+
+    tracing::subscriber::with_default(subscriber, f);
+    let captured = outputs.lock().unwrap().clone();
+    captured
+        .iter()
+        .map(|line| {
+            assert!(!line.contains('\n'), "JSON output must be one line: {line}");
+            serde_json::from_str(line).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn progress_event_serializes_numbers_as_numbers() {
+    let lines = capture_json(|| {
+        tracing::debug!(
+            event = "progress",
+            phase = "Local Search",
+            steps = 12_345u64,
+            speed = 1_234_567u64,
+            current_score = "0hard/-5soft",
+            best_score = "0hard/-3soft",
+        );
+    });
+
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert_eq!(line["event"], "progress");
+    assert_eq!(line["steps"], 12_345);
+    assert_eq!(line["speed"], 1_234_567);
+    assert_eq!(line["score"], "0hard/-5soft");
+    assert_eq!(line["best_score"], "0hard/-3soft");
+    assert_eq!(line["phase"], "Local Search");
+    assert!(line["elapsed_secs"].is_f64());
+}
+
+#[test]
+fn stable_keys_are_present_even_without_values() {
+    let lines = capture_json(|| {
+        tracing::info!(event = "phase_start", phase = "Construction Heuristic");
+    });
+
+    let line = lines[0].as_object().unwrap();
+    for key in ["event", "elapsed_secs", "score", "steps", "speed"] {
+        assert!(line.contains_key(key), "missing `{key}`");
+    }
+    assert!(line["score"].is_null());
+    assert!(line["steps"].is_null());
+}
+
+#[test]
+fn phase_end_uses_move_speed_and_final_score() {
+    let lines = capture_json(|| {
+        tracing::info!(
+            event = "phase_end",
+            phase = "Local Search",
+            phase_index = 1u64,
+            steps = 40u64,
+            moves_speed = 900u64,
+            score = "-2",
+        );
+    });
+
+    let line = &lines[0];
+    assert_eq!(line["speed"], 900);
+    assert_eq!(line["score"], "-2");
+    assert_eq!(line["phase_index"], 1);
+}
+
+#[test]
+fn step_events_need_trace_level_and_unknown_events_are_skipped() {
+    let lines = capture_json(|| {
+        tracing::debug!(event = "step", step = 1u64, score = "-1");
+        tracing::info!(event = "unrelated");
+        tracing::trace!(event = "step", step = 2u64, score = "-1", accepted = true);
+    });
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["steps"], 2);
+}
@@ -1,36 +1,156 @@
 // Tracing layer that routes solver events to the console formatter.
 
+use crate::color::{stdout_supports_color, strip_ansi};
 use crate::format::format_event;
+use crate::json::format_json_event;
+use crate::throttle::ProgressThrottle;
 use crate::visitor::EventVisitor;
 use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use tracing::Level;
 use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::Context;
 use tracing_subscriber::Layer;
 
-/// A tracing layer that formats solver events with colors.
-pub struct SolverConsoleLayer;
+// Rendering style for solver events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+/// A tracing layer that formats solver events for stdout.
+///
+/// [`SolverConsoleLayer::new`] renders colored text; [`SolverConsoleLayer::json`]
+/// writes one JSON object per event instead, for log aggregators.
+///
+/// Colors are used only when stdout is a terminal and `NO_COLOR` is unset or
+/// empty; [`SolverConsoleLayer::with_color`] overrides that detection.
+#[derive(Debug, Clone)]
+pub struct SolverConsoleLayer {
+    format: OutputFormat,
+    color: bool,
+    progress_throttle: Option<ProgressThrottle>,
+}
+
+impl Default for SolverConsoleLayer {
+    fn default() -> Self {
+        Self::with_format(OutputFormat::Pretty)
+    }
+}
+
+impl SolverConsoleLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a layer that writes each event as a single JSON line.
+    ///
+    /// Objects carry the stable keys `event`, `elapsed_secs`, `score`,
+    /// `steps`, and `speed`, with numbers left unformatted.
+    pub fn json() -> Self {
+        Self::with_format(OutputFormat::Json)
+    }
+
+    /// Forces colored (`true`) or plain (`false`) pretty output.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Drops `progress` events that arrive less than `interval` after the
+    /// last printed one.
+    ///
+    /// The solve and phase lifecycle events (`solve_start`, `phase_start`,
+    /// `phase_end`, `solve_end`) always pass and restart the interval; other
+    /// events such as TRACE `step` pass without restarting it. Scores carried
+    /// by dropped events are kept so the next printed progress line is not
+    /// missing them.
+    pub fn with_progress_interval(mut self, interval: Duration) -> Self {
+        self.progress_throttle = Some(ProgressThrottle::new(interval));
+        self
+    }
+
+    pub(crate) fn with_format(format: OutputFormat) -> Self {
+        Self {
+            format,
+            color: stdout_supports_color(),
+            progress_throttle: None,
+        }
+    }
+}
 
 impl<S: Subscriber> Layer<S> for SolverConsoleLayer {
     fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
-        let metadata = event.metadata();
-        let target = metadata.target();
-
-        // Accept events from solver modules
-        if !target.starts_with("solverforge_solver")
-            && !target.starts_with("solverforge_dynamic")
-            && !target.starts_with("solverforge_py")
-            && !target.starts_with("solverforge::")
-        {
+        let Some((mut visitor, level)) = collect_event(event) else {
             return;
+        };
+        if let Some(throttle) = &self.progress_throttle {
+            if !throttle.admit(&mut visitor, Instant::now()) {
+                return;
+            }
         }
+        if let Some(output) = render_visitor(&visitor, level, self.format) {
+            let output = if self.color {
+                output
+            } else {
+                strip_ansi(&output)
+            };
+            let _ = writeln!(io::stdout(), "{}", output);
+        }
+    }
+}
 
-        let mut visitor = EventVisitor::default();
-        event.record(&mut visitor);
+// Console layer that writes formatted events to a caller-supplied writer.
+pub(crate) struct SolverConsoleWriterLayer<W> {
+    make_writer: W,
+}
 
-        let level = *metadata.level();
-        let output = format_event(&visitor, level);
-        if !output.is_empty() {
-            let _ = writeln!(io::stdout(), "{}", output);
+impl<W> SolverConsoleWriterLayer<W> {
+    pub(crate) fn new(make_writer: W) -> Self {
+        Self { make_writer }
+    }
+}
+
+impl<S, W> Layer<S> for SolverConsoleWriterLayer<W>
+where
+    S: Subscriber,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some((visitor, level)) = collect_event(event) else {
+            return;
+        };
+        if let Some(output) = render_visitor(&visitor, level, OutputFormat::Pretty) {
+            let _ = writeln!(self.make_writer.make_writer(), "{}", output);
         }
     }
 }
+
+fn collect_event(event: &Event<'_>) -> Option<(EventVisitor, Level)> {
+    let metadata = event.metadata();
+    let target = metadata.target();
+
+    // Accept events from solver modules
+    if !target.starts_with("solverforge_solver")
+        && !target.starts_with("solverforge_dynamic")
+        && !target.starts_with("solverforge_py")
+        && !target.starts_with("solverforge::")
+    {
+        return None;
+    }
+
+    let mut visitor = EventVisitor::default();
+    event.record(&mut visitor);
+    Some((visitor, *metadata.level()))
+}
+
+fn render_visitor(visitor: &EventVisitor, level: Level, format: OutputFormat) -> Option<String> {
+    let output = match format {
+        OutputFormat::Pretty => format_event(visitor, level),
+        OutputFormat::Json => format_json_event(visitor, level),
+    };
+    (!output.is_empty()).then_some(output)
+}
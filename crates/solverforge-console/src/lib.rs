@@ -1,20 +1,25 @@
 /* Colorful console output for solver metrics.
 
-Provides a custom `tracing` layer that formats solver events with colors.
+Provides a custom `tracing` layer that formats solver events with colors,
+or as one JSON object per line when `SOLVERFORGE_LOG_FORMAT=json`.
 
 ## Log Levels
 
 - **INFO**: Lifecycle events (solving/phase start/end)
-- **DEBUG**: Progress updates (1/sec with speed and score)
+- **DEBUG**: Progress updates with speed and score, at the cadence the solver
+  emits them; `SolverConsoleLayer::with_progress_interval` debounces them
 - **TRACE**: Individual step evaluations
 */
 
 mod banner;
+mod color;
 mod format;
 mod init;
+mod json;
 mod layer;
+mod throttle;
 mod time;
 mod visitor;
 
-pub use init::init;
+pub use init::{init, init_for_tests};
 pub use layer::SolverConsoleLayer;
@@ -0,0 +1,80 @@
+// Layer-side debouncing of progress events.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::visitor::EventVisitor;
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    last_emitted: Option<Instant>,
+    dropped_current_score: Option<String>,
+    dropped_best_score: Option<String>,
+}
+
+/* Drops `progress` events that arrive within `interval` of the last emitted
+one. Solve and phase lifecycle events pass and restart the window, so each
+phase shows its first progress line; any other event, such as a TRACE `step`,
+passes without touching the window. Scores from dropped events fill in
+whatever the next emitted progress line is missing.
+*/
+#[derive(Debug)]
+pub(crate) struct ProgressThrottle {
+    interval: Duration,
+    state: Mutex<ThrottleState>,
+}
+
+impl ProgressThrottle {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            state: Mutex::new(ThrottleState::default()),
+        }
+    }
+
+    pub(crate) fn admit(&self, visitor: &mut EventVisitor, now: Instant) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match visitor.event.as_deref() {
+            Some("progress") => {}
+            Some("solve_start" | "phase_start" | "phase_end" | "solve_end") => {
+                *state = ThrottleState::default();
+                return true;
+            }
+            _ => return true,
+        }
+
+        let due = state
+            .last_emitted
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.interval);
+        if !due {
+            if visitor.current_score.is_some() {
+                state.dropped_current_score = visitor.current_score.take();
+            }
+            if visitor.best_score.is_some() {
+                state.dropped_best_score = visitor.best_score.take();
+            }
+            return false;
+        }
+
+        if visitor.current_score.is_none() {
+            visitor.current_score = state.dropped_current_score.take();
+        }
+        if visitor.best_score.is_none() {
+            visitor.best_score = state.dropped_best_score.take();
+        }
+        state.dropped_current_score = None;
+        state.dropped_best_score = None;
+        state.last_emitted = Some(now);
+        true
+    }
+}
+
+impl Clone for ProgressThrottle {
+    fn clone(&self) -> Self {
+        Self::new(self.interval)
+    }
+}
+
+#[cfg(test)]
+#[path = "throttle_tests.rs"]
+mod tests;
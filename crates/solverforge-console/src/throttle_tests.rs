@@ -0,0 +1,86 @@
+use super::*;
+
+fn progress(current_score: Option<&str>) -> EventVisitor {
+    EventVisitor {
+        event: Some("progress".to_string()),
+        current_score: current_score.map(str::to_string),
+        ..EventVisitor::default()
+    }
+}
+
+fn event(name: &str) -> EventVisitor {
+    EventVisitor {
+        event: Some(name.to_string()),
+        ..EventVisitor::default()
+    }
+}
+
+#[test]
+fn progress_inside_the_interval_is_dropped() {
+    let throttle = ProgressThrottle::new(Duration::from_secs(1));
+    let start = Instant::now();
+
+    assert!(throttle.admit(&mut progress(Some("-5")), start));
+    assert!(!throttle.admit(&mut progress(Some("-4")), start + Duration::from_millis(10)));
+    assert!(!throttle.admit(
+        &mut progress(Some("-3")),
+        start + Duration::from_millis(999)
+    ));
+    assert!(throttle.admit(&mut progress(Some("-2")), start + Duration::from_secs(1)));
+}
+
+#[test]
+fn lifecycle_events_always_pass_and_reopen_the_window() {
+    let throttle = ProgressThrottle::new(Duration::from_secs(60));
+    let start = Instant::now();
+
+    assert!(throttle.admit(&mut progress(Some("-5")), start));
+    for name in ["phase_end", "phase_start", "solve_end", "solve_start"] {
+        assert!(throttle.admit(&mut event(name), start));
+    }
+    assert!(throttle.admit(&mut progress(Some("-1")), start));
+}
+
+#[test]
+fn other_events_pass_without_reopening_the_window() {
+    let throttle = ProgressThrottle::new(Duration::from_secs(1));
+    let start = Instant::now();
+
+    assert!(throttle.admit(&mut progress(Some("-5")), start));
+    assert!(throttle.admit(&mut event("step"), start + Duration::from_millis(100)));
+    assert!(!throttle.admit(
+        &mut progress(Some("-4")),
+        start + Duration::from_millis(200)
+    ));
+    assert!(throttle.admit(
+        &mut event("partition_merge_conflict"),
+        start + Duration::from_millis(300)
+    ));
+    assert!(throttle.admit(&mut event("step"), start + Duration::from_millis(400)));
+
+    let mut next = progress(None);
+    assert!(!throttle.admit(&mut next, start + Duration::from_millis(500)));
+    assert!(throttle.admit(&mut next, start + Duration::from_secs(1)));
+    assert_eq!(next.current_score.as_deref(), Some("-4"));
+}
+
+#[test]
+fn next_emitted_line_inherits_missing_scores_from_dropped_events() {
+    let throttle = ProgressThrottle::new(Duration::from_secs(1));
+    let start = Instant::now();
+    assert!(throttle.admit(&mut progress(Some("-5")), start));
+
+    let mut dropped = progress(Some("-3"));
+    dropped.best_score = Some("-2".to_string());
+    assert!(!throttle.admit(&mut dropped, start + Duration::from_millis(500)));
+
+    let mut next = progress(None);
+    assert!(throttle.admit(&mut next, start + Duration::from_secs(2)));
+    assert_eq!(next.current_score.as_deref(), Some("-3"));
+    assert_eq!(next.best_score.as_deref(), Some("-2"));
+
+    let mut own_score = progress(Some("-1"));
+    assert!(throttle.admit(&mut own_score, start + Duration::from_secs(4)));
+    assert_eq!(own_score.current_score.as_deref(), Some("-1"));
+    assert_eq!(own_score.best_score, None);
+}
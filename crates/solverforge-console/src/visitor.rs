@@ -16,6 +16,7 @@ pub(crate) struct EventVisitor {
     pub(crate) step: Option<u64>,
     pub(crate) move_index: Option<u64>,
     pub(crate) accepted: Option<bool>,
+    pub(crate) temperature: Option<f64>,
     pub(crate) duration: Option<String>,
     pub(crate) entity_count: Option<u64>,
     pub(crate) scale_count: Option<u64>,
@@ -97,6 +98,12 @@ impl Visit for EventVisitor {
         }
     }
 
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "temperature" {
+            self.temperature = Some(value);
+        }
+    }
+
     fn record_bool(&mut self, field: &Field, value: bool) {
         match field.name() {
             "accepted" => self.accepted = Some(value),
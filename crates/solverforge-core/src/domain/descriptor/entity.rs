@@ -5,6 +5,7 @@ use std::fmt;
 
 use super::{EntityClassId, VariableDescriptor};
 use crate::domain::entity_ref::{EntityExtractor, EntityRef};
+use crate::domain::PlanningEntity;
 
 /// Describes a planning entity type at runtime.
 pub struct EntityDescriptor {
@@ -26,6 +27,12 @@ pub struct EntityDescriptor {
     pub id_field: Option<&'static str>,
     // The pinning field name, if any.
     pub pin_field: Option<&'static str>,
+    // Reads pin state from one type-erased entity, if pinning is declared.
+    pub pinned: Option<fn(&dyn Any) -> bool>,
+}
+
+fn entity_is_pinned<E: PlanningEntity>(entity: &dyn Any) -> bool {
+    entity.downcast_ref::<E>().is_some_and(E::is_pinned)
 }
 
 impl EntityDescriptor {
@@ -40,6 +47,7 @@ impl EntityDescriptor {
             extractor: None,
             id_field: None,
             pin_field: None,
+            pinned: None,
         }
     }
 
@@ -68,6 +76,33 @@ impl EntityDescriptor {
         self
     }
 
+    /// Declares `field` as the pin field and reads pin state through
+    /// [`PlanningEntity::is_pinned`] on `E`.
+    pub fn with_pinning<E: PlanningEntity>(mut self, field: &'static str) -> Self {
+        self.pin_field = Some(field);
+        self.pinned = Some(entity_is_pinned::<E>);
+        self
+    }
+
+    pub fn has_pinning(&self) -> bool {
+        self.pinned.is_some()
+    }
+
+    /// Returns true if the entity at `index` is pinned.
+    ///
+    /// Entities of a type without declared pinning are never pinned.
+    pub fn is_pinned(&self, solution: &dyn Any, index: usize) -> bool {
+        let Some(pinned) = self.pinned else {
+            return false;
+        };
+        self.get_entity(solution, index).is_some_and(pinned)
+    }
+
+    /// All variable descriptors in declaration order, genuine and shadow.
+    pub fn variables(&self) -> &[VariableDescriptor] {
+        &self.variable_descriptors
+    }
+
     pub fn genuine_variable_descriptors(&self) -> impl Iterator<Item = &VariableDescriptor> {
         self.variable_descriptors
             .iter()
@@ -158,6 +193,7 @@ impl Clone for EntityDescriptor {
             extractor: self.extractor.clone(),
             id_field: self.id_field,
             pin_field: self.pin_field,
+            pinned: self.pinned,
         }
     }
 }
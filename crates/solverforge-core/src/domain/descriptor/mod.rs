@@ -17,7 +17,7 @@ pub use problem_fact::ProblemFactDescriptor;
 pub use solution::SolutionDescriptor;
 pub use var_descriptor::{
     UsizeCandidateValues, UsizeConstructionEntityOrderKey, UsizeConstructionValueOrderKey,
-    UsizeEntityValueProvider, UsizeGetter, UsizeNearbyEntityDistanceMeter,
+    UsizeEntityValueProvider, UsizeGetter, UsizeListGetter, UsizeNearbyEntityDistanceMeter,
     UsizeNearbyValueDistanceMeter, UsizeSetter, VariableDescriptor,
 };
 
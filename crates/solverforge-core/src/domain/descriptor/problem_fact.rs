@@ -1,7 +1,9 @@
 // Problem fact descriptor.
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 
 use super::ProblemFactClassId;
 use crate::domain::entity_ref::EntityExtractor;
@@ -20,6 +22,10 @@ pub struct ProblemFactDescriptor {
     pub is_collection: bool,
     // The ID field name, if any (for value range provider lookups).
     pub id_field: Option<&'static str>,
+    // The secondary natural-key field name, if any (for lookups by that key).
+    pub lookup_key_field: Option<&'static str>,
+    // Borrows a fact's lookup key field as `&dyn Any`.
+    pub lookup_key: Option<fn(&dyn Any) -> Option<&dyn Any>>,
     // Extractor for getting facts from a solution.
     pub extractor: Option<Box<dyn EntityExtractor>>,
 }
@@ -33,6 +39,8 @@ impl ProblemFactDescriptor {
             solution_field,
             is_collection: true,
             id_field: None,
+            lookup_key_field: None,
+            lookup_key: None,
             extractor: None,
         }
     }
@@ -56,6 +64,44 @@ impl ProblemFactDescriptor {
         self.id_field = Some(field);
         self
     }
+
+    /// Declares `field` as the `#[lookup_key]` field and reads its value
+    /// through `key`, which borrows that field from a fact.
+    pub fn with_lookup_key(
+        mut self,
+        field: &'static str,
+        key: fn(&dyn Any) -> Option<&dyn Any>,
+    ) -> Self {
+        self.lookup_key_field = Some(field);
+        self.lookup_key = Some(key);
+        self
+    }
+
+    /* Builds a map from each fact's `#[lookup_key]` value to its index.
+
+    Build it once when the solution is loaded and resolve keys against the
+    map. `K` must be the lookup field's exact type; when two facts share a key
+    the first one wins. Returns `None` when the descriptor has no lookup key or
+    extractor, when `solution` is not the extractor's solution type, or when a
+    fact's key is not a `K`.
+    */
+    pub fn lookup_key_index<K>(&self, solution: &dyn Any) -> Option<HashMap<K, usize>>
+    where
+        K: Eq + Hash + Clone + 'static,
+    {
+        let key = self.lookup_key?;
+        let extractor = self.extractor.as_ref()?;
+        let count = extractor.count(solution)?;
+        let mut index = HashMap::with_capacity(count);
+        for fact_index in 0..count {
+            let fact_key = extractor
+                .get(solution, fact_index)
+                .and_then(key)?
+                .downcast_ref::<K>()?;
+            index.entry(fact_key.clone()).or_insert(fact_index);
+        }
+        Some(index)
+    }
 }
 
 impl Clone for ProblemFactDescriptor {
@@ -67,6 +113,8 @@ impl Clone for ProblemFactDescriptor {
             solution_field: self.solution_field,
             is_collection: self.is_collection,
             id_field: self.id_field,
+            lookup_key_field: self.lookup_key_field,
+            lookup_key: self.lookup_key,
             extractor: self.extractor.clone(),
         }
     }
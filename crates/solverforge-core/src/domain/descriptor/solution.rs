@@ -3,9 +3,11 @@
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 use super::{EntityClassId, EntityDescriptor, ProblemFactDescriptor, VariableDescriptor};
 use crate::domain::entity_ref::EntityRef;
+use crate::domain::variable::VariableType;
 
 /// Describes a planning solution at runtime.
 ///
@@ -164,6 +166,19 @@ impl SolutionDescriptor {
             .get_entity_mut(solution, entity_index)
     }
 
+    /// Returns true if the entity at `entity_index` of descriptor
+    /// `descriptor_index` is pinned and must not be changed by the solver.
+    pub fn is_entity_pinned(
+        &self,
+        solution: &dyn Any,
+        descriptor_index: usize,
+        entity_index: usize,
+    ) -> bool {
+        self.entity_descriptors
+            .get(descriptor_index)
+            .is_some_and(|desc| desc.is_pinned(solution, entity_index))
+    }
+
     pub fn entity_descriptor_count(&self) -> usize {
         self.entity_descriptors.len()
     }
@@ -175,6 +190,53 @@ impl SolutionDescriptor {
     pub fn all_extractors_configured(&self) -> bool {
         self.entity_descriptors.iter().all(|d| d.has_extractor())
     }
+
+    /// Returns a checksum of every planning variable value in the solution.
+    ///
+    /// Entities are visited in descriptor order and variables in declaration
+    /// order. Scalar variables contribute through their usize getter and list
+    /// variables through their element getter, including element order.
+    /// Shadow variables, problem facts, and scores never contribute.
+    ///
+    /// Returns `None` when the checksum could not cover the whole planning
+    /// state: an entity collection without an extractor, a scalar variable
+    /// without a usize getter, or a list variable without a list getter.
+    ///
+    /// The value comes from `DefaultHasher`, whose algorithm may change between
+    /// Rust releases. Compare checksums only within one process, such as before
+    /// and after a trial move; never persist them or compare them across builds.
+    pub fn state_checksum<S: 'static>(&self, solution: &S) -> Option<u64> {
+        let covered = self.entity_descriptors.iter().all(|descriptor| {
+            descriptor.has_extractor()
+                && descriptor.genuine_variable_descriptors().all(|variable| {
+                    match variable.variable_type {
+                        VariableType::List => variable.list_getter.is_some(),
+                        _ => variable.usize_getter.is_some(),
+                    }
+                })
+        });
+        if !covered {
+            return None;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        self.for_each_entity(solution, |descriptor_index, entity_index, entity| {
+            let variables =
+                self.entity_descriptors[descriptor_index].genuine_variable_descriptors();
+            for (variable_index, variable) in variables.enumerate() {
+                (descriptor_index, entity_index, variable_index).hash(&mut hasher);
+                match variable.variable_type {
+                    VariableType::List => variable
+                        .list_getter
+                        .map(|get| get(entity).hash(&mut hasher)),
+                    _ => variable
+                        .usize_getter
+                        .map(|get| get(entity).hash(&mut hasher)),
+                };
+            }
+        });
+        Some(hasher.finish())
+    }
 }
 
 impl Clone for SolutionDescriptor {
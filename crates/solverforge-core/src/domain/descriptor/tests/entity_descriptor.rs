@@ -154,3 +154,70 @@ fn test_no_extractor() {
     assert!(descriptor.get_entity(&solution as &dyn Any, 0).is_none());
     assert!(descriptor.entity_refs(&solution as &dyn Any).is_empty());
 }
+
+#[derive(Clone, Debug)]
+struct PinnableEntity {
+    pinned: bool,
+}
+
+impl crate::domain::PlanningEntity for PinnableEntity {
+    fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PinnableSolution {
+    entities: Vec<PinnableEntity>,
+}
+
+#[test]
+fn test_is_pinned_reads_entity_pin_state() {
+    let extractor = Box::new(EntityCollectionExtractor::new(
+        "PinnableEntity",
+        "entities",
+        |s: &PinnableSolution| &s.entities,
+        |s: &mut PinnableSolution| &mut s.entities,
+    ));
+    let descriptor =
+        EntityDescriptor::new("PinnableEntity", TypeId::of::<PinnableEntity>(), "entities")
+            .with_extractor(extractor)
+            .with_pinning::<PinnableEntity>("pinned");
+    let solution = PinnableSolution {
+        entities: vec![
+            PinnableEntity { pinned: false },
+            PinnableEntity { pinned: true },
+        ],
+    };
+
+    assert!(descriptor.has_pinning());
+    assert_eq!(descriptor.pin_field, Some("pinned"));
+    assert!(!descriptor.is_pinned(&solution, 0));
+    assert!(descriptor.is_pinned(&solution, 1));
+    assert!(!descriptor.is_pinned(&solution, 2));
+
+    let solution_descriptor =
+        SolutionDescriptor::new("PinnableSolution", TypeId::of::<PinnableSolution>())
+            .with_entity(descriptor);
+    assert!(solution_descriptor.is_entity_pinned(&solution, 0, 1));
+    assert!(!solution_descriptor.is_entity_pinned(&solution, 1, 1));
+}
+
+#[test]
+fn test_is_pinned_without_pinning_is_false() {
+    let descriptor = create_test_entity_descriptor();
+    let solution = TestSolution {
+        entities: vec![TestEntity { id: 1, row: None }],
+    };
+
+    assert!(!descriptor.has_pinning());
+    assert!(!descriptor.is_pinned(&solution, 0));
+}
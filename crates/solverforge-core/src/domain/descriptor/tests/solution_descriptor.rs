@@ -209,3 +209,116 @@ fn logical_entity_ids_resolve_to_descriptor_indexes() {
         Some(1)
     );
 }
+
+#[derive(Clone, Debug)]
+struct Route {
+    depot: Option<usize>,
+    visits: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct RoutePlan {
+    routes: Vec<Route>,
+}
+
+fn get_routes(s: &RoutePlan) -> &Vec<Route> {
+    &s.routes
+}
+
+fn get_routes_mut(s: &mut RoutePlan) -> &mut Vec<Route> {
+    &mut s.routes
+}
+
+fn get_depot(entity: &dyn Any) -> Option<usize> {
+    entity.downcast_ref::<Route>().unwrap().depot
+}
+
+fn set_depot(entity: &mut dyn Any, value: Option<usize>) {
+    entity.downcast_mut::<Route>().unwrap().depot = value;
+}
+
+fn get_visits(entity: &dyn Any) -> &[usize] {
+    &entity.downcast_ref::<Route>().unwrap().visits
+}
+
+fn route_plan_descriptor() -> SolutionDescriptor {
+    let extractor = Box::new(EntityCollectionExtractor::new(
+        "Route",
+        "routes",
+        get_routes,
+        get_routes_mut,
+    ));
+    let route = EntityDescriptor::new("Route", TypeId::of::<Route>(), "routes")
+        .with_extractor(extractor)
+        .with_variable(
+            VariableDescriptor::genuine("depot").with_usize_accessors(get_depot, set_depot),
+        )
+        .with_variable(VariableDescriptor::list("visits").with_list_getter(get_visits));
+    SolutionDescriptor::new("RoutePlan", TypeId::of::<RoutePlan>()).with_entity(route)
+}
+
+fn route_plan() -> RoutePlan {
+    RoutePlan {
+        routes: vec![
+            Route {
+                depot: Some(0),
+                visits: vec![1, 2],
+            },
+            Route {
+                depot: None,
+                visits: vec![3],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_state_checksum_is_stable_across_clones() {
+    let descriptor = route_plan_descriptor();
+    let plan = route_plan();
+
+    assert!(descriptor.state_checksum(&plan).is_some());
+    assert_eq!(
+        descriptor.state_checksum(&plan),
+        descriptor.state_checksum(&plan.clone())
+    );
+}
+
+#[test]
+fn test_state_checksum_changes_when_any_variable_changes() {
+    let descriptor = route_plan_descriptor();
+    let baseline = descriptor.state_checksum(&route_plan()).unwrap();
+
+    let mut depot_changed = route_plan();
+    depot_changed.routes[1].depot = Some(0);
+    assert_ne!(descriptor.state_checksum(&depot_changed), Some(baseline));
+
+    let mut visit_moved = route_plan();
+    let visit = visit_moved.routes[0].visits.pop().unwrap();
+    visit_moved.routes[1].visits.push(visit);
+    assert_ne!(descriptor.state_checksum(&visit_moved), Some(baseline));
+
+    let mut visits_reordered = route_plan();
+    visits_reordered.routes[0].visits.reverse();
+    assert_ne!(descriptor.state_checksum(&visits_reordered), Some(baseline));
+}
+
+#[test]
+fn test_state_checksum_refuses_uncovered_variables() {
+    let extractor = Box::new(EntityCollectionExtractor::new(
+        "Route",
+        "routes",
+        get_routes,
+        get_routes_mut,
+    ));
+    let route = EntityDescriptor::new("Route", TypeId::of::<Route>(), "routes")
+        .with_extractor(extractor)
+        .with_variable(
+            VariableDescriptor::genuine("depot").with_usize_accessors(get_depot, set_depot),
+        )
+        .with_variable(VariableDescriptor::list("visits"));
+    let descriptor =
+        SolutionDescriptor::new("RoutePlan", TypeId::of::<RoutePlan>()).with_entity(route);
+
+    assert_eq!(descriptor.state_checksum(&route_plan()), None);
+}
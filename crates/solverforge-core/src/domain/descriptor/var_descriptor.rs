@@ -7,6 +7,7 @@ use crate::domain::variable::{ShadowVariableKind, ValueRangeType, VariableType};
 
 pub type UsizeGetter = for<'a> fn(&'a dyn Any) -> Option<usize>;
 pub type UsizeSetter = fn(&mut dyn Any, Option<usize>);
+pub type UsizeListGetter = for<'a> fn(&'a dyn Any) -> &'a [usize];
 pub type UsizeEntityValueProvider = for<'a> fn(&'a dyn Any) -> Vec<usize>;
 pub type UsizeCandidateValues = for<'a> fn(&'a dyn Any, usize, usize) -> &'a [usize];
 pub type UsizeNearbyValueDistanceMeter = fn(&dyn Any, usize, usize) -> f64;
@@ -36,6 +37,7 @@ pub struct VariableDescriptor {
     // Dynamic accessors for canonical scalar-variable solving.
     pub usize_getter: Option<UsizeGetter>,
     pub usize_setter: Option<UsizeSetter>,
+    pub list_getter: Option<UsizeListGetter>,
     pub entity_value_provider: Option<UsizeEntityValueProvider>,
     pub candidate_values: Option<UsizeCandidateValues>,
     pub nearby_value_candidates: Option<UsizeCandidateValues>,
@@ -59,6 +61,7 @@ impl VariableDescriptor {
             source_entity: None,
             usize_getter: None,
             usize_setter: None,
+            list_getter: None,
             entity_value_provider: None,
             candidate_values: None,
             nearby_value_candidates: None,
@@ -82,6 +85,7 @@ impl VariableDescriptor {
             source_entity: None,
             usize_getter: None,
             usize_setter: None,
+            list_getter: None,
             entity_value_provider: None,
             candidate_values: None,
             nearby_value_candidates: None,
@@ -105,6 +109,7 @@ impl VariableDescriptor {
             source_entity: None,
             usize_getter: None,
             usize_setter: None,
+            list_getter: None,
             entity_value_provider: None,
             candidate_values: None,
             nearby_value_candidates: None,
@@ -147,6 +152,7 @@ impl VariableDescriptor {
             source_entity: None,
             usize_getter: None,
             usize_setter: None,
+            list_getter: None,
             entity_value_provider: None,
             candidate_values: None,
             nearby_value_candidates: None,
@@ -175,6 +181,11 @@ impl VariableDescriptor {
         self
     }
 
+    pub fn with_list_getter(mut self, getter: UsizeListGetter) -> Self {
+        self.list_getter = Some(getter);
+        self
+    }
+
     pub fn with_entity_value_provider(mut self, provider: UsizeEntityValueProvider) -> Self {
         self.entity_value_provider = Some(provider);
         self
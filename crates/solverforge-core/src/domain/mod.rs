@@ -22,7 +22,7 @@ mod tests;
 pub use descriptor::{
     EntityClassId, EntityDescriptor, ProblemFactClassId, ProblemFactDescriptor, SolutionDescriptor,
     UsizeCandidateValues, UsizeConstructionEntityOrderKey, UsizeConstructionValueOrderKey,
-    UsizeEntityValueProvider, UsizeGetter, UsizeNearbyEntityDistanceMeter,
+    UsizeEntityValueProvider, UsizeGetter, UsizeListGetter, UsizeNearbyEntityDistanceMeter,
     UsizeNearbyValueDistanceMeter, UsizeSetter, VariableDescriptor, VariableId,
 };
 pub use dynamic::{
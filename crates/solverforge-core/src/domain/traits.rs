@@ -2,6 +2,7 @@
 
 use std::any::Any;
 use std::hash::Hash;
+use std::ops::Range;
 
 use crate::score::Score;
 
@@ -62,6 +63,21 @@ pub trait PlanningSolution: Clone + Send + Sync + 'static {
     */
     fn update_entity_shadows(&mut self, _descriptor_index: usize, _entity_index: usize) {}
 
+    /* Updates shadow variables after a list variable changed in known windows.
+
+    Each range holds final list positions of inserted elements; an empty range
+    marks the gap left by a removal. Only those positions and their immediate
+    neighbors need fresh list links. The default refreshes the whole entity.
+    */
+    fn update_list_shadows(
+        &mut self,
+        descriptor_index: usize,
+        entity_index: usize,
+        _changed: &[Range<usize>],
+    ) {
+        self.update_entity_shadows(descriptor_index, entity_index);
+    }
+
     /* Updates all shadow variables for the full solution.
 
     Default implementation is a no-op. Solutions with derived state can override
@@ -72,7 +72,7 @@ macro_rules! impl_score_scale {
     };
 }
 
-/* Generates `ParseableScore` impl for scores using the `"Xsuffix/Ysuffix"` format.
+/* Generates `ParseableScore` and `FromStr` impls for scores using the `"Xsuffix/Ysuffix"` format.
 
 Each field maps to a suffix label (e.g., `hard => "hard"`, `soft => "soft"`).
 All values are parsed as `i64`.
@@ -135,6 +135,14 @@ macro_rules! impl_score_parse {
                 parts.join("/")
             }
         }
+
+        impl ::std::str::FromStr for $type {
+            type Err = $crate::score::traits::ScoreParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Self as $crate::score::traits::ParseableScore>::parse(s)
+            }
+        }
     };
 }
 
@@ -52,6 +52,35 @@ fn test_arithmetic() {
     assert_eq!(neg.soft_scores(), &[10, 20]);
 }
 
+#[test]
+fn test_arithmetic_two_hard_three_soft() {
+    let s1: BendableScore<2, 3> = BendableScore::of([-1, -2], [-10, -20, -30]);
+    let s2: BendableScore<2, 3> = BendableScore::of([3, -4], [5, 0, -7]);
+
+    let sum = s1 + s2;
+    assert_eq!(sum.hard_scores(), &[2, -6]);
+    assert_eq!(sum.soft_scores(), &[-5, -20, -37]);
+
+    let difference = s1 - s2;
+    assert_eq!(difference.hard_scores(), &[-4, 2]);
+    assert_eq!(difference.soft_scores(), &[-15, -20, -23]);
+
+    let neg = -s2;
+    assert_eq!(neg.hard_scores(), &[-3, 4]);
+    assert_eq!(neg.soft_scores(), &[-5, 0, 7]);
+
+    let scaled = s1.multiply(2.5);
+    assert_eq!(scaled.hard_scores(), &[-3, -5]);
+    assert_eq!(scaled.soft_scores(), &[-25, -50, -75]);
+
+    let halved = s1.divide(2.0);
+    assert_eq!(halved.hard_scores(), &[-1, -1]);
+    assert_eq!(halved.soft_scores(), &[-5, -10, -15]);
+
+    assert_eq!(s1 + BendableScore::zero(), s1);
+    assert_eq!(s1 + -s1, BendableScore::zero());
+}
+
 #[test]
 fn test_copy() {
     let s1: BendableScore<1, 1> = BendableScore::of([-1], [-10]);
@@ -66,6 +95,16 @@ fn test_level_label() {
     assert_eq!(BendableScore::<2, 3>::level_label(2), ScoreLevel::Soft);
     assert_eq!(BendableScore::<2, 3>::level_label(3), ScoreLevel::Soft);
     assert_eq!(BendableScore::<2, 3>::level_label(4), ScoreLevel::Soft);
+    assert_eq!(
+        BendableScore::<2, 3>::level_labels(),
+        vec![
+            ScoreLevel::Hard,
+            ScoreLevel::Hard,
+            ScoreLevel::Soft,
+            ScoreLevel::Soft,
+            ScoreLevel::Soft,
+        ]
+    );
 }
 
 #[test]
@@ -109,6 +109,10 @@ fn custom_score_implements_required_level_number() {
     assert_eq!(score.level_number(0), -3);
     assert_eq!(score.level_number(1), -14);
     assert_eq!(score.to_level_numbers(), vec![-3, -14]);
+    assert_eq!(
+        CustomScore::level_labels(),
+        vec![ScoreLevel::Hard, ScoreLevel::Soft]
+    );
 }
 
 #[test]
@@ -53,6 +53,39 @@ fn test_parse() {
     );
 }
 
+#[test]
+fn test_parse_round_trips_display() {
+    for score in [
+        HardMediumSoftScore::ZERO,
+        HardMediumSoftScore::of(-1, -10, -100),
+        HardMediumSoftScore::of(3, -7, 42),
+    ] {
+        assert_eq!(HardMediumSoftScore::parse(&score.to_string()), Ok(score));
+        assert_eq!(score.to_string().parse::<HardMediumSoftScore>(), Ok(score));
+    }
+    assert_eq!(
+        " -2hard / 0medium / -5soft ".parse::<HardMediumSoftScore>(),
+        Ok(HardMediumSoftScore::of(-2, 0, -5))
+    );
+}
+
+#[test]
+fn test_parse_rejects_malformed_input() {
+    for input in [
+        "0hard/0soft",
+        "0hard/0medium/0soft/0soft",
+        "0hard/0soft/0medium",
+        "xhard/0medium/0soft",
+        "0hard/0medium/",
+        "",
+    ] {
+        assert!(
+            input.parse::<HardMediumSoftScore>().is_err(),
+            "{input:?} should not parse"
+        );
+    }
+}
+
 #[test]
 fn test_display() {
     let score = HardMediumSoftScore::of(-1, -10, -100);
@@ -64,6 +97,10 @@ fn test_level_label() {
     assert_eq!(HardMediumSoftScore::level_label(0), ScoreLevel::Hard);
     assert_eq!(HardMediumSoftScore::level_label(1), ScoreLevel::Medium);
     assert_eq!(HardMediumSoftScore::level_label(2), ScoreLevel::Soft);
+    assert_eq!(
+        HardMediumSoftScore::level_labels(),
+        vec![ScoreLevel::Hard, ScoreLevel::Medium, ScoreLevel::Soft]
+    );
 }
 
 #[test]
@@ -86,6 +86,10 @@ fn test_level_numbers() {
 fn test_level_label() {
     assert_eq!(HardSoftScore::level_label(0), ScoreLevel::Hard);
     assert_eq!(HardSoftScore::level_label(1), ScoreLevel::Soft);
+    assert_eq!(
+        HardSoftScore::level_labels(),
+        vec![ScoreLevel::Hard, ScoreLevel::Soft]
+    );
 }
 
 #[test]
@@ -69,6 +69,7 @@ fn test_level_numbers() {
 #[test]
 fn test_level_label() {
     assert_eq!(SoftScore::level_label(0), ScoreLevel::Soft);
+    assert_eq!(SoftScore::level_labels(), vec![ScoreLevel::Soft]);
 }
 
 #[test]
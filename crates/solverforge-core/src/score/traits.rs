@@ -25,6 +25,11 @@ use super::ScoreLevel;
 /// - Soft constraints: Optimization objectives to maximize/minimize
 ///
 /// When comparing scores, higher-priority levels are compared first.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a score type",
+    label = "expected a type implementing `Score`",
+    note = "`#[planning_score]` fields must be `Option<S>` where `S: Score`, e.g. `Option<HardSoftScore>`"
+)]
 pub trait Score:
     Copy
     + Debug
@@ -117,6 +122,15 @@ pub trait Score:
     */
     fn level_label(index: usize) -> ScoreLevel;
 
+    /* Returns the semantic labels of every level, highest priority first.
+
+    Pairs index-for-index with `to_level_numbers()`, so tooling can render
+    any score type as labeled numeric levels.
+    */
+    fn level_labels() -> Vec<ScoreLevel> {
+        (0..Self::levels_count()).map(Self::level_label).collect()
+    }
+
     /* Compares two scores, returning the ordering.
 
     Default implementation uses the Ord trait.
@@ -0,0 +1,47 @@
+use crate::{ProblemData, UNREACHABLE};
+
+/// Arrival and departure time at one visit of a route.
+///
+/// `arrival` is when the vehicle reaches the visit. Service starts at
+/// `max(arrival, min_start)`, so any waiting for the window to open is
+/// `departure - arrival - service_duration`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StopTiming {
+    pub arrival: i64,
+    pub departure: i64,
+}
+
+/// Computes per-visit arrival and departure times for `route`.
+///
+/// The vehicle leaves the depot at `depart_time` and follows
+/// `data.travel_times`, waiting for each visit's window to open and then
+/// serving for its service duration. The result is index-aligned with
+/// `route`, which makes it suitable for filling an arrival-time shadow field
+/// from route order. Unreachable legs saturate to `UNREACHABLE` for the rest
+/// of the route.
+///
+/// Callers must pass visit indices that are valid for `data`.
+pub fn compute_arrival_times(
+    route: &[usize],
+    depart_time: i64,
+    data: &ProblemData,
+) -> Vec<StopTiming> {
+    let mut timings = Vec::with_capacity(route.len());
+    let mut current_time = depart_time;
+    let mut previous = data.depot;
+
+    for &visit in route {
+        let travel_time = data.travel_time(previous, visit).unwrap_or(UNREACHABLE);
+        let arrival = current_time.saturating_add(travel_time);
+        let (min_start, _) = data.time_windows[visit];
+        let departure = arrival
+            .max(min_start)
+            .saturating_add(data.service_durations[visit]);
+        timings.push(StopTiming { arrival, departure });
+
+        current_time = departure;
+        previous = visit;
+    }
+
+    timings
+}
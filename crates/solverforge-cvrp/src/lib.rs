@@ -2,14 +2,18 @@
 
 Provides `ProblemData`, `MatrixDistanceMeter`, `MatrixIntraDistanceMeter`,
 the `VrpSolution` trait, and the stock helpers behind
-`#[planning_list_variable(domain = "cvrp")]`.
+`#[planning_list_variable(domain = "cvrp")]`. The `time_window` module holds
+solver-independent route timing checks.
 */
 
+mod arrival;
 mod helpers;
 mod meters;
 mod problem_data;
 mod solution;
+pub mod time_window;
 
+pub use arrival::{compute_arrival_times, StopTiming};
 pub use helpers::{
     depot_for_entity, get_route, replace_route, route_distance, route_feasible, route_hooks,
     savings_depot_for_entity, savings_distance, savings_feasible, savings_hooks,
@@ -18,6 +22,7 @@ pub use helpers::{
 pub use meters::{MatrixDistanceMeter, MatrixIntraDistanceMeter};
 pub use problem_data::{ProblemData, UNREACHABLE};
 pub use solution::VrpSolution;
+pub use time_window::{route_time_feasible, Stop, TimeWindow, TimeWindowViolation};
 
 #[cfg(test)]
 mod tests;
@@ -408,3 +408,75 @@ fn distance_meters_treat_unreachable_legs_as_infinite() {
         .distance(&solution, 0, 0, 0, 1)
         .is_infinite());
 }
+
+fn stop(travel_time: i64, distance: i64, earliest: i64, latest: i64) -> Stop {
+    Stop {
+        travel_time,
+        distance,
+        service_duration: 2,
+        window: TimeWindow::new(earliest, latest),
+    }
+}
+
+#[test]
+fn empty_stop_route_is_time_feasible_with_zero_distance() {
+    assert_eq!(route_time_feasible(&[], 50), Ok(()));
+    assert_eq!(time_window::route_distance(&[]), 0);
+}
+
+#[test]
+fn just_feasible_stop_route_finishes_on_each_window_close() {
+    // Stop 0: arrive 5, serve until 7. Stop 1: arrive 10, wait to 12, serve until 14.
+    let route = [stop(5, 50, 0, 7), stop(3, 30, 12, 14)];
+
+    assert_eq!(route_time_feasible(&route, 0), Ok(()));
+    assert_eq!(time_window::route_distance(&route), 80);
+}
+
+#[test]
+fn stop_route_reports_second_stop_violation_with_slack() {
+    let route = [stop(5, 50, 0, 7), stop(4, 40, 0, 12), stop(1, 10, 0, 100)];
+
+    let violation = route_time_feasible(&route, 0).unwrap_err();
+
+    assert_eq!(
+        violation,
+        TimeWindowViolation {
+            stop_index: 1,
+            service_end: 13,
+            slack: -1,
+        }
+    );
+    assert_eq!(
+        violation.to_string(),
+        "stop 1 finishes service at 13, 1 after its window closes"
+    );
+}
+
+#[test]
+fn arrival_times_wait_for_second_stop_window_and_add_service() {
+    let mut data = base_problem_data();
+    data.time_windows[2] = (15, 30);
+
+    let timings = compute_arrival_times(&[1, 2, 3], 0, &data);
+
+    assert_eq!(
+        timings,
+        vec![
+            StopTiming {
+                arrival: 5,
+                departure: 7,
+            },
+            // Arrives at 11 and waits until the window opens at 15.
+            StopTiming {
+                arrival: 11,
+                departure: 17,
+            },
+            StopTiming {
+                arrival: 20,
+                departure: 23,
+            },
+        ]
+    );
+    assert!(compute_arrival_times(&[], 0, &data).is_empty());
+}
@@ -0,0 +1,89 @@
+/* Solver-independent route timing checks.
+
+A route is a sequence of `Stop`s, each carrying the leg that reaches it from
+the previous stop (or from the depot for the first stop). Nothing here
+depends on `VrpSolution` or `ProblemData`, so the helpers can validate routes
+produced outside the solver.
+*/
+
+use std::error::Error;
+use std::fmt;
+
+/// Inclusive service window for a stop.
+///
+/// Arriving before `earliest` waits until `earliest`; service must finish by
+/// `latest`, matching the stock CVRP time-window semantics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeWindow {
+    pub earliest: i64,
+    pub latest: i64,
+}
+
+impl TimeWindow {
+    pub fn new(earliest: i64, latest: i64) -> Self {
+        Self { earliest, latest }
+    }
+}
+
+/// One visited stop together with the leg that reaches it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stop {
+    // Travel time from the previous stop.
+    pub travel_time: i64,
+    // Distance from the previous stop.
+    pub distance: i64,
+    pub service_duration: i64,
+    pub window: TimeWindow,
+}
+
+/// First stop whose service finishes after its window closes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeWindowViolation {
+    // Position of the violated stop in the route.
+    pub stop_index: usize,
+    // Time at which service at the stop would finish.
+    pub service_end: i64,
+    // `window.latest - service_end`; always negative for a violation.
+    pub slack: i64,
+}
+
+impl fmt::Display for TimeWindowViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stop {} finishes service at {}, {} after its window closes",
+            self.stop_index, self.service_end, -self.slack
+        )
+    }
+}
+
+impl Error for TimeWindowViolation {}
+
+/// Walks `route` from `start_time`, accumulating travel, waiting, and service.
+///
+/// Returns the first stop whose service ends after `window.latest`. An empty
+/// route is always feasible.
+pub fn route_time_feasible(route: &[Stop], start_time: i64) -> Result<(), TimeWindowViolation> {
+    let mut current_time = start_time;
+    for (stop_index, stop) in route.iter().enumerate() {
+        current_time = current_time
+            .saturating_add(stop.travel_time)
+            .max(stop.window.earliest)
+            .saturating_add(stop.service_duration);
+        if current_time > stop.window.latest {
+            return Err(TimeWindowViolation {
+                stop_index,
+                service_end: current_time,
+                slack: stop.window.latest.saturating_sub(current_time),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Total distance over every leg of `route`.
+pub fn route_distance(route: &[Stop]) -> i64 {
+    route
+        .iter()
+        .fold(0_i64, |total, stop| total.saturating_add(stop.distance))
+}
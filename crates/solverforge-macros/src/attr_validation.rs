@@ -104,7 +104,7 @@ const SHADOW_UPDATES_ARGS: &[AttributeArgSpec] = &[
     AttributeArgSpec::string("index_field"),
     AttributeArgSpec::string("previous_field"),
     AttributeArgSpec::string("next_field"),
-    AttributeArgSpec::string("cascading_listener"),
+    AttributeArgSpec::repeated_string("cascading_listener"),
     AttributeArgSpec::string("post_update_listener"),
     AttributeArgSpec::repeated_string("entity_aggregate"),
     AttributeArgSpec::repeated_string("entity_compute"),
@@ -78,7 +78,7 @@ pub fn derive_planning_solution(input: TokenStream) -> TokenStream {
 }
 
 #[doc(hidden)]
-#[proc_macro_derive(ProblemFactImpl, attributes(planning_id))]
+#[proc_macro_derive(ProblemFactImpl, attributes(planning_id, lookup_key))]
 pub fn derive_problem_fact(input: TokenStream) -> TokenStream {
     entrypoints::derive_problem_fact(input)
 }
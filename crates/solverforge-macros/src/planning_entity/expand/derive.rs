@@ -179,7 +179,10 @@ pub(crate) fn expand_derive(input: DeriveInput) -> Result<TokenStream, Error> {
                 let field_name = field.ident.as_ref().unwrap();
                 let field_name_str = field_name.to_string();
                 return Some(
-                    quote! { ::solverforge::__internal::VariableDescriptor::list(#field_name_str) },
+                    quote! {
+                        ::solverforge::__internal::VariableDescriptor::list(#field_name_str)
+                            .with_list_getter(Self::__solverforge_list_elements)
+                    },
                 );
             }
 
@@ -404,7 +407,7 @@ pub(crate) fn expand_derive(input: DeriveInput) -> Result<TokenStream, Error> {
 
     let pin_field_descriptor = if let Some(field) = pin_field {
         let field_name = field.ident.as_ref().unwrap();
-        quote! { desc = desc.with_pin_field(stringify!(#field_name)); }
+        quote! { desc = desc.with_pinning::<Self>(stringify!(#field_name)); }
     } else {
         TokenStream::new()
     };
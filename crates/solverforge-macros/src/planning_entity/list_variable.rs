@@ -127,6 +127,14 @@ pub(super) fn generate_list_metadata(
             &entity.#field_name
         }
 
+        #[inline]
+        fn __solverforge_list_elements(entity: &dyn ::std::any::Any) -> &[usize] {
+            let entity = entity
+                .downcast_ref::<Self>()
+                .expect("entity type mismatch for planning list variable getter");
+            &entity.#field_name
+        }
+
         #[inline]
         fn __solverforge_list_field_mut(entity: &mut Self) -> &mut ::std::vec::Vec<usize> {
             &mut entity.#field_name
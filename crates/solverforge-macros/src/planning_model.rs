@@ -26,5 +26,6 @@ include!("planning_model/metadata.rs");
 include!("planning_model/support_groups.rs");
 include!("planning_model/support.rs");
 include!("planning_model/shadows.rs");
+include!("planning_model/inverse_relations.rs");
 #[cfg(test)]
 include!("planning_model/tests.rs");
@@ -0,0 +1,110 @@
+struct GeneratedInverseRelationUpdate {
+    update: TokenStream,
+    reset: TokenStream,
+    source: (usize, Ident),
+}
+
+/* Inverse relations mirror a scalar planning variable onto the entity it
+points at: `#[inverse_relation_shadow_variable(source_variable_name = "vehicle")]`
+on a `Vec<usize>` field of `Vehicle` lists the indexes of every entity whose
+`vehicle` variable holds that vehicle's index. Lists stay sorted by source
+entity index, so their contents do not depend on move history.
+*/
+fn generate_inverse_relation_updates(
+    model: &ModelMetadata,
+    solution_path: &TokenStream,
+) -> Result<Vec<GeneratedInverseRelationUpdate>> {
+    let mut generated = Vec::new();
+    for (target_type_name, target_entity) in &model.entities {
+        for relation in &target_entity.inverse_relations {
+            let target_collection = model
+                .solution
+                .collections
+                .iter()
+                .find(|collection| {
+                    collection.descriptor_index.is_some()
+                        && canonical_type_name(&model.aliases, &collection.type_name)
+                            == target_type_name
+                })
+                .ok_or_else(|| {
+                    Error::new(
+                        proc_macro2::Span::call_site(),
+                        format!(
+                            "#[inverse_relation_shadow_variable] on `{target_type_name}.{}` requires a #[planning_entity_collection] of `{target_type_name}`",
+                            relation.field_name,
+                        ),
+                    )
+                })?;
+            let sources = model
+                .solution
+                .collections
+                .iter()
+                .filter(|collection| collection.descriptor_index.is_some())
+                .filter(|collection| {
+                    model
+                        .entities
+                        .get(canonical_type_name(&model.aliases, &collection.type_name))
+                        .is_some_and(|entity| {
+                            entity.scalar_variables.iter().any(|variable| {
+                                variable.field_name == relation.source_variable_name
+                            })
+                        })
+                })
+                .collect::<Vec<_>>();
+            let [source_collection] = sources.as_slice() else {
+                return Err(Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "#[inverse_relation_shadow_variable(source_variable_name = \"{}\")] on `{target_type_name}.{}` must name a #[planning_variable] declared by exactly one entity collection; found {}",
+                        relation.source_variable_name,
+                        relation.field_name,
+                        sources.len(),
+                    ),
+                ));
+            };
+
+            let source_descriptor_index = source_collection.descriptor_index.unwrap();
+            let source_accessor =
+                format_ident!("__solverforge_collection_{}", source_collection.field_ident);
+            let target_mut_accessor =
+                format_ident!("__solverforge_collection_{}_mut", target_collection.field_ident);
+            let variable_ident =
+                Ident::new(&relation.source_variable_name, proc_macro2::Span::call_site());
+            let inverse_ident = Ident::new(&relation.field_name, proc_macro2::Span::call_site());
+
+            let update = quote! {
+                if descriptor_index == #source_descriptor_index
+                    && entity_index < #solution_path::#source_accessor(solution).len()
+                {
+                    let assigned = #solution_path::#source_accessor(solution)[entity_index].#variable_ident;
+                    let targets = #solution_path::#target_mut_accessor(solution);
+                    for (target_index, target) in targets.iter_mut().enumerate() {
+                        if assigned == Some(target_index) {
+                            continue;
+                        }
+                        if let Ok(position) = target.#inverse_ident.binary_search(&entity_index) {
+                            target.#inverse_ident.remove(position);
+                        }
+                    }
+                    if let Some(target) = assigned.and_then(|target_index| targets.get_mut(target_index)) {
+                        if let Err(position) = target.#inverse_ident.binary_search(&entity_index) {
+                            target.#inverse_ident.insert(position, entity_index);
+                        }
+                    }
+                    updated = true;
+                }
+            };
+            let reset = quote! {
+                for target in #solution_path::#target_mut_accessor(solution) {
+                    target.#inverse_ident.clear();
+                }
+            };
+            generated.push(GeneratedInverseRelationUpdate {
+                update,
+                reset,
+                source: (source_descriptor_index, source_accessor),
+            });
+        }
+    }
+    Ok(generated)
+}
@@ -85,10 +85,17 @@ struct ScalarVariableMetadata {
     hooks: HookPaths,
 }
 
+#[derive(Clone)]
+struct InverseRelationMetadata {
+    field_name: String,
+    source_variable_name: String,
+}
+
 #[derive(Clone)]
 struct EntityMetadata {
     type_name: String,
     scalar_variables: Vec<ScalarVariableMetadata>,
+    inverse_relations: Vec<InverseRelationMetadata>,
     list_variable_name: Option<String>,
     list_element_collection: Option<String>,
     list_element_owner_fn: Option<syn::Path>,
@@ -126,7 +133,7 @@ struct ShadowConfig {
     index_field: Option<String>,
     previous_field: Option<String>,
     next_field: Option<String>,
-    cascading_listener: Option<String>,
+    cascading_listeners: Vec<String>,
     post_update_listener: Option<String>,
     entity_aggregates: Vec<String>,
     entity_computes: Vec<String>,
@@ -75,7 +75,7 @@ fn parse_shadow_config(attrs: &[Attribute]) -> Result<ShadowConfig> {
         config.index_field = parse_attribute_string(attr, "index_field");
         config.previous_field = parse_attribute_string(attr, "previous_field");
         config.next_field = parse_attribute_string(attr, "next_field");
-        config.cascading_listener = parse_attribute_string(attr, "cascading_listener");
+        config.cascading_listeners = parse_attribute_list(attr, "cascading_listener");
         config.post_update_listener = parse_attribute_string(attr, "post_update_listener");
         config.entity_aggregates = parse_attribute_list(attr, "entity_aggregate");
         config.entity_computes = parse_attribute_list(attr, "entity_compute");
@@ -90,6 +90,7 @@ fn parse_entity(module: &ModuleSource, item_struct: &ItemStruct) -> Result<Entit
     let fields = named_fields(item_struct, "#[planning_entity] requires named fields")?;
     validate_entity_fields(fields)?;
     let mut scalar_variables = Vec::new();
+    let mut inverse_relations = Vec::new();
     let mut list_variable_name = None;
     let mut list_element_collection = None;
     let mut list_element_owner_fn = None;
@@ -157,6 +158,23 @@ fn parse_entity(module: &ModuleSource, item_struct: &ItemStruct) -> Result<Entit
             });
         }
 
+        if let Some(attr) = get_attribute(&field.attrs, "inverse_relation_shadow_variable") {
+            let Some(field_ident) = field.ident.as_ref() else {
+                continue;
+            };
+            let source_variable_name = parse_attribute_string(attr, "source_variable_name")
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        field,
+                        "#[inverse_relation_shadow_variable] requires `source_variable_name = \"planning_variable_field\"`",
+                    )
+                })?;
+            inverse_relations.push(InverseRelationMetadata {
+                field_name: field_ident.to_string(),
+                source_variable_name,
+            });
+        }
+
         if has_attribute(&field.attrs, "planning_list_variable") {
             if let Some(field_ident) = field.ident.as_ref() {
                 list_variable_name = Some(field_ident.to_string());
@@ -192,6 +210,7 @@ fn parse_entity(module: &ModuleSource, item_struct: &ItemStruct) -> Result<Entit
     Ok(EntityMetadata {
         type_name: item_struct.ident.to_string(),
         scalar_variables,
+        inverse_relations,
         list_variable_name,
         list_element_collection,
         list_element_owner_fn,
@@ -1,5 +1,8 @@
 struct GeneratedListShadowUpdate {
     update: TokenStream,
+    // Boundary-only update for list changes; present when every configured
+    // list shadow depends only on an element's immediate neighbors.
+    windowed_update: Option<TokenStream>,
     reset: TokenStream,
     descriptor: Option<(usize, Ident)>,
 }
@@ -11,8 +14,44 @@ fn generate_shadow_methods(model: &ModelMetadata) -> Result<TokenStream> {
     let generated = generate_list_shadow_update(model, &solution_path)?;
     let list_update = generated.update;
     let list_reset = generated.reset;
+    let inverse_relations = generate_inverse_relation_updates(model, &solution_path)?;
+    // Inverse relations sourced from the list owner still need the full hook.
+    let list_descriptor_index = generated.descriptor.as_ref().map(|(index, _)| *index);
+    let windowed_update = generated.windowed_update.filter(|_| {
+        inverse_relations
+            .iter()
+            .all(|relation| Some(relation.source.0) != list_descriptor_index)
+    });
+    let list_windowed_shadows = windowed_update.map(|windowed_update| {
+        quote! {
+            fn update_list_shadows(
+                solution: &mut Self,
+                descriptor_index: usize,
+                entity_index: usize,
+                changed: &[::core::ops::Range<usize>],
+            ) -> bool {
+                #windowed_update
+                <Self as ::solverforge::__internal::PlanningModelSupport>::update_entity_shadows(
+                    solution,
+                    descriptor_index,
+                    entity_index,
+                )
+            }
+        }
+    });
+
+    // Descriptors whose entities are revisited by update_all_shadows().
+    let mut refreshed: BTreeMap<usize, Ident> = BTreeMap::new();
+    if let Some((descriptor_index, collection_accessor)) = generated.descriptor {
+        refreshed.insert(descriptor_index, collection_accessor);
+    }
+    for relation in &inverse_relations {
+        refreshed
+            .entry(relation.source.0)
+            .or_insert_with(|| relation.source.1.clone());
+    }
 
-    let Some((descriptor_index, collection_accessor)) = generated.descriptor else {
+    if refreshed.is_empty() {
         return Ok(quote! {
             fn update_entity_shadows(
                 _solution: &mut Self,
@@ -26,7 +65,23 @@ fn generate_shadow_methods(model: &ModelMetadata) -> Result<TokenStream> {
                 false
             }
         });
-    };
+    }
+
+    let inverse_updates = inverse_relations.iter().map(|relation| &relation.update);
+    let inverse_resets = inverse_relations.iter().map(|relation| &relation.reset);
+    let refreshes = refreshed
+        .iter()
+        .map(|(descriptor_index, collection_accessor)| {
+            quote! {
+                for entity_index in 0..#solution_path::#collection_accessor(solution).len() {
+                    let _ = <Self as ::solverforge::__internal::PlanningModelSupport>::update_entity_shadows(
+                        solution,
+                        #descriptor_index,
+                        entity_index,
+                    );
+                }
+            }
+        });
 
     Ok(quote! {
         fn update_entity_shadows(
@@ -36,18 +91,16 @@ fn generate_shadow_methods(model: &ModelMetadata) -> Result<TokenStream> {
         ) -> bool {
             let mut updated = false;
             #list_update
+            #(#inverse_updates)*
             updated
         }
 
+        #list_windowed_shadows
+
         fn update_all_shadows(solution: &mut Self) -> bool {
             #list_reset
-            for entity_index in 0..#solution_path::#collection_accessor(solution).len() {
-                let _ = <Self as ::solverforge::__internal::PlanningModelSupport>::update_entity_shadows(
-                    solution,
-                    #descriptor_index,
-                    entity_index,
-                );
-            }
+            #(#inverse_resets)*
+            #(#refreshes)*
             true
         }
     })
@@ -61,6 +114,7 @@ fn generate_list_shadow_update(
     if !list_shadow_updates_requested(config) {
         return Ok(GeneratedListShadowUpdate {
             update: TokenStream::new(),
+            windowed_update: None,
             reset: TokenStream::new(),
             descriptor: None,
         });
@@ -187,11 +241,18 @@ fn generate_list_shadow_update(
             }
         }
     });
-    let cascading_update = config.cascading_listener.as_ref().map(|method| {
-        let method_ident = Ident::new(method, proc_macro2::Span::call_site());
+    // Element-major so each element sees its predecessor's fully cascaded state;
+    // listeners run in declaration order, which is the dependency order within
+    // one element.
+    let cascading_update = (!config.cascading_listeners.is_empty()).then(|| {
+        let method_idents: Vec<_> = config
+            .cascading_listeners
+            .iter()
+            .map(|method| Ident::new(method, proc_macro2::Span::call_site()))
+            .collect();
         quote! {
             for &element_idx in &element_indices {
-                solution.#method_ident(element_idx);
+                #( solution.#method_idents(element_idx); )*
             }
         }
     });
@@ -225,8 +286,59 @@ fn generate_list_shadow_update(
             updated = true;
         }
     };
+    let windowed_update = list_shadows_are_neighbor_local(config).then(|| {
+        let inverse_link = config.inverse_field.as_ref().map(|field| {
+            let field_ident = Ident::new(field, proc_macro2::Span::call_site());
+            quote! {
+                if position >= start && position < end {
+                    elements[element_idx].#field_ident = Some(entity_index);
+                }
+            }
+        });
+        let previous_link = config.previous_field.as_ref().map(|field| {
+            let field_ident = Ident::new(field, proc_macro2::Span::call_site());
+            quote! {
+                elements[element_idx].#field_ident =
+                    position.checked_sub(1).map(|previous| context[previous - context_start]);
+            }
+        });
+        let next_link = config.next_field.as_ref().map(|field| {
+            let field_ident = Ident::new(field, proc_macro2::Span::call_site());
+            quote! {
+                elements[element_idx].#field_ident =
+                    (position + 1 < len).then(|| context[position + 1 - context_start]);
+            }
+        });
+        quote! {
+            if descriptor_index == #descriptor_index
+                && entity_index < #solution_path::#owner_accessor(solution).len()
+            {
+                for range in changed {
+                    let list = &#solution_path::#owner_accessor(solution)[entity_index]
+                        .#list_variable_ident;
+                    let len = list.len();
+                    let end = range.end.min(len);
+                    let start = range.start.min(end);
+                    let first = start.saturating_sub(1);
+                    let last = (end + 1).min(len);
+                    let context_start = first.saturating_sub(1);
+                    let context = list[context_start..(last + 1).min(len)].to_vec();
+                    let elements = #solution_path::#element_mut_accessor(solution);
+                    for position in first..last {
+                        let element_idx = context[position - context_start];
+                        #inverse_link
+                        #previous_link
+                        #next_link
+                    }
+                }
+                return true;
+            }
+        }
+    });
+
     Ok(GeneratedListShadowUpdate {
         update,
+        windowed_update,
         reset: index_reset.unwrap_or_default(),
         descriptor: Some((descriptor_index, owner_accessor)),
     })
@@ -282,12 +394,22 @@ fn generate_list_compute_updates(
         .collect()
 }
 
+// Index, cascading, aggregate, compute, and post-update shadows can change for
+// elements far from the edit, so those configurations refresh the whole list.
+fn list_shadows_are_neighbor_local(config: &ShadowConfig) -> bool {
+    config.index_field.is_none()
+        && config.cascading_listeners.is_empty()
+        && config.post_update_listener.is_none()
+        && config.entity_aggregates.is_empty()
+        && config.entity_computes.is_empty()
+}
+
 fn list_shadow_updates_requested(config: &ShadowConfig) -> bool {
     config.inverse_field.is_some()
         || config.index_field.is_some()
         || config.previous_field.is_some()
         || config.next_field.is_some()
-        || config.cascading_listener.is_some()
+        || !config.cascading_listeners.is_empty()
         || config.post_update_listener.is_some()
         || !config.entity_aggregates.is_empty()
         || !config.entity_computes.is_empty()
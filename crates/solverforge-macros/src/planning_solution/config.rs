@@ -9,7 +9,7 @@ pub(super) struct ShadowConfig {
     pub(super) index_field: Option<String>,
     pub(super) previous_field: Option<String>,
     pub(super) next_field: Option<String>,
-    pub(super) cascading_listener: Option<String>,
+    pub(super) cascading_listeners: Vec<String>,
     pub(super) post_update_listener: Option<String>,
     pub(super) entity_aggregates: Vec<String>,
     pub(super) entity_computes: Vec<String>,
@@ -63,7 +63,7 @@ pub(super) fn parse_shadow_config(attrs: &[syn::Attribute]) -> ShadowConfig {
         config.index_field = parse_attribute_string(attr, "index_field");
         config.previous_field = parse_attribute_string(attr, "previous_field");
         config.next_field = parse_attribute_string(attr, "next_field");
-        config.cascading_listener = parse_attribute_string(attr, "cascading_listener");
+        config.cascading_listeners = parse_attribute_list(attr, "cascading_listener");
         config.post_update_listener = parse_attribute_string(attr, "post_update_listener");
         config.entity_aggregates = parse_attribute_list(attr, "entity_aggregate");
         config.entity_computes = parse_attribute_list(attr, "entity_compute");
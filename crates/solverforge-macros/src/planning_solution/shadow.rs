@@ -9,7 +9,7 @@ fn shadow_updates_requested(config: &ShadowConfig) -> bool {
         || config.index_field.is_some()
         || config.previous_field.is_some()
         || config.next_field.is_some()
-        || config.cascading_listener.is_some()
+        || !config.cascading_listeners.is_empty()
         || config.post_update_listener.is_some()
         || !config.entity_aggregates.is_empty()
         || !config.entity_computes.is_empty()
@@ -20,11 +20,9 @@ pub(super) fn generate_shadow_support(
     _fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
     _solution_name: &Ident,
 ) -> Result<TokenStream, Error> {
-    if !shadow_updates_requested(config) {
-        return Ok(TokenStream::new());
-    }
-
-    if config.list_owner.is_none() {
+    // Always delegate: inverse relations are declared on entities, which this
+    // macro cannot see, so the planning_model! support impl decides what runs.
+    if shadow_updates_requested(config) && config.list_owner.is_none() {
         return Err(Error::new(
             proc_macro2::Span::call_site(),
             "#[shadow_variable_updates(...)] requires `list_owner = \"entity_collection_field\"` when shadow updates are configured",
@@ -41,6 +39,21 @@ pub(super) fn generate_shadow_support(
             );
         }
 
+        #[inline]
+        fn update_list_shadows(
+            &mut self,
+            descriptor_index: usize,
+            entity_idx: usize,
+            changed: &[::core::ops::Range<usize>],
+        ) {
+            let _ = <Self as ::solverforge::__internal::PlanningModelSupport>::update_list_shadows(
+                self,
+                descriptor_index,
+                entity_idx,
+                changed,
+            );
+        }
+
         #[inline]
         fn update_all_shadows(&mut self) {
             let _ = <Self as ::solverforge::__internal::PlanningModelSupport>::update_all_shadows(
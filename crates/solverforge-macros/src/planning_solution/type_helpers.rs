@@ -12,7 +12,13 @@ pub(super) fn extract_option_inner_type(ty: &syn::Type) -> Result<&syn::Type, Er
             }
         }
     }
-    Err(Error::new_spanned(ty, "Score field must be Option<Score>"))
+    let written = quote::ToTokens::to_token_stream(ty)
+        .to_string()
+        .replace(' ', "");
+    Err(Error::new_spanned(
+        ty,
+        format!("#[planning_score] field must be Option<Score>; wrap it as `Option<{written}>`"),
+    ))
 }
 
 pub(super) fn extract_collection_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
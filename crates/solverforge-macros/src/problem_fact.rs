@@ -34,6 +34,20 @@ pub fn expand_derive(input: DeriveInput) -> Result<TokenStream, Error> {
         if let Some(attr) = get_attribute(&field.attrs, "planning_id") {
             validate_no_attribute_args(attr, "planning_id")?;
         }
+        if let Some(attr) = get_attribute(&field.attrs, "lookup_key") {
+            validate_no_attribute_args(attr, "lookup_key")?;
+        }
+    }
+
+    let mut lookup_key_fields = fields
+        .iter()
+        .filter(|f| has_attribute(&f.attrs, "lookup_key"));
+    let lookup_key_field = lookup_key_fields.next();
+    if let Some(duplicate) = lookup_key_fields.next() {
+        return Err(Error::new_spanned(
+            duplicate.ident.as_ref().unwrap(),
+            "#[problem_fact] supports at most one #[lookup_key] field",
+        ));
     }
 
     let id_field = fields
@@ -60,6 +74,26 @@ pub fn expand_derive(input: DeriveInput) -> Result<TokenStream, Error> {
         TokenStream::new()
     };
 
+    let (lookup_key_descriptor, lookup_key_method) = if let Some(field) = lookup_key_field {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+        (
+            quote! {
+                desc = desc.with_lookup_key(stringify!(#field_name), |fact| {
+                    fact.downcast_ref::<Self>()
+                        .map(|fact| &fact.#field_name as &dyn ::std::any::Any)
+                });
+            },
+            quote! {
+                pub fn lookup_key(&self) -> &#field_type {
+                    &self.#field_name
+                }
+            },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let expanded = quote! {
         impl #impl_generics ::solverforge::__internal::ProblemFact for #name #ty_generics #where_clause {
             fn as_any(&self) -> &dyn ::std::any::Any { self }
@@ -77,8 +111,11 @@ pub fn expand_derive(input: DeriveInput) -> Result<TokenStream, Error> {
                     solution_field,
                 );
                 #id_field_descriptor
+                #lookup_key_descriptor
                 desc
             }
+
+            #lookup_key_method
         }
     };
 
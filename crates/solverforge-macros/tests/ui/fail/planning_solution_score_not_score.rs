@@ -0,0 +1,18 @@
+use solverforge::prelude::*;
+
+#[planning_entity]
+struct Task {
+    #[planning_id]
+    id: String,
+}
+
+#[planning_solution]
+struct Plan {
+    #[planning_entity_collection]
+    tasks: Vec<Task>,
+
+    #[planning_score]
+    score: Option<String>,
+}
+
+fn main() {}
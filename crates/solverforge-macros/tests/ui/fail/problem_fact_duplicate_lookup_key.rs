@@ -0,0 +1,13 @@
+use solverforge_macros::problem_fact;
+
+#[problem_fact]
+struct Employee {
+    #[planning_id]
+    id: usize,
+    #[lookup_key]
+    email: String,
+    #[lookup_key]
+    badge: String,
+}
+
+fn main() {}
@@ -15,8 +15,8 @@ use super::{
         DirectedBi as ProjectedDirectedBi, Grouped as ProjectedGrouped, Uni as ProjectedUni,
     },
     BalanceConstraint, FlattenedBiConstraint, IncrementalBiConstraint, IncrementalExistsConstraint,
-    IncrementalPentaConstraint, IncrementalQuadConstraint, IncrementalTriConstraint,
-    IncrementalUniConstraint,
+    IncrementalHexaConstraint, IncrementalPentaConstraint, IncrementalQuadConstraint,
+    IncrementalTriConstraint, IncrementalUniConstraint,
 };
 
 impl<S, A, E, F, W, Sc> IncrementalConstraintSealed for IncrementalUniConstraint<S, A, E, F, W, Sc> where
@@ -52,6 +52,13 @@ where
 {
 }
 
+impl<S, A, K, E, KE, F, W, Sc> IncrementalConstraintSealed
+    for IncrementalHexaConstraint<S, A, K, E, KE, F, W, Sc>
+where
+    Sc: Score,
+{
+}
+
 impl<S, A, B, K, EA, EB, KA, KB, F, W, Sc> IncrementalConstraintSealed
     for CrossBi<S, A, B, K, EA, EB, KA, KB, F, W, Sc>
 where
@@ -21,7 +21,7 @@ impl_get_matches_nary!(bi: self, solution)
 }
 ```
 
-Available arities: `bi`, `tri`, `quad`, `penta`
+Available arities: `bi`, `tri`, `quad`, `penta`, `hexa`
 */
 #[macro_export]
 macro_rules! impl_get_matches_nary {
@@ -239,6 +239,78 @@ macro_rules! impl_get_matches_nary {
         }
         matches
     }};
+    // Hexa-constraint: 6 entities
+    (hexa: $self:expr, $solution:expr) => {{
+        use std::collections::HashMap;
+        use $crate::api::analysis::{ConstraintJustification, DetailedConstraintMatch, EntityRef};
+
+        let entities = $crate::stream::collection_extract::CollectionExtract::extract(
+            &$self.extractor,
+            $solution,
+        );
+        let cref = $self.constraint_ref();
+
+        let mut temp_index: HashMap<_, Vec<usize>> = HashMap::new();
+        for (i, entity) in entities.iter().enumerate() {
+            let key = $crate::stream::key_extract::KeyExtract::extract(
+                &$self.key_extractor,
+                $solution,
+                entity,
+                i,
+            );
+            temp_index.entry(key).or_default().push(i);
+        }
+
+        let mut matches = Vec::new();
+        for indices in temp_index.values() {
+            for pos_i in 0..indices.len() {
+                for pos_j in (pos_i + 1)..indices.len() {
+                    for pos_k in (pos_j + 1)..indices.len() {
+                        for pos_l in (pos_k + 1)..indices.len() {
+                            for pos_m in (pos_l + 1)..indices.len() {
+                                for pos_n in (pos_m + 1)..indices.len() {
+                                    let i = indices[pos_i];
+                                    let j = indices[pos_j];
+                                    let k = indices[pos_k];
+                                    let l = indices[pos_l];
+                                    let m = indices[pos_m];
+                                    let n = indices[pos_n];
+                                    let a = &entities[i];
+                                    let b = &entities[j];
+                                    let c = &entities[k];
+                                    let d = &entities[l];
+                                    let e = &entities[m];
+                                    let f = &entities[n];
+                                    if ($self.filter)($solution, a, b, c, d, e, f, i, j, k, l, m, n)
+                                    {
+                                        let justification = ConstraintJustification::new(vec![
+                                            EntityRef::new(a),
+                                            EntityRef::new(b),
+                                            EntityRef::new(c),
+                                            EntityRef::new(d),
+                                            EntityRef::new(e),
+                                            EntityRef::new(f),
+                                        ]);
+                                        let score = $self.compute_score(
+                                            $solution,
+                                            entities,
+                                            (i, j, k, l, m, n),
+                                        );
+                                        matches.push(DetailedConstraintMatch::new(
+                                            cref,
+                                            score,
+                                            justification,
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        matches
+    }};
 }
 
 pub use impl_get_matches_nary;
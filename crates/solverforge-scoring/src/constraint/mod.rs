@@ -39,6 +39,6 @@ pub use flattened_bi::FlattenedBiConstraint;
 pub use incremental::IncrementalUniConstraint;
 pub use list_precedence::ListPrecedenceMakespanConstraint;
 pub use nary_incremental::{
-    IncrementalBiConstraint, IncrementalPentaConstraint, IncrementalQuadConstraint,
-    IncrementalTriConstraint,
+    IncrementalBiConstraint, IncrementalHexaConstraint, IncrementalPentaConstraint,
+    IncrementalQuadConstraint, IncrementalTriConstraint,
 };
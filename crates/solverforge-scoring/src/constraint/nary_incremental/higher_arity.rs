@@ -1,4 +1,4 @@
-/* Higher-arity incremental constraint macros for tri/quad/penta arities.
+/* Higher-arity incremental constraint macros for tri/quad/penta/hexa arities.
 
 The arity-specific macro definitions now live in dedicated files so this
 module root stays limited to module wiring and re-exports.
@@ -7,12 +7,15 @@ module root stays limited to module wiring and re-exports.
 #[macro_use]
 mod shared;
 #[macro_use]
+mod hexa;
+#[macro_use]
 mod penta;
 #[macro_use]
 mod quad;
 #[macro_use]
 mod tri;
 
+pub use hexa::impl_incremental_hexa_constraint;
 pub use penta::impl_incremental_penta_constraint;
 pub use quad::impl_incremental_quad_constraint;
 pub use tri::impl_incremental_tri_constraint;
@@ -0,0 +1,16 @@
+#[macro_export]
+macro_rules! impl_incremental_hexa_constraint {
+    ($struct_name:ident) => {
+        impl_incremental_higher_arity_constraint_common!(
+            struct_name = $struct_name,
+            match_kind = hexa,
+            entities = [a, b, c, d, e, f],
+            match_indices = [a_idx, b_idx, c_idx, d_idx, e_idx, f_idx],
+            combo_positions = [pos_i, pos_j, pos_k, pos_l, pos_m, pos_n],
+            combo_values = [i, j, k, l, m, n],
+            other_values = [i, j, k, l, m]
+        );
+    };
+}
+
+pub use impl_incremental_hexa_constraint;
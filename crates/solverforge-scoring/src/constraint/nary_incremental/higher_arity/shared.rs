@@ -1,4 +1,4 @@
-/* Shared higher-arity incremental constraint scaffolding for tri/quad/penta.
+/* Shared higher-arity incremental constraint scaffolding for tri/quad/penta/hexa.
 
 This keeps the exported per-arity macros explicit while centralizing the
 repeated lifecycle, indexing, and delta-application structure.
@@ -1,7 +1,7 @@
 /* Macro-generated N-ary incremental constraints for self-join evaluation.
 
 This module provides the `impl_incremental_nary_constraint!` macro that generates
-fully monomorphized incremental constraint implementations for bi/tri/quad/penta arities.
+fully monomorphized incremental constraint implementations for bi/tri/quad/penta/hexa arities.
 
 Zero-erasure: all closures are concrete generic types, no trait objects, no Arc.
 */
@@ -12,6 +12,7 @@ mod bi;
 mod higher_arity;
 
 pub use bi::impl_incremental_bi_constraint;
+pub use higher_arity::impl_incremental_hexa_constraint;
 pub use higher_arity::impl_incremental_penta_constraint;
 pub use higher_arity::impl_incremental_quad_constraint;
 pub use higher_arity::impl_incremental_tri_constraint;
@@ -32,6 +33,7 @@ impl_incremental_nary_constraint!(bi, IncrementalBiConstraint);
 impl_incremental_nary_constraint!(tri, IncrementalTriConstraint);
 impl_incremental_nary_constraint!(quad, IncrementalQuadConstraint);
 impl_incremental_nary_constraint!(penta, IncrementalPentaConstraint);
+impl_incremental_nary_constraint!(hexa, IncrementalHexaConstraint);
 ```
 */
 #[macro_export]
@@ -48,6 +50,9 @@ macro_rules! impl_incremental_nary_constraint {
     (penta, $struct_name:ident) => {
         $crate::impl_incremental_penta_constraint!($struct_name);
     };
+    (hexa, $struct_name:ident) => {
+        $crate::impl_incremental_hexa_constraint!($struct_name);
+    };
 }
 
 pub use impl_incremental_nary_constraint;
@@ -68,3 +73,4 @@ impl_incremental_nary_constraint!(bi, IncrementalBiConstraint);
 impl_incremental_nary_constraint!(tri, IncrementalTriConstraint);
 impl_incremental_nary_constraint!(quad, IncrementalQuadConstraint);
 impl_incremental_nary_constraint!(penta, IncrementalPentaConstraint);
+impl_incremental_nary_constraint!(hexa, IncrementalHexaConstraint);
@@ -82,6 +82,66 @@ fn test_exists_updates_all_matching_a_entities_when_b_descriptor_changes() {
     assert_eq!(total, SoftScore::of(-2));
 }
 
+#[test]
+fn test_if_not_exists_stops_penalizing_once_a_match_appears() {
+    let mut constraint = ConstraintFactory::<TaskSchedule, SoftScore>::new()
+        .for_each(source(
+            workers as fn(&TaskSchedule) -> &[Worker],
+            ChangeSource::Static,
+        ))
+        .if_not_exists((
+            ConstraintFactory::<TaskSchedule, SoftScore>::new().for_each(source(
+                tasks as fn(&TaskSchedule) -> &[Task],
+                ChangeSource::Descriptor(0),
+            )),
+            equal_bi(
+                |worker: &Worker| Some(worker.id),
+                |task: &Task| task.assignee,
+            ),
+        ))
+        .penalize(SoftScore::of(1))
+        .named("idle worker");
+
+    let mut schedule = TaskSchedule {
+        tasks: vec![Task { assignee: None }, Task { assignee: Some(0) }],
+        workers: vec![
+            Worker {
+                id: 0,
+                available: true,
+            },
+            Worker {
+                id: 1,
+                available: true,
+            },
+        ],
+    };
+
+    // Worker 1 has no task, so it is penalized once; the match is not emitted as a pair.
+    let mut total = constraint.initialize(&schedule);
+    assert_eq!(total, SoftScore::of(-1));
+    assert_eq!(constraint.match_count(&schedule), 1);
+
+    total = total + constraint.on_retract(&schedule, 0, 0);
+    schedule.tasks[0].assignee = Some(1);
+    total = total + constraint.on_insert(&schedule, 0, 0);
+    assert_eq!(total, SoftScore::of(0));
+    assert_eq!(total, constraint.evaluate(&schedule));
+
+    // A second matching task keeps worker 1 covered after the first one leaves.
+    schedule.tasks.push(Task { assignee: Some(1) });
+    total = total + constraint.on_insert(&schedule, 2, 0);
+    total = total + constraint.on_retract(&schedule, 0, 0);
+    schedule.tasks[0].assignee = None;
+    total = total + constraint.on_insert(&schedule, 0, 0);
+    assert_eq!(total, SoftScore::of(0));
+
+    total = total + constraint.on_retract(&schedule, 2, 0);
+    schedule.tasks[2].assignee = None;
+    total = total + constraint.on_insert(&schedule, 2, 0);
+    assert_eq!(total, SoftScore::of(-1));
+    assert_eq!(total, constraint.evaluate(&schedule));
+}
+
 #[derive(Clone)]
 struct CustomerState {
     customers: Vec<usize>,
@@ -268,6 +268,46 @@ fn test_grouped_fluent_chain_appends_terminals_to_one_node() {
     assert_eq!(constraints.state().update_count(), 2);
 }
 
+#[test]
+fn test_grouped_count_penalizes_overflow_above_cap() {
+    const MAX_SHIFTS: usize = 2;
+
+    let mut constraints = ConstraintFactory::<GroupedSolution, SoftScore>::new()
+        .for_each(source(
+            vec(|s: &GroupedSolution| &s.shifts),
+            ChangeSource::Descriptor(0),
+        ))
+        .group_by(|shift: &GroupedShift| shift.employee_id, count())
+        .penalize(|_employee_id: &usize, count: &usize| {
+            SoftScore::of(count.saturating_sub(MAX_SHIFTS) as i64)
+        })
+        .named("Max shifts per employee");
+
+    let mut solution = GroupedSolution {
+        shifts: [1, 1, 1, 1, 2, 2, 3]
+            .into_iter()
+            .map(|employee_id| GroupedShift { employee_id })
+            .collect(),
+    };
+
+    // Employee 1 is two shifts over the cap; employees 2 and 3 are within it.
+    assert_eq!(constraints.initialize_all(&solution), SoftScore::of(-2));
+
+    // Moving a shift from employee 3 to employee 2 pushes employee 2 over.
+    let mut total = constraints.initialize_all(&solution);
+    total = total + constraints.on_retract_all(&solution, 6, 0);
+    solution.shifts[6].employee_id = 2;
+    total = total + constraints.on_insert_all(&solution, 6, 0);
+    assert_eq!(total, SoftScore::of(-3));
+    assert_eq!(total, constraints.evaluate_all(&solution));
+
+    // One more shift for employee 1 raises the penalty by one.
+    solution.shifts.push(GroupedShift { employee_id: 1 });
+    total = total + constraints.on_insert_all(&solution, 7, 0);
+    assert_eq!(total, SoftScore::of(-4));
+    assert_eq!(total, constraints.evaluate_all(&solution));
+}
+
 #[test]
 fn test_shared_grouped_constraint_set_refreshes_only_dirty_keys() {
     let state = GroupedNodeState::new(
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use solverforge_core::score::SoftScore;
+use solverforge_core::{ConstraintRef, ImpactType};
+
+use crate::api::constraint_set::IncrementalConstraint;
+use crate::constraint::grouped::Uni;
+use crate::stream::collection_extract::{source, vec, ChangeSource};
+use crate::stream::collector::sum;
+use crate::stream::filter::TrueFilter;
+
+const CAPACITY_MINUTES: i64 = 480;
+
+#[derive(Clone)]
+struct Task {
+    assignee: usize,
+    minutes: i64,
+}
+
+#[derive(Clone)]
+struct Workload {
+    tasks: Vec<Task>,
+}
+
+fn batch_minutes_per_assignee(solution: &Workload) -> BTreeMap<usize, i64> {
+    let mut totals = BTreeMap::new();
+    for task in &solution.tasks {
+        *totals.entry(task.assignee).or_insert(0) += task.minutes;
+    }
+    totals
+}
+
+fn batch_overflow(solution: &Workload) -> SoftScore {
+    let overflow: i64 = batch_minutes_per_assignee(solution)
+        .values()
+        .map(|minutes| (minutes - CAPACITY_MINUTES).max(0))
+        .sum();
+    SoftScore::of(-overflow)
+}
+
+#[test]
+fn test_grouped_sum_tracks_batch_totals_through_inserts_and_retracts() {
+    let mut constraint = Uni::new(
+        ConstraintRef::new("", "Workload capacity"),
+        ImpactType::Penalty,
+        source(vec(|s: &Workload| &s.tasks), ChangeSource::Descriptor(0)),
+        TrueFilter,
+        |task: &Task| task.assignee,
+        sum(|task: &Task| task.minutes),
+        |_assignee: &usize, minutes: &i64| SoftScore::of((*minutes - CAPACITY_MINUTES).max(0)),
+        false,
+    );
+
+    let mut solution = Workload {
+        tasks: vec![
+            Task {
+                assignee: 1,
+                minutes: 300,
+            },
+            Task {
+                assignee: 1,
+                minutes: 240,
+            },
+            Task {
+                assignee: 2,
+                minutes: 120,
+            },
+            Task {
+                assignee: 3,
+                minutes: 500,
+            },
+        ],
+    };
+
+    let mut total = constraint.initialize(&solution);
+    assert_eq!(total, SoftScore::of(-80));
+    assert_eq!(total, batch_overflow(&solution));
+
+    // Move the only task of assignee 3 to assignee 2, emptying group 3.
+    total = total + constraint.on_retract(&solution, 3, 0);
+    solution.tasks[3].assignee = 2;
+    total = total + constraint.on_insert(&solution, 3, 0);
+    assert_eq!(total, batch_overflow(&solution));
+    assert_eq!(total, SoftScore::of(-(60 + 140)));
+    assert_eq!(constraint.match_count(&solution), 2);
+
+    // Shorten a task of assignee 1 so that it falls back under capacity.
+    total = total + constraint.on_retract(&solution, 1, 0);
+    solution.tasks[1].minutes = 60;
+    total = total + constraint.on_insert(&solution, 1, 0);
+    assert_eq!(total, batch_overflow(&solution));
+    assert_eq!(total, SoftScore::of(-140));
+
+    // Repopulate the emptied group with a new long task.
+    solution.tasks.push(Task {
+        assignee: 3,
+        minutes: 600,
+    });
+    total = total + constraint.on_insert(&solution, 4, 0);
+    assert_eq!(total, batch_overflow(&solution));
+    assert_eq!(total, SoftScore::of(-(140 + 120)));
+    assert_eq!(total, constraint.evaluate(&solution));
+    assert_eq!(
+        batch_minutes_per_assignee(&solution),
+        BTreeMap::from([(1, 360), (2, 620), (3, 600)])
+    );
+}
@@ -0,0 +1,150 @@
+// Unit tests for IncrementalHexaConstraint.
+
+use crate::api::constraint_set::IncrementalConstraint;
+use crate::constraint::IncrementalHexaConstraint;
+use crate::stream::collection_extract::{source, ChangeSource};
+use solverforge_core::score::SoftScore;
+use solverforge_core::{ConstraintRef, ImpactType};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Task {
+    team: u32,
+    load: i64,
+}
+
+#[derive(Clone)]
+struct Solution {
+    tasks: Vec<Task>,
+}
+
+fn tasks(s: &Solution) -> &[Task] {
+    s.tasks.as_slice()
+}
+
+fn team(team: u32, count: usize) -> Vec<Task> {
+    (0..count).map(|_| Task { team, load: 1 }).collect()
+}
+
+type Filter = fn(
+    &Solution,
+    &Task,
+    &Task,
+    &Task,
+    &Task,
+    &Task,
+    &Task,
+    usize,
+    usize,
+    usize,
+    usize,
+    usize,
+    usize,
+) -> bool;
+type Weight = fn(&Solution, &[Task], usize, usize, usize, usize, usize, usize) -> SoftScore;
+
+const ANY_SIX: Filter = |_s, _a, _b, _c, _d, _e, _f, _ai, _bi, _ci, _di, _ei, _fi| true;
+
+const UNIT_WEIGHT: Weight = |_s, _entities, _a, _b, _c, _d, _e, _f| SoftScore::of(1);
+
+fn cluster(
+    impact_type: ImpactType,
+    filter: Filter,
+    weight: Weight,
+) -> impl IncrementalConstraint<Solution, SoftScore> {
+    IncrementalHexaConstraint::new(
+        ConstraintRef::new("", "Cluster"),
+        impact_type,
+        source(
+            tasks as fn(&Solution) -> &[Task],
+            ChangeSource::Descriptor(0),
+        ),
+        |_s: &Solution, t: &Task, _idx: usize| t.team,
+        filter,
+        weight,
+        false,
+    )
+}
+
+#[test]
+fn test_hexa_constraint_evaluate() {
+    let constraint = cluster(ImpactType::Penalty, ANY_SIX, UNIT_WEIGHT);
+    let mut tasks = team(1, 6);
+    tasks.extend(team(2, 5));
+
+    // One sextuple on team 1; team 2 is one task short.
+    let solution = Solution { tasks };
+    assert_eq!(constraint.evaluate(&solution), SoftScore::of(-1));
+    assert_eq!(constraint.match_count(&solution), 1);
+}
+
+#[test]
+fn test_hexa_constraint_multiple_hexas() {
+    let constraint = cluster(ImpactType::Penalty, ANY_SIX, UNIT_WEIGHT);
+
+    // Seven tasks on one team = C(7,6) = 7 sextuples
+    let solution = Solution { tasks: team(1, 7) };
+    assert_eq!(constraint.evaluate(&solution), SoftScore::of(-7));
+    assert_eq!(constraint.get_matches(&solution).len(), 7);
+}
+
+#[test]
+fn test_hexa_constraint_reward() {
+    let constraint = cluster(
+        ImpactType::Reward,
+        ANY_SIX,
+        |_s, entities, a, _, _, _, _, f| SoftScore::of(entities[a].load + entities[f].load),
+    );
+
+    let solution = Solution { tasks: team(1, 6) };
+    assert_eq!(constraint.evaluate(&solution), SoftScore::of(2));
+}
+
+#[test]
+fn hexa_filter_receives_source_indexes() {
+    let mut constraint = cluster(
+        ImpactType::Penalty,
+        |_s, _a, _b, _c, _d, _e, _f, a_idx, b_idx, c_idx, d_idx, e_idx, f_idx| {
+            (a_idx, b_idx, c_idx, d_idx, e_idx, f_idx) == (1, 2, 3, 4, 5, 6)
+        },
+        UNIT_WEIGHT,
+    );
+    let solution = Solution { tasks: team(1, 7) };
+
+    assert_eq!(constraint.match_count(&solution), 1);
+    assert_eq!(constraint.evaluate(&solution), SoftScore::of(-1));
+    assert_eq!(constraint.initialize(&solution), SoftScore::of(-1));
+}
+
+#[test]
+fn test_hexa_constraint_incremental_deltas_match_full_recompute() {
+    // Sextuples only count while their total load stays under a cap.
+    let mut constraint = cluster(
+        ImpactType::Penalty,
+        |s, _a, _b, _c, _d, _e, _f, a_idx, b_idx, c_idx, d_idx, e_idx, f_idx| {
+            [a_idx, b_idx, c_idx, d_idx, e_idx, f_idx]
+                .iter()
+                .map(|&idx| s.tasks[idx].load)
+                .sum::<i64>()
+                <= 8
+        },
+        |_s, entities, a, _, _, _, _, _| SoftScore::of(entities[a].load),
+    );
+    let mut solution = Solution { tasks: team(1, 7) };
+
+    let mut total = constraint.initialize(&solution);
+    assert_eq!(total, constraint.evaluate(&solution));
+    assert_eq!(total, SoftScore::of(-7));
+
+    let edits = [(0, 2, 3), (3, 1, 2), (6, 2, 1), (0, 1, 1), (4, 1, 2)];
+    for (index, team, load) in edits {
+        total = total + constraint.on_retract(&solution, index, 0);
+        solution.tasks[index] = Task { team, load };
+        total = total + constraint.on_insert(&solution, index, 0);
+
+        assert_eq!(
+            total,
+            constraint.evaluate(&solution),
+            "incremental total diverged after editing task {index}"
+        );
+    }
+}
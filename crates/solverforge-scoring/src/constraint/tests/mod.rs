@@ -8,6 +8,8 @@ mod exists;
 mod exists_storage;
 mod flattened_bi;
 mod grouped;
+mod grouped_sum;
+mod hexa_incr;
 mod penta_incr;
 mod projected;
 mod quad_incr;
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::score::Score;
 
@@ -16,6 +18,7 @@ where
             .field("initialized", &self.is_initialized())
             .field("cached_score", &self.get_score())
             .field("constraint_count", &self.constraint_count())
+            .field("constraint_match_enabled", &self.constraint_match_enabled())
             .finish()
     }
 }
@@ -58,6 +61,15 @@ where
         self.after_variable_changed_impl(descriptor_index, entity_index);
     }
 
+    fn after_list_changed(
+        &mut self,
+        descriptor_index: usize,
+        entity_index: usize,
+        changed: &[Range<usize>],
+    ) {
+        self.after_list_changed_impl(descriptor_index, entity_index, changed);
+    }
+
     fn entity_count(&self, descriptor_index: usize) -> Option<usize> {
         Some((self.entity_counter)(
             &self.working_solution,
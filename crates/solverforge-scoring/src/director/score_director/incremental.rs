@@ -1,8 +1,10 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::score::Score;
 
+use crate::api::analysis::{DetailedConstraintMatch, IndictmentMap};
 use crate::api::constraint_set::{ConstraintMetadata, ConstraintSet};
 
 /* A zero-erasure score director for incremental scoring.
@@ -78,6 +80,8 @@ where
     throughout the solver pipeline.
     */
     pub(super) entity_counter: fn(&S, usize) -> usize,
+    // Whether detailed constraint matches and indictments are collected.
+    constraint_match_enabled: bool,
     _phantom: PhantomData<fn() -> S>,
 }
 
@@ -129,10 +133,21 @@ where
             initialized: false,
             solution_descriptor,
             entity_counter,
+            constraint_match_enabled: true,
             _phantom: PhantomData,
         }
     }
 
+    /* Enables or disables detailed constraint-match collection.
+
+    When disabled, `get_matches()` and `indictments()` return empty results
+    without evaluating the constraints. Scores are unaffected.
+    */
+    pub fn with_constraint_match_enabled(mut self, enabled: bool) -> Self {
+        self.constraint_match_enabled = enabled;
+        self
+    }
+
     /* =========================================================================
     Private implementation methods (shared between inherent and trait impl)
     =========================================================================
@@ -184,6 +199,23 @@ where
         self.cached_score = self.cached_score + delta;
     }
 
+    pub(crate) fn after_list_changed_impl(
+        &mut self,
+        descriptor_index: usize,
+        entity_index: usize,
+        changed: &[Range<usize>],
+    ) {
+        if !self.initialized {
+            return;
+        }
+        self.working_solution
+            .update_list_shadows(descriptor_index, entity_index, changed);
+        let delta =
+            self.constraints
+                .on_insert_all(&self.working_solution, entity_index, descriptor_index);
+        self.cached_score = self.cached_score + delta;
+    }
+
     pub(crate) fn reset_impl(&mut self) {
         self.constraints.reset_all();
         self.initialized = false;
@@ -359,6 +391,34 @@ where
             .collect()
     }
 
+    // Returns whether detailed constraint matches are collected.
+    pub fn constraint_match_enabled(&self) -> bool {
+        self.constraint_match_enabled
+    }
+
+    /* Returns every constraint match with its justification.
+
+    Empty when constraint-match collection is disabled.
+    */
+    pub fn get_matches(&self) -> Vec<DetailedConstraintMatch<'_, S::Score>> {
+        if !self.constraint_match_enabled {
+            return Vec::new();
+        }
+        self.constraints
+            .evaluate_detailed(&self.working_solution)
+            .into_iter()
+            .flat_map(|analysis| analysis.matches)
+            .collect()
+    }
+
+    /* Groups constraint matches by the entities they justify.
+
+    Empty when constraint-match collection is disabled.
+    */
+    pub fn indictments(&self) -> IndictmentMap<'_, S::Score> {
+        IndictmentMap::from_matches(self.get_matches())
+    }
+
     /* Consumes the director and returns the working solution.
 
     Use this to extract the final solution after solving.
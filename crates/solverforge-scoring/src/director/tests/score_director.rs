@@ -307,3 +307,28 @@ fn test_add_then_remove_value() {
     director.do_change(0, 0, |s| s.values[0] = None);
     assert_eq!(director.get_score(), SoftScore::of(-2));
 }
+
+#[test]
+fn get_matches_follows_constraint_match_toggle() {
+    let solution = TestSolution {
+        values: vec![Some(1), None, None, Some(2)],
+        score: None,
+    };
+
+    let mut enabled = ScoreDirector::new(solution.clone(), (make_unassigned_constraint(),));
+    assert!(enabled.constraint_match_enabled());
+    assert_eq!(enabled.calculate_score(), SoftScore::of(-2));
+    let matches = enabled.get_matches();
+    assert_eq!(matches.len(), 2);
+    assert!(matches
+        .iter()
+        .all(|m| m.constraint_ref.name == "Unassigned" && m.score == SoftScore::of(-1)));
+    assert!(!enabled.indictments().is_empty());
+
+    let mut disabled = ScoreDirector::new(solution, (make_unassigned_constraint(),))
+        .with_constraint_match_enabled(false);
+    assert!(!disabled.constraint_match_enabled());
+    assert_eq!(disabled.calculate_score(), SoftScore::of(-2));
+    assert!(disabled.get_matches().is_empty());
+    assert!(disabled.indictments().is_empty());
+}
@@ -1,5 +1,7 @@
 // Score director trait definition.
 
+use std::ops::Range;
+
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::ConstraintRef;
 
@@ -51,12 +53,36 @@ pub trait Director<S: PlanningSolution>: Send {
     // Called after a planning variable is changed.
     fn after_variable_changed(&mut self, descriptor_index: usize, entity_index: usize);
 
+    /* Called after a list variable is changed, in place of `after_variable_changed`.
+
+    `changed` holds final-position windows as described by
+    `PlanningSolution::update_list_shadows`, so shadow maintenance can touch
+    only the boundary elements. Defaults to a whole-entity notification.
+    */
+    fn after_list_changed(
+        &mut self,
+        descriptor_index: usize,
+        entity_index: usize,
+        _changed: &[Range<usize>],
+    ) {
+        self.after_variable_changed(descriptor_index, entity_index);
+    }
+
     // Returns the number of entities for a given descriptor index.
     fn entity_count(&self, descriptor_index: usize) -> Option<usize>;
 
     // Returns the total number of entities across all collections.
     fn total_entity_count(&self) -> Option<usize>;
 
+    // Returns true if the entity is pinned and must not be changed by moves.
+    fn is_entity_pinned(&self, descriptor_index: usize, entity_index: usize) -> bool {
+        self.solution_descriptor().is_entity_pinned(
+            self.working_solution(),
+            descriptor_index,
+            entity_index,
+        )
+    }
+
     // Returns immutable scoring-constraint metadata known to this director.
     fn constraint_metadata(&self) -> Vec<ConstraintMetadata<'_>>;
 
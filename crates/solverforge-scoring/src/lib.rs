@@ -26,8 +26,9 @@ Zero-Erasure Incremental Constraints
 */
 
 pub use constraint::{
-    IncrementalBiConstraint, IncrementalPentaConstraint, IncrementalQuadConstraint,
-    IncrementalTriConstraint, IncrementalUniConstraint, ListPrecedenceMakespanConstraint,
+    IncrementalBiConstraint, IncrementalHexaConstraint, IncrementalPentaConstraint,
+    IncrementalQuadConstraint, IncrementalTriConstraint, IncrementalUniConstraint,
+    ListPrecedenceMakespanConstraint,
 };
 
 /* ============================================================================
@@ -62,6 +62,10 @@ where
     `ConstraintFactory::new().for_each(Schedule::shifts())`.
     Low-level callers can still pass any `CollectionExtract<S, Item = A>`.
     The extractor type is preserved as a concrete generic for full zero-erasure.
+
+    A following `.filter(pred)` is fused into the stream's filter chain, so the
+    predicate runs once per extracted item and rejected items never reach
+    joins, grouping, or scoring; there is no separate filtered entry point.
     */
     pub fn for_each<A, E>(self, extractor: E) -> UniConstraintStream<S, A, E, TrueFilter, Sc>
     where
@@ -146,6 +146,18 @@ where
         }
     }
 
+    fn progress_updated(&mut self, progress: f64) {
+        match self {
+            Self::HillClimbing(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::StepCountingHillClimbing(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::TabuSearch(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::SimulatedAnnealing(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::LateAcceptance(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::DiversifiedLateAcceptance(a) => Acceptor::<S>::progress_updated(a, progress),
+            Self::GreatDeluge(a) => Acceptor::<S>::progress_updated(a, progress),
+        }
+    }
+
     fn step_started(&mut self) {
         match self {
             Self::HillClimbing(a) => Acceptor::<S>::step_started(a),
@@ -187,6 +199,18 @@ where
             }
         }
     }
+
+    fn temperature(&self) -> Option<f64> {
+        match self {
+            Self::HillClimbing(a) => Acceptor::<S>::temperature(a),
+            Self::StepCountingHillClimbing(a) => Acceptor::<S>::temperature(a),
+            Self::TabuSearch(a) => Acceptor::<S>::temperature(a),
+            Self::SimulatedAnnealing(a) => Acceptor::<S>::temperature(a),
+            Self::LateAcceptance(a) => Acceptor::<S>::temperature(a),
+            Self::DiversifiedLateAcceptance(a) => Acceptor::<S>::temperature(a),
+            Self::GreatDeluge(a) => Acceptor::<S>::temperature(a),
+        }
+    }
 }
 
 /// Builder for constructing acceptors from configuration.
@@ -201,6 +225,22 @@ impl AcceptorBuilder {
         Self::build_with_seed(config, None)
     }
 
+    /// Checks settings that would otherwise panic when the acceptor is built.
+    ///
+    /// An auto-tuned great deluge needs a `target_acceptance_rate` in (0, 1);
+    /// an out-of-range rate is returned as an error naming the setting.
+    pub fn validate(config: &AcceptorConfig) -> Result<(), String> {
+        if let AcceptorConfig::GreatDeluge(gd_config) = config {
+            let target = gd_config.target_acceptance_rate.unwrap_or(0.2);
+            if gd_config.auto && !(target > 0.0 && target < 1.0) {
+                return Err(format!(
+                    "acceptor.target_acceptance_rate must be in (0, 1), got {target}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Builds a concrete [`AnyAcceptor`] from configuration with an optional deterministic seed.
     pub fn build_with_seed<S: PlanningSolution>(
         config: &AcceptorConfig,
@@ -241,7 +281,12 @@ impl AcceptorBuilder {
 
             AcceptorConfig::GreatDeluge(gd_config) => {
                 let rain_speed = gd_config.water_level_increase_ratio.unwrap_or(0.001);
-                AnyAcceptor::GreatDeluge(GreatDelugeAcceptor::<S>::new(rain_speed))
+                if gd_config.auto {
+                    let target = gd_config.target_acceptance_rate.unwrap_or(0.2);
+                    AnyAcceptor::GreatDeluge(GreatDelugeAcceptor::<S>::auto(rain_speed, target))
+                } else {
+                    AnyAcceptor::GreatDeluge(GreatDelugeAcceptor::<S>::new(rain_speed))
+                }
             }
         }
     }
@@ -294,9 +339,9 @@ where
         }
     };
 
-    if let Some(level_temperatures) = &config.level_temperatures {
+    let acceptor = if let Some(level_temperatures) = &config.level_temperatures {
         validate_level_temperatures(level_temperatures, level_count);
-        return match random_seed {
+        match random_seed {
             Some(seed) => SimulatedAnnealingAcceptor::with_level_temperatures_and_seed(
                 level_temperatures.clone(),
                 decay_rate,
@@ -310,25 +355,29 @@ where
                 hill_climbing_temperature,
                 hard_regression_policy,
             ),
-        };
-    }
-
-    let calibration = normalize_simulated_annealing_calibration(config.calibration.as_ref());
-    validate_simulated_annealing_calibration(calibration);
-    match random_seed {
-        Some(seed) => SimulatedAnnealingAcceptor::with_calibration_and_seed(
-            decay_rate,
-            hill_climbing_temperature,
-            hard_regression_policy,
-            calibration,
-            seed,
-        ),
-        None => SimulatedAnnealingAcceptor::with_calibration(
-            decay_rate,
-            hill_climbing_temperature,
-            hard_regression_policy,
-            calibration,
-        ),
+        }
+    } else {
+        let calibration = normalize_simulated_annealing_calibration(config.calibration.as_ref());
+        validate_simulated_annealing_calibration(calibration);
+        match random_seed {
+            Some(seed) => SimulatedAnnealingAcceptor::with_calibration_and_seed(
+                decay_rate,
+                hill_climbing_temperature,
+                hard_regression_policy,
+                calibration,
+                seed,
+            ),
+            None => SimulatedAnnealingAcceptor::with_calibration(
+                decay_rate,
+                hill_climbing_temperature,
+                hard_regression_policy,
+                calibration,
+            ),
+        }
+    };
+    match config.ending_temperature {
+        Some(ending_temperature) => acceptor.with_ending_temperature(ending_temperature),
+        None => acceptor,
     }
 }
 
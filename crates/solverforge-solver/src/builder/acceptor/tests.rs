@@ -1,6 +1,6 @@
 use super::*;
 use solverforge_config::{
-    AcceptorConfig, HardRegressionPolicyConfig, LateAcceptanceConfig,
+    AcceptorConfig, GreatDelugeConfig, HardRegressionPolicyConfig, LateAcceptanceConfig,
     SimulatedAnnealingCalibrationConfig, SimulatedAnnealingConfig, TabuSearchConfig,
 };
 use solverforge_core::score::{HardSoftScore, SoftScore};
@@ -139,6 +139,7 @@ fn test_acceptor_builder_simulated_annealing() {
     let config = AcceptorConfig::SimulatedAnnealing(SimulatedAnnealingConfig {
         level_temperatures: Some(vec![2.0]),
         decay_rate: None,
+        ending_temperature: None,
         hill_climbing_temperature: None,
         hard_regression_policy: None,
         calibration: None,
@@ -151,6 +152,7 @@ fn test_acceptor_builder_simulated_annealing_accepts_fractional_level_temperatur
     let config = AcceptorConfig::SimulatedAnnealing(SimulatedAnnealingConfig {
         level_temperatures: Some(vec![2.5]),
         decay_rate: None,
+        ending_temperature: None,
         hill_climbing_temperature: None,
         hard_regression_policy: None,
         calibration: None,
@@ -158,6 +160,19 @@ fn test_acceptor_builder_simulated_annealing_accepts_fractional_level_temperatur
     let _acceptor: AnyAcceptor<TestSolution> = AcceptorBuilder::build(&config);
 }
 
+#[test]
+fn test_acceptor_builder_simulated_annealing_applies_ending_temperature() {
+    let config = AcceptorConfig::SimulatedAnnealing(SimulatedAnnealingConfig {
+        level_temperatures: Some(vec![10.0]),
+        ending_temperature: Some(2.0),
+        ..Default::default()
+    });
+    let mut acceptor: AnyAcceptor<TestSolution> = AcceptorBuilder::build(&config);
+    acceptor.phase_started(&SoftScore::of(0));
+    acceptor.progress_updated(0.5);
+    assert_eq!(acceptor.temperature(), Some(6.0));
+}
+
 #[test]
 fn test_acceptor_builder_simulated_annealing_accepts_hard_regression_policy() {
     let config = AcceptorConfig::SimulatedAnnealing(SimulatedAnnealingConfig {
@@ -218,3 +233,91 @@ fn test_acceptor_builder_late_acceptance() {
     });
     let _acceptor: AnyAcceptor<TestSolution> = AcceptorBuilder::build(&config);
 }
+
+#[test]
+fn test_acceptor_builder_great_deluge_auto_mode() {
+    let config = AcceptorConfig::GreatDeluge(GreatDelugeConfig {
+        water_level_increase_ratio: Some(0.01),
+        auto: true,
+        target_acceptance_rate: Some(0.3),
+    });
+    let acceptor: AnyAcceptor<TestSolution> = AcceptorBuilder::build(&config);
+    let AnyAcceptor::GreatDeluge(acceptor) = acceptor else {
+        panic!("great_deluge config should build a great deluge acceptor");
+    };
+    assert_eq!(acceptor.rain_speed(), 0.01);
+    assert!(format!("{acceptor:?}").contains("target_acceptance_rate: Some(0.3)"));
+}
+
+#[test]
+fn test_acceptor_builder_rejects_out_of_range_great_deluge_target() {
+    for target in [0.0, 1.0, 1.5, f64::NAN] {
+        let config = AcceptorConfig::GreatDeluge(GreatDelugeConfig {
+            water_level_increase_ratio: None,
+            auto: true,
+            target_acceptance_rate: Some(target),
+        });
+        assert_eq!(
+            AcceptorBuilder::validate(&config).unwrap_err(),
+            format!("acceptor.target_acceptance_rate must be in (0, 1), got {target}")
+        );
+    }
+}
+
+#[test]
+fn test_acceptor_builder_accepts_default_great_deluge_target() {
+    let config = AcceptorConfig::GreatDeluge(GreatDelugeConfig {
+        water_level_increase_ratio: None,
+        auto: true,
+        target_acceptance_rate: None,
+    });
+    assert_eq!(AcceptorBuilder::validate(&config), Ok(()));
+}
+
+#[test]
+fn test_acceptor_builder_maps_each_config_type_to_its_acceptor() {
+    fn build_from_toml(acceptor_type: &str) -> AnyAcceptor<TestSolution> {
+        let toml = format!(
+            r#"
+            [[phases]]
+            type = "local_search"
+            [phases.acceptor]
+            type = "{acceptor_type}"
+            "#
+        );
+        let config = solverforge_config::SolverConfig::from_toml_str(&toml).unwrap();
+        let solverforge_config::PhaseConfig::LocalSearch(local_search) = &config.phases[0] else {
+            panic!("phase should be local search");
+        };
+        AcceptorBuilder::build(local_search.acceptor.as_ref().unwrap())
+    }
+
+    assert!(matches!(
+        build_from_toml("hill_climbing"),
+        AnyAcceptor::HillClimbing(_)
+    ));
+    assert!(matches!(
+        build_from_toml("step_counting_hill_climbing"),
+        AnyAcceptor::StepCountingHillClimbing(_)
+    ));
+    assert!(matches!(
+        build_from_toml("tabu_search"),
+        AnyAcceptor::TabuSearch(_)
+    ));
+    assert!(matches!(
+        build_from_toml("simulated_annealing"),
+        AnyAcceptor::SimulatedAnnealing(_)
+    ));
+    assert!(matches!(
+        build_from_toml("late_acceptance"),
+        AnyAcceptor::LateAcceptance(_)
+    ));
+    assert!(matches!(
+        build_from_toml("diversified_late_acceptance"),
+        AnyAcceptor::DiversifiedLateAcceptance(_)
+    ));
+    assert!(matches!(
+        build_from_toml("great_deluge"),
+        AnyAcceptor::GreatDeluge(_)
+    ));
+}
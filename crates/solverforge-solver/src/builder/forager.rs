@@ -134,16 +134,26 @@ impl ForagerBuilder {
         match cfg {
             ForagerConfig::AcceptedCount(accepted) => {
                 let limit = accepted.limit.unwrap_or(1).max(1);
-                AnyForager::AcceptedCount(AcceptedCountForager::new(limit, random_ties))
+                AnyForager::AcceptedCount(
+                    AcceptedCountForager::new(limit, random_ties)
+                        .with_pick_early_type(accepted.pick_early_type),
+                )
             }
             ForagerConfig::BestScore => AnyForager::BestScore(BestScoreForager::new(random_ties)),
             ForagerConfig::FirstAccepted => AnyForager::FirstAccepted(FirstAcceptedForager::new()),
             ForagerConfig::FirstBestScoreImproving => {
                 AnyForager::BestScoreImproving(FirstBestScoreImprovingForager::new(random_ties))
             }
-            ForagerConfig::FirstLastStepScoreImproving => AnyForager::LastStepScoreImproving(
-                FirstLastStepScoreImprovingForager::new(random_ties),
-            ),
+            ForagerConfig::FirstLastStepScoreImproving(improving) => {
+                let forager = FirstLastStepScoreImprovingForager::new(random_ties);
+                AnyForager::LastStepScoreImproving(match improving.accepted_count_limit {
+                    Some(limit) => forager.with_accepted_count_limit(limit),
+                    None => forager,
+                })
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,101 @@
+use super::*;
+use crate::heuristic::r#move::ChangeMove;
+use solverforge_config::PhaseConfig;
+use solverforge_config::{FirstLastStepScoreImprovingForagerConfig, SolverConfig};
+use solverforge_core::score::SoftScore;
+
+#[derive(Clone, Debug)]
+struct TestSolution {
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for TestSolution {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+type TestMove = ChangeMove<TestSolution, i32>;
+
+fn accepted_count_limit(forager: &AnyForager<TestSolution>) -> Option<usize> {
+    <AnyForager<TestSolution> as LocalSearchForager<TestSolution, TestMove>>::accepted_count_limit(
+        forager,
+    )
+}
+
+#[test]
+fn last_step_improving_forager_honors_configured_accepted_count_limit() {
+    let config = SolverConfig::from_toml_str(
+        r#"
+        [[phases]]
+        type = "local_search"
+        [phases.forager]
+        type = "first_last_step_score_improving"
+        accepted_count_limit = 16
+    "#,
+    )
+    .unwrap();
+    let PhaseConfig::LocalSearch(local_search) = &config.phases[0] else {
+        panic!("phase should be local_search");
+    };
+
+    let forager =
+        ForagerBuilder::build::<TestSolution>(local_search.forager.as_ref(), ScoreTieBreak::First);
+
+    assert!(matches!(forager, AnyForager::LastStepScoreImproving(_)));
+    assert_eq!(accepted_count_limit(&forager), Some(16));
+}
+
+#[test]
+fn last_step_improving_forager_is_unbounded_without_limit() {
+    let config = ForagerConfig::FirstLastStepScoreImproving(
+        FirstLastStepScoreImprovingForagerConfig::default(),
+    );
+
+    let forager = ForagerBuilder::build::<TestSolution>(Some(&config), ScoreTieBreak::First);
+
+    assert_eq!(accepted_count_limit(&forager), None);
+}
+
+#[test]
+fn accepted_count_forager_honors_configured_pick_early_type() {
+    let config = SolverConfig::from_toml_str(
+        r#"
+        [[phases]]
+        type = "local_search"
+        [phases.forager]
+        type = "accepted_count"
+        limit = 8
+        pick_early_type = "first_best_score_improving"
+    "#,
+    )
+    .unwrap();
+    let PhaseConfig::LocalSearch(local_search) = &config.phases[0] else {
+        panic!("phase should be local_search");
+    };
+    let mut forager =
+        ForagerBuilder::build::<TestSolution>(local_search.forager.as_ref(), ScoreTieBreak::First);
+
+    <AnyForager<TestSolution> as LocalSearchForager<TestSolution, TestMove>>::step_started(
+        &mut forager,
+        SoftScore::of(-3),
+        SoftScore::of(-3),
+        0,
+    );
+    <AnyForager<TestSolution> as LocalSearchForager<TestSolution, TestMove>>::add_move_index(
+        &mut forager,
+        CandidateId::new(0),
+        SoftScore::of(-1),
+    );
+
+    assert!(<AnyForager<TestSolution> as LocalSearchForager<
+        TestSolution,
+        TestMove,
+    >>::is_quit_early(&forager));
+}
@@ -0,0 +1,41 @@
+// Restart strategy builder for local search phases.
+
+use solverforge_config::LocalSearchRestartConfig;
+
+use crate::phase::localsearch::RestartStrategy;
+
+/// Builder for constructing a [`RestartStrategy`] from configuration.
+pub struct RestartBuilder;
+
+impl RestartBuilder {
+    /// Builds the configured restart strategy; `None` keeps restarts off.
+    ///
+    /// Omitted counts default to one. A zero count is returned as an error
+    /// naming the setting, matching what `SolverConfig::validate` rejects.
+    pub fn build(
+        config: Option<&LocalSearchRestartConfig>,
+    ) -> Result<Option<RestartStrategy>, String> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        let stuck_step_count = config.stuck_step_count.unwrap_or(1);
+        let perturbation_size = config.perturbation_size.unwrap_or(1);
+        for (name, value) in [
+            ("max_restarts", config.max_restarts),
+            ("stuck_step_count", stuck_step_count),
+            ("perturbation_size", perturbation_size as u64),
+        ] {
+            if value == 0 {
+                return Err(format!("restart.{name} must be >= 1"));
+            }
+        }
+        Ok(Some(
+            RestartStrategy::new(config.max_restarts)
+                .with_stuck_step_count(stuck_step_count)
+                .with_perturbation_size(perturbation_size),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,43 @@
+use super::*;
+
+fn restart_config(
+    max_restarts: u64,
+    stuck_step_count: Option<u64>,
+    perturbation_size: Option<usize>,
+) -> LocalSearchRestartConfig {
+    LocalSearchRestartConfig {
+        max_restarts,
+        stuck_step_count,
+        perturbation_size,
+    }
+}
+
+#[test]
+fn omitted_restart_builds_nothing() {
+    assert!(RestartBuilder::build(None).unwrap().is_none());
+}
+
+#[test]
+fn omitted_counts_default_to_one() {
+    let strategy = RestartBuilder::build(Some(&restart_config(3, None, None)))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(strategy.max_restarts(), 3);
+    assert_eq!(strategy.stuck_step_count(), 1);
+    assert_eq!(strategy.perturbation_size(), 1);
+}
+
+#[test]
+fn zero_counts_are_rejected_by_name() {
+    for (config, name) in [
+        (restart_config(0, None, None), "max_restarts"),
+        (restart_config(1, Some(0), None), "stuck_step_count"),
+        (restart_config(1, None, Some(0)), "perturbation_size"),
+    ] {
+        assert_eq!(
+            RestartBuilder::build(Some(&config)).unwrap_err(),
+            format!("restart.{name} must be >= 1")
+        );
+    }
+}
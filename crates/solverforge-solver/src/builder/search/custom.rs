@@ -74,8 +74,8 @@ where
     }
 }
 
-impl<S, PD, Part, SDF, PF, CP> CustomSearchPhase<S>
-    for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP>
+impl<S, PD, Part, SDF, PF, CP, RF> CustomSearchPhase<S>
+    for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF>
 where
     S: PlanningSolution + 'static,
     PD: Director<S> + 'static,
@@ -83,6 +83,7 @@ where
     SDF: Fn(S) -> PD + Send + Sync,
     PF: Fn() -> CP + Send + Sync,
     CP: ChildPhases<S, PD> + Send,
+    RF: Fn() -> CP + Send + Sync,
 {
     fn solve<D, ProgressCb>(&mut self, solver_scope: &mut SolverScope<'_, S, D, ProgressCb>)
     where
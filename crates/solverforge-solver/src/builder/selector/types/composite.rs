@@ -101,6 +101,7 @@ where
     left: Box<SelectorCompositionChild<S, M, Flat, FlatState>>,
     right: Box<SelectorCompositionChild<S, M, Flat, FlatState>>,
     require_hard_improvement: bool,
+    max_moves: Option<usize>,
     _marker: PhantomData<fn() -> (S, M, FlatState)>,
 }
 
@@ -117,6 +118,7 @@ where
             left: Box::new(left),
             right: Box::new(right),
             require_hard_improvement: false,
+            max_moves: None,
             _marker: PhantomData,
         }
     }
@@ -125,6 +127,12 @@ where
         self.require_hard_improvement = require_hard_improvement;
         self
     }
+
+    /// Caps the number of composed pairs yielded per cursor.
+    pub fn with_max_moves(mut self, max_moves: Option<usize>) -> Self {
+        self.max_moves = max_moves;
+        self
+    }
 }
 
 impl<S, M, Flat, FlatState> Debug for SelectorCompositionCartesian<S, M, Flat, FlatState>
@@ -138,6 +146,7 @@ where
             .field("left", &self.left)
             .field("right", &self.right)
             .field("require_hard_improvement", &self.require_hard_improvement)
+            .field("max_moves", &self.max_moves)
             .finish()
     }
 }
@@ -61,6 +61,7 @@ where
     Flat: StatefulComposedFlat<S, M, FlatState, Resources> + 'a,
 {
     require_hard_improvement: bool,
+    max_moves: Option<usize>,
     left_cursor: Box<SelectorCompositionCursor<'a, S, M, Flat, FlatState, Resources>>,
     right_selector: &'a SelectorCompositionChild<S, M, Flat, FlatState>,
     right_stream_state: Option<SelectorCompositionStreamState<FlatState>>,
@@ -80,6 +81,7 @@ where
 {
     pub(super) fn new<D: Director<S>>(
         require_hard_improvement: bool,
+        max_moves: Option<usize>,
         left_cursor: SelectorCompositionCursor<'a, S, M, Flat, FlatState, Resources>,
         right_selector: &'a SelectorCompositionChild<S, M, Flat, FlatState>,
         right_stream_state: SelectorCompositionStreamState<FlatState>,
@@ -91,6 +93,7 @@ where
     {
         Self {
             require_hard_improvement,
+            max_moves,
             left_cursor: Box::new(left_cursor),
             right_selector,
             right_stream_state: Some(right_stream_state),
@@ -262,6 +265,9 @@ where
     Flat: StatefulComposedFlat<S, M, FlatState, Resources>,
 {
     fn next_candidate_with_resources(&mut self, resources: &mut Resources) -> Option<CandidateId> {
+        if self.max_moves.is_some_and(|max| self.pairs.len() >= max) {
+            return None;
+        }
         loop {
             if self.active_row.is_some() {
                 let inspected = {
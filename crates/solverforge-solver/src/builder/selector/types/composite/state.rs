@@ -164,6 +164,7 @@ where
             );
             SelectorCompositionCursor::Cartesian(SelectorCompositionCartesianCursor::new(
                 selector.require_hard_improvement,
+                selector.max_moves,
                 left_cursor,
                 &selector.right,
                 *right,
@@ -195,8 +196,11 @@ where
             .iter()
             .map(|child| selector_size(child, score_director))
             .sum(),
-        SelectorComposition::Cartesian(selector) => selector_size(&selector.left, score_director)
-            .saturating_mul(selector_size(&selector.right, score_director)),
+        SelectorComposition::Cartesian(selector) => {
+            let size = selector_size(&selector.left, score_director)
+                .saturating_mul(selector_size(&selector.right, score_director));
+            selector.max_moves.map_or(size, |max| size.min(max))
+        }
     }
 }
 
@@ -3,7 +3,9 @@ mod move_types;
 mod selectors;
 
 pub use bindings::descriptor_has_bindings;
-pub(crate) use bindings::{collect_bindings, ResolvedVariableBinding};
+pub(crate) use bindings::{
+    collect_bindings, find_binding, ResolvedVariableBinding, VariableBinding,
+};
 pub use move_types::{
     DescriptorChangeMove, DescriptorMoveUnion, DescriptorPillarChangeMove,
     DescriptorPillarSwapMove, DescriptorRuinRecreateMove, DescriptorSwapMove,
@@ -101,6 +101,10 @@ where
         self.binding.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "change"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let current = self.current_value(score_director.working_solution());
         let from_id = encode_option_usize(current);
@@ -136,6 +136,10 @@ where
         self.binding.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "pillar_change"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let from_value = self.entity_indices.first().and_then(|&entity_index| {
             self.current_value(score_director.working_solution(), entity_index)
@@ -149,6 +149,10 @@ where
         self.binding.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "swap"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let left_val =
             self.current_value(score_director.working_solution(), self.left_entity_index);
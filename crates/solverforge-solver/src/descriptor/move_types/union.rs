@@ -134,6 +134,26 @@ where
         }
     }
 
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Change(m) => m.type_name(),
+            Self::Swap(m) => m.type_name(),
+            Self::PillarChange(m) => m.type_name(),
+            Self::PillarSwap(m) => m.type_name(),
+            Self::RuinRecreate(m) => m.type_name(),
+        }
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        match self {
+            Self::Change(m) => m.affected_entities(),
+            Self::Swap(m) => m.affected_entities(),
+            Self::PillarChange(m) => m.affected_entities(),
+            Self::PillarSwap(m) => m.affected_entities(),
+            Self::RuinRecreate(m) => m.affected_entities(),
+        }
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         match self {
             Self::Change(m) => m.tabu_signature(score_director),
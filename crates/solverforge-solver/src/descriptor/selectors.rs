@@ -7,7 +7,7 @@ use std::marker::PhantomData;
 use rand::rngs::SmallRng;
 use rand::{RngExt, SeedableRng};
 use smallvec::SmallVec;
-use solverforge_config::{MoveSelectorConfig, RecreateHeuristicType};
+use solverforge_config::{MoveSelectorConfig, RecreateHeuristicType, UnionWeighting};
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor, ValueRangeType};
 use solverforge_core::score::Score;
 use solverforge_scoring::Director;
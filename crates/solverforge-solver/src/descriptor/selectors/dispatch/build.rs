@@ -367,7 +367,8 @@ where
                 );
                 nodes.push(DescriptorSelectorNode::Cartesian(
                     CartesianProductSelector::new(left, right)
-                        .with_require_hard_improvement(cartesian.require_hard_improvement),
+                        .with_require_hard_improvement(cartesian.require_hard_improvement)
+                        .with_max_moves(cartesian.max_moves),
                 ));
             }
             other => {
@@ -382,14 +383,75 @@ where
     }
 
     let mut nodes = Vec::new();
-    collect_nodes::<S>(config, descriptor, random_seed, &mut nodes);
+    let mut child_node_counts = Vec::new();
+    match config {
+        Some(MoveSelectorConfig::UnionMoveSelector(union)) => {
+            for child in &union.selectors {
+                let before = nodes.len();
+                collect_nodes::<S>(Some(child), descriptor, random_seed, &mut nodes);
+                child_node_counts.push(nodes.len() - before);
+            }
+        }
+        _ => collect_nodes::<S>(config, descriptor, random_seed, &mut nodes),
+    }
     assert!(
         !nodes.is_empty(),
         "move selector configuration produced no scalar neighborhoods"
     );
-    let selection_order = match config {
-        Some(MoveSelectorConfig::UnionMoveSelector(union)) => union.selection_order,
-        _ => solverforge_config::UnionSelectionOrder::default(),
-    };
-    VecUnionSelector::with_selection_order(nodes, selection_order)
+    match config {
+        Some(MoveSelectorConfig::UnionMoveSelector(union)) => {
+            let weights =
+                expand_union_weights(union.weighting, &union.weights, &child_node_counts);
+            VecUnionSelector::with_selection_order(nodes, union.selection_order)
+                .with_weighting(union.weighting, weights)
+        }
+        _ => VecUnionSelector::with_selection_order(
+            nodes,
+            solverforge_config::UnionSelectionOrder::default(),
+        ),
+    }
+}
+
+/// Spreads per-child fixed union weights over the nodes each child expanded into.
+///
+/// A child that expands into several nodes (one per matched variable) splits
+/// its weight evenly, so each child keeps its configured share of draws.
+/// Non-fixed weightings carry no explicit weights.
+fn expand_union_weights(
+    weighting: UnionWeighting,
+    fixed: &[u64],
+    child_node_counts: &[usize],
+) -> Vec<u64> {
+    if weighting != UnionWeighting::Fixed {
+        return fixed.to_vec();
+    }
+    assert_eq!(
+        fixed.len(),
+        child_node_counts.len(),
+        "fixed union weight count must match child count"
+    );
+    let common = child_node_counts
+        .iter()
+        .filter(|count| **count > 0)
+        .fold(1u64, |acc, count| lcm(acc, *count as u64));
+    fixed
+        .iter()
+        .zip(child_node_counts)
+        .flat_map(|(weight, count)| {
+            let node_weight = if *count == 0 {
+                0
+            } else {
+                weight.saturating_mul(common / *count as u64)
+            };
+            std::iter::repeat_n(node_weight, *count)
+        })
+        .collect()
+}
+
+fn lcm(left: u64, right: u64) -> u64 {
+    let (mut a, mut b) = (left, right);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    left / a * right
 }
@@ -24,3 +24,4 @@ use super::build_descriptor_move_selector;
 include!("mod/support.rs");
 include!("mod/selectors.rs");
 include!("mod/ruin_recreate.rs");
+include!("mod/statistics.rs");
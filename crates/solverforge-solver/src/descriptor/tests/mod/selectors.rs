@@ -2,3 +2,4 @@
 include!("selectors/swap_nearby.rs");
 include!("selectors/pillar.rs");
 include!("selectors/cartesian.rs");
+include!("selectors/union_weights.rs");
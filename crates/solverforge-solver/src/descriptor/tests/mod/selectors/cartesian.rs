@@ -17,6 +17,7 @@ fn descriptor_cartesian_builds_composite_moves() {
     let director = ScoreDirector::simple(plan, descriptor.clone(), |s, _| s.tasks.len());
     let config = MoveSelectorConfig::CartesianProductMoveSelector(CartesianProductConfig {
         require_hard_improvement: false,
+        max_moves: None,
         selectors: vec![
             MoveSelectorConfig::ChangeMoveSelector(ChangeMoveConfig {
                 selection_order: None,
@@ -74,6 +75,7 @@ fn descriptor_cartesian_selector_survives_filtering_wrapper() {
     let director = ScoreDirector::simple(plan, descriptor.clone(), |s, _| s.tasks.len());
     let config = MoveSelectorConfig::CartesianProductMoveSelector(CartesianProductConfig {
         require_hard_improvement: false,
+        max_moves: None,
         selectors: vec![
             MoveSelectorConfig::ChangeMoveSelector(ChangeMoveConfig {
                 selection_order: None,
@@ -113,6 +115,7 @@ fn descriptor_cartesian_rejects_score_seeking_left_child() {
     let descriptor = descriptor();
     let config = MoveSelectorConfig::CartesianProductMoveSelector(CartesianProductConfig {
         require_hard_improvement: false,
+        max_moves: None,
         selectors: vec![
             MoveSelectorConfig::RuinRecreateMoveSelector(RuinRecreateMoveSelectorConfig::default()),
             MoveSelectorConfig::ChangeMoveSelector(ChangeMoveConfig {
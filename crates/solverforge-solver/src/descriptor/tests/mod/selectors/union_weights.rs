@@ -0,0 +1,54 @@
+fn weighted_union_draws(seed: u64, draws: usize) -> Vec<&'static str> {
+    use crate::heuristic::selector::move_selector::MoveStreamContext;
+    use solverforge_config::{UnionMoveSelectorConfig, UnionSelectionOrder, UnionWeighting};
+
+    let descriptor = descriptor_with_allows_unassigned(false);
+    let plan = Plan {
+        workers: vec![Worker; 40],
+        tasks: (0..40)
+            .map(|index| Task {
+                worker_idx: Some(index),
+            })
+            .collect(),
+        score: None,
+    };
+    let director = ScoreDirector::simple(plan, descriptor.clone(), |s, _| s.tasks.len());
+    let config = MoveSelectorConfig::UnionMoveSelector(UnionMoveSelectorConfig {
+        selection_order: UnionSelectionOrder::Random,
+        weighting: UnionWeighting::Fixed,
+        weights: vec![3, 1],
+        selectors: vec![
+            MoveSelectorConfig::ChangeMoveSelector(ChangeMoveConfig::default()),
+            MoveSelectorConfig::SwapMoveSelector(SwapMoveConfig::default()),
+        ],
+    });
+
+    let selector = build_descriptor_move_selector::<Plan>(Some(&config), &descriptor, None);
+    let mut cursor = selector.open_cursor_with_context(&director, MoveStreamContext::new(0, seed, None));
+    (0..draws)
+        .map(|_| {
+            match cursor
+                .next_owned_candidate()
+                .expect("both children hold more candidates than the draw count")
+            {
+                super::DescriptorMoveUnion::Change(_) => "change",
+                super::DescriptorMoveUnion::Swap(_) => "swap",
+                other => panic!("unexpected move {other:?}"),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn descriptor_union_fixed_weights_bias_child_draws() {
+    let draws = weighted_union_draws(7, 400);
+    let changes = draws.iter().filter(|kind| **kind == "change").count();
+    let swaps = draws.len() - changes;
+
+    let ratio = changes as f64 / swaps as f64;
+    assert!(
+        (2.5..=3.5).contains(&ratio),
+        "expected a 3:1 change:swap ratio, got {changes}:{swaps}"
+    );
+    assert_eq!(draws, weighted_union_draws(7, 400));
+}
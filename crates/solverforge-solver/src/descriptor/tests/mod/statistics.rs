@@ -0,0 +1,48 @@
+#[test]
+fn descriptor_union_reports_accepted_improvements_per_move_type() {
+    use crate::phase::localsearch::{BestScoreForager, HillClimbingAcceptor, LocalSearchPhase};
+    use crate::phase::Phase;
+    use crate::scope::SolverScope;
+
+    let descriptor = descriptor_with_allows_unassigned(false);
+    let plan = Plan {
+        workers: vec![Worker, Worker, Worker],
+        tasks: vec![
+            Task {
+                worker_idx: Some(1),
+            },
+            Task {
+                worker_idx: Some(0),
+            },
+            Task {
+                worker_idx: Some(0),
+            },
+        ],
+        score: None,
+    };
+    // Swapping the first two tasks fixes both; only a change fixes the third.
+    let director = PlanScoreDirector::with_mode(
+        plan,
+        descriptor.clone(),
+        PlanScoreMode::MatchTargets([0, 1, 2]),
+    );
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.enable_statistics();
+    solver_scope.start_solving();
+
+    let selector = build_descriptor_move_selector::<Plan>(None, &descriptor, None);
+    let forager: BestScoreForager<_> = BestScoreForager::new(false);
+    let mut phase: LocalSearchPhase<_, super::DescriptorMoveUnion<Plan>, _, _, _> =
+        LocalSearchPhase::new(selector, HillClimbingAcceptor::new(), forager, Some(4));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.best_score(), Some(&SoftScore::of(0)));
+    let improvements = &solver_scope
+        .stats()
+        .statistics()
+        .expect("statistics collection was enabled")
+        .move_type_improvements;
+    assert_eq!(improvements.get("swap"), Some(&1));
+    assert_eq!(improvements.get("change"), Some(&1));
+}
@@ -64,6 +64,8 @@ enum PlanScoreMode {
         unassigned_score: i64,
         assigned_scores: [i64; 3],
     },
+    // Penalizes every task whose worker differs from its target.
+    MatchTargets([usize; 3]),
 }
 
 impl PlanScoreDirector {
@@ -117,6 +119,15 @@ impl solverforge_scoring::Director<Plan> for PlanScoreDirector {
                     .map(|worker_idx| assigned_scores[worker_idx])
                     .unwrap_or(unassigned_score),
             ),
+            PlanScoreMode::MatchTargets(targets) => SoftScore::of(
+                -(self
+                    .working_solution
+                    .tasks
+                    .iter()
+                    .zip(targets)
+                    .filter(|(task, target)| task.worker_idx != Some(*target))
+                    .count() as i64),
+            ),
         };
         self.working_solution.set_score(Some(score));
         score
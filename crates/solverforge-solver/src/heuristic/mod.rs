@@ -11,12 +11,12 @@ pub mod selector;
 // Re-export move types
 pub use r#move::k_opt_reconnection;
 pub use r#move::{
-    ChangeMove, CompositeMove, CompoundScalarEdit, CompoundScalarMove, CutPoint,
-    DynamicListChangeMove, DynamicScalarChangeMove, DynamicScalarSwapMove, KOptMove,
+    ChangeMove, CompositeMove, CompositeMoveBuilder, CompoundScalarEdit, CompoundScalarMove,
+    CutPoint, DynamicListChangeMove, DynamicScalarChangeMove, DynamicScalarSwapMove, KOptMove,
     ListChangeMove, ListMoveUnion, ListMultiSwapMove, ListPermuteMove, ListReverseMove,
-    ListRuinMove, ListSwapMove, Move, MoveArena, PillarChangeMove, PillarSwapMove, RuinMove,
-    RuinRecreateMove, ScalarMoveUnion, ScalarRecreateValueSource, SublistChangeMove,
-    SublistSwapMove, SwapMove,
+    ListRuinMove, ListSwapMove, Move, MoveArena, PillarChangeMove, PillarSwapMove,
+    RecreateStrategy, RuinMove, RuinRecreateMove, ScalarMoveUnion, ScalarRecreateValueSource,
+    SublistChangeMove, SublistSwapMove, SwapMove,
 };
 
 // Re-export selector types
@@ -33,8 +33,8 @@ pub use selector::{
     MimicReplayingEntitySelector, MoveSelector, NearbyDistanceMeter, NearbyEntitySelector,
     NearbyKOptMoveSelector, NearbyListChangeMoveSelector, NearbyListSwapMoveSelector,
     NearbySelectionConfig, PerEntitySliceValueSelector, PerEntityValueSelector, Pillar,
-    PillarSelector, RuinMoveSelector, RuinVariableAccess, ScalarChangeMoveSelector,
-    ScalarNeighborhoodBindingError, ScalarSwapMoveSelector, SelectionOrder, StaticValueSelector,
-    SubPillarConfig, SublistChangeMoveSelector, SublistSwapMoveSelector, SwapMoveSelector,
-    ValueSelector,
+    PillarSelector, PillarSwapMoveSelector, RuinMoveSelector, RuinVariableAccess,
+    ScalarChangeMoveSelector, ScalarNeighborhoodBindingError, ScalarSwapMoveSelector,
+    SelectionOrder, StaticValueSelector, SubPillarConfig, SublistChangeMoveSelector,
+    SublistSwapMoveSelector, SwapMoveSelector, ValueSelector,
 };
@@ -204,6 +204,28 @@ impl<M> MoveArena<M> {
     pub fn capacity(&self) -> usize {
         self.storage.capacity()
     }
+
+    // Reserves room for at least `additional` more moves beyond the current length.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let wanted = self.len + additional;
+        if wanted > self.storage.len() {
+            self.storage.reserve(wanted - self.storage.len());
+        }
+    }
+
+    /* Releases retained capacity down to `min_capacity`, never below `len()`.
+
+    Use after a step with an unusually large neighborhood so the arena does
+    not hold onto peak memory for the rest of the solve.
+    */
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        // Slots past `len` were already dropped or taken; truncating
+        // `MaybeUninit` storage runs no destructors.
+        self.storage.truncate(self.len.max(min_capacity));
+        self.storage.shrink_to(min_capacity);
+    }
 }
 
 impl<M> Default for MoveArena<M> {
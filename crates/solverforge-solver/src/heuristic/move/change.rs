@@ -186,6 +186,10 @@ where
         self.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "change"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let current = (self.getter)(
             score_director.working_solution(),
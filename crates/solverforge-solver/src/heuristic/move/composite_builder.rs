@@ -0,0 +1,62 @@
+/* CompositeMoveBuilder - assembles a CompositeMove from two arenas.
+
+The builder only yields a composite whose members are doable in sequence,
+so callers never hold a composite that would fail at apply time.
+*/
+
+use std::marker::PhantomData;
+
+use solverforge_core::domain::PlanningSolution;
+use solverforge_scoring::Director;
+
+use super::{CompositeMove, Move, MoveArena};
+
+/// Builds a `CompositeMove` over two arenas, admitting it only when every
+/// member is doable in sequence.
+pub struct CompositeMoveBuilder<'a, S, M1, M2>
+where
+    S: PlanningSolution,
+    M1: Move<S>,
+    M2: Move<S>,
+{
+    arena_1: &'a MoveArena<M1>,
+    arena_2: &'a MoveArena<M2>,
+    index_1: Option<usize>,
+    index_2: Option<usize>,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<'a, S, M1, M2> CompositeMoveBuilder<'a, S, M1, M2>
+where
+    S: PlanningSolution,
+    M1: Move<S>,
+    M2: Move<S>,
+{
+    pub fn new(arena_1: &'a MoveArena<M1>, arena_2: &'a MoveArena<M2>) -> Self {
+        Self {
+            arena_1,
+            arena_2,
+            index_1: None,
+            index_2: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_first(mut self, index: usize) -> Self {
+        self.index_1 = Some(index);
+        self
+    }
+
+    pub fn with_second(mut self, index: usize) -> Self {
+        self.index_2 = Some(index);
+        self
+    }
+
+    /// Returns the composite if both members are set and `all_doable` holds.
+    pub fn build<D: Director<S>>(&self, score_director: &D) -> Option<CompositeMove<S, M1, M2>> {
+        let composite = CompositeMove::new(self.index_1?, self.index_2?);
+        composite
+            .all_doable(self.arena_1, self.arena_2, score_director)
+            .then_some(composite)
+    }
+}
@@ -6,6 +6,7 @@ use solverforge_scoring::Director;
 
 use crate::stats::CandidateTraceIdentity;
 
+use super::relocation::{notify_relocation, Relocation};
 use super::{ListChangeAccess, ListMoveAccess};
 use crate::heuristic::r#move::metadata::{
     encode_usize, hash_str, MoveTabuScope, ScopedEntityTabuToken,
@@ -113,10 +114,17 @@ pub(crate) fn change_do_move<S, A, D>(
         ),
     }
 
-    score_director.after_variable_changed(descriptor_index, coordinates.source_entity);
-    if !coordinates.is_intra_list() {
-        score_director.after_variable_changed(descriptor_index, coordinates.destination_entity);
-    }
+    notify_relocation(
+        score_director,
+        descriptor_index,
+        Relocation {
+            source_entity: coordinates.source_entity,
+            source_start: coordinates.source_position,
+            len: 1,
+            dest_entity: coordinates.destination_entity,
+            dest_position: destination,
+        },
+    );
 }
 
 pub(crate) fn change_undo_move<S, A, D>(
@@ -146,10 +154,17 @@ pub(crate) fn change_undo_move<S, A, D>(
         coordinates.source_position,
         removed,
     );
-    score_director.after_variable_changed(descriptor_index, coordinates.destination_entity);
-    if !coordinates.is_intra_list() {
-        score_director.after_variable_changed(descriptor_index, coordinates.source_entity);
-    }
+    notify_relocation(
+        score_director,
+        descriptor_index,
+        Relocation {
+            source_entity: coordinates.destination_entity,
+            source_start: coordinates.adjusted_destination(),
+            len: 1,
+            dest_entity: coordinates.source_entity,
+            dest_position: coordinates.source_position,
+        },
+    );
 }
 
 pub(crate) fn change_tabu_signature<S, A, D>(
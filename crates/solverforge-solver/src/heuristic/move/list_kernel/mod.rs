@@ -11,6 +11,8 @@ mod multi_swap;
 mod permute;
 mod range_access;
 mod range_static;
+mod recreate;
+mod relocation;
 mod reverse;
 mod ruin;
 mod ruin_access;
@@ -36,6 +38,7 @@ pub(crate) use permute::{
 };
 pub(crate) use range_access::{ListRangeAccess, ListReverseAccess, ListWindowAccess};
 pub(crate) use range_static::{StaticListReverseAccess, StaticListWindowAccess};
+pub use recreate::RecreateStrategy;
 pub(crate) use reverse::{
     reverse_candidate_trace_identity, reverse_do_move, reverse_is_doable, reverse_tabu_signature,
     ReverseCoordinates,
@@ -0,0 +1,214 @@
+//! Recreate half of ruin-and-recreate: placement search and insertion order.
+
+use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::Score;
+use solverforge_scoring::Director;
+
+use crate::heuristic::r#move::metadata::encode_usize;
+use crate::heuristic::selector::precedence_route::{node_index, PrecedenceRouteGraph};
+
+use super::{ListRuinAccess, RuinSources};
+
+/// Order in which ruined list elements are reinserted.
+///
+/// Every strategy places the chosen element at its best-scoring feasible
+/// position; they differ only in which remaining element goes next.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RecreateStrategy {
+    /// Insert the element whose best placement scores highest.
+    #[default]
+    Greedy,
+    /// Insert the element with the largest gap between its best and
+    /// second-best placement; elements with a single placement go first.
+    Regret,
+    /// Insert elements in a pseudo-random order derived from the ruined
+    /// coordinates, so the same move always recreates the same way.
+    Random,
+}
+
+/// Best placement of one element plus the runner-up score used for regret.
+pub(super) struct ElementPlacements<Sc> {
+    pub(super) entity: usize,
+    pub(super) position: usize,
+    best_score: Sc,
+    second_score: Option<Sc>,
+}
+
+impl<Sc: Score> ElementPlacements<Sc> {
+    // Elements with a single feasible placement rank above any finite regret.
+    fn regret(&self) -> (bool, Option<Sc>) {
+        match self.second_score {
+            Some(second) => (false, Some(self.best_score - second)),
+            None => (true, None),
+        }
+    }
+}
+
+/// Picks the next remaining element to reinsert, together with its placement.
+///
+/// `evaluate` scores one remaining element by index and returns `None` when it
+/// has no feasible placement.
+pub(super) fn choose_next<Sc: Score>(
+    strategy: RecreateStrategy,
+    seed: u64,
+    round: u64,
+    remaining: usize,
+    mut evaluate: impl FnMut(usize) -> Option<ElementPlacements<Sc>>,
+) -> Option<(usize, ElementPlacements<Sc>)> {
+    match strategy {
+        RecreateStrategy::Greedy => {
+            rank_by(remaining, evaluate, |placements| placements.best_score)
+        }
+        RecreateStrategy::Regret => rank_by(remaining, evaluate, ElementPlacements::regret),
+        RecreateStrategy::Random => {
+            let index = (mix_seed(seed ^ round) % remaining as u64) as usize;
+            evaluate(index).map(|placements| (index, placements))
+        }
+    }
+}
+
+// First element with the highest key wins ties.
+fn rank_by<Sc, K: PartialOrd>(
+    remaining: usize,
+    mut evaluate: impl FnMut(usize) -> Option<ElementPlacements<Sc>>,
+    key: impl Fn(&ElementPlacements<Sc>) -> K,
+) -> Option<(usize, ElementPlacements<Sc>)> {
+    let mut best: Option<(usize, ElementPlacements<Sc>)> = None;
+    for index in 0..remaining {
+        let Some(placements) = evaluate(index) else {
+            continue;
+        };
+        if best
+            .as_ref()
+            .is_none_or(|(_, current)| key(&placements) > key(current))
+        {
+            best = Some((index, placements));
+        }
+    }
+    best
+}
+
+pub(super) fn best_placements<S, A, D>(
+    access: &A,
+    element: &A::Element,
+    entity_count: usize,
+    skip_empty_destinations: bool,
+    precedence_graph: Option<&(Vec<A::Element>, PrecedenceRouteGraph)>,
+    score_director: &mut D,
+) -> Option<ElementPlacements<S::Score>>
+where
+    S: PlanningSolution,
+    A: ListRuinAccess<S>,
+    D: Director<S>,
+{
+    let descriptor_index = access.descriptor_index();
+    let restriction =
+        access.owner_restriction(score_director.working_solution(), entity_count, element);
+    let mut placements: Option<ElementPlacements<S::Score>> = None;
+    for destination_entity in 0..entity_count {
+        if !restriction.allows(destination_entity) {
+            continue;
+        }
+        let destination_len =
+            access.list_len(score_director.working_solution(), destination_entity);
+        if skip_empty_destinations && !access.has_owner_binding() && destination_len == 0 {
+            continue;
+        }
+        for destination_position in 0..=destination_len {
+            if precedence_graph.is_some_and(|(elements, graph)| {
+                let Some(element_node) = node_index(elements, element) else {
+                    return false;
+                };
+                let previous = (destination_position > 0)
+                    .then(|| {
+                        access.list_get(
+                            score_director.working_solution(),
+                            destination_entity,
+                            destination_position - 1,
+                        )
+                    })
+                    .flatten();
+                let next = (destination_position < destination_len)
+                    .then(|| {
+                        access.list_get(
+                            score_director.working_solution(),
+                            destination_entity,
+                            destination_position,
+                        )
+                    })
+                    .flatten();
+                graph.insertion_introduces_cycle(
+                    previous
+                        .as_ref()
+                        .and_then(|value| node_index(elements, value)),
+                    element_node,
+                    next.as_ref().and_then(|value| node_index(elements, value)),
+                )
+            }) {
+                continue;
+            }
+
+            score_director.before_variable_changed(descriptor_index, destination_entity);
+            access.list_insert(
+                score_director.working_solution_mut(),
+                destination_entity,
+                destination_position,
+                element.clone(),
+            );
+            score_director.after_variable_changed(descriptor_index, destination_entity);
+
+            let candidate_score = score_director.calculate_score();
+            match &mut placements {
+                None => {
+                    placements = Some(ElementPlacements {
+                        entity: destination_entity,
+                        position: destination_position,
+                        best_score: candidate_score,
+                        second_score: None,
+                    });
+                }
+                Some(current) if candidate_score > current.best_score => {
+                    current.second_score = Some(current.best_score);
+                    current.entity = destination_entity;
+                    current.position = destination_position;
+                    current.best_score = candidate_score;
+                }
+                Some(current) => {
+                    if current
+                        .second_score
+                        .is_none_or(|second| candidate_score > second)
+                    {
+                        current.second_score = Some(candidate_score);
+                    }
+                }
+            }
+
+            score_director.before_variable_changed(descriptor_index, destination_entity);
+            let _ = access.list_remove(
+                score_director.working_solution_mut(),
+                destination_entity,
+                destination_position,
+            );
+            score_director.after_variable_changed(descriptor_index, destination_entity);
+        }
+    }
+    placements
+}
+
+pub(super) fn recreate_random_seed(sources: &RuinSources) -> u64 {
+    let mut seed = 0x52EC_4EA7_E000_0001u64;
+    for (entity, indices) in sources {
+        seed = mix_seed(seed ^ encode_usize(*entity));
+        for &index in indices {
+            seed = mix_seed(seed ^ encode_usize(index));
+        }
+    }
+    seed
+}
+
+fn mix_seed(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
@@ -0,0 +1,66 @@
+//! Post-change notifications for list relocations.
+
+use std::ops::Range;
+
+use solverforge_core::domain::PlanningSolution;
+use solverforge_scoring::Director;
+
+/// A block of `len` elements removed at `source_start` and reinserted at
+/// `dest_position`, indexed after the removal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Relocation {
+    pub(crate) source_entity: usize,
+    pub(crate) source_start: usize,
+    pub(crate) len: usize,
+    pub(crate) dest_entity: usize,
+    pub(crate) dest_position: usize,
+}
+
+impl Relocation {
+    fn inserted(self) -> Range<usize> {
+        self.dest_position..self.dest_position + self.len
+    }
+
+    /* Final position of the gap the removal closed. Within one list, an
+    insertion before the gap shifts it right by the block length.
+    */
+    fn gap(self) -> Range<usize> {
+        let position =
+            if self.source_entity == self.dest_entity && self.dest_position < self.source_start {
+                self.source_start + self.len
+            } else {
+                self.source_start
+            };
+        position..position
+    }
+}
+
+/// Sends `after_list_changed` for both sides of an applied relocation, so
+/// shadow maintenance only revisits the boundary elements.
+pub(crate) fn notify_relocation<S, D>(
+    score_director: &mut D,
+    descriptor_index: usize,
+    relocation: Relocation,
+) where
+    S: PlanningSolution,
+    D: Director<S>,
+{
+    if relocation.source_entity == relocation.dest_entity {
+        score_director.after_list_changed(
+            descriptor_index,
+            relocation.source_entity,
+            &[relocation.gap(), relocation.inserted()],
+        );
+    } else {
+        score_director.after_list_changed(
+            descriptor_index,
+            relocation.source_entity,
+            &[relocation.gap()],
+        );
+        score_director.after_list_changed(
+            descriptor_index,
+            relocation.dest_entity,
+            &[relocation.inserted()],
+        );
+    }
+}
@@ -8,9 +8,9 @@ use crate::heuristic::r#move::metadata::{
     encode_usize, hash_str, MoveTabuScope, ScopedValueTabuToken,
 };
 use crate::heuristic::r#move::MoveTabuSignature;
-use crate::heuristic::selector::precedence_route::node_index;
 
-use super::{ListMoveAccess, ListRuinAccess};
+use super::recreate::{best_placements, choose_next, recreate_random_seed, ElementPlacements};
+use super::{ListMoveAccess, ListRuinAccess, RecreateStrategy};
 
 pub(crate) type RuinSources = SmallVec<[(usize, SmallVec<[usize; 8]>); 4]>;
 pub(crate) type RuinUndo = SmallVec<[(usize, usize, usize); 8]>;
@@ -130,6 +130,7 @@ pub(crate) fn ruin_do_move<S, A, D>(
     sources: &RuinSources,
     skip_empty_destinations: bool,
     transfer: RuinValueTransfer,
+    recreate: RecreateStrategy,
     score_director: &mut D,
 ) -> RuinUndo
 where
@@ -167,89 +168,30 @@ where
         })
         .collect::<SmallVec<[(usize, usize, usize, A::Element); 8]>>();
     let entity_count = access.entity_count(score_director.working_solution());
+    let random_seed = recreate_random_seed(sources);
+    let mut round = 0u64;
 
     while !remaining.is_empty() {
         let precedence_graph = access.recreate_precedence_graph(score_director.working_solution());
-        let mut best_choice: Option<(usize, usize, usize, S::Score)> = None;
-
-        for (remaining_index, (_, _, _, element)) in remaining.iter().enumerate() {
-            let restriction =
-                access.owner_restriction(score_director.working_solution(), entity_count, element);
-            for destination_entity in 0..entity_count {
-                if !restriction.allows(destination_entity) {
-                    continue;
-                }
-                let destination_len =
-                    access.list_len(score_director.working_solution(), destination_entity);
-                if skip_empty_destinations && !access.has_owner_binding() && destination_len == 0 {
-                    continue;
-                }
-                for destination_position in 0..=destination_len {
-                    if precedence_graph.as_ref().is_some_and(|(elements, graph)| {
-                        let Some(element_node) = node_index(elements, element) else {
-                            return false;
-                        };
-                        let previous = (destination_position > 0)
-                            .then(|| {
-                                access.list_get(
-                                    score_director.working_solution(),
-                                    destination_entity,
-                                    destination_position - 1,
-                                )
-                            })
-                            .flatten();
-                        let next = (destination_position < destination_len)
-                            .then(|| {
-                                access.list_get(
-                                    score_director.working_solution(),
-                                    destination_entity,
-                                    destination_position,
-                                )
-                            })
-                            .flatten();
-                        graph.insertion_introduces_cycle(
-                            previous
-                                .as_ref()
-                                .and_then(|value| node_index(elements, value)),
-                            element_node,
-                            next.as_ref().and_then(|value| node_index(elements, value)),
-                        )
-                    }) {
-                        continue;
-                    }
-
-                    score_director.before_variable_changed(descriptor_index, destination_entity);
-                    access.list_insert(
-                        score_director.working_solution_mut(),
-                        destination_entity,
-                        destination_position,
-                        element.clone(),
-                    );
-                    score_director.after_variable_changed(descriptor_index, destination_entity);
-
-                    let candidate_score = score_director.calculate_score();
-                    if best_choice.is_none_or(|(_, _, _, best_score)| candidate_score > best_score)
-                    {
-                        best_choice = Some((
-                            remaining_index,
-                            destination_entity,
-                            destination_position,
-                            candidate_score,
-                        ));
-                    }
-
-                    score_director.before_variable_changed(descriptor_index, destination_entity);
-                    let _ = access.list_remove(
-                        score_director.working_solution_mut(),
-                        destination_entity,
-                        destination_position,
-                    );
-                    score_director.after_variable_changed(descriptor_index, destination_entity);
-                }
-            }
-        }
+        let choice = choose_next(recreate, random_seed, round, remaining.len(), |index| {
+            best_placements(
+                access,
+                &remaining[index].3,
+                entity_count,
+                skip_empty_destinations,
+                precedence_graph.as_ref(),
+                score_director,
+            )
+        });
+        round += 1;
 
-        let Some((remaining_index, entity, position, _)) = best_choice else {
+        let Some((
+            remaining_index,
+            ElementPlacements {
+                entity, position, ..
+            },
+        )) = choice
+        else {
             restore_removed_elements(access, &placements, &removed, score_director);
             return RuinUndo::new();
         };
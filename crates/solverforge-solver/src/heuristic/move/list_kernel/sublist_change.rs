@@ -8,11 +8,12 @@ use crate::heuristic::r#move::metadata::{
     encode_usize, hash_str, MoveTabuScope, ScopedEntityTabuToken, ScopedValueTabuToken,
 };
 use crate::heuristic::r#move::segment_layout::{
-    derive_segment_relocation_layout, SegmentRelocationCoords,
+    derive_segment_relocation_layout, SegmentRelocationCoords, SegmentRelocationLayout,
 };
 use crate::heuristic::r#move::MoveTabuSignature;
 use crate::stats::CandidateTraceIdentity;
 
+use super::relocation::{notify_relocation, Relocation};
 use super::{ListRangeAccess, ListWindowAccess};
 
 pub(crate) fn sublist_change_is_doable<S, A, D>(
@@ -58,13 +59,7 @@ pub(crate) fn sublist_change_do_move<S, A, D>(
     A: ListWindowAccess<S>,
     D: Director<S>,
 {
-    let layout = derive_segment_relocation_layout(
-        coordinates.source_entity_index,
-        coordinates.source_range.start,
-        coordinates.source_range.end,
-        coordinates.dest_entity_index,
-        coordinates.dest_position,
-    );
+    let layout = relocation_layout(coordinates);
     apply_sublist_change(access, layout.exact, score_director);
 }
 
@@ -77,6 +72,12 @@ pub(crate) fn sublist_change_undo_move<S, A, D>(
     A: ListWindowAccess<S>,
     D: Director<S>,
 {
+    let layout = relocation_layout(coordinates);
+    apply_sublist_change(access, layout.inverse, score_director);
+}
+
+// Reversal is its own inverse, so the undo relocation carries the same flag.
+fn relocation_layout(coordinates: SegmentRelocationCoords) -> SegmentRelocationLayout {
     let layout = derive_segment_relocation_layout(
         coordinates.source_entity_index,
         coordinates.source_range.start,
@@ -84,7 +85,10 @@ pub(crate) fn sublist_change_undo_move<S, A, D>(
         coordinates.dest_entity_index,
         coordinates.dest_position,
     );
-    apply_sublist_change(access, layout.inverse, score_director);
+    SegmentRelocationLayout {
+        exact: layout.exact.with_reversed(coordinates.reversed),
+        inverse: layout.inverse.with_reversed(coordinates.reversed),
+    }
 }
 
 fn apply_sublist_change<S, A, D>(
@@ -102,7 +106,7 @@ fn apply_sublist_change<S, A, D>(
         score_director.before_variable_changed(descriptor_index, coordinates.dest_entity_index);
     }
 
-    let elements = access
+    let mut elements = access
         .sublist_remove(
             score_director.working_solution_mut(),
             coordinates.source_entity_index,
@@ -110,6 +114,10 @@ fn apply_sublist_change<S, A, D>(
             coordinates.source_range.end,
         )
         .expect("validated list window access should remove the requested segment");
+    let len = elements.len();
+    if coordinates.reversed {
+        elements.reverse();
+    }
     access
         .sublist_insert(
             score_director.working_solution_mut(),
@@ -119,10 +127,17 @@ fn apply_sublist_change<S, A, D>(
         )
         .expect("validated list window access should insert the requested segment");
 
-    score_director.after_variable_changed(descriptor_index, coordinates.source_entity_index);
-    if coordinates.source_entity_index != coordinates.dest_entity_index {
-        score_director.after_variable_changed(descriptor_index, coordinates.dest_entity_index);
-    }
+    notify_relocation(
+        score_director,
+        descriptor_index,
+        Relocation {
+            source_entity: coordinates.source_entity_index,
+            source_start: coordinates.source_range.start,
+            len,
+            dest_entity: coordinates.dest_entity_index,
+            dest_position: coordinates.dest_position,
+        },
+    );
 }
 
 pub(crate) fn sublist_change_tabu_signature<S, A, D>(
@@ -135,13 +150,7 @@ where
     A: ListRangeAccess<S>,
     D: Director<S>,
 {
-    let layout = derive_segment_relocation_layout(
-        coordinates.source_entity_index,
-        coordinates.source_range.start,
-        coordinates.source_range.end,
-        coordinates.dest_entity_index,
-        coordinates.dest_position,
-    );
+    let layout = relocation_layout(coordinates);
     let mut moved_ids: SmallVec<[u64; 2]> = SmallVec::new();
     for position in coordinates.source_range.start..coordinates.source_range.end {
         let value = access.list_get(
@@ -174,6 +183,9 @@ where
         encode_usize(layout.exact.dest_position)
     ];
     move_id.extend(moved_ids.iter().copied());
+    if coordinates.reversed {
+        move_id.push(hash_str("reversed"));
+    }
     let mut undo_move_id = smallvec![
         encode_usize(access.descriptor_index()),
         hash_str(access.variable_name()),
@@ -183,7 +195,13 @@ where
         encode_usize(layout.inverse.dest_entity_index),
         encode_usize(layout.inverse.dest_position)
     ];
-    undo_move_id.extend(moved_ids.iter().copied());
+    if coordinates.reversed {
+        // The inverse relocation reads the segment back in reversed order.
+        undo_move_id.extend(moved_ids.iter().rev().copied());
+        undo_move_id.push(hash_str("reversed"));
+    } else {
+        undo_move_id.extend(moved_ids.iter().copied());
+    }
 
     MoveTabuSignature::new(scope, move_id, undo_move_id)
         .with_entity_tokens(entity_tokens)
@@ -200,7 +218,11 @@ where
     CandidateTraceIdentity::logical_move(
         access.descriptor_index(),
         access.variable_name(),
-        "sublist_change",
+        if coordinates.reversed {
+            "reversed_sublist_change"
+        } else {
+            "sublist_change"
+        },
         [
             coordinates.source_entity_index,
             coordinates.source_range.start,
@@ -8,8 +8,8 @@ use solverforge_scoring::Director;
 
 use super::list_kernel::{
     merged_ruin_sources, ruin_count, ruin_do_move, ruin_entity_indices, ruin_is_doable,
-    ruin_tabu_signature, ruin_undo_move, single_ruin_source, RuinSources, RuinUndo,
-    RuinValueTransfer, StaticListRuinAccess,
+    ruin_tabu_signature, ruin_undo_move, single_ruin_source, RecreateStrategy, RuinSources,
+    RuinUndo, RuinValueTransfer, StaticListRuinAccess,
 };
 use super::{Move, MoveTabuSignature};
 
@@ -26,6 +26,7 @@ pub struct ListRuinMove<S, V> {
     entity_indices: SmallVec<[usize; 8]>,
     access: StaticListRuinAccess<S, V>,
     skip_empty_destinations: bool,
+    recreate: RecreateStrategy,
 }
 
 impl<S, V> Clone for ListRuinMove<S, V> {
@@ -37,6 +38,7 @@ impl<S, V> Clone for ListRuinMove<S, V> {
             entity_indices: self.entity_indices.clone(),
             access: self.access,
             skip_empty_destinations: self.skip_empty_destinations,
+            recreate: self.recreate,
         }
     }
 }
@@ -134,6 +136,7 @@ impl<S, V> ListRuinMove<S, V> {
                 descriptor_index,
             },
             skip_empty_destinations: false,
+            recreate: RecreateStrategy::Greedy,
         }
     }
 
@@ -162,6 +165,15 @@ impl<S, V> ListRuinMove<S, V> {
         self
     }
 
+    pub fn with_recreate_strategy(mut self, recreate: RecreateStrategy) -> Self {
+        self.recreate = recreate;
+        self
+    }
+
+    pub fn recreate_strategy(&self) -> RecreateStrategy {
+        self.recreate
+    }
+
     pub fn entity_index(&self) -> usize {
         self.entity_index
     }
@@ -192,6 +204,7 @@ where
             &self.sources,
             self.skip_empty_destinations,
             RuinValueTransfer::CloneBeforeInsert,
+            self.recreate,
             score_director,
         )
     }
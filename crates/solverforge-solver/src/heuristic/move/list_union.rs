@@ -6,6 +6,7 @@ without trait-object dispatch.
 
 use std::fmt::Debug;
 
+use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
@@ -238,6 +239,34 @@ where
         }
     }
 
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::ListChange(m) => m.type_name(),
+            Self::ListSwap(m) => m.type_name(),
+            Self::ListMultiSwap(m) => m.type_name(),
+            Self::ListPermute(m) => m.type_name(),
+            Self::SublistChange(m) => m.type_name(),
+            Self::SublistSwap(m) => m.type_name(),
+            Self::ListReverse(m) => m.type_name(),
+            Self::KOpt(m) => m.type_name(),
+            Self::ListRuin(m) => m.type_name(),
+        }
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        match self {
+            Self::ListChange(m) => m.affected_entities(),
+            Self::ListSwap(m) => m.affected_entities(),
+            Self::ListMultiSwap(m) => m.affected_entities(),
+            Self::ListPermute(m) => m.affected_entities(),
+            Self::SublistChange(m) => m.affected_entities(),
+            Self::SublistSwap(m) => m.affected_entities(),
+            Self::ListReverse(m) => m.affected_entities(),
+            Self::KOpt(m) => m.affected_entities(),
+            Self::ListRuin(m) => m.affected_entities(),
+        }
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         match self {
             Self::ListChange(m) => m.requires_hard_improvement(),
@@ -10,6 +10,7 @@ All moves are fully monomorphized with inline value storage for maximum performa
 - `ChangeMove<S, V>` - assigns a value to a variable
 - `SwapMove<S, V>` - swaps values between two entities
 - `CompositeMove<'a, S, M1, M2>` - applies two moves by reference
+- `CompositeMoveBuilder` - admits a `CompositeMove` only when every member is doable
 - `SequentialCompositeMove<S, M>` - applies two owned moves in sequence
 - `PillarChangeMove<S, V>` - changes multiple entities with same value
 - `PillarSwapMove<S, V>` - swaps between two pillars
@@ -57,6 +58,7 @@ arena.reset();
 mod arena;
 mod change;
 mod composite;
+mod composite_builder;
 mod compound_scalar;
 mod conflict_repair;
 mod dynamic_list_change;
@@ -80,6 +82,7 @@ mod ruin_recreate;
 mod runtime_compound;
 mod scalar_union;
 mod segment_layout;
+mod sequential_composite;
 mod sublist_change;
 mod sublist_swap;
 mod swap;
@@ -91,9 +94,7 @@ mod tests;
 pub use arena::MoveArena;
 pub use change::ChangeMove;
 pub use composite::CompositeMove;
-pub use composite::SequentialCompositeMove;
-pub(crate) use composite::SequentialCompositeMoveRef;
-pub(crate) use composite::SequentialPreviewDirector;
+pub use composite_builder::CompositeMoveBuilder;
 pub use compound_scalar::{CompoundScalarEdit, CompoundScalarMove, COMPOUND_SCALAR_VARIABLE};
 pub use conflict_repair::{ConflictRepairMove, ConflictRepairScalarEdit};
 pub use dynamic_list_change::DynamicListChangeMove;
@@ -101,6 +102,7 @@ pub use dynamic_scalar_change::DynamicScalarChangeMove;
 pub use dynamic_scalar_swap::DynamicScalarSwapMove;
 pub use k_opt::{CutPoint, KOptMove};
 pub use list_change::ListChangeMove;
+pub use list_kernel::RecreateStrategy;
 pub use list_multi_swap::ListMultiSwapMove;
 pub use list_permute::{ListPermuteMove, MAX_LIST_PERMUTE_WINDOW_SIZE};
 pub use list_reverse::ListReverseMove;
@@ -119,6 +121,9 @@ pub(crate) use runtime_compound::{
 pub use runtime_compound::{RuntimeCompoundMove, RuntimeCompoundMoveKind};
 pub use scalar_union::ScalarMoveUnion;
 pub(crate) use segment_layout::{SegmentRelocationCoords, SegmentSwapCoords};
+pub use sequential_composite::SequentialCompositeMove;
+pub(crate) use sequential_composite::SequentialCompositeMoveRef;
+pub(crate) use sequential_composite::SequentialPreviewDirector;
 pub use sublist_change::SublistChangeMove;
 pub use sublist_swap::SublistSwapMove;
 pub use swap::SwapMove;
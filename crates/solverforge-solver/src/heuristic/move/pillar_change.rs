@@ -206,6 +206,10 @@ where
         self.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "pillar_change"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let from_value = self.entity_indices.first().and_then(|&idx| {
             (self.getter)(score_director.working_solution(), idx, self.variable_index)
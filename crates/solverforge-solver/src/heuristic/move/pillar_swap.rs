@@ -26,6 +26,13 @@ use super::{Move, MoveTabuSignature};
 /// Stores pillar indices and concrete function pointers for zero-erasure access.
 /// `do_move` returns the previous pillar values as typed undo data.
 ///
+/// Pillars may differ in size. Every entity of the left pillar takes the right
+/// pillar's shared value and every entity of the right pillar takes the left
+/// pillar's, so the entities beyond the shorter pillar's length join the other
+/// value group. A 3-entity pillar on `A` swapped with a 2-entity pillar on `B`
+/// leaves three entities on `B` and two on `A`; undo restores each entity's
+/// own previous value.
+///
 /// # Type Parameters
 /// * `S` - The planning solution type
 /// * `V` - The variable value type
@@ -39,6 +46,8 @@ pub struct PillarSwapMove<S, V> {
     // Concrete setter function pointer - zero erasure.
     setter: fn(&mut S, usize, usize, Option<V>),
     variable_index: usize,
+    // Optional entity value-range check: (solution, entity, variable, value).
+    value_is_legal: Option<fn(&S, usize, usize, Option<&V>) -> bool>,
 }
 
 impl<S, V: Clone> Clone for PillarSwapMove<S, V> {
@@ -51,6 +60,7 @@ impl<S, V: Clone> Clone for PillarSwapMove<S, V> {
             getter: self.getter,
             setter: self.setter,
             variable_index: self.variable_index,
+            value_is_legal: self.value_is_legal,
         }
     }
 }
@@ -94,9 +104,19 @@ impl<S, V> PillarSwapMove<S, V> {
             getter,
             setter,
             variable_index,
+            value_is_legal: None,
         }
     }
 
+    /// Rejects swaps that would hand an entity a value outside its value range.
+    pub fn with_value_is_legal(
+        mut self,
+        value_is_legal: Option<fn(&S, usize, usize, Option<&V>) -> bool>,
+    ) -> Self {
+        self.value_is_legal = value_is_legal;
+        self
+    }
+
     pub fn left_indices(&self) -> &[usize] {
         &self.left_indices
     }
@@ -138,7 +158,23 @@ where
             .first()
             .map(|&idx| (self.getter)(score_director.working_solution(), idx, self.variable_index));
 
-        left_val != right_val
+        if left_val == right_val {
+            return false;
+        }
+
+        let Some(value_is_legal) = self.value_is_legal else {
+            return true;
+        };
+        let solution = score_director.working_solution();
+        let left_val = left_val.flatten();
+        let right_val = right_val.flatten();
+        self.left_indices
+            .iter()
+            .all(|&idx| value_is_legal(solution, idx, self.variable_index, right_val.as_ref()))
+            && self
+                .right_indices
+                .iter()
+                .all(|&idx| value_is_legal(solution, idx, self.variable_index, left_val.as_ref()))
     }
 
     fn do_move<D: Director<S>>(&self, score_director: &mut D) -> Self::Undo {
@@ -230,6 +266,10 @@ where
         self.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "pillar_swap"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let left_value = self.left_indices.first().and_then(|&idx| {
             (self.getter)(score_director.working_solution(), idx, self.variable_index)
@@ -6,6 +6,7 @@ ruin-recreate moves without trait-object dispatch.
 
 use std::fmt::Debug;
 
+use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
@@ -204,6 +205,32 @@ where
         }
     }
 
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Change(m) => m.type_name(),
+            Self::Swap(m) => m.type_name(),
+            Self::PillarChange(m) => m.type_name(),
+            Self::PillarSwap(m) => m.type_name(),
+            Self::RuinRecreate(m) => m.type_name(),
+            Self::CompoundScalar(m) => m.type_name(),
+            Self::ConflictRepair(m) => m.type_name(),
+            Self::RuntimeCompound(m) => m.type_name(),
+        }
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        match self {
+            Self::Change(m) => m.affected_entities(),
+            Self::Swap(m) => m.affected_entities(),
+            Self::PillarChange(m) => m.affected_entities(),
+            Self::PillarSwap(m) => m.affected_entities(),
+            Self::RuinRecreate(m) => m.affected_entities(),
+            Self::CompoundScalar(m) => m.affected_entities(),
+            Self::ConflictRepair(m) => m.affected_entities(),
+            Self::RuntimeCompound(m) => m.affected_entities(),
+        }
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         match self {
             Self::Change(m) => m.requires_hard_improvement(),
@@ -20,6 +20,8 @@ pub(crate) struct SegmentRelocationCoords {
     pub source_range: SegmentRange,
     pub dest_entity_index: usize,
     pub dest_position: usize,
+    // Whether the segment is inserted in reverse order.
+    pub reversed: bool,
 }
 
 impl SegmentRelocationCoords {
@@ -35,8 +37,14 @@ impl SegmentRelocationCoords {
             source_range: SegmentRange::new(source_start, source_end),
             dest_entity_index,
             dest_position,
+            reversed: false,
         }
     }
+
+    pub const fn with_reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -90,6 +90,8 @@ pub struct SublistChangeMove<S, V> {
     dest_entity_index: usize,
     // Position in destination list to insert at
     dest_position: usize,
+    // Whether the sublist is inserted in reverse order
+    reversed: bool,
     list_len: fn(&S, usize) -> usize,
     list_get: fn(&S, usize, usize) -> Option<V>,
     // Remove sublist [start, end), returns removed elements
@@ -118,6 +120,7 @@ impl<S, V: Debug> Debug for SublistChangeMove<S, V> {
             .field("source_range", &(self.source_start..self.source_end))
             .field("dest_entity", &self.dest_entity_index)
             .field("dest_position", &self.dest_position)
+            .field("reversed", &self.reversed)
             .field("variable_name", &self.variable_name)
             .finish()
     }
@@ -158,6 +161,7 @@ impl<S, V> SublistChangeMove<S, V> {
             source_end,
             dest_entity_index,
             dest_position,
+            reversed: false,
             list_len,
             list_get,
             sublist_remove,
@@ -169,6 +173,17 @@ impl<S, V> SublistChangeMove<S, V> {
         }
     }
 
+    /// Inserts the relocated sublist in reverse order, e.g. `[3, 4, 5]`
+    /// lands as `[5, 4, 3]`. Undo restores the original orientation.
+    pub fn with_reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
     pub fn source_entity_index(&self) -> usize {
         self.source_entity_index
     }
@@ -216,6 +231,7 @@ impl<S, V> SublistChangeMove<S, V> {
             self.dest_entity_index,
             self.dest_position,
         )
+        .with_reversed(self.reversed)
     }
 }
 
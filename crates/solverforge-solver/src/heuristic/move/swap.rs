@@ -225,6 +225,10 @@ where
         self.variable_name
     }
 
+    fn type_name(&self) -> &'static str {
+        "swap"
+    }
+
     fn tabu_signature<D: Director<S>>(&self, score_director: &D) -> MoveTabuSignature {
         let left_val = (self.getter)(
             score_director.working_solution(),
@@ -108,3 +108,45 @@ fn test_arena_double_take_panics() {
     arena.take(0);
     arena.take(1);
 }
+
+#[test]
+fn test_arena_reserve_grows_capacity_beyond_len() {
+    let mut arena: MoveArena<i32> = MoveArena::new();
+    arena.push(1);
+    arena.reserve(50);
+    assert!(arena.capacity() >= 51);
+    assert_eq!(arena.len(), 1);
+}
+
+#[test]
+fn test_arena_reset_extend_cycles_keep_capacity_bounded() {
+    let mut arena: MoveArena<i32> = MoveArena::new();
+    arena.extend(0..64);
+    let capacity = arena.capacity();
+
+    for step in 0..1_000 {
+        arena.reset();
+        arena.reserve(64);
+        arena.extend((0..64).map(|i| i + step));
+        assert_eq!(arena.len(), 64);
+    }
+    assert_eq!(arena.capacity(), capacity);
+}
+
+#[test]
+fn test_arena_shrink_to_bounds_memory_after_spike() {
+    let mut arena: MoveArena<String> = MoveArena::new();
+    arena.extend((0..1_000).map(|i| i.to_string()));
+    let _ = arena.take(3);
+    arena.reset();
+
+    arena.shrink_to(8);
+    assert!(arena.capacity() < 1_000);
+    assert!(arena.capacity() >= 8);
+
+    arena.extend((0..4).map(|i| i.to_string()));
+    arena.shrink_to(0);
+    assert_eq!(arena.len(), 4);
+    assert!(arena.capacity() >= 4);
+    assert_eq!(arena.get(3).map(String::as_str), Some("3"));
+}
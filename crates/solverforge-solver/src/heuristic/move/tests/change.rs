@@ -109,6 +109,21 @@ fn test_change_move_entity_indices() {
     assert_eq!(m.entity_indices(), &[3]);
 }
 
+#[test]
+fn test_change_move_type_name_and_affected_entities() {
+    let m = ChangeMove::<TaskSolution, i32>::new(
+        3,
+        Some(5),
+        get_priority,
+        set_priority,
+        0,
+        "priority",
+        0,
+    );
+    assert_eq!(m.type_name(), "change");
+    assert_eq!(m.affected_entities().as_slice(), &[3]);
+}
+
 #[test]
 fn test_change_move_copy() {
     let m1 = ChangeMove::<TaskSolution, i32>::new(
@@ -0,0 +1,106 @@
+// Tests for CompositeMove atomicity; builder and sequential composite
+// coverage lives in the submodules and shares the slot fixture below.
+
+use super::*;
+
+#[derive(Clone, Debug)]
+struct Slot {
+    value: Option<i32>,
+}
+
+#[derive(Clone, Debug)]
+struct SlotSolution {
+    slots: Vec<Slot>,
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for SlotSolution {
+    type Score = SoftScore;
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+fn get_value(s: &SlotSolution, i: usize, _variable_index: usize) -> Option<i32> {
+    s.slots.get(i).and_then(|slot| slot.value)
+}
+
+fn set_value(s: &mut SlotSolution, i: usize, _variable_index: usize, v: Option<i32>) {
+    if let Some(slot) = s.slots.get_mut(i) {
+        slot.value = v;
+    }
+}
+
+type SlotChange = ChangeMove<SlotSolution, i32>;
+
+fn change(entity_index: usize, value: i32) -> SlotChange {
+    ChangeMove::new(
+        entity_index,
+        Some(value),
+        get_value,
+        set_value,
+        0,
+        "value",
+        0,
+    )
+}
+
+fn create_director(values: &[i32]) -> ScoreDirector<SlotSolution, ()> {
+    let solution = SlotSolution {
+        slots: values.iter().map(|&v| Slot { value: Some(v) }).collect(),
+        score: None,
+    };
+    let descriptor = SolutionDescriptor::new("SlotSolution", TypeId::of::<SlotSolution>());
+    ScoreDirector::simple(solution, descriptor, |s, _| s.slots.len())
+}
+
+fn values(director: &ScoreDirector<SlotSolution, ()>) -> Vec<Option<i32>> {
+    director
+        .working_solution()
+        .slots
+        .iter()
+        .map(|slot| slot.value)
+        .collect()
+}
+
+#[test]
+fn composite_with_undoable_second_member_leaves_solution_unchanged() {
+    let mut director = create_director(&[1, 2]);
+    let mut first = MoveArena::new();
+    first.push(change(0, 5));
+    let mut second = MoveArena::new();
+    // Slot 1 already holds 2, so this member is not doable.
+    second.push(change(1, 2));
+
+    let composite = CompositeMove::<SlotSolution, SlotChange, SlotChange>::new(0, 0);
+    assert!(!composite.all_doable(&first, &second, &director));
+    assert!(composite
+        .do_move_with_arenas(&first, &second, &mut director)
+        .is_none());
+    assert_eq!(values(&director), vec![Some(1), Some(2)]);
+}
+
+#[test]
+fn composite_rolls_back_when_members_conflict_at_apply_time() {
+    let mut director = create_director(&[1, 2]);
+    let mut first = MoveArena::new();
+    first.push(change(0, 5));
+    let mut second = MoveArena::new();
+    // Doable on its own, but not once the first member has run.
+    second.push(change(0, 5));
+
+    let composite = CompositeMove::<SlotSolution, SlotChange, SlotChange>::new(0, 0);
+    assert!(first.get(0).unwrap().is_doable(&director));
+    assert!(second.get(0).unwrap().is_doable(&director));
+    assert!(!composite.all_doable(&first, &second, &director));
+    assert!(composite
+        .do_move_with_arenas(&first, &second, &mut director)
+        .is_none());
+    assert_eq!(values(&director), vec![Some(1), Some(2)]);
+}
+
+mod builder;
+mod sequential;
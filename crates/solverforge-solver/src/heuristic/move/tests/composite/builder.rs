@@ -0,0 +1,33 @@
+// Tests for CompositeMoveBuilder admission.
+
+use super::*;
+
+#[test]
+fn composite_builder_admits_only_fully_doable_composites() {
+    let mut director = create_director(&[1, 2]);
+    let mut first = MoveArena::new();
+    first.push(change(0, 5));
+    let mut second = MoveArena::new();
+    second.push(change(1, 2));
+    second.push(change(1, 7));
+
+    let incomplete = CompositeMoveBuilder::new(&first, &second).with_first(0);
+    assert!(incomplete.build(&director).is_none());
+    let rejected = CompositeMoveBuilder::new(&first, &second)
+        .with_first(0)
+        .with_second(0);
+    assert!(rejected.build(&director).is_none());
+
+    let composite = CompositeMoveBuilder::new(&first, &second)
+        .with_first(0)
+        .with_second(1)
+        .build(&director)
+        .expect("both members are doable");
+    let undo = composite
+        .do_move_with_arenas(&first, &second, &mut director)
+        .expect("composite applies");
+    assert_eq!(values(&director), vec![Some(5), Some(7)]);
+
+    composite.undo_move_with_arenas(&first, &second, &mut director, undo);
+    assert_eq!(values(&director), vec![Some(1), Some(2)]);
+}
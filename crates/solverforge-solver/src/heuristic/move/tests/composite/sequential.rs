@@ -0,0 +1,29 @@
+// Tests for SequentialCompositeMove telemetry.
+
+use super::*;
+
+#[test]
+fn sequential_composite_reports_type_name_and_merged_affected_entities() {
+    let scope = metadata::MoveTabuScope::new(0, "value");
+    let signature = MoveTabuSignature::new(scope, smallvec::smallvec![1], smallvec::smallvec![1]);
+    let distinct = SequentialCompositeMove::<SlotSolution, _>::new(
+        change(2, 7),
+        change(0, 7),
+        0,
+        smallvec::smallvec![2, 0],
+        "value",
+        signature.clone(),
+    );
+    let repeated = SequentialCompositeMove::<SlotSolution, _>::new(
+        change(2, 7),
+        change(2, 9),
+        0,
+        smallvec::smallvec![2, 2],
+        "value",
+        signature,
+    );
+
+    assert_eq!(distinct.type_name(), "composite");
+    assert_eq!(distinct.affected_entities().as_slice(), &[2, 0]);
+    assert_eq!(repeated.affected_entities().as_slice(), &[2]);
+}
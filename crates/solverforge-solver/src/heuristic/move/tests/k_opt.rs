@@ -1,7 +1,9 @@
 // Tests for KOptMove and k-opt reconnection enumeration.
 
 use super::*;
-use crate::heuristic::r#move::k_opt_reconnection::THREE_OPT_RECONNECTIONS;
+use crate::heuristic::r#move::k_opt_reconnection::{
+    enumerate_reconnections, KOptReconnection, THREE_OPT_RECONNECTIONS,
+};
 
 /* =============================================================================
 KOptMove tests
@@ -165,6 +167,91 @@ mod k_opt_tests {
         assert_eq!(cities, &[1, 2, 3, 4, 5, 6, 7, 8]);
     }
 
+    #[test]
+    fn four_opt_reorders_and_reverses_segments() {
+        let tours = vec![Tour {
+            cities: vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        }];
+        let mut director = create_director(tours);
+
+        let cuts = [
+            CutPoint::new(0, 2),
+            CutPoint::new(0, 4),
+            CutPoint::new(0, 6),
+            CutPoint::new(0, 8),
+        ];
+        // A-D-C'-B-E
+        let reconnection = KOptReconnection::new([0, 3, 2, 1, 4, 0], 0b0100, 5);
+
+        let m = KOptMove::<TspSolution, i32>::new(
+            &cuts,
+            &reconnection,
+            list_len,
+            list_get,
+            sublist_remove,
+            sublist_insert,
+            "cities",
+            0,
+        );
+
+        assert!(m.is_doable(&director));
+        assert_eq!(m.k(), 4);
+
+        {
+            let mut recording = SnapshotDirector::new(&mut director);
+            m.do_move(&mut recording);
+
+            let cities = &director.working_solution().tours[0].cities;
+            assert_eq!(cities, &[1, 2, 7, 8, 6, 5, 3, 4, 9, 10]);
+
+            recording.undo_changes();
+        }
+
+        let cities = &director.working_solution().tours[0].cities;
+        assert_eq!(cities, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn every_four_opt_reconnection_keeps_each_city_exactly_once() {
+        let original: Vec<i32> = (1..=10).collect();
+        let mut director = create_director(vec![Tour {
+            cities: original.clone(),
+        }]);
+        let cuts = [
+            CutPoint::new(0, 2),
+            CutPoint::new(0, 4),
+            CutPoint::new(0, 6),
+            CutPoint::new(0, 8),
+        ];
+
+        for reconnection in enumerate_reconnections(4) {
+            let m = KOptMove::<TspSolution, i32>::new(
+                &cuts,
+                &reconnection,
+                list_len,
+                list_get,
+                sublist_remove,
+                sublist_insert,
+                "cities",
+                0,
+            );
+            assert!(m.is_doable(&director));
+
+            let mut recording = SnapshotDirector::new(&mut director);
+            m.do_move(&mut recording);
+
+            let mut cities = recording.working_solution().tours[0].cities.clone();
+            assert_ne!(cities, original, "{reconnection:?} left the tour unchanged");
+            cities.sort_unstable();
+            assert_eq!(
+                cities, original,
+                "{reconnection:?} dropped or duplicated a city"
+            );
+
+            recording.undo_changes();
+        }
+    }
+
     #[test]
     fn invalid_cuts_not_doable() {
         let tours = vec![Tour {
@@ -0,0 +1,280 @@
+// Tests for boundary-only previous/next maintenance after list relocations.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use super::*;
+
+#[derive(Clone, Debug)]
+struct Vehicle {
+    visits: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct LinkedSolution {
+    vehicles: Vec<Vehicle>,
+    previous: Vec<Option<usize>>,
+    next: Vec<Option<usize>>,
+    // Visits whose links were rewritten since the last clear.
+    touched: BTreeSet<usize>,
+    score: Option<SoftScore>,
+}
+
+impl LinkedSolution {
+    fn link(&mut self, visits: &[usize], position: usize) {
+        let visit = visits[position];
+        self.previous[visit] = position.checked_sub(1).map(|p| visits[p]);
+        self.next[visit] = visits.get(position + 1).copied();
+        self.touched.insert(visit);
+    }
+
+    fn expected_links(&self) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+        let mut previous = vec![None; self.previous.len()];
+        let mut next = vec![None; self.next.len()];
+        for vehicle in &self.vehicles {
+            for (position, &visit) in vehicle.visits.iter().enumerate() {
+                previous[visit] = position.checked_sub(1).map(|p| vehicle.visits[p]);
+                next[visit] = vehicle.visits.get(position + 1).copied();
+            }
+        }
+        (previous, next)
+    }
+}
+
+impl PlanningSolution for LinkedSolution {
+    type Score = SoftScore;
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+
+    fn update_entity_shadows(&mut self, _descriptor_index: usize, entity_index: usize) {
+        let visits = self.vehicles[entity_index].visits.clone();
+        for position in 0..visits.len() {
+            self.link(&visits, position);
+        }
+    }
+
+    fn update_list_shadows(
+        &mut self,
+        _descriptor_index: usize,
+        entity_index: usize,
+        changed: &[Range<usize>],
+    ) {
+        let visits = self.vehicles[entity_index].visits.clone();
+        for range in changed {
+            let end = range.end.min(visits.len());
+            let first = range.start.min(end).saturating_sub(1);
+            for position in first..(end + 1).min(visits.len()) {
+                self.link(&visits, position);
+            }
+        }
+    }
+
+    fn update_all_shadows(&mut self) {
+        for entity_index in 0..self.vehicles.len() {
+            self.update_entity_shadows(0, entity_index);
+        }
+    }
+}
+
+fn get_vehicles(s: &LinkedSolution) -> &Vec<Vehicle> {
+    &s.vehicles
+}
+fn get_vehicles_mut(s: &mut LinkedSolution) -> &mut Vec<Vehicle> {
+    &mut s.vehicles
+}
+
+fn list_len(s: &LinkedSolution, entity_idx: usize) -> usize {
+    s.vehicles.get(entity_idx).map_or(0, |v| v.visits.len())
+}
+fn list_get(s: &LinkedSolution, entity_idx: usize, pos: usize) -> Option<usize> {
+    s.vehicles
+        .get(entity_idx)
+        .and_then(|v| v.visits.get(pos))
+        .copied()
+}
+fn list_remove(s: &mut LinkedSolution, entity_idx: usize, pos: usize) -> Option<usize> {
+    s.vehicles.get_mut(entity_idx).map(|v| v.visits.remove(pos))
+}
+fn list_insert(s: &mut LinkedSolution, entity_idx: usize, pos: usize, val: usize) {
+    if let Some(v) = s.vehicles.get_mut(entity_idx) {
+        v.visits.insert(pos, val);
+    }
+}
+fn sublist_remove(
+    s: &mut LinkedSolution,
+    entity_idx: usize,
+    start: usize,
+    end: usize,
+) -> Vec<usize> {
+    s.vehicles
+        .get_mut(entity_idx)
+        .map(|v| v.visits.drain(start..end).collect())
+        .unwrap_or_default()
+}
+fn sublist_insert(s: &mut LinkedSolution, entity_idx: usize, pos: usize, items: Vec<usize>) {
+    if let Some(v) = s.vehicles.get_mut(entity_idx) {
+        v.visits.splice(pos..pos, items);
+    }
+}
+
+// Two routes: [0, 1, 2, 3, 4] and [5, 6, 7].
+fn create_director() -> ScoreDirector<LinkedSolution, ()> {
+    let solution = LinkedSolution {
+        vehicles: vec![
+            Vehicle {
+                visits: vec![0, 1, 2, 3, 4],
+            },
+            Vehicle {
+                visits: vec![5, 6, 7],
+            },
+        ],
+        previous: vec![None; 8],
+        next: vec![None; 8],
+        touched: BTreeSet::new(),
+        score: None,
+    };
+    let extractor = Box::new(EntityCollectionExtractor::new(
+        "Vehicle",
+        "vehicles",
+        get_vehicles,
+        get_vehicles_mut,
+    ));
+    let entity_desc = EntityDescriptor::new("Vehicle", TypeId::of::<Vehicle>(), "vehicles")
+        .with_extractor(extractor);
+    let descriptor = SolutionDescriptor::new("LinkedSolution", TypeId::of::<LinkedSolution>())
+        .with_entity(entity_desc);
+    let mut director = ScoreDirector::simple(solution, descriptor, |s, _| s.vehicles.len());
+    director.calculate_score();
+    director.working_solution_mut().touched.clear();
+    director
+}
+
+fn assert_links_consistent(director: &ScoreDirector<LinkedSolution, ()>) {
+    let solution = director.working_solution();
+    let (previous, next) = solution.expected_links();
+    assert_eq!(solution.previous, previous);
+    assert_eq!(solution.next, next);
+}
+
+fn take_touched(director: &mut ScoreDirector<LinkedSolution, ()>) -> Vec<usize> {
+    std::mem::take(&mut director.working_solution_mut().touched)
+        .into_iter()
+        .collect()
+}
+
+fn change_move(
+    source_entity: usize,
+    source_position: usize,
+    dest_entity: usize,
+    dest_position: usize,
+) -> ListChangeMove<LinkedSolution, usize> {
+    ListChangeMove::new(
+        source_entity,
+        source_position,
+        dest_entity,
+        dest_position,
+        list_len,
+        list_get,
+        list_remove,
+        list_insert,
+        "visits",
+        0,
+    )
+}
+
+#[test]
+fn inter_list_change_relinks_only_old_and_new_neighbors() {
+    let mut director = create_director();
+    let m = change_move(0, 2, 1, 1);
+
+    m.do_move(&mut director);
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![0, 1, 3, 4]
+    );
+    assert_eq!(
+        director.working_solution().vehicles[1].visits,
+        vec![5, 2, 6, 7]
+    );
+    assert_links_consistent(&director);
+    assert_eq!(take_touched(&mut director), vec![1, 2, 3, 5, 6]);
+
+    m.undo_move(&mut director, ());
+    assert_links_consistent(&director);
+    assert_eq!(take_touched(&mut director), vec![1, 2, 3, 5, 6]);
+}
+
+#[test]
+fn intra_list_change_relinks_both_boundaries() {
+    let mut director = create_director();
+    let m = change_move(0, 0, 0, 4);
+
+    m.do_move(&mut director);
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![1, 2, 3, 0, 4]
+    );
+    assert_links_consistent(&director);
+    assert_eq!(take_touched(&mut director), vec![0, 1, 3, 4]);
+
+    m.undo_move(&mut director, ());
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_links_consistent(&director);
+}
+
+#[test]
+fn backward_intra_list_change_keeps_links_consistent() {
+    let mut director = create_director();
+    let m = change_move(0, 4, 0, 1);
+
+    m.do_move(&mut director);
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![0, 4, 1, 2, 3]
+    );
+    assert_links_consistent(&director);
+    assert_eq!(take_touched(&mut director), vec![0, 1, 3, 4]);
+
+    m.undo_move(&mut director, ());
+    assert_links_consistent(&director);
+}
+
+#[test]
+fn sublist_change_relinks_segment_boundaries() {
+    let mut director = create_director();
+    let m = SublistChangeMove::<LinkedSolution, usize>::new(
+        0,
+        1,
+        3,
+        1,
+        3,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    );
+
+    m.do_move(&mut director);
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![0, 3, 4]
+    );
+    assert_eq!(
+        director.working_solution().vehicles[1].visits,
+        vec![5, 6, 7, 1, 2]
+    );
+    assert_links_consistent(&director);
+    assert_eq!(take_touched(&mut director), vec![0, 1, 2, 3, 7]);
+
+    m.undo_move(&mut director, ());
+    assert_links_consistent(&director);
+}
@@ -467,4 +467,6 @@ fn precedence_ruin_restores_original_when_recreate_has_no_safe_position() {
     assert_eq!(director.working_solution().routes[0].stops, vec![1, 2]);
 }
 
+#[path = "list_ruin_recreate.rs"]
+mod recreate;
 mod transfer;
@@ -11,7 +11,7 @@ use solverforge_scoring::ScoreDirector;
 use crate::heuristic::r#move::list_kernel::{
     ruin_do_move, single_ruin_source, RuinValueTransfer, StaticListRuinAccess,
 };
-use crate::heuristic::r#move::{ListRuinMove, Move};
+use crate::heuristic::r#move::{ListRuinMove, Move, RecreateStrategy};
 
 #[derive(Debug)]
 struct CloneProbe {
@@ -141,6 +141,7 @@ fn transfer_clone_count(counter: Arc<AtomicUsize>) -> usize {
         &single_ruin_source(0, &[0]),
         false,
         RuinValueTransfer::MoveIntoInsert,
+        RecreateStrategy::Greedy,
         &mut score_director,
     );
     counter.load(Ordering::SeqCst)
@@ -153,3 +153,51 @@ fn list_moves_report_specific_telemetry_labels() {
     assert_eq!(sublist_swap.telemetry_label(), "sublist_swap");
     assert_eq!(k_opt.telemetry_label(), "k_opt");
 }
+
+#[test]
+fn list_change_and_k_opt_report_type_names_and_affected_entities() {
+    let intra = ListChangeMove::<Plan, usize>::new(
+        0,
+        0,
+        0,
+        2,
+        list_len,
+        list_get,
+        list_remove,
+        list_insert,
+        "routes",
+        0,
+    );
+    let inter = ListChangeMove::<Plan, usize>::new(
+        0,
+        0,
+        1,
+        0,
+        list_len,
+        list_get,
+        list_remove,
+        list_insert,
+        "routes",
+        0,
+    );
+    let k_opt = KOptMove::<Plan, usize>::new(
+        &[
+            CutPoint::new(1, 1),
+            CutPoint::new(1, 2),
+            CutPoint::new(1, 3),
+        ],
+        &THREE_OPT_RECONNECTIONS[3],
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "routes",
+        0,
+    );
+
+    assert_eq!(intra.type_name(), "list_change");
+    assert_eq!(intra.affected_entities().as_slice(), &[0]);
+    assert_eq!(inter.affected_entities().as_slice(), &[0, 1]);
+    assert_eq!(k_opt.type_name(), "k_opt");
+    assert_eq!(k_opt.affected_entities().as_slice(), &[1]);
+}
@@ -117,10 +117,12 @@ where
 
 mod arena;
 mod change;
+mod composite;
 mod compound_scalar;
 mod conflict_repair;
 mod k_opt;
 mod list_change;
+mod list_links;
 mod list_multi_swap;
 mod list_permute;
 mod list_reverse;
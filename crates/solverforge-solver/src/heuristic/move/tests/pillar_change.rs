@@ -165,3 +165,18 @@ fn test_pillar_change_entity_indices() {
     );
     assert_eq!(m.entity_indices(), &[1, 3, 5]);
 }
+
+#[test]
+fn test_pillar_change_type_name_and_affected_entities() {
+    let m = PillarChangeMove::<ScheduleSolution, i32>::new(
+        vec![1, 3, 5],
+        Some(5),
+        get_shift,
+        set_shift,
+        0,
+        "shift",
+        0,
+    );
+    assert_eq!(m.type_name(), "pillar_change");
+    assert_eq!(m.affected_entities().as_slice(), &[1, 3, 5]);
+}
@@ -108,6 +108,70 @@ fn test_pillar_swap_all_entities() {
     assert_eq!(solution.employees[3].id, 3);
 }
 
+fn employees_on(shifts: &[i32]) -> Vec<Employee> {
+    shifts
+        .iter()
+        .enumerate()
+        .map(|(id, &shift)| Employee {
+            id,
+            shift: Some(shift),
+        })
+        .collect()
+}
+
+fn shifts(director: &ScoreDirector<Solution, ()>) -> Vec<Option<i32>> {
+    director
+        .working_solution()
+        .employees
+        .iter()
+        .map(|e| e.shift)
+        .collect()
+}
+
+#[test]
+fn pillar_swap_of_unequal_sizes_moves_the_longer_tail_and_undoes_exactly() {
+    let mut director = create_director(employees_on(&[1, 1, 1, 2, 2]));
+    let m = PillarSwapMove::<Solution, i32>::new(
+        vec![0, 1, 2],
+        vec![3, 4],
+        get_shift,
+        set_shift,
+        0,
+        "shift",
+        0,
+    );
+    assert!(m.is_doable(&director));
+
+    let undo = m.do_move(&mut director);
+    assert_eq!(
+        shifts(&director),
+        vec![Some(2), Some(2), Some(2), Some(1), Some(1)]
+    );
+
+    m.undo_move(&mut director, undo);
+    assert_eq!(
+        shifts(&director),
+        vec![Some(1), Some(1), Some(1), Some(2), Some(2)]
+    );
+}
+
+// Employee 2 cannot work shift 2.
+fn shift_is_legal(_: &Solution, idx: usize, _variable_index: usize, shift: Option<&i32>) -> bool {
+    !(idx == 2 && shift == Some(&2))
+}
+
+#[test]
+fn pillar_swap_rejects_values_outside_an_entity_value_range() {
+    let director = create_director(employees_on(&[1, 1, 1, 2, 2]));
+    let swap = |left: Vec<usize>| {
+        PillarSwapMove::<Solution, i32>::new(left, vec![3, 4], get_shift, set_shift, 0, "shift", 0)
+            .with_value_is_legal(Some(shift_is_legal))
+    };
+
+    assert!(!swap(vec![0, 1, 2]).is_doable(&director));
+    assert!(swap(vec![0, 1]).is_doable(&director));
+}
+
 #[test]
 fn test_pillar_swap_same_value_not_doable() {
     let director = create_director(vec![
@@ -377,3 +377,5 @@ fn inter_list_tabu_inverse_matches_reverse_move() {
     assert_eq!(solution.vehicles[0].visits, vec![1, 2, 3, 4]);
     assert_eq!(solution.vehicles[1].visits, vec![10, 20]);
 }
+
+mod reversed;
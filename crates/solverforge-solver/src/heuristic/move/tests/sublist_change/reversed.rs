@@ -0,0 +1,174 @@
+// Tests for reversed-insertion SublistChangeMove operations.
+
+use super::*;
+
+#[test]
+fn reversed_inter_list_move_inserts_backwards_and_undoes_exactly() {
+    let vehicles = vec![
+        Vehicle {
+            visits: vec![1, 2, 3, 4, 5],
+        },
+        Vehicle {
+            visits: vec![10, 20],
+        },
+    ];
+    let mut director = create_director(vehicles);
+
+    let m = SublistChangeMove::<RoutingSolution, i32>::new(
+        0,
+        1,
+        4,
+        1,
+        1,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    )
+    .with_reversed(true);
+
+    assert!(m.is_reversed());
+    assert!(m.is_doable(&director));
+
+    m.do_move(&mut director);
+    let sol = director.working_solution();
+    assert_eq!(sol.vehicles[0].visits, vec![1, 5]);
+    assert_eq!(sol.vehicles[1].visits, vec![10, 4, 3, 2, 20]);
+
+    m.undo_move(&mut director, ());
+    let sol = director.working_solution();
+    assert_eq!(sol.vehicles[0].visits, vec![1, 2, 3, 4, 5]);
+    assert_eq!(sol.vehicles[1].visits, vec![10, 20]);
+}
+
+#[test]
+fn reversed_intra_list_relocation_undoes_exactly() {
+    let vehicles = vec![Vehicle {
+        visits: vec![1, 2, 3, 4, 5, 6],
+    }];
+    let mut director = create_director(vehicles);
+
+    let m = SublistChangeMove::<RoutingSolution, i32>::new(
+        0,
+        1,
+        3,
+        0,
+        4,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    )
+    .with_reversed(true);
+
+    assert!(m.is_doable(&director));
+
+    m.do_move(&mut director);
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![1, 4, 5, 6, 3, 2]
+    );
+
+    m.undo_move(&mut director, ());
+    assert_eq!(
+        director.working_solution().vehicles[0].visits,
+        vec![1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[test]
+fn reversed_move_at_source_start_not_doable() {
+    let vehicles = vec![Vehicle {
+        visits: vec![1, 2, 3, 4, 5],
+    }];
+    let director = create_director(vehicles);
+
+    let m = SublistChangeMove::<RoutingSolution, i32>::new(
+        0,
+        1,
+        4,
+        0,
+        1,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    )
+    .with_reversed(true);
+
+    assert!(!m.is_doable(&director));
+}
+
+#[test]
+fn reversed_tabu_inverse_matches_reversed_return_move() {
+    let vehicles = vec![
+        Vehicle {
+            visits: vec![1, 2, 3, 4],
+        },
+        Vehicle {
+            visits: vec![10, 20],
+        },
+    ];
+    let mut director = create_director(vehicles);
+
+    let m = SublistChangeMove::<RoutingSolution, i32>::new(
+        0,
+        1,
+        3,
+        1,
+        1,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    )
+    .with_reversed(true);
+    let signature = m.tabu_signature(&director);
+
+    m.do_move(&mut director);
+    let return_move = SublistChangeMove::<RoutingSolution, i32>::new(
+        1,
+        1,
+        3,
+        0,
+        1,
+        list_len,
+        list_get,
+        sublist_remove,
+        sublist_insert,
+        "visits",
+        0,
+    )
+    .with_reversed(true);
+
+    assert_eq!(
+        signature.undo_move_id,
+        return_move.tabu_signature(&director).move_id
+    );
+    assert_ne!(
+        signature.move_id,
+        SublistChangeMove::<RoutingSolution, i32>::new(
+            0,
+            1,
+            3,
+            1,
+            1,
+            list_len,
+            list_get,
+            sublist_remove,
+            sublist_insert,
+            "visits",
+            0,
+        )
+        .tabu_signature(&director)
+        .move_id
+    );
+}
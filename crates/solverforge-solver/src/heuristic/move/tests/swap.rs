@@ -134,6 +134,13 @@ fn test_swap_entity_indices() {
     assert_eq!(m.entity_indices(), &[2, 5]);
 }
 
+#[test]
+fn test_swap_type_name_and_affected_entities() {
+    let m = SwapMove::<TaskSolution, i32>::new(2, 5, get_priority, set_priority, 0, "priority", 0);
+    assert_eq!(m.type_name(), "swap");
+    assert_eq!(m.affected_entities().as_slice(), &[2, 5]);
+}
+
 #[test]
 fn swap_tabu_identity_is_direction_stable() {
     use crate::phase::localsearch::{Acceptor, TabuSearchAcceptor, TabuSearchPolicy};
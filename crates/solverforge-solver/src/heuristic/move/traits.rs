@@ -2,6 +2,7 @@
 
 use std::fmt::Debug;
 
+use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
@@ -58,6 +59,21 @@ pub trait Move<S: PlanningSolution>: Send + Sync + Debug {
         "move"
     }
 
+    /// Returns the stable name of this move's type.
+    ///
+    /// Opt-in solver statistics aggregate accepted improvements under this
+    /// name. Defaults to the telemetry label.
+    fn type_name(&self) -> &'static str {
+        self.telemetry_label()
+    }
+
+    /// Returns the entities this move changes, without duplicates.
+    ///
+    /// Defaults to `entity_indices()`.
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        SmallVec::from_slice(self.entity_indices())
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         false
     }
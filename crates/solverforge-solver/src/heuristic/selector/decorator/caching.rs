@@ -0,0 +1,233 @@
+/* Caching move selector decorator.
+
+Snapshots the moves of an inner selector and lends them to every cursor
+opened until the working solution changes.
+*/
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use solverforge_core::domain::PlanningSolution;
+use solverforge_scoring::Director;
+
+use crate::heuristic::r#move::Move;
+use crate::heuristic::selector::move_selector::{
+    CandidateId, MoveCandidateRef, MoveCursor, MoveSelector, MoveStreamContext, UnitResourceCursor,
+};
+use crate::phase::localsearch::MoveCursorSource;
+
+// The moves generated against the working solution as of the last commit.
+struct MoveCache<M> {
+    moves: Vec<M>,
+}
+
+/// Memoizes the move list of an inner selector while the solution is unchanged.
+///
+/// `CachingMoveSelector` is a phase-facing [`MoveCursorSource`]: the cache is
+/// plain `&mut self` state captured on the first cursor opened after a
+/// committed change. Every later cursor lends the cached moves by index
+/// instead of regenerating them, so local-search steps that commit nothing
+/// reuse one snapshot. The cache is dropped when one of its candidates is
+/// taken or applied, and when the phase calls
+/// [`MoveCursorSource::solution_changed`] or
+/// [`MoveCursorSource::phase_started`].
+///
+/// Cached moves are never cloned. Taking or applying a candidate moves it out
+/// of the snapshot and discards the rest, because the working solution is
+/// about to change. A reused snapshot keeps the order of the stream it was
+/// captured from; later stream seeds do not reorder it.
+///
+/// The inner selector must be finite.
+///
+/// # Example
+///
+/// ```
+/// use solverforge_solver::heuristic::selector::decorator::CachingMoveSelector;
+/// use solverforge_solver::heuristic::selector::ChangeMoveSelector;
+/// use solverforge_solver::heuristic::r#move::ChangeMove;
+/// use solverforge_core::domain::PlanningSolution;
+/// use solverforge_core::score::SoftScore;
+///
+/// #[derive(Clone, Debug)]
+/// struct Task { priority: Option<i32> }
+///
+/// #[derive(Clone, Debug)]
+/// struct Solution { tasks: Vec<Task>, score: Option<SoftScore> }
+///
+/// impl PlanningSolution for Solution {
+///     type Score = SoftScore;
+///     fn score(&self) -> Option<Self::Score> { self.score }
+///     fn set_score(&mut self, score: Option<Self::Score>) { self.score = score; }
+/// }
+///
+/// fn get_priority(s: &Solution, i: usize, _variable_index: usize) -> Option<i32> { s.tasks.get(i).and_then(|t| t.priority) }
+/// fn set_priority(s: &mut Solution, i: usize, _variable_index: usize, v: Option<i32>) { if let Some(t) = s.tasks.get_mut(i) { t.priority = v; } }
+///
+/// let inner = ChangeMoveSelector::simple(
+///     get_priority, set_priority, 0, 0, "priority", vec![10, 60, 80],
+/// );
+/// let cached: CachingMoveSelector<Solution, ChangeMove<Solution, i32>, _> =
+///     CachingMoveSelector::new(inner);
+/// assert_eq!(cached.cached_move_count(), None);
+/// ```
+pub struct CachingMoveSelector<S, M, Inner> {
+    inner: Inner,
+    cache: Option<MoveCache<M>>,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<S, M, Inner> CachingMoveSelector<S, M, Inner>
+where
+    S: PlanningSolution,
+    M: Move<S>,
+    Inner: MoveSelector<S, M>,
+{
+    pub fn new(inner: Inner) -> Self {
+        assert!(
+            !inner.is_never_ending(),
+            "CachingMoveSelector requires a finite inner selector"
+        );
+        Self {
+            inner,
+            cache: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn inner(&self) -> &Inner {
+        &self.inner
+    }
+
+    /// Number of moves in the current snapshot, or `None` when nothing is cached.
+    pub fn cached_move_count(&self) -> Option<usize> {
+        self.cache.as_ref().map(|cache| cache.moves.len())
+    }
+
+    fn generate<D: Director<S>>(&self, score_director: &D, context: MoveStreamContext) -> Vec<M> {
+        let mut cursor = self.inner.open_cursor_with_context(score_director, context);
+        let mut moves = Vec::new();
+        while let Some(mov) = cursor.next_owned_candidate() {
+            moves.push(mov);
+        }
+        moves
+    }
+}
+
+impl<S, M, Inner: Debug> Debug for CachingMoveSelector<S, M, Inner> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachingMoveSelector")
+            .field("inner", &self.inner)
+            .field(
+                "cached_moves",
+                &self.cache.as_ref().map(|cache| cache.moves.len()),
+            )
+            .finish()
+    }
+}
+
+impl<S, M, Inner> MoveCursorSource<S, M> for CachingMoveSelector<S, M, Inner>
+where
+    S: PlanningSolution,
+    M: Move<S>,
+    Inner: MoveSelector<S, M>,
+{
+    type Resources = ();
+
+    type Cursor<'a>
+        = UnitResourceCursor<CachedMoveCursor<'a, S, M>>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<S>>(
+        &'a mut self,
+        _resources: &mut Self::Resources,
+        score_director: &D,
+        context: MoveStreamContext,
+    ) -> Self::Cursor<'a> {
+        if self.cache.is_none() {
+            let moves = self.generate(score_director, context);
+            self.cache = Some(MoveCache { moves });
+        }
+        UnitResourceCursor::new(CachedMoveCursor::new(&mut self.cache))
+    }
+
+    fn phase_started(&mut self) {
+        self.cache = None;
+    }
+
+    fn solution_changed(&mut self) {
+        self.cache = None;
+    }
+}
+
+/// Cursor lending the moves of a [`CachingMoveSelector`] snapshot by index.
+#[doc(hidden)]
+pub struct CachedMoveCursor<'a, S, M> {
+    cache: &'a mut Option<MoveCache<M>>,
+    released: Vec<bool>,
+    next_index: usize,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<'a, S, M> CachedMoveCursor<'a, S, M> {
+    fn new(cache: &'a mut Option<MoveCache<M>>) -> Self {
+        let len = cache.as_ref().map_or(0, |cache| cache.moves.len());
+        Self {
+            cache,
+            released: vec![false; len],
+            next_index: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn live_move(&self, id: CandidateId) -> Option<&M> {
+        if *self.released.get(id.index())? {
+            return None;
+        }
+        self.cache.as_ref()?.moves.get(id.index())
+    }
+}
+
+impl<S, M> MoveCursor<S, M> for CachedMoveCursor<'_, S, M>
+where
+    S: PlanningSolution,
+    M: Move<S>,
+{
+    fn next_candidate(&mut self) -> Option<CandidateId> {
+        while self.next_index < self.released.len() {
+            let id = CandidateId::new(self.next_index);
+            self.next_index += 1;
+            if self.live_move(id).is_some() {
+                return Some(id);
+            }
+        }
+        None
+    }
+
+    fn candidate(&self, id: CandidateId) -> Option<MoveCandidateRef<'_, S, M>> {
+        self.live_move(id).map(MoveCandidateRef::Borrowed)
+    }
+
+    fn take_candidate(&mut self, id: CandidateId) -> M {
+        assert!(
+            self.live_move(id).is_some(),
+            "cached candidate {id:?} was already released or taken"
+        );
+        let mut cache = self
+            .cache
+            .take()
+            .expect("live cached candidate implies a snapshot");
+        cache.moves.swap_remove(id.index())
+    }
+
+    fn release_candidate(&mut self, id: CandidateId) -> bool {
+        if self.live_move(id).is_none() {
+            return false;
+        }
+        self.released[id.index()] = true;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests;
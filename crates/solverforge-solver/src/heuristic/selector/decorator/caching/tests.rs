@@ -0,0 +1,141 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::super::test_utils::{create_director, get_priority, set_priority, Task, TaskSolution};
+use super::*;
+use crate::heuristic::r#move::ChangeMove;
+use crate::heuristic::selector::move_selector::ResourceMoveCursor;
+use crate::heuristic::selector::{
+    ChangeMoveSelector, FromSolutionEntitySelector, StaticValueSelector,
+};
+
+type TaskChangeSelector = ChangeMoveSelector<
+    TaskSolution,
+    i32,
+    FromSolutionEntitySelector,
+    StaticValueSelector<TaskSolution, i32>,
+>;
+
+#[derive(Debug)]
+struct CountingSelector {
+    inner: TaskChangeSelector,
+    opened: AtomicUsize,
+}
+
+impl CountingSelector {
+    fn new(values: Vec<i32>) -> Self {
+        Self {
+            inner: ChangeMoveSelector::simple(get_priority, set_priority, 0, 0, "priority", values),
+            opened: AtomicUsize::new(0),
+        }
+    }
+
+    fn opened(&self) -> usize {
+        self.opened.load(Ordering::SeqCst)
+    }
+}
+
+impl MoveSelector<TaskSolution, ChangeMove<TaskSolution, i32>> for CountingSelector {
+    type Cursor<'a>
+        = <TaskChangeSelector as MoveSelector<TaskSolution, ChangeMove<TaskSolution, i32>>>::Cursor<
+        'a,
+    >
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<TaskSolution>>(
+        &'a self,
+        score_director: &D,
+    ) -> Self::Cursor<'a> {
+        self.opened.fetch_add(1, Ordering::SeqCst);
+        self.inner.open_cursor(score_director)
+    }
+
+    fn size<D: Director<TaskSolution>>(&self, score_director: &D) -> usize {
+        self.inner.size(score_director)
+    }
+}
+
+type TaskMove = ChangeMove<TaskSolution, i32>;
+type CachedTaskSelector = CachingMoveSelector<TaskSolution, TaskMove, CountingSelector>;
+
+fn step(step_index: u64, step_seed: u64) -> MoveStreamContext {
+    MoveStreamContext::new(step_index, step_seed, None)
+}
+
+// Drains one cursor and returns the proposed values in cursor order.
+fn drain_values<D: Director<TaskSolution>>(
+    cached: &mut CachedTaskSelector,
+    director: &D,
+    context: MoveStreamContext,
+) -> Vec<Option<i32>> {
+    let mut cursor = MoveCursorSource::open_cursor(cached, &mut (), director, context);
+    let mut values = Vec::new();
+    while let Some(id) = cursor.next_candidate_with_resources(&mut ()) {
+        let MoveCandidateRef::Borrowed(mov) = cursor.candidate(id).unwrap() else {
+            panic!("cached candidates are lent as borrowed moves");
+        };
+        values.push(mov.to_value().copied());
+        assert!(cursor.release_candidate(id));
+    }
+    values
+}
+
+#[test]
+fn reopening_without_a_committed_change_reuses_cached_moves() {
+    let director = create_director(vec![Task::with_priority(1), Task::with_priority(2)]);
+    let mut cached = CachingMoveSelector::new(CountingSelector::new(vec![10, 20, 30]));
+
+    let first = drain_values(&mut cached, &director, step(0, 7));
+    let second = drain_values(&mut cached, &director, step(1, 8));
+
+    assert_eq!(first.len(), 6);
+    assert_eq!(first, second);
+    assert_eq!(cached.inner().opened(), 1);
+    assert_eq!(cached.cached_move_count(), Some(6));
+}
+
+#[test]
+fn solution_change_drops_the_snapshot() {
+    let director = create_director(vec![Task::with_priority(1)]);
+    let mut cached = CachingMoveSelector::new(CountingSelector::new(vec![10, 20]));
+
+    assert_eq!(drain_values(&mut cached, &director, step(0, 7)).len(), 2);
+    MoveCursorSource::<TaskSolution, TaskMove>::solution_changed(&mut cached);
+    assert_eq!(cached.cached_move_count(), None);
+
+    assert_eq!(drain_values(&mut cached, &director, step(1, 7)).len(), 2);
+    assert_eq!(cached.inner().opened(), 2);
+}
+
+#[test]
+fn applying_a_candidate_invalidates_the_snapshot() {
+    let mut director = create_director(vec![Task::with_priority(1)]);
+    let mut cached = CachingMoveSelector::new(CountingSelector::new(vec![10, 20]));
+
+    {
+        let mut cursor = MoveCursorSource::open_cursor(&mut cached, &mut (), &director, step(0, 7));
+        let id = cursor.next_candidate_with_resources(&mut ()).unwrap();
+        cursor.apply_owned_candidate(id, &mut director);
+    }
+    assert_eq!(director.working_solution().tasks[0].priority, Some(10));
+    assert_eq!(cached.cached_move_count(), None);
+
+    assert_eq!(
+        drain_values(&mut cached, &director, step(0, 7)),
+        vec![Some(10), Some(20)]
+    );
+    assert_eq!(cached.inner().opened(), 2);
+}
+
+#[test]
+fn phase_start_drops_the_snapshot() {
+    let director = create_director(vec![Task::with_priority(1)]);
+    let mut cached = CachingMoveSelector::new(CountingSelector::new(vec![10, 20]));
+
+    drain_values(&mut cached, &director, step(0, 7));
+    MoveCursorSource::<TaskSolution, TaskMove>::phase_started(&mut cached);
+    assert_eq!(cached.cached_move_count(), None);
+
+    drain_values(&mut cached, &director, step(0, 7));
+    assert_eq!(cached.inner().opened(), 2);
+}
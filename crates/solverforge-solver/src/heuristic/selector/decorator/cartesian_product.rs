@@ -157,6 +157,7 @@ where
     Right: MoveSelector<S, M>,
 {
     require_hard_improvement: bool,
+    max_moves: Option<usize>,
     left_cursor: LeftCursor,
     right_selector: &'a Right,
     rows: Vec<Option<CartesianRow<S, M>>>,
@@ -176,6 +177,7 @@ where
 {
     fn new<D: Director<S>>(
         require_hard_improvement: bool,
+        max_moves: Option<usize>,
         left_cursor: LeftCursor,
         right_selector: &'a Right,
         score_director: &D,
@@ -183,6 +185,7 @@ where
     ) -> Self {
         Self {
             require_hard_improvement,
+            max_moves,
             left_cursor,
             right_selector,
             rows: Vec::new(),
@@ -248,6 +251,9 @@ where
     Right: MoveSelector<S, M>,
 {
     fn next_candidate(&mut self) -> Option<CandidateId> {
+        if self.max_moves.is_some_and(|max| self.pairs.len() >= max) {
+            return None;
+        }
         loop {
             if self.active_row.is_some() {
                 let inspected = {
@@ -450,6 +456,7 @@ pub struct CartesianProductSelector<S, M, Left, Right> {
     left: Left,
     right: Right,
     require_hard_improvement: bool,
+    max_moves: Option<usize>,
     _phantom: PhantomData<fn() -> (S, M)>,
 }
 
@@ -465,6 +472,7 @@ where
             left,
             right,
             require_hard_improvement: false,
+            max_moves: None,
             _phantom: PhantomData,
         }
     }
@@ -473,6 +481,12 @@ where
         self.require_hard_improvement = require_hard_improvement;
         self
     }
+
+    /// Caps the number of composed pairs yielded per cursor.
+    pub fn with_max_moves(mut self, max_moves: Option<usize>) -> Self {
+        self.max_moves = max_moves;
+        self
+    }
 }
 
 impl<S, M, Left, Right> Debug for CartesianProductSelector<S, M, Left, Right>
@@ -487,6 +501,7 @@ where
             .field("left", &self.left)
             .field("right", &self.right)
             .field("require_hard_improvement", &self.require_hard_improvement)
+            .field("max_moves", &self.max_moves)
             .finish()
     }
 }
@@ -515,6 +530,7 @@ where
         self.right.validate_cursor(score_director);
         CartesianProductCursor::new(
             self.require_hard_improvement,
+            self.max_moves,
             self.left.open_cursor_with_context(score_director, context),
             &self.right,
             score_director,
@@ -537,9 +553,11 @@ where
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
-        self.left
+        let size = self
+            .left
             .size(score_director)
-            .saturating_mul(self.right.size(score_director))
+            .saturating_mul(self.right.size(score_director));
+        self.max_moves.map_or(size, |max| size.min(max))
     }
 
     fn append_moves<D: Director<S>>(&self, _score_director: &D, _arena: &mut MoveArena<M>) {
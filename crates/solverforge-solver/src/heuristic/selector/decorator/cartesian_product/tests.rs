@@ -525,3 +525,57 @@ fn cartesian_product_reverse_signature_matches_true_inverse_order() {
         Some(&reverse_signature),
     ));
 }
+
+#[test]
+fn cartesian_product_of_change_selectors_yields_doable_and_undoable_pairs() {
+    let mut director = create_director(vec![Task {
+        x: Some(0),
+        y: Some(0),
+        shadow_y_target: None,
+    }]);
+    let selector = CartesianProductSelector::new(
+        ChangeMoveSelector::simple(get_x, set_x, 0, 0, "x", vec![1, 2, 3]),
+        ChangeMoveSelector::simple(get_y, set_y, 0, 0, "y", vec![10, 20]),
+    );
+
+    let mut cursor = selector.open_cursor(&director);
+    let indices: Vec<_> = std::iter::from_fn(|| cursor.next_candidate()).collect();
+
+    assert_eq!(selector.size(&director), 6);
+    assert_eq!(indices.len(), 6);
+    for &index in &indices {
+        let mov = cursor
+            .candidate(index)
+            .expect("cartesian candidate must remain valid");
+        assert!(mov.is_doable(&director));
+        assert_eq!(mov.type_name(), "composite");
+
+        let undo = mov.do_move(&mut director);
+        assert_ne!(get_x(director.working_solution(), 0, 0), Some(0));
+        assert_ne!(get_y(director.working_solution(), 0, 0), Some(0));
+        mov.undo_move(&mut director, undo);
+        assert_eq!(get_x(director.working_solution(), 0, 0), Some(0));
+        assert_eq!(get_y(director.working_solution(), 0, 0), Some(0));
+    }
+}
+
+#[test]
+fn cartesian_product_max_moves_caps_yielded_pairs() {
+    let director = create_director(vec![Task {
+        x: Some(0),
+        y: Some(0),
+        shadow_y_target: None,
+    }]);
+    let selector = CartesianProductSelector::new(
+        ChangeMoveSelector::simple(get_x, set_x, 0, 0, "x", vec![1, 2, 3]),
+        ChangeMoveSelector::simple(get_y, set_y, 0, 0, "y", vec![10, 20]),
+    )
+    .with_max_moves(Some(4));
+
+    let mut cursor = selector.open_cursor(&director);
+    let indices: Vec<_> = std::iter::from_fn(|| cursor.next_candidate()).collect();
+
+    assert_eq!(selector.size(&director), 4);
+    assert_eq!(indices.len(), 4);
+    assert!(cursor.next_candidate().is_none());
+}
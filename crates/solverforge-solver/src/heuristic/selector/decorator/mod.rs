@@ -3,10 +3,12 @@
 Decorators wrap an inner [`MoveSelector`] to modify its behavior without
 changing the move type. All decorators preserve the zero-erasure architecture.
 
+- [`CachingMoveSelector`] - lends a per-step move snapshot to every cursor in that step
 - [`CartesianProductArena`] - stores moves from two selectors for pair iteration
 - [`FilteringMoveSelector`] - filters moves by predicate
 */
 
+mod caching;
 mod cartesian_product;
 mod filtering;
 mod limited;
@@ -15,6 +17,7 @@ mod mapped_cursor;
 mod test_utils;
 mod vec_union;
 
+pub use caching::CachingMoveSelector;
 pub(crate) use cartesian_product::CartesianProductCursor;
 pub use cartesian_product::{CartesianProductArena, CartesianProductSelector};
 pub use filtering::FilteringMoveSelector;
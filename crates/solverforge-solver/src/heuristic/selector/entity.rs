@@ -71,16 +71,43 @@ impl<S: PlanningSolution> EntitySelector<S> for FromSolutionEntitySelector {
             .entity_count(self.descriptor_index)
             .unwrap_or(0);
         let desc_idx = self.descriptor_index;
-        (0..count).map(move |i| EntityReference::new(desc_idx, i))
+        let pinned = pinned_entities(score_director, desc_idx, count);
+        (0..count)
+            .filter(move |&i| pinned.as_ref().is_none_or(|pinned| !pinned[i]))
+            .map(move |i| EntityReference::new(desc_idx, i))
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
-        score_director
+        let count = score_director
             .entity_count(self.descriptor_index)
-            .unwrap_or(0)
+            .unwrap_or(0);
+        match pinned_entities(score_director, self.descriptor_index, count) {
+            Some(pinned) => pinned.iter().filter(|&&pinned| !pinned).count(),
+            None => count,
+        }
     }
 }
 
+/* Pin state of every entity of one descriptor, or `None` when the entity type
+declares no pinning and every entity is selectable.
+*/
+fn pinned_entities<S: PlanningSolution, D: Director<S>>(
+    score_director: &D,
+    descriptor_index: usize,
+    count: usize,
+) -> Option<Vec<bool>> {
+    let has_pinning = score_director
+        .solution_descriptor()
+        .entity_descriptors
+        .get(descriptor_index)
+        .is_some_and(|desc| desc.has_pinning());
+    has_pinning.then(|| {
+        (0..count)
+            .map(|i| score_director.is_entity_pinned(descriptor_index, i))
+            .collect()
+    })
+}
+
 // An entity selector that iterates over all entities from all descriptors.
 #[derive(Debug, Clone, Default)]
 pub struct AllEntitiesSelector;
@@ -103,7 +130,9 @@ impl<S: PlanningSolution> EntitySelector<S> for AllEntitiesSelector {
         for desc_idx in 0..descriptor_count {
             let count = score_director.entity_count(desc_idx).unwrap_or(0);
             for entity_idx in 0..count {
-                refs.push(EntityReference::new(desc_idx, entity_idx));
+                if !score_director.is_entity_pinned(desc_idx, entity_idx) {
+                    refs.push(EntityReference::new(desc_idx, entity_idx));
+                }
             }
         }
 
@@ -111,7 +140,7 @@ impl<S: PlanningSolution> EntitySelector<S> for AllEntitiesSelector {
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
-        score_director.total_entity_count().unwrap_or(0)
+        self.iter(score_director).count()
     }
 }
 
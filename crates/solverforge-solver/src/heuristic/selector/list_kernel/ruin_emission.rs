@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use solverforge_core::domain::PlanningSolution;
 
-use crate::heuristic::r#move::{ListRuinMove, Move};
+use crate::heuristic::r#move::{ListRuinMove, Move, RecreateStrategy};
 
 /// Converts a selected ruin coordinate set into its concrete move carrier.
 ///
@@ -27,6 +27,7 @@ pub(crate) struct NativeRuinEmitter<S, V> {
     list_insert: fn(&mut S, usize, usize, V),
     element_owner_fn: Option<fn(&S, &V) -> Option<usize>>,
     skip_empty_destinations: bool,
+    recreate: RecreateStrategy,
     variable_name: &'static str,
     descriptor_index: usize,
     _phantom: PhantomData<fn() -> (S, V)>,
@@ -53,11 +54,17 @@ impl<S, V> NativeRuinEmitter<S, V> {
             list_insert,
             element_owner_fn,
             skip_empty_destinations,
+            recreate: RecreateStrategy::Greedy,
             variable_name,
             descriptor_index,
             _phantom: PhantomData,
         }
     }
+
+    pub(crate) fn with_recreate_strategy(mut self, recreate: RecreateStrategy) -> Self {
+        self.recreate = recreate;
+        self
+    }
 }
 
 impl<S, V> Debug for NativeRuinEmitter<S, V> {
@@ -91,5 +98,6 @@ where
         )
         .with_element_owner_fn(self.element_owner_fn)
         .with_skip_empty_destinations(self.skip_empty_destinations)
+        .with_recreate_strategy(self.recreate)
     }
 }
@@ -56,6 +56,9 @@ where
     max_segment_size: usize,
     owners: SelectedListOwners,
     precedence_route_graph: Option<PrecedenceRouteGraph>,
+    reversed_insertion: bool,
+    // Reversed twin of the last emitted relocation, streamed next.
+    pending_reversed: Option<(usize, usize, usize, usize, usize)>,
     descriptor_index: usize,
 }
 
@@ -94,6 +97,8 @@ where
             max_segment_size,
             owners,
             precedence_route_graph: None,
+            reversed_insertion: false,
+            pending_reversed: None,
             descriptor_index,
         }
     }
@@ -106,6 +111,40 @@ where
         self
     }
 
+    pub(crate) fn with_reversed_insertion(mut self, reversed_insertion: bool) -> Self {
+        self.reversed_insertion = reversed_insertion;
+        self
+    }
+
+    // Emits the forward relocation and queues its reversed twin when the
+    // segment has an orientation to flip.
+    fn emit(
+        &mut self,
+        source_entity: usize,
+        segment_start: usize,
+        segment_end: usize,
+        destination_entity: usize,
+        destination_position: usize,
+    ) -> E::Move {
+        if self.reversed_insertion && segment_end - segment_start >= 2 {
+            self.pending_reversed = Some((
+                source_entity,
+                segment_start,
+                segment_end,
+                destination_entity,
+                destination_position,
+            ));
+        }
+        self.emitter.emit_sublist_change(
+            source_entity,
+            segment_start,
+            segment_end,
+            destination_entity,
+            destination_position,
+            false,
+        )
+    }
+
     fn segment_size_count(&self, source_len: usize, segment_start: usize) -> usize {
         let max_valid = self
             .max_segment_size
@@ -171,6 +210,18 @@ where
 
     #[inline(always)]
     fn next_move(&mut self) -> Option<E::Move> {
+        if let Some((source_entity, start, end, destination_entity, position)) =
+            self.pending_reversed.take()
+        {
+            return Some(self.emitter.emit_sublist_change(
+                source_entity,
+                start,
+                end,
+                destination_entity,
+                position,
+                true,
+            ));
+        }
         loop {
             let (source_entity, source_len, segment_start, segment_end, segment_size) =
                 self.current_segment()?;
@@ -214,7 +265,7 @@ where
                         }) {
                             continue;
                         }
-                        return Some(self.emitter.emit_sublist_change(
+                        return Some(self.emit(
                             source_entity,
                             segment_start,
                             segment_end,
@@ -259,7 +310,7 @@ where
                             {
                                 continue;
                             }
-                            return Some(self.emitter.emit_sublist_change(
+                            return Some(self.emit(
                                 source_entity,
                                 segment_start,
                                 segment_end,
@@ -21,6 +21,7 @@ pub(crate) trait SublistChangeEmitter<S: PlanningSolution>: Send + Sync + Debug
         source_end: usize,
         destination_entity: usize,
         destination_position: usize,
+        reversed: bool,
     ) -> Self::Move;
 }
 
@@ -175,6 +176,7 @@ where
         source_end: usize,
         destination_entity: usize,
         destination_position: usize,
+        reversed: bool,
     ) -> Self::Move {
         SublistChangeMove::new(
             source_entity,
@@ -189,6 +191,7 @@ where
             self.variable_name,
             self.descriptor_index,
         )
+        .with_reversed(reversed)
     }
 }
 
@@ -10,7 +10,7 @@ use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
-use crate::heuristic::r#move::ListRuinMove;
+use crate::heuristic::r#move::{ListRuinMove, RecreateStrategy};
 use crate::heuristic::selector::list_kernel::{NativeRuinEmitter, RuinCursor, RuinSourcePool};
 
 use super::move_selector::{
@@ -22,7 +22,8 @@ use super::move_selector::{
 pub struct ListRuinMoveSelector<S, V> {
     min_ruin_count: usize,
     max_ruin_count: usize,
-    rng: RefCell<SmallRng>,
+    // Explicit RNG state; when unset, each cursor is seeded from the step seed.
+    rng: Option<RefCell<SmallRng>>,
     entity_count: fn(&S) -> usize,
     list_len: fn(&S, usize) -> usize,
     list_get: fn(&S, usize, usize) -> Option<V>,
@@ -34,6 +35,7 @@ pub struct ListRuinMoveSelector<S, V> {
     moves_per_step: usize,
     max_source_list_len: Option<usize>,
     skip_empty_destinations: bool,
+    recreate: RecreateStrategy,
     _phantom: PhantomData<fn() -> V>,
 }
 
@@ -49,6 +51,7 @@ impl<S, V: Debug> Debug for ListRuinMoveSelector<S, V> {
             .field("moves_per_step", &self.moves_per_step)
             .field("max_source_list_len", &self.max_source_list_len)
             .field("skip_empty_destinations", &self.skip_empty_destinations)
+            .field("recreate", &self.recreate)
             .field("variable_name", &self.variable_name)
             .field("descriptor_index", &self.descriptor_index)
             .finish()
@@ -133,7 +136,7 @@ impl<S, V> ListRuinMoveSelector<S, V> {
         Self {
             min_ruin_count,
             max_ruin_count,
-            rng: RefCell::new(SmallRng::from_rng(&mut rand::rng())),
+            rng: None,
             entity_count,
             list_len,
             list_get,
@@ -145,10 +148,15 @@ impl<S, V> ListRuinMoveSelector<S, V> {
             moves_per_step: 10,
             max_source_list_len: None,
             skip_empty_destinations: false,
+            recreate: RecreateStrategy::Greedy,
             _phantom: PhantomData,
         }
     }
 
+    /// Sets how many ruin moves each cursor emits.
+    ///
+    /// Sources are restricted to non-empty (and otherwise eligible) routes
+    /// before sampling, so the count is exact whenever any source exists.
     pub fn with_moves_per_step(mut self, count: usize) -> Self {
         self.moves_per_step = count;
         self
@@ -161,8 +169,14 @@ impl<S, V> ListRuinMoveSelector<S, V> {
         self.skip_empty_destinations = skip_empty_destinations;
         self
     }
+    /// Chooses how emitted moves reinsert ruined elements; defaults to
+    /// [`RecreateStrategy::Greedy`].
+    pub fn with_recreate_strategy(mut self, recreate: RecreateStrategy) -> Self {
+        self.recreate = recreate;
+        self
+    }
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = RefCell::new(SmallRng::seed_from_u64(seed));
+        self.rng = Some(RefCell::new(SmallRng::seed_from_u64(seed)));
         self
     }
     pub fn with_element_owner_fn(
@@ -233,8 +247,12 @@ where
         } else {
             RuinSourcePool::Unrestricted(non_empty)
         };
-        let seed = self.rng.borrow_mut().random::<u64>()
-            ^ context.offset_seed(0x7157_8011_C0DE_0001) as u64;
+        let seed = match &self.rng {
+            Some(rng) => {
+                rng.borrow_mut().random::<u64>() ^ context.offset_seed(0x7157_8011_C0DE_0001) as u64
+            }
+            None => context.step_seed() ^ 0x7157_8011_C0DE_0001,
+        };
         ListRuinMoveCursor::new(RuinCursor::new(
             NativeRuinEmitter::new(
                 self.entity_count,
@@ -246,7 +264,8 @@ where
                 self.skip_empty_destinations,
                 self.variable_name,
                 self.descriptor_index,
-            ),
+            )
+            .with_recreate_strategy(self.recreate),
             SmallRng::seed_from_u64(seed),
             source_pool,
             self.moves_per_step,
@@ -120,9 +120,13 @@ impl MimicRecorder {
         &self.id
     }
 
-    /// Resets the state for a new iteration.
+    /// Resets the state for a new step.
+    ///
+    /// Clears the recorded entity so a replaying selector yields nothing until
+    /// the recording selector records again; no entity leaks across steps.
+    /// The recording selector calls this at the start of every iteration.
     pub fn reset(&self) {
-        self.shared().state.set(MimicState::default());
+        self.record_has_next(false);
     }
 }
 
@@ -8,17 +8,31 @@ selected move once the forager commits to an index.
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
-use crate::heuristic::r#move::{ChangeMove, Move, MoveArena, SequentialCompositeMoveRef, SwapMove};
+use crate::heuristic::r#move::{
+    ChangeMove, Move, MoveArena, PillarSwapMove, SequentialCompositeMoveRef, SwapMove,
+};
 
-use super::entity::{EntitySelector, FromSolutionEntitySelector};
+use super::entity::{EntityReference, EntitySelector, FromSolutionEntitySelector};
+use super::pillar::PillarSelector;
 use super::value_selector::{StaticValueSelector, ValueSelector};
 
 mod scalar_union;
 
+// Pinned entities never take part in change or swap moves, whatever entity
+// selector feeds the move selector.
+fn is_pinned<S: PlanningSolution, D: Director<S>>(
+    score_director: &D,
+    entity_ref: &EntityReference,
+) -> bool {
+    score_director.is_entity_pinned(entity_ref.descriptor_index, entity_ref.entity_index)
+}
+
 include!("move_selector/borrowed.rs");
 include!("move_selector/iter.rs");
 include!("move_selector/change.rs");
 include!("move_selector/swap.rs");
+include!("move_selector/pillar_swap.rs");
@@ -35,6 +35,14 @@ where
         (**self).telemetry_label()
     }
 
+    fn type_name(&self) -> &'static str {
+        (**self).type_name()
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        (**self).affected_entities()
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         (**self).requires_hard_improvement()
     }
@@ -175,6 +183,20 @@ where
         }
     }
 
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Borrowed(mov) => mov.type_name(),
+            Self::Sequential(mov) => mov.type_name(),
+        }
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        match self {
+            Self::Borrowed(mov) => mov.affected_entities(),
+            Self::Sequential(mov) => mov.affected_entities(),
+        }
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         match self {
             Self::Borrowed(mov) => mov.requires_hard_improvement(),
@@ -249,7 +249,11 @@ where
         context: MoveStreamContext,
     ) -> Self::Cursor<'a> {
         let solution = score_director.working_solution();
-        let canonical_entities = self.entity_selector.iter(score_director).collect::<Vec<_>>();
+        let canonical_entities = self
+            .entity_selector
+            .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+            .collect::<Vec<_>>();
         let entity_count = canonical_entities.len();
         let entity_salt = 0xC4A4_6E00_0000_0001
             ^ ((self.descriptor_index as u64) << 32)
@@ -309,6 +313,7 @@ where
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
         self.entity_selector
             .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
             .map(|entity_ref| {
                 self.value_selector.size(
                     score_director,
@@ -326,4 +331,11 @@ where
             })
             .sum()
     }
+
+    fn size_hint<D: Director<S>>(&self, score_director: &D) -> (usize, Option<usize>) {
+        // Every entity yields each of its values, plus one to-none move when
+        // unassignment is allowed and the entity is currently assigned.
+        let size = self.size(score_director);
+        (size, Some(size))
+    }
 }
@@ -266,7 +266,21 @@ pub trait MoveSelector<S: PlanningSolution, M: Move<S>>: Send + Debug {
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize;
 
+    /// Lower and optional upper bound on the candidates a fresh cursor yields.
+    ///
+    /// The default trusts `size()` only as an upper bound; selectors whose
+    /// `size()` is exact report it as both bounds.
+    fn size_hint<D: Director<S>>(&self, score_director: &D) -> (usize, Option<usize>) {
+        if self.is_never_ending() {
+            (0, None)
+        } else {
+            (0, Some(self.size(score_director)))
+        }
+    }
+
     fn append_moves<D: Director<S>>(&self, score_director: &D, arena: &mut MoveArena<M>) {
+        let (lower, upper) = self.size_hint(score_director);
+        arena.reserve(upper.unwrap_or(lower));
         let mut cursor = self.open_cursor(score_director);
         for id in collect_cursor_indices::<S, M, _>(&mut cursor) {
             arena.push(cursor.take_candidate(id));
@@ -0,0 +1,195 @@
+/// A pillar swap move selector that generates one `PillarSwapMove` per
+/// unordered pair of pillars from a `PillarSelector`.
+///
+/// Pinned entities are dropped from each pillar. With
+/// [`with_value_is_legal`](Self::with_value_is_legal), every generated move
+/// carries the value-range check, so a swap that would hand an entity a value
+/// outside its range is not doable.
+pub struct PillarSwapMoveSelector<S, V, PS> {
+    pillar_selector: PS,
+    getter: fn(&S, usize, usize) -> Option<V>,
+    setter: fn(&mut S, usize, usize, Option<V>),
+    descriptor_index: usize,
+    variable_index: usize,
+    variable_name: &'static str,
+    value_is_legal: Option<fn(&S, usize, usize, Option<&V>) -> bool>,
+    _phantom: PhantomData<(fn() -> S, fn() -> V)>,
+}
+
+pub struct PillarSwapMoveCursor<S, V>
+where
+    S: PlanningSolution,
+    V: Clone + PartialEq + Send + Sync + Debug + 'static,
+{
+    store: CandidateStore<S, PillarSwapMove<S, V>>,
+    pillars: Vec<Vec<usize>>,
+    left_offset: usize,
+    right_offset: usize,
+    getter: fn(&S, usize, usize) -> Option<V>,
+    setter: fn(&mut S, usize, usize, Option<V>),
+    descriptor_index: usize,
+    variable_index: usize,
+    variable_name: &'static str,
+    value_is_legal: Option<fn(&S, usize, usize, Option<&V>) -> bool>,
+}
+
+impl<S, V> MoveCursor<S, PillarSwapMove<S, V>> for PillarSwapMoveCursor<S, V>
+where
+    S: PlanningSolution,
+    V: Clone + PartialEq + Send + Sync + Debug + 'static,
+{
+    fn next_candidate(&mut self) -> Option<CandidateId> {
+        while self.left_offset < self.pillars.len() {
+            if self.right_offset < self.pillars.len() {
+                let right_offset = self.right_offset;
+                self.right_offset += 1;
+                let mov = PillarSwapMove::new(
+                    self.pillars[self.left_offset].clone(),
+                    self.pillars[right_offset].clone(),
+                    self.getter,
+                    self.setter,
+                    self.variable_index,
+                    self.variable_name,
+                    self.descriptor_index,
+                )
+                .with_value_is_legal(self.value_is_legal);
+                return Some(self.store.push(mov));
+            }
+
+            self.left_offset += 1;
+            self.right_offset = self.left_offset + 1;
+        }
+
+        None
+    }
+
+    fn candidate(
+        &self,
+        id: CandidateId,
+    ) -> Option<MoveCandidateRef<'_, S, PillarSwapMove<S, V>>> {
+        self.store.candidate(id)
+    }
+
+    fn take_candidate(&mut self, id: CandidateId) -> PillarSwapMove<S, V> {
+        self.store.take_candidate(id)
+    }
+
+    fn release_candidate(&mut self, id: CandidateId) -> bool {
+        self.store.release_candidate(id)
+    }
+}
+
+impl<S, V> Iterator for PillarSwapMoveCursor<S, V>
+where
+    S: PlanningSolution,
+    V: Clone + PartialEq + Send + Sync + Debug + 'static,
+{
+    type Item = PillarSwapMove<S, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.next_candidate()?;
+        Some(self.take_candidate(id))
+    }
+}
+
+impl<S, V, PS: Debug> Debug for PillarSwapMoveSelector<S, V, PS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PillarSwapMoveSelector")
+            .field("pillar_selector", &self.pillar_selector)
+            .field("descriptor_index", &self.descriptor_index)
+            .field("variable_index", &self.variable_index)
+            .field("variable_name", &self.variable_name)
+            .field("has_value_is_legal", &self.value_is_legal.is_some())
+            .finish()
+    }
+}
+
+impl<S: PlanningSolution, V, PS> PillarSwapMoveSelector<S, V, PS> {
+    pub fn new(
+        pillar_selector: PS,
+        getter: fn(&S, usize, usize) -> Option<V>,
+        setter: fn(&mut S, usize, usize, Option<V>),
+        descriptor_index: usize,
+        variable_index: usize,
+        variable_name: &'static str,
+    ) -> Self {
+        Self {
+            pillar_selector,
+            getter,
+            setter,
+            descriptor_index,
+            variable_index,
+            variable_name,
+            value_is_legal: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the entity value-range check passed to every generated move:
+    /// `(solution, entity, variable, value) -> legal`.
+    pub fn with_value_is_legal(
+        mut self,
+        value_is_legal: fn(&S, usize, usize, Option<&V>) -> bool,
+    ) -> Self {
+        self.value_is_legal = Some(value_is_legal);
+        self
+    }
+}
+
+impl<S, V, PS> PillarSwapMoveSelector<S, V, PS>
+where
+    S: PlanningSolution,
+    PS: PillarSelector<S>,
+{
+    fn unpinned_pillars<D: Director<S>>(&self, score_director: &D) -> Vec<Vec<usize>> {
+        self.pillar_selector
+            .iter(score_director)
+            .map(|pillar| {
+                pillar
+                    .iter()
+                    .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+                    .map(|entity_ref| entity_ref.entity_index)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|pillar| !pillar.is_empty())
+            .collect()
+    }
+}
+
+impl<S, V, PS> MoveSelector<S, PillarSwapMove<S, V>> for PillarSwapMoveSelector<S, V, PS>
+where
+    S: PlanningSolution,
+    V: Clone + PartialEq + Send + Sync + Debug + 'static,
+    PS: PillarSelector<S>,
+{
+    type Cursor<'a>
+        = PillarSwapMoveCursor<S, V>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<S>>(&'a self, score_director: &D) -> Self::Cursor<'a> {
+        PillarSwapMoveCursor {
+            store: CandidateStore::new(),
+            pillars: self.unpinned_pillars(score_director),
+            left_offset: 0,
+            right_offset: 1,
+            getter: self.getter,
+            setter: self.setter,
+            descriptor_index: self.descriptor_index,
+            variable_index: self.variable_index,
+            variable_name: self.variable_name,
+            value_is_legal: self.value_is_legal,
+        }
+    }
+
+    fn size<D: Director<S>>(&self, score_director: &D) -> usize {
+        let pillar_count = self.unpinned_pillars(score_director).len();
+        pillar_count.saturating_mul(pillar_count.saturating_sub(1)) / 2
+    }
+
+    fn size_hint<D: Director<S>>(&self, score_director: &D) -> (usize, Option<usize>) {
+        // Unordered pillar pairs only.
+        let size = self.size(score_director);
+        (size, Some(size))
+    }
+}
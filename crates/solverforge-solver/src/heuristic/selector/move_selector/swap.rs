@@ -198,8 +198,16 @@ where
         score_director: &D,
         context: MoveStreamContext,
     ) -> Self::Cursor<'a> {
-        let canonical_right: Vec<_> = self.right_entity_selector.iter(score_director).collect();
-        let canonical_left: Vec<_> = self.left_entity_selector.iter(score_director).collect();
+        let canonical_right: Vec<_> = self
+            .right_entity_selector
+            .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+            .collect();
+        let canonical_left: Vec<_> = self
+            .left_entity_selector
+            .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+            .collect();
         let salt = ((self.descriptor_index as u64) << 32) ^ self.variable_index as u64;
         let left_entities = (0..canonical_left.len())
             .map(|offset| {
@@ -231,8 +239,22 @@ where
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
-        let left_count = self.left_entity_selector.iter(score_director).count();
-        let right_count = self.right_entity_selector.iter(score_director).count();
+        let left_count = self
+            .left_entity_selector
+            .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+            .count();
+        let right_count = self
+            .right_entity_selector
+            .iter(score_director)
+            .filter(|entity_ref| !is_pinned(score_director, entity_ref))
+            .count();
         left_count.saturating_mul(right_count.saturating_sub(1)) / 2
     }
+
+    fn size_hint<D: Director<S>>(&self, score_director: &D) -> (usize, Option<usize>) {
+        // Unordered pairs only: n choose 2 over the shared entity set.
+        let size = self.size(score_director);
+        (size, Some(size))
+    }
 }
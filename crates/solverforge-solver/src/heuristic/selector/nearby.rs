@@ -13,6 +13,7 @@ is more likely to improve the solution than swapping with distant ones.
 */
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use solverforge_core::domain::PlanningSolution;
@@ -83,6 +84,9 @@ pub struct NearbySelectionConfig {
     pub max_nearby_size: Option<usize>,
     // Minimum distance to include a candidate (exclusive of origin).
     pub min_distance: f64,
+    // Whether to rank each origin's neighbors once at phase start and replay
+    // them. Only valid when distances do not depend on the working solution.
+    pub cache_neighbors: bool,
 }
 
 impl Default for NearbySelectionConfig {
@@ -91,6 +95,7 @@ impl Default for NearbySelectionConfig {
             distribution_type: NearbyDistributionType::Linear,
             max_nearby_size: None,
             min_distance: 0.0,
+            cache_neighbors: false,
         }
     }
 }
@@ -114,6 +119,14 @@ impl NearbySelectionConfig {
         self.min_distance = min_distance;
         self
     }
+
+    /// Ranks every origin's neighbors once in
+    /// [`NearbyEntitySelector::phase_started`], so picks during the phase skip
+    /// the distance meter.
+    pub fn with_cached_neighbors(mut self, cache_neighbors: bool) -> Self {
+        self.cache_neighbors = cache_neighbors;
+        self
+    }
 }
 
 /// Type-erased distance meter for dynamic dispatch.
@@ -132,6 +145,11 @@ pub trait DynDistanceMeter: Send + Sync + Debug {
 /// The origin entity is obtained from a mimic recorder, allowing this selector
 /// to be synchronized with another selector that picks the "current" entity.
 ///
+/// With [`NearbySelectionConfig::cache_neighbors`] set,
+/// [`phase_started`](Self::phase_started) ranks the neighbors of every entity
+/// the child selector yields, and selection replays those lists until the
+/// next phase start. An origin without a prepared list is ranked on demand.
+///
 /// # Zero-Erasure Design
 ///
 /// The child entity selector `ES` is stored as a concrete generic type parameter,
@@ -145,6 +163,8 @@ pub struct NearbyEntitySelector<S, M, ES> {
     distance_meter: M,
     // Configuration for nearby selection.
     config: NearbySelectionConfig,
+    // Neighbor lists ranked at phase start when `config.cache_neighbors` is set.
+    neighbors: HashMap<EntityReference, Vec<EntityReference>>,
     // Marker for solution type.
     _phantom: std::marker::PhantomData<fn() -> S>,
 }
@@ -161,6 +181,7 @@ impl<S, M, ES> NearbyEntitySelector<S, M, ES> {
             origin_recorder,
             distance_meter,
             config,
+            neighbors: HashMap::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -177,35 +198,45 @@ impl<S: PlanningSolution, M: DynDistanceMeter, ES: Debug> Debug for NearbyEntity
     }
 }
 
-impl<S, M, ES> EntitySelector<S> for NearbyEntitySelector<S, M, ES>
+impl<S, M, ES> NearbyEntitySelector<S, M, ES>
 where
     S: PlanningSolution,
     M: DynDistanceMeter + 'static,
     ES: EntitySelector<S>,
 {
-    fn iter<'a, D: Director<S>>(
-        &'a self,
-        score_director: &D,
-    ) -> impl Iterator<Item = EntityReference> + 'a {
-        // Get the origin entity from the recorder
-        let origin = self.origin_recorder.get_recorded_entity();
-
-        // Collect all candidate entities with their distances (empty if no origin)
-        let mut candidates: Vec<(EntityReference, f64)> = match origin {
-            Some(origin) => self
-                .child
+    /// Ranks the neighbors of every entity the child selector yields when
+    /// [`NearbySelectionConfig::cache_neighbors`] is set; otherwise a no-op.
+    ///
+    /// Call at each phase start so the lists match the entities of that phase.
+    pub fn phase_started<D: Director<S>>(&mut self, score_director: &D) {
+        self.neighbors = if self.config.cache_neighbors {
+            self.child
                 .iter(score_director)
-                .filter(move |&dest| dest != origin) // Exclude the origin itself
-                .map(move |dest| {
-                    let dist = self
-                        .distance_meter
-                        .distance_between(score_director, origin, dest);
-                    (dest, dist)
-                })
-                .filter(|(_, dist)| *dist >= self.config.min_distance)
-                .collect(),
-            None => Vec::new(),
+                .map(|origin| (origin, self.rank_neighbors(score_director, origin)))
+                .collect()
+        } else {
+            HashMap::new()
         };
+    }
+
+    fn rank_neighbors<D: Director<S>>(
+        &self,
+        score_director: &D,
+        origin: EntityReference,
+    ) -> Vec<EntityReference> {
+        // Collect all candidate entities with their distances
+        let mut candidates: Vec<(EntityReference, f64)> = self
+            .child
+            .iter(score_director)
+            .filter(|&dest| dest != origin) // Exclude the origin itself
+            .map(|dest| {
+                let dist = self
+                    .distance_meter
+                    .distance_between(score_director, origin, dest);
+                (dest, dist)
+            })
+            .filter(|(_, dist)| *dist >= self.config.min_distance)
+            .collect();
 
         // Sort by distance (closest first)
         candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
@@ -215,7 +246,28 @@ where
             candidates.truncate(max_size);
         }
 
-        candidates.into_iter().map(|(entity, _)| entity)
+        candidates.into_iter().map(|(entity, _)| entity).collect()
+    }
+}
+
+impl<S, M, ES> EntitySelector<S> for NearbyEntitySelector<S, M, ES>
+where
+    S: PlanningSolution,
+    M: DynDistanceMeter + 'static,
+    ES: EntitySelector<S>,
+{
+    fn iter<'a, D: Director<S>>(
+        &'a self,
+        score_director: &D,
+    ) -> impl Iterator<Item = EntityReference> + 'a {
+        // Get the origin entity from the recorder
+        let origin = self.origin_recorder.get_recorded_entity();
+        let prepared = origin.and_then(|origin| self.neighbors.get(&origin));
+        let ranked = match (origin, prepared) {
+            (Some(origin), None) => self.rank_neighbors(score_director, origin),
+            _ => Vec::new(),
+        };
+        prepared.into_iter().flatten().copied().chain(ranked)
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
@@ -83,7 +83,7 @@ use solverforge_scoring::Director;
 use crate::heuristic::r#move::RuinMove;
 
 use super::move_selector::{
-    CandidateId, CandidateStore, MoveCandidateRef, MoveCursor, MoveSelector,
+    CandidateId, CandidateStore, MoveCandidateRef, MoveCursor, MoveSelector, MoveStreamContext,
 };
 
 pub struct RuinVariableAccess<S, V> {
@@ -151,8 +151,9 @@ pub struct RuinMoveSelector<S, V> {
     min_ruin_count: usize,
     // Maximum entities to include in each ruin move.
     max_ruin_count: usize,
-    // RNG state for reproducible subset selection.
-    rng: RefCell<SmallRng>,
+    // Explicit RNG state; when unset, subsets are drawn from the step seed
+    // the solver scope hands to each cursor.
+    rng: Option<RefCell<SmallRng>>,
     access: RuinVariableAccess<S, V>,
     // Number of ruin moves to generate per iteration.
     moves_per_step: usize,
@@ -282,7 +283,7 @@ impl<S, V> RuinMoveSelector<S, V> {
         Self {
             min_ruin_count,
             max_ruin_count,
-            rng: RefCell::new(SmallRng::from_rng(&mut rand::rng())),
+            rng: None,
             access,
             moves_per_step: 10, // Default: generate 10 ruin moves per step
         }
@@ -296,8 +297,9 @@ impl<S, V> RuinMoveSelector<S, V> {
         self
     }
 
+    /// Pins the selector to its own RNG stream instead of the solver's step seed.
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.rng = RefCell::new(SmallRng::seed_from_u64(seed));
+        self.rng = Some(RefCell::new(SmallRng::seed_from_u64(seed)));
         self
     }
 }
@@ -313,6 +315,14 @@ where
         Self: 'a;
 
     fn open_cursor<'a, D: Director<S>>(&'a self, score_director: &D) -> Self::Cursor<'a> {
+        self.open_cursor_with_context(score_director, MoveStreamContext::default())
+    }
+
+    fn open_cursor_with_context<'a, D: Director<S>>(
+        &'a self,
+        score_director: &D,
+        context: MoveStreamContext,
+    ) -> Self::Cursor<'a> {
         let access = self.access;
         let total_entities = (access.entity_count)(score_director.working_solution());
 
@@ -320,8 +330,12 @@ where
         let max = self.max_ruin_count.min(total_entities);
         let moves_count = self.moves_per_step;
 
-        // Pre-generate subsets using RNG
-        let mut rng = self.rng.borrow_mut();
+        // Pre-generate subsets from a per-cursor RNG
+        let seed = match &self.rng {
+            Some(rng) => rng.borrow_mut().random::<u64>(),
+            None => context.step_seed() ^ 0x5CA1_A8F0_0000_0001,
+        };
+        let mut rng = SmallRng::seed_from_u64(seed);
         let mut permutation: Vec<usize> = (0..total_entities).collect();
         let subsets: Vec<SmallVec<[usize; 8]>> = (0..moves_count)
             .map(|_| {
@@ -29,6 +29,7 @@ pub struct SublistChangeMoveSelector<S, V, ES> {
     sublist_remove: fn(&mut S, usize, usize, usize) -> Vec<V>,
     sublist_insert: fn(&mut S, usize, usize, Vec<V>),
     element_owner_fn: Option<fn(&S, &V) -> Option<usize>>,
+    reversed_insertion: bool,
     variable_name: &'static str,
     descriptor_index: usize,
     _phantom: PhantomData<(fn() -> S, fn() -> V)>,
@@ -100,6 +101,7 @@ impl<S, V: Debug, ES: Debug> Debug for SublistChangeMoveSelector<S, V, ES> {
             .field("entity_selector", &self.entity_selector)
             .field("min_sublist_size", &self.min_sublist_size)
             .field("max_sublist_size", &self.max_sublist_size)
+            .field("reversed_insertion", &self.reversed_insertion)
             .field("variable_name", &self.variable_name)
             .field("descriptor_index", &self.descriptor_index)
             .finish()
@@ -133,6 +135,7 @@ impl<S, V, ES> SublistChangeMoveSelector<S, V, ES> {
             sublist_remove,
             sublist_insert,
             element_owner_fn: None,
+            reversed_insertion: false,
             variable_name,
             descriptor_index,
             _phantom: PhantomData,
@@ -146,6 +149,28 @@ impl<S, V, ES> SublistChangeMoveSelector<S, V, ES> {
         self.element_owner_fn = element_owner_fn;
         self
     }
+
+    /// Also yields every relocation of two or more elements with the sublist
+    /// inserted in reverse order, right after its forward twin.
+    pub fn with_reversed_insertion(mut self, reversed_insertion: bool) -> Self {
+        self.reversed_insertion = reversed_insertion;
+        self
+    }
+
+    // Number of orientations streamed for a segment of `segment_size`.
+    fn orientation_count(&self, segment_size: usize) -> usize {
+        1 + usize::from(self.reversed_insertion && segment_size >= 2)
+    }
+
+    // Counts moves per route with `count`, adding the reversed twins of
+    // segments that have at least two elements.
+    fn count_with_orientations(&self, count: impl Fn(usize) -> usize) -> usize {
+        let forward = count(self.min_sublist_size);
+        if !self.reversed_insertion || self.max_sublist_size < 2 {
+            return forward;
+        }
+        forward + count(self.min_sublist_size.max(2))
+    }
 }
 
 impl<S, V, ES> MoveSelector<S, SublistChangeMove<S, V>> for SublistChangeMoveSelector<S, V, ES>
@@ -185,24 +210,27 @@ where
             self.list_get,
         );
         let owners = SelectedListOwners::from_selected_restrictions(owner_restrictions);
-        SublistChangeMoveCursor::new(SublistChangeCursor::new(
-            NativeWindowEmitter::new(
-                self.list_len,
-                self.list_get,
-                self.sublist_remove,
-                self.sublist_insert,
-                self.variable_name,
+        SublistChangeMoveCursor::new(
+            SublistChangeCursor::new(
+                NativeWindowEmitter::new(
+                    self.list_len,
+                    self.list_get,
+                    self.sublist_remove,
+                    self.sublist_insert,
+                    self.variable_name,
+                    self.descriptor_index,
+                ),
+                selected.entities,
+                selected.route_lens,
+                context,
+                STATIC_SUBLIST_CHANGE_SALTS,
+                self.min_sublist_size,
+                self.max_sublist_size,
+                owners,
                 self.descriptor_index,
-            ),
-            selected.entities,
-            selected.route_lens,
-            context,
-            STATIC_SUBLIST_CHANGE_SALTS,
-            self.min_sublist_size,
-            self.max_sublist_size,
-            owners,
-            self.descriptor_index,
-        ))
+            )
+            .with_reversed_insertion(self.reversed_insertion),
+        )
     }
 
     fn size<D: Director<S>>(&self, score_director: &D) -> usize {
@@ -218,25 +246,29 @@ where
             &selected.route_lens,
             self.list_get,
         ) else {
-            return unfiltered_sublist_change_size(
-                &selected.route_lens,
-                self.min_sublist_size,
-                self.max_sublist_size,
-            );
+            return self.count_with_orientations(|min_sublist_size| {
+                unfiltered_sublist_change_size(
+                    &selected.route_lens,
+                    min_sublist_size,
+                    self.max_sublist_size,
+                )
+            });
         };
         if owner_restrictions.is_fixed_to_current() {
-            return selected
-                .route_lens
-                .iter()
-                .map(|&route_len| {
-                    count_sublist_change_moves_for_len(
-                        route_len,
-                        0,
-                        self.min_sublist_size,
-                        self.max_sublist_size,
-                    )
-                })
-                .sum();
+            return self.count_with_orientations(|min_sublist_size| {
+                selected
+                    .route_lens
+                    .iter()
+                    .map(|&route_len| {
+                        count_sublist_change_moves_for_len(
+                            route_len,
+                            0,
+                            min_sublist_size,
+                            self.max_sublist_size,
+                        )
+                    })
+                    .sum()
+            });
         }
         let element_owners = owner_restrictions
             .mixed()
@@ -252,6 +284,7 @@ where
                 let max_segment = self.max_sublist_size.min(source_len - segment_start);
                 for segment_size in self.min_sublist_size..=max_segment {
                     let segment_end = segment_start + segment_size;
+                    let orientations = self.orientation_count(segment_size);
                     if selected_segment_allows(
                         element_owners,
                         source_idx,
@@ -259,7 +292,7 @@ where
                         segment_end,
                         source_entity,
                     ) {
-                        count += source_len - segment_size;
+                        count += (source_len - segment_size) * orientations;
                     }
                     for (destination_idx, (&destination_entity, &destination_len)) in selected
                         .entities
@@ -277,7 +310,7 @@ where
                             segment_end,
                             destination_entity,
                         ) {
-                            count += destination_len + 1;
+                            count += (destination_len + 1) * orientations;
                         }
                     }
                 }
@@ -155,3 +155,63 @@ fn test_mimic_empty_selector() {
     let replayed: Vec<_> = replaying.iter(&director).collect();
     assert_eq!(replayed.len(), 0);
 }
+
+#[test]
+fn test_mimic_round_trip_pairs_for_swap() {
+    let director = create_test_director(3);
+
+    let recorder = MimicRecorder::new("swap");
+    let left =
+        MimicRecordingEntitySelector::new(FromSolutionEntitySelector::new(0), recorder.clone());
+    let right = MimicReplayingEntitySelector::new(recorder);
+
+    // Each recorded left entity pairs with exactly the same replayed right entity.
+    let pairs: Vec<_> = left
+        .iter(&director)
+        .flat_map(|recorded| {
+            right
+                .iter(&director)
+                .map(move |replayed| (recorded, replayed))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    assert_eq!(
+        pairs,
+        (0..3)
+            .map(|i| (EntityReference::new(0, i), EntityReference::new(0, i)))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_mimic_reset_starts_a_fresh_step() {
+    let recorder = MimicRecorder::new("step");
+    let recording =
+        MimicRecordingEntitySelector::new(FromSolutionEntitySelector::new(0), recorder.clone());
+    let replaying = MimicReplayingEntitySelector::new(recorder.clone());
+
+    // Step one stops mid-iteration with an entity still recorded.
+    let first_step = create_test_director(3);
+    let mut recording_iter = recording.iter(&first_step);
+    recording_iter.next();
+    assert_eq!(recording_iter.next(), Some(EntityReference::new(0, 1)));
+    drop(recording_iter);
+
+    // Step boundary: nothing is replayed from the previous step.
+    recorder.reset();
+    assert_eq!(recorder.get_recorded_entity(), None);
+    assert_eq!(replaying.iter(&first_step).count(), 0);
+    assert_eq!(
+        EntitySelector::<NQueensSolution>::size(&replaying, &first_step),
+        0
+    );
+
+    // Step two records fresh entities from its own solution.
+    let second_step = create_test_director(2);
+    for recorded in recording.iter(&second_step) {
+        let replayed: Vec<_> = replaying.iter(&second_step).collect();
+        assert_eq!(replayed, vec![recorded]);
+    }
+    assert_eq!(replaying.iter(&second_step).count(), 0);
+}
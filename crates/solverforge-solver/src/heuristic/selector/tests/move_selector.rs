@@ -488,3 +488,6 @@ fn test_swap_do_and_undo() {
     assert_eq!(val0, Some(10));
     assert_eq!(val1, Some(20));
 }
+
+#[path = "move_selector_size_hint.rs"]
+mod size_hint;
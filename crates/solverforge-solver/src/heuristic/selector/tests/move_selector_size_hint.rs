@@ -0,0 +1,62 @@
+// Tests for move selector size hints and arena reservation.
+
+use super::*;
+use crate::heuristic::r#move::MoveArena;
+
+#[test]
+fn change_selector_size_hint_matches_produced_moves() {
+    let director = create_director(vec![
+        Task {
+            id: 0,
+            priority: Some(1),
+        },
+        Task {
+            id: 1,
+            priority: None,
+        },
+        Task {
+            id: 2,
+            priority: Some(3),
+        },
+    ]);
+
+    let selector = ChangeMoveSelector::simple(
+        get_priority,
+        set_priority,
+        0,
+        0,
+        "priority",
+        vec![10, 20, 30],
+    )
+    .with_allows_unassigned(true);
+
+    let produced = selector.iter_moves(&director).count();
+    let (lower, upper) = selector.size_hint(&director);
+
+    // 3 entities * 3 values + 2 to-none moves for the assigned entities
+    assert_eq!(produced, 11);
+    assert_eq!(upper, Some(produced));
+    assert_eq!(lower, produced);
+
+    let mut arena = MoveArena::new();
+    selector.append_moves(&director, &mut arena);
+    assert_eq!(arena.len(), produced);
+    assert!(arena.capacity() >= produced);
+}
+
+#[test]
+fn swap_selector_size_hint_counts_unordered_pairs() {
+    let director = create_director(
+        (0..5)
+            .map(|id| Task {
+                id,
+                priority: Some(id as i32),
+            })
+            .collect(),
+    );
+
+    let selector = SwapMoveSelector::simple(get_priority, set_priority, 0, 0, "priority");
+
+    assert_eq!(selector.size_hint(&director), (10, Some(10)));
+    assert_eq!(selector.iter_moves(&director).count(), 10);
+}
@@ -12,6 +12,8 @@ use solverforge_core::domain::{
 use solverforge_core::score::SoftScore;
 use solverforge_scoring::{Director, ScoreDirector};
 use std::any::TypeId;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 struct Location {
@@ -217,3 +219,125 @@ fn test_nearby_selector_excludes_origin() {
     let nearby: Vec<_> = nearby_selector.iter(&director).collect();
     assert!(!nearby.contains(&origin));
 }
+
+// Euclidean meter that counts how often it is consulted.
+#[derive(Debug)]
+struct CountingDistanceMeter {
+    inner: EuclideanDistanceMeter,
+    calls: Arc<AtomicUsize>,
+}
+
+impl DynDistanceMeter for CountingDistanceMeter {
+    fn distance_between<S: PlanningSolution>(
+        &self,
+        score_director: &dyn Director<S>,
+        origin: EntityReference,
+        destination: EntityReference,
+    ) -> f64 {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .distance_between(score_director, origin, destination)
+    }
+}
+
+// Runs two full origin sweeps and returns every nearby ordering plus the
+// number of distance evaluations.
+fn sweep_nearby_orderings(
+    director: &ScoreDirector<RoutingSolution, ()>,
+    config: NearbySelectionConfig,
+) -> (Vec<Vec<EntityReference>>, usize) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let distance_meter = CountingDistanceMeter {
+        inner: EuclideanDistanceMeter::new(&director.working_solution().locations),
+        calls: calls.clone(),
+    };
+    let recorder = MimicRecorder::new("origin");
+    let origin_selector =
+        MimicRecordingEntitySelector::new(FromSolutionEntitySelector::new(0), recorder.clone());
+    let mut nearby_selector = NearbyEntitySelector::new(
+        FromSolutionEntitySelector::new(0),
+        recorder.clone(),
+        distance_meter,
+        config,
+    );
+    nearby_selector.phase_started(director);
+
+    let mut orderings = Vec::new();
+    for _ in 0..2 {
+        for _origin in origin_selector.iter(director) {
+            orderings.push(nearby_selector.iter(director).collect());
+        }
+    }
+    (orderings, calls.load(Ordering::Relaxed))
+}
+
+#[test]
+fn test_cached_nearby_selection_matches_uncached_ordering() {
+    let director = create_test_director();
+    let config = NearbySelectionConfig::default().with_max_nearby_size(3);
+
+    let (uncached, uncached_calls) = sweep_nearby_orderings(&director, config.clone());
+    let (cached, cached_calls) =
+        sweep_nearby_orderings(&director, config.with_cached_neighbors(true));
+
+    assert_eq!(cached, uncached);
+    // 6 origins x 5 destinations, measured once per origin instead of per sweep.
+    assert_eq!(uncached_calls, 60);
+    assert_eq!(cached_calls, 30);
+}
+
+#[test]
+fn test_cached_nearby_selection_rebuilds_at_phase_start() {
+    let mut director = create_test_director();
+    let recorder = MimicRecorder::new("origin");
+    let origin_selector =
+        MimicRecordingEntitySelector::new(FromSolutionEntitySelector::new(0), recorder.clone());
+    let distance_meter = EuclideanDistanceMeter::new(&director.working_solution().locations);
+    let mut nearby_selector = NearbyEntitySelector::new(
+        FromSolutionEntitySelector::new(0),
+        recorder.clone(),
+        distance_meter,
+        NearbySelectionConfig::default().with_cached_neighbors(true),
+    );
+    nearby_selector.phase_started(&director);
+
+    origin_selector.iter(&director).next();
+    let before: Vec<_> = nearby_selector.iter(&director).collect();
+    assert_eq!(before.len(), 5);
+
+    director.working_solution_mut().locations.pop();
+    nearby_selector.phase_started(&director);
+    origin_selector.iter(&director).next();
+    let after: Vec<_> = nearby_selector.iter(&director).collect();
+
+    assert_eq!(after.len(), 4);
+    assert!(after.iter().all(|entity| entity.entity_index < 5));
+}
+
+#[test]
+fn test_cached_nearby_selection_ranks_unprepared_origins_on_demand() {
+    let director = create_test_director();
+    let recorder = MimicRecorder::new("origin");
+    let origin_selector =
+        MimicRecordingEntitySelector::new(FromSolutionEntitySelector::new(0), recorder.clone());
+    let config = NearbySelectionConfig::default().with_max_nearby_size(3);
+    let distance_meter = EuclideanDistanceMeter::new(&director.working_solution().locations);
+    let uncached = NearbyEntitySelector::new(
+        FromSolutionEntitySelector::new(0),
+        recorder.clone(),
+        distance_meter,
+        config.clone(),
+    );
+    let distance_meter = EuclideanDistanceMeter::new(&director.working_solution().locations);
+    let unprepared = NearbyEntitySelector::new(
+        FromSolutionEntitySelector::new(0),
+        recorder.clone(),
+        distance_meter,
+        config.with_cached_neighbors(true),
+    );
+
+    origin_selector.iter(&director).next();
+    let expected: Vec<_> = uncached.iter(&director).collect();
+    assert_eq!(expected.len(), 3);
+    assert_eq!(unprepared.iter(&director).collect::<Vec<_>>(), expected);
+}
@@ -1,7 +1,9 @@
 // Tests for pillar selector.
 
 use super::*;
+use crate::heuristic::r#move::Move;
 use crate::heuristic::selector::entity::FromSolutionEntitySelector;
+use crate::heuristic::selector::move_selector::{MoveSelector, PillarSwapMoveSelector};
 use crate::heuristic::selector::pillar::{
     DefaultPillarSelector, Pillar, PillarSelector, SubPillarConfig,
 };
@@ -320,3 +322,77 @@ fn test_sub_pillar_config() {
     assert_eq!(config.minimum_size, 2);
     assert_eq!(config.maximum_size, 5);
 }
+
+fn get_shift(s: &ScheduleSolution, idx: usize, _variable_index: usize) -> Option<i32> {
+    s.employees[idx].shift
+}
+
+fn set_shift(s: &mut ScheduleSolution, idx: usize, _variable_index: usize, v: Option<i32>) {
+    s.employees[idx].shift = v;
+}
+
+// Employee 0 cannot work shift 3.
+fn shift_is_legal(
+    _s: &ScheduleSolution,
+    idx: usize,
+    _variable_index: usize,
+    v: Option<&i32>,
+) -> bool {
+    idx != 0 || v != Some(&3)
+}
+
+fn pillar_swap_doability(with_range_check: bool) -> Vec<(Vec<usize>, Vec<usize>, bool)> {
+    let employees = [1, 1, 2, 2, 3, 3]
+        .into_iter()
+        .enumerate()
+        .map(|(id, shift)| Employee {
+            id,
+            shift: Some(shift),
+        })
+        .collect();
+    let director = create_test_director(employees);
+    let pillars = DefaultPillarSelector::<ScheduleSolution, i32, _, _>::new(
+        FromSolutionEntitySelector::new(0),
+        0,
+        "shift",
+        |sd: &dyn Director<ScheduleSolution>, _desc_idx, entity_idx| {
+            sd.working_solution().employees[entity_idx].shift
+        },
+    );
+    let mut selector = PillarSwapMoveSelector::new(pillars, get_shift, set_shift, 0, 0, "shift");
+    if with_range_check {
+        selector = selector.with_value_is_legal(shift_is_legal);
+    }
+
+    assert_eq!(selector.size(&director), 3);
+    let mut moves = selector
+        .iter_moves(&director)
+        .map(|mov| {
+            let mut pair = [mov.left_indices().to_vec(), mov.right_indices().to_vec()];
+            pair.sort();
+            let [left, right] = pair;
+            (left, right, mov.is_doable(&director))
+        })
+        .collect::<Vec<_>>();
+    moves.sort();
+    moves
+}
+
+#[test]
+fn test_pillar_swap_selector_passes_value_range_check_to_moves() {
+    assert_eq!(
+        pillar_swap_doability(true),
+        vec![
+            (vec![0, 1], vec![2, 3], true),
+            (vec![0, 1], vec![4, 5], false),
+            (vec![2, 3], vec![4, 5], true),
+        ]
+    );
+}
+
+#[test]
+fn test_pillar_swap_selector_without_range_check_keeps_every_pair_doable() {
+    assert!(pillar_swap_doability(false)
+        .iter()
+        .all(|(_, _, doable)| *doable));
+}
@@ -2,7 +2,7 @@ use std::any::TypeId;
 use std::hint::black_box;
 use std::time::Instant;
 
-use crate::heuristic::r#move::Move;
+use crate::heuristic::r#move::{Move, SublistChangeMove};
 use crate::heuristic::selector::entity::FromSolutionEntitySelector;
 use crate::heuristic::selector::sublist_change::SublistChangeMoveSelector;
 use crate::heuristic::selector::sublist_swap::SublistSwapMoveSelector;
@@ -217,6 +217,61 @@ fn sublist_change_moves_are_doable() {
     }
 }
 
+#[test]
+fn sublist_change_reversed_insertion_follows_each_multi_element_relocation() {
+    let director = create_director(vec![
+        Vehicle {
+            visits: vec![1, 2, 3, 4],
+        },
+        Vehicle {
+            visits: vec![10, 20, 30],
+        },
+    ]);
+    let selector = |reversed_insertion| {
+        SublistChangeMoveSelector::<Plan, usize, _>::new(
+            FromSolutionEntitySelector::new(0),
+            1,
+            3,
+            list_len,
+            list_get,
+            sublist_remove,
+            sublist_insert,
+            "visits",
+            0,
+        )
+        .with_reversed_insertion(reversed_insertion)
+    };
+    let forward_selector = selector(false);
+    let reversing_selector = selector(true);
+
+    let forward: Vec<_> = forward_selector.iter_moves(&director).collect();
+    let with_reversed: Vec<_> = reversing_selector.iter_moves(&director).collect();
+
+    let mut expected = Vec::new();
+    for mov in &forward {
+        expected.push(*mov);
+        if mov.sublist_len() >= 2 {
+            expected.push(mov.with_reversed(true));
+        }
+    }
+    let describe = |m: &SublistChangeMove<Plan, usize>| {
+        (
+            m.source_entity_index(),
+            m.source_start(),
+            m.source_end(),
+            m.dest_entity_index(),
+            m.dest_position(),
+            m.is_reversed(),
+        )
+    };
+    assert_eq!(
+        with_reversed.iter().map(describe).collect::<Vec<_>>(),
+        expected.iter().map(describe).collect::<Vec<_>>()
+    );
+    assert_eq!(reversing_selector.size(&director), with_reversed.len());
+    assert!(with_reversed.iter().all(|m| m.is_doable(&director)));
+}
+
 #[test]
 fn sublist_change_keeps_unrestricted_elements_when_owner_hook_exists() {
     let director = create_director(vec![
@@ -56,6 +56,7 @@ pub use heuristic::{
     ChangeMove,
     ChangeMoveSelector,
     CompositeMove,
+    CompositeMoveBuilder,
     CompoundScalarEdit,
     CompoundScalarMove,
     CrossEntityDistanceMeter,
@@ -95,6 +96,8 @@ pub use heuristic::{
     PillarChangeMove,
     PillarSelector,
     PillarSwapMove,
+    PillarSwapMoveSelector,
+    RecreateStrategy,
     RuinMove,
     RuinMoveSelector,
     RuinVariableAccess,
@@ -112,10 +115,10 @@ pub use manager::{
     analyze, Analyzable, ConstraintAnalysis, ConstructionPhaseFactory, KOptPhase, KOptPhaseBuilder,
     ListCheapestInsertionPhase, ListClarkeWrightPhase, ListConstructionPhase,
     ListConstructionPhaseBuilder, ListKOptPhase, ListRegretInsertionPhase, LocalSearchPhaseFactory,
-    PhaseFactory, ScoreAnalysis, Solvable, SolverEvent, SolverEventMetadata, SolverFactory,
-    SolverFactoryBuilder, SolverLifecycleState, SolverManager, SolverManagerError,
-    SolverPanicPayload, SolverRuntime, SolverSnapshot, SolverSnapshotAnalysis, SolverStatus,
-    SolverTelemetryDetail, SolverTerminalReason,
+    ParallelScoreCalculator, PhaseFactory, ScoreAnalysis, Solvable, SolverEvent,
+    SolverEventMetadata, SolverFactory, SolverFactoryBuilder, SolverLifecycleState, SolverManager,
+    SolverManagerError, SolverPanicPayload, SolverRuntime, SolverSnapshot, SolverSnapshotAnalysis,
+    SolverStatus, SolverTelemetryDetail, SolverTerminalReason,
 };
 pub use model_support::PlanningModelSupport;
 pub use phase::{
@@ -125,20 +128,20 @@ pub use phase::{
         FirstFitForager, ForagerType, Placement, QueuedEntityPlacer,
     },
     exhaustive::{
-        BounderType, ExhaustiveSearchConfig, ExhaustiveSearchDecider, ExhaustiveSearchNode,
-        ExhaustiveSearchPhase, ExplorationType, FixedOffsetBounder, ScoreBounder, SimpleDecider,
-        SoftScoreBounder,
+        BounderType, DescriptorDecider, ExhaustiveSearchConfig, ExhaustiveSearchDecider,
+        ExhaustiveSearchNode, ExhaustiveSearchPhase, ExplorationType, FixedOffsetBounder,
+        ScoreBounder, SimpleDecider, SoftScoreBounder,
     },
     localsearch::{
         AcceptedCountForager, Acceptor, BestScoreForager, DiversifiedLateAcceptanceAcceptor,
         FirstAcceptedForager, FirstBestScoreImprovingForager, FirstLastStepScoreImprovingForager,
         GreatDelugeAcceptor, HardRegressionPolicy, HillClimbingAcceptor, LateAcceptanceAcceptor,
-        LocalSearchForager, LocalSearchPhase, SimulatedAnnealingAcceptor,
+        LocalSearchForager, LocalSearchPhase, RestartStrategy, SimulatedAnnealingAcceptor,
         SimulatedAnnealingCalibration, StepCountingHillClimbingAcceptor, TabuSearchAcceptor,
     },
     partitioned::{
-        ChildPhases, FunctionalPartitioner, PartitionedSearchConfig, PartitionedSearchPhase,
-        SolutionPartitioner, ThreadCount,
+        BalancedPartitioner, ChildPhases, FunctionalPartitioner, PartitionCount,
+        PartitionedSearchConfig, PartitionedSearchPhase, SolutionPartitioner, ThreadCount,
     },
     sequence::PhaseSequence,
     Phase,
@@ -151,15 +154,16 @@ pub use planning::{
 pub use run::{log_solve_start, try_run_solver_with_config_and_search};
 pub use runtime::{ListVariableEntity, ListVariableMetadata};
 pub use runtime_build_error::{RuntimeBuildError, RuntimeBuildResult};
-pub use scope::{PhaseScope, SolverScope, StepScope};
+pub use scope::{CloneStrategy, PhaseScope, SolverScope, StepEvent, StepScope};
 pub use solver::{MaybeTermination, NoTermination, SolveResult, Solver};
 pub use stats::{
-    AppliedMoveTelemetry, MoveTelemetry, PhaseStats, PhaseTelemetry, SelectorTelemetry,
-    SolverStats, SolverTelemetry,
+    AppliedMoveTelemetry, MoveTelemetry, PhaseStatistics, PhaseStats, PhaseTelemetry,
+    ScoreImprovement, SelectorTelemetry, SolverStatistics, SolverStats, SolverTelemetry,
+    StatisticsCollector,
 };
 pub use termination::{
     AndTermination, BestScoreFeasibleTermination, BestScoreTermination,
-    DiminishedReturnsTermination, MoveCountTermination, OrTermination,
+    DiminishedReturnsTermination, MoveCountTermination, NestedTermination, OrTermination,
     ScoreCalculationCountTermination, StepCountTermination, Termination, TimeTermination,
     UnimprovedStepCountTermination, UnimprovedTimeTermination,
 };
@@ -9,9 +9,11 @@ use solverforge_scoring::Director;
 
 use crate::phase::Phase;
 use crate::solver::NoTermination;
-use crate::termination::{OrTermination, StepCountTermination, Termination, TimeTermination};
+use crate::termination::{
+    BestScoreFeasibleTermination, OrTermination, StepCountTermination, Termination, TimeTermination,
+};
 
-use super::{PhaseFactory, SolverFactory};
+use super::{ParallelScoreCalculator, PhaseFactory, SolverFactory};
 
 /// Builder for SolverFactory with zero type erasure.
 ///
@@ -75,6 +77,19 @@ where
         }
     }
 
+    /// Replaces the score calculator with a chunked parallel recalculation.
+    pub fn with_parallel_calculator(
+        self,
+        calculator: ParallelScoreCalculator<S>,
+    ) -> SolverFactoryBuilder<S, D, impl Fn(&S) -> S::Score + Send + Sync, P, T> {
+        SolverFactoryBuilder {
+            score_calculator: move |solution: &S| calculator.calculate(solution),
+            phases: self.phases,
+            termination: self.termination,
+            _marker: PhantomData,
+        }
+    }
+
     pub fn with_config(
         self,
         config: SolverConfig,
@@ -113,6 +128,25 @@ where
         }
     }
 
+    /// Stops once the best score has been feasible for `grace_period`.
+    ///
+    /// A zero grace period stops at the first feasible best score.
+    #[allow(clippy::type_complexity)]
+    pub fn with_feasible_then(
+        self,
+        grace_period: Duration,
+    ) -> SolverFactoryBuilder<S, D, C, P, BestScoreFeasibleTermination<S, fn(&S::Score) -> bool>>
+    {
+        SolverFactoryBuilder {
+            score_calculator: self.score_calculator,
+            phases: self.phases,
+            termination: BestScoreFeasibleTermination::score_is_feasible_with_grace_period(
+                grace_period,
+            ),
+            _marker: PhantomData,
+        }
+    }
+
     // Combines current termination with time limit.
     #[allow(clippy::type_complexity)]
     pub fn with_time_limit_or(
@@ -66,3 +66,18 @@ fn test_builder_with_step_limit() {
     let score = factory.calculate_score(&solution);
     assert_eq!(score, SoftScore::of(-10));
 }
+
+#[test]
+fn test_builder_with_feasible_then() {
+    fn calculator(s: &TestSolution) -> SoftScore {
+        SoftScore::of(-s.value)
+    }
+    let factory = SolverFactoryBuilder::<TestSolution, TestDirector, _, _, _>::new(
+        calculator as fn(&TestSolution) -> SoftScore,
+    )
+    .with_feasible_then(Duration::from_secs(5))
+    .build()
+    .expect("Failed to build factory");
+
+    assert_eq!(factory.termination().grace_period(), Duration::from_secs(5));
+}
@@ -8,6 +8,7 @@ which generates concrete types at compile time.
 */
 
 mod builder;
+mod parallel_calculator;
 mod phase_factory;
 mod phase_factory_trait;
 mod solution_manager;
@@ -22,6 +23,7 @@ mod mod_tests;
 mod mod_tests_integration;
 
 pub use builder::{SolverBuildError, SolverFactoryBuilder};
+pub use parallel_calculator::ParallelScoreCalculator;
 pub(crate) use phase_factory::{
     run_cheapest, run_clarke_wright, run_list_k_opt, run_regret, run_round_robin,
     PhaseCheapestInsertionObserver, ScoredListConstructionAccess,
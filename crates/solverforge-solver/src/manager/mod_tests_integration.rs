@@ -2,6 +2,7 @@
 
 mod analysis_tests;
 mod basic;
+mod batch_tests;
 mod common;
 mod gates;
 mod lifecycle_solutions;
@@ -0,0 +1,247 @@
+use solverforge_core::score::SoftScore;
+use solverforge_core::PlanningSolution;
+
+use super::super::solver_manager::MAX_JOBS;
+use super::super::{
+    Solvable, SolverEvent, SolverManager, SolverManagerError, SolverRuntime, SolverTerminalReason,
+};
+use super::common::recv_event;
+use super::gates::BlockingPoint;
+use super::runtime_helpers::zero_telemetry;
+
+static SELF_DELETING_MANAGER: SolverManager<QueensBatchSolution> = SolverManager::new();
+
+// What a job does around its solve, so tests fix the completion order with
+// gates rather than timing.
+#[derive(Clone, Debug)]
+enum BatchRole {
+    Solve,
+    WaitFor(BlockingPoint),
+    Release(BlockingPoint),
+    DeleteSelf,
+}
+
+#[derive(Clone, Debug)]
+struct QueensBatchSolution {
+    rows: Vec<usize>,
+    role: BatchRole,
+    score: Option<SoftScore>,
+}
+
+impl QueensBatchSolution {
+    fn new(n: usize, role: BatchRole) -> Self {
+        Self {
+            rows: vec![0; n],
+            role,
+            score: None,
+        }
+    }
+
+    fn conflicts(&self) -> i64 {
+        let mut conflicts = 0;
+        for (i, &a) in self.rows.iter().enumerate() {
+            for (j, &b) in self.rows.iter().enumerate().skip(i + 1) {
+                if a == b || a.abs_diff(b) == j - i {
+                    conflicts += 1;
+                }
+            }
+        }
+        conflicts
+    }
+
+    fn place(&mut self, column: usize) -> bool {
+        if column == self.rows.len() {
+            return true;
+        }
+        for row in 0..self.rows.len() {
+            let safe = self.rows[..column]
+                .iter()
+                .enumerate()
+                .all(|(other, &placed)| placed != row && placed.abs_diff(row) != column - other);
+            if safe {
+                self.rows[column] = row;
+                if self.place(column + 1) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+impl PlanningSolution for QueensBatchSolution {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+impl Solvable for QueensBatchSolution {
+    fn solve(
+        mut self,
+        runtime: SolverRuntime<Self>,
+        _provenance: Option<crate::stats::QualifiedCandidateTraceRunProvenance>,
+    ) {
+        if let BatchRole::WaitFor(gate) = &self.role {
+            gate.block();
+        }
+        self.place(0);
+        let score = SoftScore::of(-self.conflicts());
+        self.set_score(Some(score));
+        let role = self.role.clone();
+        runtime.emit_completed(
+            self,
+            Some(score),
+            score,
+            zero_telemetry(),
+            SolverTerminalReason::Completed,
+        );
+        match role {
+            BatchRole::Release(gate) => gate.release(),
+            BatchRole::DeleteSelf => SELF_DELETING_MANAGER
+                .delete(runtime.job_id())
+                .expect("a completed job is deletable"),
+            BatchRole::Solve | BatchRole::WaitFor(_) => {}
+        }
+    }
+}
+
+fn completed_solution(event: SolverEvent<QueensBatchSolution>) -> QueensBatchSolution {
+    match event {
+        SolverEvent::Completed { solution, .. } => solution,
+        other => panic!("unexpected terminal event: {other:?}"),
+    }
+}
+
+// Runs the batch on its own pool so gated jobs overlap whatever the CPU count.
+fn solve_all_on_pool(
+    manager: &'static SolverManager<QueensBatchSolution>,
+    problems: Vec<QueensBatchSolution>,
+    max_concurrent_jobs: usize,
+) -> Result<Vec<SolverEvent<QueensBatchSolution>>, SolverManagerError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(max_concurrent_jobs + 1)
+        .build()
+        .expect("batch test pool should build")
+        .install(|| manager.solve_all(problems, max_concurrent_jobs))
+}
+
+#[test]
+fn solve_all_returns_results_in_input_order() {
+    static MANAGER: SolverManager<QueensBatchSolution> = SolverManager::new();
+
+    // The first instance finishes last, so completion order differs from input order.
+    let gate = BlockingPoint::new();
+    let problems = vec![
+        QueensBatchSolution::new(4, BatchRole::WaitFor(gate.clone())),
+        QueensBatchSolution::new(5, BatchRole::Solve),
+        QueensBatchSolution::new(6, BatchRole::Release(gate)),
+    ];
+
+    let results = solve_all_on_pool(&MANAGER, problems, 3).expect("batch should solve");
+
+    let solutions: Vec<_> = results.into_iter().map(completed_solution).collect();
+    assert_eq!(
+        solutions
+            .iter()
+            .map(|solution| solution.rows.len())
+            .collect::<Vec<_>>(),
+        vec![4, 5, 6]
+    );
+    for solution in &solutions {
+        assert_eq!(solution.score, Some(SoftScore::of(0)));
+        assert_eq!(solution.conflicts(), 0);
+    }
+    assert_eq!(MANAGER.active_job_count(), 0);
+}
+
+#[test]
+fn solve_all_refills_slots_freed_behind_a_slow_job() {
+    static MANAGER: SolverManager<QueensBatchSolution> = SolverManager::new();
+
+    // The head job holds one of the two slots until the last job runs, so the
+    // batch only finishes if the jobs behind it free and reuse the other slot.
+    let gate = BlockingPoint::new();
+    let problems = vec![
+        QueensBatchSolution::new(4, BatchRole::WaitFor(gate.clone())),
+        QueensBatchSolution::new(5, BatchRole::Solve),
+        QueensBatchSolution::new(6, BatchRole::Solve),
+        QueensBatchSolution::new(4, BatchRole::Release(gate)),
+    ];
+
+    let results = solve_all_on_pool(&MANAGER, problems, 2).expect("batch should solve");
+
+    let sizes: Vec<_> = results
+        .into_iter()
+        .map(|event| completed_solution(event).rows.len())
+        .collect();
+    assert_eq!(sizes, vec![4, 5, 6, 4]);
+    assert_eq!(MANAGER.active_job_count(), 0);
+}
+
+#[test]
+fn solve_all_bounds_concurrency_and_reuses_slots() {
+    static MANAGER: SolverManager<QueensBatchSolution> = SolverManager::new();
+
+    let problems = (0..20)
+        .map(|index| QueensBatchSolution::new(4 + index % 3, BatchRole::Solve))
+        .collect();
+
+    let results = MANAGER.solve_all(problems, 2).expect("batch should solve");
+
+    assert_eq!(results.len(), 20);
+    for (index, event) in results.into_iter().enumerate() {
+        let solution = completed_solution(event);
+        assert_eq!(solution.rows.len(), 4 + index % 3);
+        assert_eq!(solution.score, Some(SoftScore::of(0)));
+    }
+}
+
+#[test]
+fn solve_all_reports_a_job_deleted_by_another_caller() {
+    let problems = vec![QueensBatchSolution::new(4, BatchRole::DeleteSelf)];
+
+    let result = SELF_DELETING_MANAGER.solve_all(problems, 1);
+
+    assert!(matches!(
+        result,
+        Err(SolverManagerError::JobNotFound { job_id: 0 })
+    ));
+    assert!(SELF_DELETING_MANAGER.slot_is_free_for_test(0));
+}
+
+#[test]
+fn solve_all_reports_no_free_slots_when_other_jobs_hold_them_all() {
+    static MANAGER: SolverManager<QueensBatchSolution> = SolverManager::new();
+
+    let gate = BlockingPoint::new();
+    let mut jobs: Vec<_> = (0..MAX_JOBS)
+        .map(|_| {
+            MANAGER
+                .solve(QueensBatchSolution::new(
+                    4,
+                    BatchRole::WaitFor(gate.clone()),
+                ))
+                .expect("job should start")
+        })
+        .collect();
+
+    let problems = vec![QueensBatchSolution::new(4, BatchRole::Solve)];
+    assert!(matches!(
+        MANAGER.solve_all(problems, 1),
+        Err(SolverManagerError::NoFreeJobSlots)
+    ));
+
+    gate.release();
+    for (job_id, receiver) in &mut jobs {
+        completed_solution(recv_event(receiver, "completed event"));
+        MANAGER
+            .delete(*job_id)
+            .expect("completed job should delete");
+    }
+}
@@ -0,0 +1,127 @@
+/* Chunked full-recalculation score calculator.
+
+Splits the entity range into contiguous chunks, scores each chunk on the
+rayon pool, and sums the partial scores.
+*/
+
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use rayon::prelude::*;
+use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::Score;
+
+/// Full-recalculation score calculator that scores entity chunks in parallel.
+///
+/// `chunk_calculator` scores the constraint matches owned by the entities in
+/// one range. The calculator must be associative: summing the chunk scores
+/// of any split of `0..entity_count(solution)` has to equal the score of the
+/// whole range, so every match must be counted by exactly one chunk.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use std::ops::Range;
+///
+/// use solverforge_core::domain::PlanningSolution;
+/// use solverforge_core::score::SoftScore;
+/// use solverforge_solver::ParallelScoreCalculator;
+///
+/// #[derive(Clone)]
+/// struct Plan {
+///     costs: Vec<i64>,
+///     score: Option<SoftScore>,
+/// }
+///
+/// impl PlanningSolution for Plan {
+///     type Score = SoftScore;
+///     fn score(&self) -> Option<Self::Score> { self.score }
+///     fn set_score(&mut self, score: Option<Self::Score>) { self.score = score; }
+/// }
+///
+/// fn chunk_cost(plan: &Plan, range: Range<usize>) -> SoftScore {
+///     SoftScore::of(-plan.costs[range].iter().sum::<i64>())
+/// }
+///
+/// let calculator = ParallelScoreCalculator::new(
+///     chunk_cost,
+///     |plan: &Plan| plan.costs.len(),
+///     NonZeroUsize::new(2).unwrap(),
+/// );
+/// let plan = Plan { costs: vec![1, 2, 3], score: None };
+/// assert_eq!(calculator.calculate(&plan), SoftScore::of(-6));
+/// ```
+pub struct ParallelScoreCalculator<S: PlanningSolution> {
+    chunk_calculator: fn(&S, Range<usize>) -> S::Score,
+    entity_count: fn(&S) -> usize,
+    chunk_count: NonZeroUsize,
+}
+
+impl<S: PlanningSolution> ParallelScoreCalculator<S> {
+    pub fn new(
+        chunk_calculator: fn(&S, Range<usize>) -> S::Score,
+        entity_count: fn(&S) -> usize,
+        chunk_count: NonZeroUsize,
+    ) -> Self {
+        Self {
+            chunk_calculator,
+            entity_count,
+            chunk_count,
+        }
+    }
+
+    pub fn chunk_count(&self) -> NonZeroUsize {
+        self.chunk_count
+    }
+
+    /// Contiguous chunks covering `0..entity_count`, at most `chunk_count`
+    /// of them and none empty.
+    pub fn chunks(&self, entity_count: usize) -> Vec<Range<usize>> {
+        let chunk_count = self.chunk_count.get().min(entity_count.max(1));
+        let base = entity_count / chunk_count;
+        let remainder = entity_count % chunk_count;
+        let mut start = 0;
+        (0..chunk_count)
+            .map(|chunk| {
+                let len = base + usize::from(chunk < remainder);
+                let range = start..start + len;
+                start += len;
+                range
+            })
+            .collect()
+    }
+
+    /// Scores every chunk on the rayon pool and sums the partials.
+    pub fn calculate(&self, solution: &S) -> S::Score {
+        let chunks = self.chunks((self.entity_count)(solution));
+        if chunks.len() == 1 {
+            return (self.chunk_calculator)(solution, chunks[0].clone());
+        }
+        chunks
+            .into_par_iter()
+            .map(|range| (self.chunk_calculator)(solution, range))
+            .reduce(S::Score::zero, |left, right| left + right)
+    }
+}
+
+impl<S: PlanningSolution> Clone for ParallelScoreCalculator<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: PlanningSolution> Copy for ParallelScoreCalculator<S> {}
+
+impl<S: PlanningSolution> fmt::Debug for ParallelScoreCalculator<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParallelScoreCalculator")
+            .field("chunk_count", &self.chunk_count)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[path = "parallel_calculator_tests.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+// Tests for ParallelScoreCalculator.
+
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use solverforge_core::score::SoftScore;
+use solverforge_scoring::ScoreDirector;
+
+use super::ParallelScoreCalculator;
+use crate::manager::SolverFactoryBuilder;
+use crate::test_utils::{calculate_conflicts, NQueensSolution};
+
+// Counts each conflicting pair once, from its lower-indexed queen.
+fn chunk_conflicts(solution: &NQueensSolution, range: Range<usize>) -> SoftScore {
+    let queens = &solution.queens;
+    let mut conflicts = 0i64;
+    for i in range {
+        for j in (i + 1)..queens.len() {
+            if let (Some(row_i), Some(row_j)) = (queens[i].row, queens[j].row) {
+                if row_i == row_j {
+                    conflicts += 1;
+                }
+                if (row_i - row_j).abs() == (queens[j].column - queens[i].column).abs() {
+                    conflicts += 1;
+                }
+            }
+        }
+    }
+    SoftScore::of(-conflicts)
+}
+
+fn queen_count(solution: &NQueensSolution) -> usize {
+    solution.queens.len()
+}
+
+fn calculator(chunk_count: usize) -> ParallelScoreCalculator<NQueensSolution> {
+    ParallelScoreCalculator::new(
+        chunk_conflicts,
+        queen_count,
+        NonZeroUsize::new(chunk_count).unwrap(),
+    )
+}
+
+#[test]
+fn parallel_score_matches_serial_nqueens_for_every_chunk_count() {
+    let solution = NQueensSolution::with_rows(&[0, 4, 7, 5, 2, 6, 1, 3, 3, 0, 5]);
+    let serial = calculate_conflicts(&solution);
+    assert!(serial < SoftScore::of(0));
+
+    for chunk_count in [1, 2, 3, 4, 7, 11, 32] {
+        assert_eq!(
+            calculator(chunk_count).calculate(&solution),
+            serial,
+            "chunk count {chunk_count}"
+        );
+    }
+}
+
+#[test]
+fn chunks_cover_entities_without_empty_ranges() {
+    assert_eq!(calculator(3).chunks(8), vec![0..3, 3..6, 6..8]);
+    assert_eq!(calculator(4).chunks(2), vec![0..1, 1..2]);
+    assert_eq!(calculator(4).chunks(0), vec![0..0]);
+}
+
+#[test]
+fn builder_uses_parallel_calculator() {
+    let solution = NQueensSolution::with_rows(&[0, 1, 2, 3]);
+    let factory =
+        SolverFactoryBuilder::<NQueensSolution, ScoreDirector<NQueensSolution, ()>, _, _, _>::new(
+            calculate_conflicts as fn(&NQueensSolution) -> SoftScore,
+        )
+        .with_parallel_calculator(calculator(2))
+        .with_step_limit(10)
+        .build()
+        .expect("Failed to build factory");
+
+    assert_eq!(
+        factory.calculate_score(&solution),
+        calculate_conflicts(&solution)
+    );
+}
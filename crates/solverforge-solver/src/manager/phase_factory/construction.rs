@@ -8,6 +8,7 @@ use solverforge_scoring::Director;
 use crate::heuristic::Move;
 use crate::phase::construction::{
     BestFitForager, ConstructionForager, ConstructionHeuristicPhase, EntityPlacer, FirstFitForager,
+    StrongestFitForager, WeakestFitForager,
 };
 
 use super::super::PhaseFactory;
@@ -72,6 +73,30 @@ where
     }
 }
 
+impl<S, M, P> ConstructionPhaseFactory<S, M, P, WeakestFitForager<S, M>>
+where
+    S: PlanningSolution,
+    M: Move<S>,
+    P: EntityPlacer<S, M>,
+{
+    /// Tries values weakest-first, as ranked by `strength_fn`.
+    pub fn weakest_fit(placer: P, strength_fn: fn(&M, &S) -> i64) -> Self {
+        Self::new(placer, WeakestFitForager::new(strength_fn))
+    }
+}
+
+impl<S, M, P> ConstructionPhaseFactory<S, M, P, StrongestFitForager<S, M>>
+where
+    S: PlanningSolution,
+    M: Move<S>,
+    P: EntityPlacer<S, M>,
+{
+    /// Tries values strongest-first, as ranked by `strength_fn`.
+    pub fn strongest_fit(placer: P, strength_fn: fn(&M, &S) -> i64) -> Self {
+        Self::new(placer, StrongestFitForager::new(strength_fn))
+    }
+}
+
 impl<S, D, M, P, Fo> PhaseFactory<S, D> for ConstructionPhaseFactory<S, M, P, Fo>
 where
     S: PlanningSolution,
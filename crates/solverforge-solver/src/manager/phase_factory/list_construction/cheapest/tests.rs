@@ -8,7 +8,7 @@ use solverforge_scoring::{ConstraintMetadata, Director, ScoreDirector};
 use super::*;
 use crate::builder::context::{bind_runtime_list_source, ListConstructionKernelError};
 use crate::builder::usize_element_source_key;
-use crate::manager::SolverTerminalReason;
+use crate::manager::{ListConstructionPhaseBuilder, SolverTerminalReason};
 use crate::phase::Phase;
 use crate::scope::{SolverProgressKind, SolverProgressRef, SolverScope};
 
@@ -257,6 +257,63 @@ fn precedence_downstream_breaks_cheapest_ties_without_reconstructing_payload_ide
     assert_eq!(scope.working_solution().routes, vec![vec![1, 0]]);
 }
 
+fn route_length(plan: &Plan) -> i64 {
+    plan.routes
+        .iter()
+        .map(|route| {
+            let mut at = 0usize;
+            let mut length = 0usize;
+            for &stop in route.iter().chain(std::iter::once(&0)) {
+                length += at.abs_diff(stop);
+                at = stop;
+            }
+            length as i64
+        })
+        .sum()
+}
+
+fn route_length_score(plan: &Plan) -> HardSoftScore {
+    HardSoftScore::of_soft(-route_length(plan))
+}
+
+fn append_to_route(plan: &mut Plan, entity: usize, element: usize) {
+    plan.routes[entity].push(element);
+}
+
+#[test]
+fn cheapest_insertion_builds_a_shorter_route_than_first_fit() {
+    let plan = Plan {
+        elements: vec![5, 1, 4, 2],
+        routes: vec![Vec::new()],
+        score: None,
+    };
+
+    let mut cheapest_scope = SolverScope::new(director(plan.clone(), route_length_score));
+    cheapest_scope.start_solving();
+    phase().solve(&mut cheapest_scope);
+
+    let mut first_fit_scope = SolverScope::new(director(plan, route_length_score));
+    first_fit_scope.start_solving();
+    ListConstructionPhaseBuilder::<Plan, usize>::new(
+        element_count,
+        assigned,
+        entity_count,
+        append_to_route,
+        index_to_element,
+        usize_element_source_key,
+        0,
+    )
+    .create_phase()
+    .solve(&mut first_fit_scope);
+
+    let cheapest = cheapest_scope.working_solution();
+    let first_fit = first_fit_scope.working_solution();
+    assert_eq!(first_fit.routes, vec![vec![5, 1, 4, 2]]);
+    assert_eq!(route_length(first_fit), 16);
+    assert_eq!(route_length(cheapest), 10);
+    assert!(route_length(cheapest) < route_length(first_fit));
+}
+
 #[derive(Clone, Copy)]
 struct Opaque(usize);
 
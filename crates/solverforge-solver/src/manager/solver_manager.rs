@@ -8,6 +8,7 @@ Provides the high-level API for:
 - Retrieving snapshot-bound solutions and score analysis
 */
 
+mod batch;
 mod manager;
 mod runtime;
 mod slot;
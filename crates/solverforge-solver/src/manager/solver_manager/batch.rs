@@ -0,0 +1,131 @@
+/* Blocking batch solve over the retained job slots.
+
+Every batch job reports its id on one shared exit channel once its worker has
+exited, which is also the point where its deleted slot becomes reusable.
+`solve_all` blocks on that channel, so whichever job finishes first is
+collected and replaced first, whatever its input position.
+*/
+
+use std::collections::VecDeque;
+
+use solverforge_core::score::Score;
+use tokio::sync::mpsc;
+
+use super::manager::{Solvable, SolverManager, MAX_JOBS};
+use super::types::{SolverEvent, SolverManagerError};
+
+struct BatchJob<S: Solvable> {
+    input_index: usize,
+    job_id: usize,
+    events: mpsc::UnboundedReceiver<SolverEvent<S>>,
+}
+
+impl<S: Solvable> SolverManager<S>
+where
+    S::Score: Score,
+{
+    /// Solves independent problems and blocks until every job is terminal.
+    ///
+    /// At most `max_concurrent_jobs` jobs (clamped to `1..=MAX_JOBS`) are
+    /// retained at once; each instance runs under its own configured
+    /// termination. Jobs are collected and deleted in the order they finish,
+    /// and each freed slot takes the next pending problem, so a slow job never
+    /// holds back finished ones. Returns each job's terminal event
+    /// (`Completed`, `Cancelled`, or `Failed`) in input order. Must not be
+    /// called from an async context.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverManagerError::NoFreeJobSlots`] when no batch job is in
+    /// flight and other jobs hold every slot, and
+    /// [`SolverManagerError::JobNotFound`] when another caller deletes a batch
+    /// job before its terminal event is collected. Batch jobs still in flight
+    /// are cancelled and deleted before an error is returned.
+    pub fn solve_all(
+        &'static self,
+        solutions: Vec<S>,
+        max_concurrent_jobs: usize,
+    ) -> Result<Vec<SolverEvent<S>>, SolverManagerError> {
+        let limit = max_concurrent_jobs.clamp(1, MAX_JOBS);
+        let mut terminal_events: Vec<Option<SolverEvent<S>>> =
+            solutions.iter().map(|_| None).collect();
+        let mut pending: VecDeque<(usize, S)> = solutions.into_iter().enumerate().collect();
+        let mut in_flight: Vec<BatchJob<S>> = Vec::with_capacity(limit);
+        let (exit_notifier, mut exits) = mpsc::unbounded_channel();
+
+        loop {
+            while in_flight.len() < limit {
+                let Some((input_index, solution)) = pending.pop_front() else {
+                    break;
+                };
+                match self.try_submit(solution, None, Some(exit_notifier.clone())) {
+                    Ok((job_id, events)) => in_flight.push(BatchJob {
+                        input_index,
+                        job_id,
+                        events,
+                    }),
+                    Err(solution) => {
+                        pending.push_front((input_index, solution));
+                        break;
+                    }
+                }
+            }
+            if in_flight.is_empty() {
+                if pending.is_empty() {
+                    break;
+                }
+                return Err(SolverManagerError::NoFreeJobSlots);
+            }
+
+            let exited = exits
+                .blocking_recv()
+                .expect("solve_all holds an exit notifier");
+            let position = in_flight
+                .iter()
+                .position(|job| job.job_id == exited)
+                .expect("only batch jobs report on the exit channel");
+            let job = in_flight.swap_remove(position);
+            match self.collect_terminal(job) {
+                Ok((input_index, event)) => terminal_events[input_index] = Some(event),
+                Err(error) => {
+                    self.abandon(in_flight, &mut exits);
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(terminal_events.into_iter().flatten().collect())
+    }
+
+    // The worker has exited, so its terminal event is already buffered.
+    fn collect_terminal(
+        &self,
+        mut job: BatchJob<S>,
+    ) -> Result<(usize, SolverEvent<S>), SolverManagerError> {
+        let job_id = job.job_id;
+        let terminal = std::iter::from_fn(|| job.events.try_recv().ok()).find(|event| {
+            matches!(
+                event,
+                SolverEvent::Completed { .. }
+                    | SolverEvent::Cancelled { .. }
+                    | SolverEvent::Failed { .. }
+            )
+        });
+        let terminal = terminal.ok_or(SolverManagerError::JobNotFound { job_id })?;
+        self.delete(job_id)?;
+        Ok((job.input_index, terminal))
+    }
+
+    fn abandon(&self, mut in_flight: Vec<BatchJob<S>>, exits: &mut mpsc::UnboundedReceiver<usize>) {
+        for job in &in_flight {
+            let _ = self.cancel(job.job_id);
+        }
+        while !in_flight.is_empty() {
+            let exited = exits
+                .blocking_recv()
+                .expect("solve_all holds an exit notifier");
+            in_flight.retain(|job| job.job_id != exited);
+            let _ = self.delete(exited);
+        }
+    }
+}
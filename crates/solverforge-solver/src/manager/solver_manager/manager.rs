@@ -95,6 +95,18 @@ where
         solution: S,
         qualified_candidate_trace_provenance: Option<QualifiedCandidateTraceRunProvenance>,
     ) -> Result<(usize, mpsc::UnboundedReceiver<SolverEvent<S>>), SolverManagerError> {
+        self.try_submit(solution, qualified_candidate_trace_provenance, None)
+            .map_err(|_| SolverManagerError::NoFreeJobSlots)
+    }
+
+    // Returns the solution back when no slot is free. `exit_notifier` receives
+    // the job id once the worker has exited, after its terminal event.
+    pub(super) fn try_submit(
+        &'static self,
+        solution: S,
+        qualified_candidate_trace_provenance: Option<QualifiedCandidateTraceRunProvenance>,
+        exit_notifier: Option<mpsc::UnboundedSender<usize>>,
+    ) -> Result<(usize, mpsc::UnboundedReceiver<SolverEvent<S>>), S> {
         let (sender, receiver) = mpsc::unbounded_channel();
 
         let Some(slot_idx) = self
@@ -102,7 +114,7 @@ where
             .iter()
             .position(|slot| slot.try_initialize(sender.clone()))
         else {
-            return Err(SolverManagerError::NoFreeJobSlots);
+            return Err(solution);
         };
 
         let slot = &self.slots[slot_idx];
@@ -140,6 +152,9 @@ where
             }
 
             runtime.slot.worker_exited();
+            if let Some(exit_notifier) = exit_notifier {
+                let _ = exit_notifier.send(slot_idx);
+            }
         });
 
         Ok((slot_idx, receiver))
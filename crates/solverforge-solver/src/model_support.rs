@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 
 use crate::builder::{ListVariableSlot, ScalarGroupBinding, ScalarVariableSlot};
@@ -36,5 +38,18 @@ pub trait PlanningModelSupport: PlanningSolution + Sized + 'static {
         entity_index: usize,
     ) -> bool;
 
+    fn update_list_shadows(
+        solution: &mut Self,
+        descriptor_index: usize,
+        entity_index: usize,
+        _changed: &[Range<usize>],
+    ) -> bool {
+        <Self as PlanningModelSupport>::update_entity_shadows(
+            solution,
+            descriptor_index,
+            entity_index,
+        )
+    }
+
     fn update_all_shadows(solution: &mut Self) -> bool;
 }
@@ -15,10 +15,12 @@ use std::marker::PhantomData;
 
 use solverforge_config::ConstructionObligation;
 use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::Score;
 use solverforge_scoring::Director;
 
 use crate::heuristic::r#move::Move;
 use crate::heuristic::selector::move_selector::{CandidateId, MoveCursor};
+use crate::phase::hard_delta::{hard_score_delta, HardScoreDelta};
 use crate::scope::{ProgressCallback, StepScope};
 
 use super::Placement;
@@ -131,37 +133,79 @@ impl<S, M> BestFitForager<S, M> {
 /// First Feasible forager - picks the first move that results in a feasible score.
 ///
 /// This forager evaluates moves until it finds one that produces a feasible
-/// (non-negative hard score) solution.
-pub struct FirstFeasibleForager<S, M> {
+/// (non-negative hard score) solution. With [`with_hard_threshold`], it instead
+/// stops at the first move whose hard levels reach the given threshold.
+///
+/// [`with_hard_threshold`]: FirstFeasibleForager::with_hard_threshold
+pub struct FirstFeasibleForager<S, M>
+where
+    S: PlanningSolution,
+{
+    // Hard-score target replacing full feasibility as the stop condition.
+    hard_threshold: Option<S::Score>,
     _phantom: PhantomData<fn() -> (S, M)>,
 }
 
-impl<S, M> Clone for FirstFeasibleForager<S, M> {
+impl<S, M> Clone for FirstFeasibleForager<S, M>
+where
+    S: PlanningSolution,
+{
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<S, M> Copy for FirstFeasibleForager<S, M> {}
+impl<S, M> Copy for FirstFeasibleForager<S, M> where S: PlanningSolution {}
 
-impl<S, M> Default for FirstFeasibleForager<S, M> {
+impl<S, M> Default for FirstFeasibleForager<S, M>
+where
+    S: PlanningSolution,
+{
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<S, M> Debug for FirstFeasibleForager<S, M> {
+impl<S, M> Debug for FirstFeasibleForager<S, M>
+where
+    S: PlanningSolution,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("FirstFeasibleForager").finish()
+        f.debug_struct("FirstFeasibleForager")
+            .field("hard_threshold", &self.hard_threshold)
+            .finish()
     }
 }
 
-impl<S, M> FirstFeasibleForager<S, M> {
+impl<S, M> FirstFeasibleForager<S, M>
+where
+    S: PlanningSolution,
+{
     pub fn new() -> Self {
         Self {
+            hard_threshold: None,
             _phantom: PhantomData,
         }
     }
+
+    /// Accepts the first move whose hard levels reach `threshold`.
+    ///
+    /// Soft levels of `threshold` are ignored. Score types without hard
+    /// levels compare the whole score instead.
+    pub fn with_hard_threshold(mut self, threshold: S::Score) -> Self {
+        self.hard_threshold = Some(threshold);
+        self
+    }
+
+    pub(crate) fn accepts(&self, score: S::Score) -> bool {
+        let Some(threshold) = self.hard_threshold else {
+            return score.is_feasible();
+        };
+        match hard_score_delta(threshold, score) {
+            Some(delta) => delta != HardScoreDelta::Worse,
+            None => score >= threshold,
+        }
+    }
 }
 
 /// Weakest Fit forager - picks the move with the lowest strength value.
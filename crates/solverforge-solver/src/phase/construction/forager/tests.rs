@@ -9,7 +9,7 @@ use solverforge_config::ConstructionObligation;
 use solverforge_core::domain::{
     EntityCollectionExtractor, EntityDescriptor, PlanningSolution, SolutionDescriptor,
 };
-use solverforge_core::score::SoftScore;
+use solverforge_core::score::{HardSoftScore, SoftScore};
 use solverforge_scoring::{Director, ScoreDirector};
 use std::any::TypeId;
 
@@ -414,3 +414,33 @@ fn first_feasible_prefers_equal_score_candidate_over_infeasible_baseline() {
         ConstructionChoice::Select(CandidateId::new(0))
     );
 }
+
+#[derive(Clone, Debug)]
+struct HardSoftSolution {
+    score: Option<HardSoftScore>,
+}
+
+impl PlanningSolution for HardSoftSolution {
+    type Score = HardSoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+#[test]
+fn first_feasible_hard_threshold_compares_only_hard_levels() {
+    let forager = FirstFeasibleForager::<HardSoftSolution, ()>::new()
+        .with_hard_threshold(HardSoftScore::of(-2, 0));
+
+    assert!(forager.accepts(HardSoftScore::of(-1, -100)));
+    assert!(forager.accepts(HardSoftScore::of(-2, -100)));
+    assert!(!forager.accepts(HardSoftScore::of(-3, 100)));
+
+    let feasible_only = FirstFeasibleForager::<HardSoftSolution, ()>::new();
+    assert!(!feasible_only.accepts(HardSoftScore::of(-1, 0)));
+}
@@ -72,7 +72,7 @@ where
         BestCb: ProgressCallback<S>,
         C: MoveCursor<S, M>,
     {
-        select_first_feasible_index(placement, construction_obligation, step_scope)
+        select_first_feasible_index(self, placement, construction_obligation, step_scope)
     }
 }
 
@@ -2,7 +2,6 @@ use std::time::{Duration, Instant};
 
 use solverforge_config::ConstructionObligation;
 use solverforge_core::domain::PlanningSolution;
-use solverforge_core::score::Score;
 use solverforge_scoring::Director;
 
 use super::decision::{
@@ -10,7 +9,9 @@ use super::decision::{
     select_first_fit, ScoredChoiceTracker,
 };
 use super::evaluation::evaluate_trial_move;
-use super::{ConstructionChoice, Placement, StrongestFitForager, WeakestFitForager};
+use super::{
+    ConstructionChoice, FirstFeasibleForager, Placement, StrongestFitForager, WeakestFitForager,
+};
 use crate::heuristic::r#move::Move;
 use crate::heuristic::selector::move_selector::{CandidateId, MoveCursor};
 use crate::phase::construction::report_construction_progress_if_due;
@@ -333,6 +334,7 @@ where
 
 #[allow(clippy::drop_non_drop)]
 pub(super) fn select_first_feasible_index<S, D, BestCb, M, C>(
+    forager: &FirstFeasibleForager<S, M>,
     placement: &mut Placement<S, M, C>,
     construction_obligation: ConstructionObligation,
     step_scope: &mut StepScope<'_, '_, '_, S, D, BestCb>,
@@ -402,7 +404,7 @@ where
             CandidateTraceDisposition::Evaluated,
             step_scope,
         );
-        if score.is_feasible() {
+        if forager.accepts(score) {
             if let Some((fallback, _)) = retained {
                 release(
                     placement,
@@ -13,12 +13,13 @@ use crate::heuristic::selector::move_selector::{
     ArenaMoveCursor, CandidateId, CandidateStore, MoveCandidateRef, MoveCursor,
 };
 use crate::heuristic::selector::{FromSolutionEntitySelector, StaticValueSelector};
+use crate::heuristic::r#move::ChangeMove;
 use crate::manager::{
     Solvable, SolverEvent, SolverLifecycleState, SolverManager, SolverRuntime, SolverTerminalReason,
 };
 use crate::phase::construction::{
     BestFitForager, EntityPlacerCursor, FirstFeasibleForager, FirstFitForager, Placement,
-    QueuedEntityPlacer,
+    QueuedEntityPlacer, StrongestFitForager, WeakestFitForager,
 };
 use crate::test_utils::{
     create_simple_nqueens_director, get_queen_row, set_queen_row, NQueensSolution,
@@ -249,3 +249,20 @@ fn test_construction_resume_retries_interrupted_placement() {
 
     MANAGER.delete(job_id).expect("delete resumed job");
 }
+
+#[test]
+fn construction_on_empty_solution_takes_no_steps() {
+    let mut solver_scope = SolverScope::new(create_simple_nqueens_director(0));
+    solver_scope.start_solving();
+    let initial_score = solver_scope.calculate_score();
+
+    let placer = create_placer((0..4).collect());
+    let forager = FirstFitForager::new();
+    let mut phase = ConstructionHeuristicPhase::new(placer, forager);
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.total_step_count(), 0);
+    assert!(solver_scope.working_solution().queens.is_empty());
+    assert_eq!(solver_scope.current_score().copied(), Some(initial_score));
+}
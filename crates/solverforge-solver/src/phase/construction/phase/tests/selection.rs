@@ -45,6 +45,38 @@ fn test_construction_best_fit() {
     assert_eq!(solver_scope.stats().moves_evaluated, 16);
 }
 
+fn row_capacity(mov: &ChangeMove<NQueensSolution, i64>, _: &NQueensSolution) -> i64 {
+    mov.to_value().copied().unwrap_or(0)
+}
+
+#[test]
+fn strongest_fit_assigns_the_highest_capacity_value_first() {
+    let director = create_simple_nqueens_director(4);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+
+    let placer = create_placer(vec![2, 0, 3, 1]);
+    let mut phase = ConstructionHeuristicPhase::new(placer, StrongestFitForager::new(row_capacity));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().queens[0].row, Some(3));
+}
+
+#[test]
+fn weakest_fit_assigns_the_lowest_capacity_value_first() {
+    let director = create_simple_nqueens_director(4);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+
+    let placer = create_placer(vec![2, 0, 3, 1]);
+    let mut phase = ConstructionHeuristicPhase::new(placer, WeakestFitForager::new(row_capacity));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().queens[0].row, Some(0));
+}
+
 #[test]
 fn best_fit_keeps_current_when_every_assignment_is_worse() {
     let director = ConstructionPauseDirector::new(ConstructionPauseSolution::new(None));
@@ -387,3 +419,45 @@ fn first_feasible_prefers_equal_score_candidate_over_infeasible_baseline() {
     );
     assert_eq!(solver_scope.stats().moves_accepted, 1);
 }
+
+#[test]
+fn first_feasible_hard_threshold_accepts_placement_full_feasibility_rejects() {
+    let director = ConstructionPauseDirector::with_score_mode(
+        ConstructionPauseSolution::new(None),
+        ConstructionPauseScoreMode::AssignedSum {
+            unassigned_score: -5,
+        },
+    );
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+
+    let placer = ScoredConstructionPlacer::new(vec![-3, -1, 5], true);
+    let forager = FirstFeasibleForager::new().with_hard_threshold(SoftScore::of(-3));
+    let mut phase = ConstructionHeuristicPhase::new(placer, forager);
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().entities[0].value, Some(-3));
+    assert_eq!(
+        solver_scope.current_score().copied(),
+        Some(SoftScore::of(-3))
+    );
+    assert_eq!(solver_scope.stats().moves_evaluated, 1);
+
+    let director = ConstructionPauseDirector::with_score_mode(
+        ConstructionPauseSolution::new(None),
+        ConstructionPauseScoreMode::AssignedSum {
+            unassigned_score: -5,
+        },
+    );
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+
+    let placer = ScoredConstructionPlacer::new(vec![-3, -1, 5], true);
+    let mut phase = ConstructionHeuristicPhase::new(placer, FirstFeasibleForager::new());
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().entities[0].value, Some(5));
+    assert_eq!(solver_scope.stats().moves_evaluated, 3);
+}
@@ -367,3 +367,6 @@ where
         None
     }
 }
+
+#[cfg(test)]
+mod tests;
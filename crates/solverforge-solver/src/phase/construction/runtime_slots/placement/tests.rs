@@ -0,0 +1,107 @@
+// Tests for runtime scalar construction entity ordering.
+
+use super::*;
+use crate::builder::{ScalarVariableSlot, ValueSource};
+use solverforge_core::score::SoftScore;
+use solverforge_scoring::ScoreDirector;
+
+#[derive(Clone, Debug)]
+struct TaskPlan {
+    workers: Vec<Option<usize>>,
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for TaskPlan {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+// Per-task difficulty weights; tasks 0 and 2 tie.
+const TASK_DIFFICULTY: [i64; 4] = [2, 7, 2, 5];
+
+fn task_count(plan: &TaskPlan) -> usize {
+    plan.workers.len()
+}
+
+fn worker_of(plan: &TaskPlan, entity_index: usize, _variable_index: usize) -> Option<usize> {
+    plan.workers[entity_index]
+}
+
+fn assign_worker(
+    plan: &mut TaskPlan,
+    entity_index: usize,
+    _variable_index: usize,
+    value: Option<usize>,
+) {
+    plan.workers[entity_index] = value;
+}
+
+fn task_difficulty(_plan: &TaskPlan, entity_index: usize, _variable_index: usize) -> Option<i64> {
+    Some(TASK_DIFFICULTY[entity_index])
+}
+
+fn placement_order(heuristic: ConstructionHeuristicType) -> Vec<usize> {
+    let slot = ScalarVariableSlot::new(
+        0,
+        0,
+        "Task",
+        task_count,
+        "worker",
+        worker_of,
+        assign_worker,
+        ValueSource::CountableRange { from: 0, to: 2 },
+        false,
+    )
+    .with_construction_entity_order_key(task_difficulty);
+    let placer = RuntimeScalarConstructionPlacer {
+        slots: vec![FrozenScalarConstructionSlot {
+            slot: RuntimeScalarSlot::Static(slot),
+            construction_slot_index: 0,
+        }],
+        entity_order: entity_order_for(heuristic),
+        value_order: value_order_for(heuristic),
+        value_candidate_limit: None,
+        live_refresh: false,
+    };
+    let director = ScoreDirector::simple_zero(TaskPlan {
+        workers: vec![None; 4],
+        score: None,
+    });
+
+    let mut cursor = placer.open_cursor(&director);
+    let mut order = Vec::new();
+    while let Some(placement) = cursor.next_placement(&director, |_| false, || false) {
+        order.push(placement.entity_ref.entity_index);
+    }
+    order
+}
+
+#[test]
+fn decreasing_heuristics_place_the_most_difficult_entity_first() {
+    for heuristic in [
+        ConstructionHeuristicType::FirstFitDecreasing,
+        ConstructionHeuristicType::WeakestFitDecreasing,
+        ConstructionHeuristicType::StrongestFitDecreasing,
+    ] {
+        assert_eq!(
+            placement_order(heuristic),
+            vec![1, 3, 0, 2],
+            "{heuristic:?}"
+        );
+    }
+}
+
+#[test]
+fn non_decreasing_heuristics_keep_entity_order() {
+    assert_eq!(
+        placement_order(ConstructionHeuristicType::FirstFit),
+        vec![0, 1, 2, 3]
+    );
+}
@@ -12,6 +12,21 @@ pub(crate) enum StepInterrupt {
     TerminatePhase,
 }
 
+/* Whether the model declares planning entity classes but the working
+solution holds no entities in any of them, so no search step can move.
+*/
+pub(crate) fn has_no_planning_entities<S, D>(score_director: &D) -> bool
+where
+    S: PlanningSolution,
+    D: Director<S>,
+{
+    !score_director
+        .solution_descriptor()
+        .entity_descriptors
+        .is_empty()
+        && score_director.total_entity_count() == Some(0)
+}
+
 pub(crate) fn should_interrupt_before_candidate<'t, 'a, 'b, S, D, ProgressCb>(
     step_scope: &StepScope<'t, 'a, 'b, S, D, ProgressCb>,
 ) -> bool
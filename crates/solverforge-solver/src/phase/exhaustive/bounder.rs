@@ -7,6 +7,7 @@ that enable branch-and-bound pruning.
 use std::fmt::Debug;
 
 use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::Score;
 use solverforge_scoring::Director;
 
 /// Calculates score bounds for exhaustive search pruning.
@@ -88,10 +89,7 @@ impl<S: PlanningSolution> FixedOffsetBounder<S> {
     }
 }
 
-impl<S: PlanningSolution, D: Director<S>> ScoreBounder<S, D> for FixedOffsetBounder<S>
-where
-    S::Score: Clone + std::ops::Add<Output = S::Score> + std::ops::Mul<i32, Output = S::Score>,
-{
+impl<S: PlanningSolution, D: Director<S>> ScoreBounder<S, D> for FixedOffsetBounder<S> {
     fn calculate_optimistic_bound(&self, score_director: &D) -> Option<S::Score> {
         // Count unassigned entities
         let total = score_director.total_entity_count()?;
@@ -102,7 +100,7 @@ where
 
         // Optimistic bound = current score + max_improvement * remaining_entities
         // Since we don't know remaining entities, we assume all could improve
-        let bound = current_score + self.max_improvement_per_entity * (total as i32);
+        let bound = current_score + self.max_improvement_per_entity.multiply(total as f64);
 
         Some(bound)
     }
@@ -1,4 +1,6 @@
 use super::*;
+use crate::test_utils::create_nqueens_director;
+use solverforge_core::score::{Score, SoftScore};
 
 #[test]
 fn test_simple_bounder_returns_none() {
@@ -17,3 +19,17 @@ fn test_bounder_type_display() {
 fn test_bounder_type_default() {
     assert_eq!(BounderType::default(), BounderType::None);
 }
+
+#[test]
+fn test_fixed_offset_bounder_scales_offset_by_entity_count() {
+    let mut director = create_nqueens_director(&[0, 1, 2]);
+    director
+        .working_solution_mut()
+        .set_score(Some(SoftScore::of(-7)));
+
+    let bounder = FixedOffsetBounder::new(SoftScore::of(2));
+    let bound = ScoreBounder::calculate_optimistic_bound(&bounder, &director);
+
+    assert_eq!(bound, Some(SoftScore::of(-1)));
+    assert!(bound.unwrap().is_better_than(&SoftScore::of(-7)));
+}
@@ -6,6 +6,11 @@ pub struct ExhaustiveSearchConfig {
     pub node_limit: Option<u64>,
     pub depth_limit: Option<usize>,
     pub enable_pruning: bool,
+    // Breadth-first only: nodes kept per level; `None` keeps the whole frontier.
+    pub beam_width: Option<usize>,
+    // Variable target for `ExhaustiveSearchPhase::from_descriptor`.
+    pub entity_class: Option<String>,
+    pub variable_name: Option<String>,
 }
 
 impl Default for ExhaustiveSearchConfig {
@@ -15,6 +20,9 @@ impl Default for ExhaustiveSearchConfig {
             node_limit: Some(10_000),
             depth_limit: None,
             enable_pruning: true,
+            beam_width: None,
+            entity_class: None,
+            variable_name: None,
         }
     }
 }
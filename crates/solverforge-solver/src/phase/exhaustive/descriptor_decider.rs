@@ -0,0 +1,235 @@
+/* Descriptor-driven exhaustive search decider.
+
+Reads candidate values for one scalar planning variable from the solution
+descriptor's value range instead of a hardcoded value list.
+*/
+
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
+use solverforge_scoring::Director;
+
+use crate::descriptor::{collect_bindings, find_binding, VariableBinding};
+
+use super::bounder::ScoreBounder;
+use super::decider::ExhaustiveSearchDecider;
+use super::node::ExhaustiveSearchNode;
+
+/// A decider that enumerates a variable's value range from the solution descriptor.
+///
+/// Candidates for each entity come from the variable's entity value provider,
+/// countable range, or `value_range_provider` collection, in that order. A
+/// variable that allows unassigned values gets `None` as its last candidate.
+///
+/// # Type Parameters
+/// * `S` - The planning solution type
+/// * `B` - The bounder type (use `Option<B>` for optional bounding)
+pub struct DescriptorDecider<S, B = ()> {
+    // Scalar variable binding resolved from the descriptor.
+    binding: VariableBinding,
+    // Descriptor used for entity lookup and value-range resolution.
+    solution_descriptor: SolutionDescriptor,
+    // Score bounder for optimistic bounds (None = no bounding).
+    bounder: Option<B>,
+    _phantom: PhantomData<fn() -> S>,
+}
+
+impl<S: PlanningSolution + 'static> DescriptorDecider<S, ()> {
+    /// Creates a decider for the single scalar variable matching the target.
+    ///
+    /// # Panics
+    /// Panics unless exactly one scalar planning variable with usize
+    /// accessors matches `entity_class` and `variable_name`.
+    pub fn new(
+        solution_descriptor: SolutionDescriptor,
+        entity_class: Option<&str>,
+        variable_name: Option<&str>,
+    ) -> Self {
+        let bindings = collect_bindings(&solution_descriptor);
+        let mut matched = find_binding(&bindings, entity_class, variable_name);
+        assert!(
+            matched.len() == 1,
+            "exhaustive search decider matched {} scalar planning variables for entity_class={:?} variable_name={:?}; expected exactly one",
+            matched.len(),
+            entity_class,
+            variable_name,
+        );
+        Self {
+            binding: matched.remove(0),
+            solution_descriptor,
+            bounder: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, B> DescriptorDecider<S, B> {
+    pub fn with_bounder<B2>(self, bounder: B2) -> DescriptorDecider<S, B2> {
+        DescriptorDecider {
+            binding: self.binding,
+            solution_descriptor: self.solution_descriptor,
+            bounder: Some(bounder),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn descriptor_index(&self) -> usize {
+        self.binding.descriptor_index
+    }
+
+    pub fn variable_name(&self) -> &'static str {
+        self.binding.variable_name
+    }
+
+    pub fn allows_unassigned(&self) -> bool {
+        self.binding.allows_unassigned
+    }
+}
+
+impl<S: PlanningSolution + 'static, B> DescriptorDecider<S, B> {
+    /// Candidate values for one entity, in expansion order.
+    pub fn candidate_values(&self, solution: &S, entity_index: usize) -> Vec<Option<usize>> {
+        let mut candidates: Vec<Option<usize>> = self
+            .binding
+            .values_for_entity_index(
+                &self.solution_descriptor,
+                solution as &dyn Any,
+                entity_index,
+            )
+            .into_iter()
+            .map(Some)
+            .collect();
+        if self.binding.allows_unassigned {
+            candidates.push(None);
+        }
+        candidates
+    }
+
+    fn assign<D: Director<S>>(
+        &self,
+        score_director: &mut D,
+        entity_index: usize,
+        value: Option<usize>,
+    ) {
+        let descriptor_index = self.binding.descriptor_index;
+        score_director.before_variable_changed(descriptor_index, entity_index);
+        let entity = self
+            .solution_descriptor
+            .get_entity_mut(
+                score_director.working_solution_mut() as &mut dyn Any,
+                descriptor_index,
+                entity_index,
+            )
+            .expect("entity lookup failed for descriptor decider");
+        (self.binding.setter)(entity, value);
+        score_director.after_variable_changed(descriptor_index, entity_index);
+    }
+}
+
+impl<S, B> Debug for DescriptorDecider<S, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DescriptorDecider")
+            .field("descriptor_index", &self.binding.descriptor_index)
+            .field("entity_type_name", &self.binding.entity_type_name)
+            .field("variable_name", &self.binding.variable_name)
+            .field("allows_unassigned", &self.binding.allows_unassigned)
+            .finish()
+    }
+}
+
+impl<S, B, D> ExhaustiveSearchDecider<S, D> for DescriptorDecider<S, B>
+where
+    S: PlanningSolution + 'static,
+    B: ScoreBounder<S, D>,
+    D: Director<S>,
+{
+    fn expand(
+        &self,
+        parent_index: usize,
+        parent: &ExhaustiveSearchNode<S>,
+        score_director: &mut D,
+    ) -> Vec<ExhaustiveSearchNode<S>> {
+        let entity_index = parent.depth();
+        let new_depth = parent.depth() + 1;
+
+        if entity_index >= self.total_entities(score_director) {
+            return Vec::new();
+        }
+
+        let candidates = self.candidate_values(score_director.working_solution(), entity_index);
+        let mut children = Vec::with_capacity(candidates.len());
+
+        for (value_index, value) in candidates.into_iter().enumerate() {
+            self.assign(score_director, entity_index, value);
+            let score = score_director.calculate_score();
+
+            let mut child = ExhaustiveSearchNode::child(
+                parent_index,
+                new_depth,
+                score,
+                self.binding.descriptor_index,
+                self.binding.variable_index,
+                entity_index,
+                value_index,
+            );
+
+            if let Some(ref bounder) = self.bounder {
+                if let Some(bound) = bounder.calculate_optimistic_bound(score_director) {
+                    child.set_optimistic_bound(bound);
+                }
+            }
+
+            children.push(child);
+
+            self.assign(score_director, entity_index, None);
+        }
+
+        children
+    }
+
+    fn reset_assignments(&self, score_director: &mut D) {
+        for entity_index in 0..self.total_entities(score_director) {
+            self.assign(score_director, entity_index, None);
+        }
+    }
+
+    fn apply_assignment(&self, node: &ExhaustiveSearchNode<S>, score_director: &mut D) {
+        let Some(descriptor_index) = node.descriptor_index() else {
+            return;
+        };
+        let Some(variable_index) = node.variable_index() else {
+            return;
+        };
+        let Some(entity_index) = node.entity_index() else {
+            return;
+        };
+        let Some(candidate_value_index) = node.candidate_value_index() else {
+            return;
+        };
+
+        assert_eq!(descriptor_index, self.binding.descriptor_index);
+        assert_eq!(variable_index, self.binding.variable_index);
+
+        let value = self
+            .candidate_values(score_director.working_solution(), entity_index)
+            .get(candidate_value_index)
+            .copied()
+            .unwrap_or_else(|| {
+                panic!("candidate value index {candidate_value_index} is out of range")
+            });
+
+        self.assign(score_director, entity_index, value);
+    }
+
+    fn total_entities(&self, score_director: &D) -> usize {
+        score_director
+            .entity_count(self.binding.descriptor_index)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+#[path = "descriptor_decider_tests.rs"]
+mod tests;
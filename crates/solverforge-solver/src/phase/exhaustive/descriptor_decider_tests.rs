@@ -0,0 +1,227 @@
+use super::*;
+use crate::phase::exhaustive::{ExhaustiveSearchConfig, ExhaustiveSearchPhase, FixedOffsetBounder};
+use crate::phase::Phase;
+use crate::scope::SolverScope;
+use solverforge_core::domain::{
+    EntityCollectionExtractor, EntityDescriptor, ProblemFactDescriptor, VariableDescriptor,
+};
+use solverforge_core::score::SoftScore;
+use solverforge_scoring::ConstraintMetadata;
+use std::any::TypeId;
+
+#[derive(Clone, Debug)]
+struct Queen {
+    column: usize,
+    row: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Board {
+    queens: Vec<Queen>,
+    rows: Vec<usize>,
+    score: Option<SoftScore>,
+}
+
+impl Board {
+    fn empty(n: usize) -> Self {
+        Self {
+            queens: (0..n).map(|column| Queen { column, row: None }).collect(),
+            rows: (0..n).collect(),
+            score: None,
+        }
+    }
+
+    // Unassigned queens never conflict, so a partial score bounds every completion.
+    fn conflicts(&self) -> i64 {
+        let mut conflicts = 0;
+        for (i, left) in self.queens.iter().enumerate() {
+            for right in &self.queens[i + 1..] {
+                let (Some(left_row), Some(right_row)) = (left.row, right.row) else {
+                    continue;
+                };
+                if left_row == right_row
+                    || left_row.abs_diff(right_row) == right.column - left.column
+                {
+                    conflicts += 1;
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+impl PlanningSolution for Board {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+fn get_row(entity: &dyn Any) -> Option<usize> {
+    entity.downcast_ref::<Queen>().and_then(|queen| queen.row)
+}
+
+fn set_row(entity: &mut dyn Any, row: Option<usize>) {
+    if let Some(queen) = entity.downcast_mut::<Queen>() {
+        queen.row = row;
+    }
+}
+
+fn board_descriptor(allows_unassigned: bool) -> SolutionDescriptor {
+    SolutionDescriptor::new("Board", TypeId::of::<Board>())
+        .with_entity(
+            EntityDescriptor::new("Queen", TypeId::of::<Queen>(), "queens")
+                .with_extractor(Box::new(EntityCollectionExtractor::new(
+                    "Queen",
+                    "queens",
+                    |s: &Board| &s.queens,
+                    |s: &mut Board| &mut s.queens,
+                )))
+                .with_variable(
+                    VariableDescriptor::genuine("row")
+                        .with_allows_unassigned(allows_unassigned)
+                        .with_value_range("rows")
+                        .with_usize_accessors(get_row, set_row),
+                ),
+        )
+        .with_problem_fact(
+            ProblemFactDescriptor::new("Row", TypeId::of::<usize>(), "rows").with_extractor(
+                Box::new(EntityCollectionExtractor::new(
+                    "Row",
+                    "rows",
+                    |s: &Board| &s.rows,
+                    |s: &mut Board| &mut s.rows,
+                )),
+            ),
+        )
+}
+
+#[derive(Clone, Debug)]
+struct BoardDirector {
+    solution: Board,
+    descriptor: SolutionDescriptor,
+}
+
+impl BoardDirector {
+    fn new(n: usize, descriptor: SolutionDescriptor) -> Self {
+        Self {
+            solution: Board::empty(n),
+            descriptor,
+        }
+    }
+}
+
+impl Director<Board> for BoardDirector {
+    fn working_solution(&self) -> &Board {
+        &self.solution
+    }
+
+    fn working_solution_mut(&mut self) -> &mut Board {
+        &mut self.solution
+    }
+
+    fn calculate_score(&mut self) -> SoftScore {
+        let score = SoftScore::of(-self.solution.conflicts());
+        self.solution.set_score(Some(score));
+        score
+    }
+
+    fn solution_descriptor(&self) -> &SolutionDescriptor {
+        &self.descriptor
+    }
+
+    fn clone_working_solution(&self) -> Board {
+        self.solution.clone()
+    }
+
+    fn before_variable_changed(&mut self, _descriptor_index: usize, _entity_index: usize) {}
+
+    fn after_variable_changed(&mut self, _descriptor_index: usize, _entity_index: usize) {}
+
+    fn entity_count(&self, _descriptor_index: usize) -> Option<usize> {
+        Some(self.solution.queens.len())
+    }
+
+    fn total_entity_count(&self) -> Option<usize> {
+        Some(self.solution.queens.len())
+    }
+
+    fn constraint_metadata(&self) -> Vec<ConstraintMetadata<'_>> {
+        Vec::new()
+    }
+}
+
+fn queen_row_config() -> ExhaustiveSearchConfig {
+    ExhaustiveSearchConfig {
+        entity_class: Some("Queen".to_string()),
+        variable_name: Some("row".to_string()),
+        ..ExhaustiveSearchConfig::default()
+    }
+}
+
+fn assert_solved_four_queens(solver_scope: &SolverScope<'_, Board, BoardDirector>) {
+    let best = solver_scope
+        .best_solution()
+        .expect("exhaustive search should publish a leaf solution");
+    assert_eq!(best.score, Some(SoftScore::of(0)));
+    assert!(best.queens.iter().all(|queen| queen.row.is_some()));
+    assert_eq!(best.conflicts(), 0);
+}
+
+#[test]
+fn branch_and_bound_over_descriptor_range_solves_four_queens() {
+    let descriptor = board_descriptor(false);
+    let decider = DescriptorDecider::new(descriptor.clone(), Some("Queen"), Some("row"))
+        .with_bounder(FixedOffsetBounder::<Board>::new(SoftScore::of(0)));
+    let mut bounded = ExhaustiveSearchPhase::new(decider, queen_row_config());
+    let mut bounded_scope = SolverScope::new(BoardDirector::new(4, descriptor.clone()));
+
+    bounded.solve(&mut bounded_scope);
+
+    assert_solved_four_queens(&bounded_scope);
+
+    let mut unbounded =
+        ExhaustiveSearchPhase::from_descriptor(descriptor.clone(), queen_row_config());
+    let mut unbounded_scope = SolverScope::new(BoardDirector::new(4, descriptor));
+
+    unbounded.solve(&mut unbounded_scope);
+
+    assert_solved_four_queens(&unbounded_scope);
+    assert!(bounded_scope.total_step_count() < unbounded_scope.total_step_count());
+}
+
+#[test]
+fn nullable_variable_adds_unassigned_candidate() {
+    let descriptor = board_descriptor(true);
+    let decider: DescriptorDecider<Board> =
+        DescriptorDecider::new(descriptor.clone(), None, Some("row"));
+    let mut director = BoardDirector::new(3, descriptor);
+    let root = ExhaustiveSearchNode::root(SoftScore::of(0));
+
+    assert!(decider.allows_unassigned());
+    assert_eq!(
+        decider.candidate_values(director.working_solution(), 0),
+        vec![Some(0), Some(1), Some(2), None]
+    );
+
+    let children = decider.expand(0, &root, &mut director);
+    assert_eq!(children.len(), 4);
+    assert!(director.working_solution().queens[0].row.is_none());
+
+    decider.apply_assignment(&children[3], &mut director);
+    assert_eq!(director.working_solution().queens[0].row, None);
+    decider.apply_assignment(&children[1], &mut director);
+    assert_eq!(director.working_solution().queens[0].row, Some(1));
+}
+
+#[test]
+#[should_panic(expected = "matched 0 scalar planning variables")]
+fn unknown_variable_target_panics() {
+    let _: DescriptorDecider<Board> =
+        DescriptorDecider::new(board_descriptor(false), Some("Queen"), Some("column"));
+}
@@ -15,6 +15,7 @@ best solution found so far.
 mod bounder;
 mod config;
 mod decider;
+mod descriptor_decider;
 mod exploration_type;
 mod node;
 mod phase;
@@ -23,6 +24,7 @@ mod priority_node;
 pub use bounder::{BounderType, FixedOffsetBounder, ScoreBounder, SoftScoreBounder};
 pub use config::ExhaustiveSearchConfig;
 pub use decider::{ExhaustiveSearchDecider, SimpleDecider};
+pub use descriptor_decider::DescriptorDecider;
 pub use exploration_type::ExplorationType;
 pub use node::ExhaustiveSearchNode;
 pub use phase::ExhaustiveSearchPhase;
@@ -3,15 +3,17 @@
 use std::collections::BinaryHeap;
 use std::fmt::Debug;
 
-use solverforge_core::domain::PlanningSolution;
+use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_scoring::Director;
 
 use crate::phase::Phase;
 use crate::scope::ProgressCallback;
 use crate::scope::{PhaseScope, SolverScope};
 
+use super::bounder::ScoreBounder;
 use super::config::ExhaustiveSearchConfig;
 use super::decider::ExhaustiveSearchDecider;
+use super::descriptor_decider::DescriptorDecider;
 use super::exploration_type::ExplorationType;
 use super::node::ExhaustiveSearchNode;
 use super::priority_node::PriorityNode;
@@ -52,25 +54,32 @@ use super::priority_node::PriorityNode;
 /// let decider = SimpleDecider::<MySolution, i32>::new(0, "value", vec![1, 2, 3], set_value);
 /// let phase = ExhaustiveSearchPhase::depth_first(decider);
 /// ```
-pub struct ExhaustiveSearchPhase<Dec> {
+pub struct ExhaustiveSearchPhase<Dec, B = ()> {
     // The decider that generates child nodes.
     decider: Dec,
     // Configuration for this phase.
     config: ExhaustiveSearchConfig,
+    // Bounder for children the decider left without an optimistic bound.
+    bounder: Option<B>,
 }
 
-impl<Dec: Debug> Debug for ExhaustiveSearchPhase<Dec> {
+impl<Dec: Debug, B: Debug> Debug for ExhaustiveSearchPhase<Dec, B> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ExhaustiveSearchPhase")
             .field("decider", &self.decider)
             .field("config", &self.config)
+            .field("bounder", &self.bounder)
             .finish()
     }
 }
 
 impl<Dec> ExhaustiveSearchPhase<Dec> {
     pub fn new(decider: Dec, config: ExhaustiveSearchConfig) -> Self {
-        Self { decider, config }
+        Self {
+            decider,
+            config,
+            bounder: None,
+        }
     }
 
     pub fn depth_first(decider: Dec) -> Self {
@@ -93,6 +102,18 @@ impl<Dec> ExhaustiveSearchPhase<Dec> {
         )
     }
 
+    /// Breadth-first search that keeps only the `beam_width` best nodes per level.
+    pub fn beam_search(decider: Dec, beam_width: usize) -> Self {
+        Self::new(
+            decider,
+            ExhaustiveSearchConfig {
+                exploration_type: ExplorationType::BreadthFirst,
+                beam_width: Some(beam_width),
+                ..Default::default()
+            },
+        )
+    }
+
     pub fn score_first(decider: Dec) -> Self {
         Self::new(
             decider,
@@ -102,6 +123,39 @@ impl<Dec> ExhaustiveSearchPhase<Dec> {
             },
         )
     }
+}
+
+impl<S: PlanningSolution + 'static> ExhaustiveSearchPhase<DescriptorDecider<S>> {
+    /// Creates a phase whose decider enumerates the value range of the
+    /// variable named by `config.entity_class` and `config.variable_name`.
+    ///
+    /// # Panics
+    /// Panics unless the target matches exactly one scalar planning variable.
+    pub fn from_descriptor(
+        solution_descriptor: SolutionDescriptor,
+        config: ExhaustiveSearchConfig,
+    ) -> Self {
+        let decider = DescriptorDecider::new(
+            solution_descriptor,
+            config.entity_class.as_deref(),
+            config.variable_name.as_deref(),
+        );
+        Self::new(decider, config)
+    }
+}
+
+impl<Dec, B> ExhaustiveSearchPhase<Dec, B> {
+    /* Attaches a bounder that sets the optimistic bound of every expanded
+    child the decider left unbounded. `OptimisticBoundFirst` ordering and
+    pruning both read these bounds.
+    */
+    pub fn with_bounder<B2>(self, bounder: B2) -> ExhaustiveSearchPhase<Dec, B2> {
+        ExhaustiveSearchPhase {
+            decider: self.decider,
+            config: self.config,
+            bounder: Some(bounder),
+        }
+    }
 
     pub fn phase_type_name(&self) -> &'static str {
         "ExhaustiveSearch"
@@ -124,14 +178,38 @@ impl<Dec> ExhaustiveSearchPhase<Dec> {
             self.decider.apply_assignment(assignment, score_director);
         }
     }
+
+    // Replays each unbounded child's assignment onto its parent path and bounds it.
+    fn bound_children<S, D>(&self, children: &mut [ExhaustiveSearchNode<S>], score_director: &mut D)
+    where
+        S: PlanningSolution,
+        D: Director<S>,
+        Dec: ExhaustiveSearchDecider<S, D>,
+        B: ScoreBounder<S, D>,
+    {
+        let Some(bounder) = &self.bounder else {
+            return;
+        };
+        for child in children {
+            if child.optimistic_bound().is_some() {
+                continue;
+            }
+            self.decider.apply_assignment(child, score_director);
+            score_director.calculate_score();
+            if let Some(bound) = bounder.calculate_optimistic_bound(score_director) {
+                child.set_optimistic_bound(bound);
+            }
+        }
+    }
 }
 
-impl<S, D, BestCb, Dec> Phase<S, D, BestCb> for ExhaustiveSearchPhase<Dec>
+impl<S, D, BestCb, Dec, B> Phase<S, D, BestCb> for ExhaustiveSearchPhase<Dec, B>
 where
     S: PlanningSolution,
     D: Director<S>,
     BestCb: ProgressCallback<S>,
     Dec: ExhaustiveSearchDecider<S, D>,
+    B: ScoreBounder<S, D>,
 {
     fn solve(&mut self, solver_scope: &mut SolverScope<S, D, BestCb>) {
         let mut phase_scope = PhaseScope::with_phase_type(solver_scope, 0, "ExhaustiveSearch");
@@ -164,7 +242,24 @@ where
         // Node storage
         let mut all_nodes: Vec<ExhaustiveSearchNode<S>> = Vec::new();
 
-        while let Some(priority_node) = frontier.pop() {
+        // Beam search keeps only the best nodes of each breadth-first level.
+        let beam_width = match self.config.exploration_type {
+            ExplorationType::BreadthFirst => self.config.beam_width,
+            _ => None,
+        };
+        let mut beam_depth = 0;
+
+        loop {
+            if let Some(width) = beam_width {
+                let next_depth = frontier.peek().map(|next| next.node.depth());
+                if next_depth.is_some_and(|depth| depth > beam_depth) {
+                    beam_depth = next_depth.unwrap_or(beam_depth);
+                    bounded |= retain_beam(&mut frontier, width);
+                }
+            }
+            let Some(priority_node) = frontier.pop() else {
+                break;
+            };
             if phase_scope.solver_scope_mut().should_terminate() {
                 return;
             }
@@ -221,9 +316,10 @@ where
             }
 
             // Expand node to generate children
-            let children = self
-                .decider
-                .expand(node_index, &node, phase_scope.score_director_mut());
+            let mut children =
+                self.decider
+                    .expand(node_index, &node, phase_scope.score_director_mut());
+            self.bound_children(&mut children, phase_scope.score_director_mut());
 
             // Store current node
             all_nodes.push(node);
@@ -258,6 +354,29 @@ where
     }
 }
 
+/* Trims a breadth-first frontier holding one complete level to its
+`width` best-scoring nodes. Returns whether any node was discarded.
+*/
+fn retain_beam<S: PlanningSolution>(
+    frontier: &mut BinaryHeap<PriorityNode<S>>,
+    width: usize,
+) -> bool {
+    if frontier.len() <= width {
+        return false;
+    }
+    let mut level = std::mem::take(frontier).into_vec();
+    level.sort_by(|left, right| {
+        right
+            .node
+            .score()
+            .cmp(left.node.score())
+            .then(left.index.cmp(&right.index))
+    });
+    level.truncate(width);
+    *frontier = level.into_iter().collect();
+    true
+}
+
 #[cfg(test)]
 #[path = "phase_tests.rs"]
 mod tests;
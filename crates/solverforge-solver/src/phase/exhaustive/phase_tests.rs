@@ -1,7 +1,7 @@
 use super::*;
 use crate::manager::SolverTerminalReason;
 use crate::phase::exhaustive::decider::SimpleDecider;
-use crate::phase::exhaustive::ExplorationType;
+use crate::phase::exhaustive::{ExplorationType, FixedOffsetBounder};
 use crate::phase::Phase;
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::score::SoftScore;
@@ -37,6 +37,7 @@ fn set_row(s: &mut TestSolution, idx: usize, v: Option<i32>) {
 struct ExhaustiveTestDirector {
     solution: TestSolution,
     descriptor: SolutionDescriptor,
+    unassigned_penalty: i64,
 }
 
 impl ExhaustiveTestDirector {
@@ -47,6 +48,15 @@ impl ExhaustiveTestDirector {
                 score: None,
             },
             descriptor: SolutionDescriptor::new("TestSolution", TypeId::of::<TestSolution>()),
+            unassigned_penalty: 100,
+        }
+    }
+
+    // Unassigned values cost nothing, so a partial score bounds every completion.
+    fn monotone(values: Vec<Option<i32>>) -> Self {
+        Self {
+            unassigned_penalty: 0,
+            ..Self::new(values)
         }
     }
 }
@@ -64,7 +74,9 @@ impl Director<TestSolution> for ExhaustiveTestDirector {
         let mut total = 0;
         for (index, value) in self.solution.values.iter().enumerate() {
             let target = index as i32 + 2;
-            total -= value.map_or(100, |actual| (actual - target).abs() as i64);
+            total -= value.map_or(self.unassigned_penalty, |actual| {
+                (actual - target).abs() as i64
+            });
         }
         let score = SoftScore::of(total);
         self.solution.set_score(Some(score));
@@ -277,3 +289,116 @@ fn solve_reports_completed_when_frontier_is_exhausted() {
         SolverTerminalReason::Completed
     );
 }
+
+#[test]
+fn solve_returns_immediately_for_empty_solution() {
+    let decider: SimpleDecider<TestSolution, i32> =
+        SimpleDecider::new(0, "row", vec![1, 2, 3], set_row);
+    let mut phase = ExhaustiveSearchPhase::depth_first(decider);
+    let director = ExhaustiveTestDirector::new(Vec::new());
+    let mut solver_scope = SolverScope::new(director);
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.total_step_count(), 0);
+    assert!(solver_scope.working_solution().values.is_empty());
+    assert!(solver_scope.best_solution().is_none());
+}
+
+#[test]
+fn beam_search_bounds_breadth_first_frontier_per_level() {
+    let values = vec![1, 2, 3, 4, 5];
+    let mut full = ExhaustiveSearchPhase::breadth_first(SimpleDecider::<TestSolution, i32>::new(
+        0,
+        "row",
+        values.clone(),
+        set_row,
+    ));
+    let mut full_scope = SolverScope::new(ExhaustiveTestDirector::new(vec![None; 4]));
+    full.solve(&mut full_scope);
+
+    let mut beam = ExhaustiveSearchPhase::beam_search(
+        SimpleDecider::<TestSolution, i32>::new(0, "row", values, set_row),
+        2,
+    );
+    let mut beam_scope = SolverScope::new(ExhaustiveTestDirector::new(vec![None; 4]));
+    beam.solve(&mut beam_scope);
+
+    // Root plus at most two nodes for each of the four levels.
+    assert_eq!(beam_scope.total_step_count(), 9);
+    assert!(full_scope.total_step_count() > 100);
+    let best = beam_scope
+        .best_solution()
+        .expect("beam search should still reach a leaf");
+    assert_eq!(best.values, vec![Some(2), Some(3), Some(4), Some(5)]);
+    assert_eq!(
+        beam_scope.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+}
+
+#[test]
+fn beam_width_is_ignored_outside_breadth_first() {
+    let mut phase = ExhaustiveSearchPhase::new(
+        SimpleDecider::<TestSolution, i32>::new(0, "row", vec![1, 2, 3], set_row),
+        ExhaustiveSearchConfig {
+            exploration_type: ExplorationType::DepthFirst,
+            beam_width: Some(1),
+            enable_pruning: false,
+            ..ExhaustiveSearchConfig::default()
+        },
+    );
+    let mut solver_scope = SolverScope::new(ExhaustiveTestDirector::new(vec![None, None]));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.total_step_count(), 13);
+}
+
+fn explored_nodes_with_current_score_bound(exploration_type: ExplorationType) -> u64 {
+    let mut phase = ExhaustiveSearchPhase::new(
+        SimpleDecider::<TestSolution, i32>::new(0, "row", vec![1, 2, 3, 4, 5], set_row),
+        ExhaustiveSearchConfig {
+            exploration_type,
+            ..ExhaustiveSearchConfig::default()
+        },
+    )
+    .with_bounder(FixedOffsetBounder::<TestSolution>::new(SoftScore::of(0)));
+    let mut solver_scope = SolverScope::new(ExhaustiveTestDirector::monotone(vec![None; 3]));
+
+    phase.solve(&mut solver_scope);
+
+    let best = solver_scope
+        .best_solution()
+        .expect("bounded search should reach a leaf");
+    assert_eq!(best.values, vec![Some(2), Some(3), Some(4)]);
+    solver_scope.total_step_count()
+}
+
+#[test]
+fn phase_bounder_lets_optimistic_bound_first_beat_depth_first() {
+    let best_first = explored_nodes_with_current_score_bound(ExplorationType::OptimisticBoundFirst);
+    let depth_first = explored_nodes_with_current_score_bound(ExplorationType::DepthFirst);
+
+    // Root plus the optimal child at each level; every other bound is worse.
+    assert_eq!(best_first, 4);
+    assert!(best_first < depth_first, "{best_first} vs {depth_first}");
+}
+
+#[test]
+fn optimistic_bound_first_without_bounder_explores_depth_first() {
+    let mut phase = ExhaustiveSearchPhase::new(
+        SimpleDecider::<TestSolution, i32>::new(0, "row", vec![1, 2, 3], set_row),
+        ExhaustiveSearchConfig {
+            exploration_type: ExplorationType::OptimisticBoundFirst,
+            node_limit: Some(3),
+            ..ExhaustiveSearchConfig::default()
+        },
+    );
+    let mut solver_scope = SolverScope::new(ExhaustiveTestDirector::new(vec![None, None]));
+
+    phase.solve(&mut solver_scope);
+
+    // Root, one child, then one of its leaves rather than a sibling child.
+    assert!(solver_scope.best_solution().is_some());
+}
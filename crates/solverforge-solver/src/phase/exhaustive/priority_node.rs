@@ -58,7 +58,8 @@ impl<S: PlanningSolution> Ord for PriorityNode<S> {
                     (Some(a), Some(b)) => a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal),
                     (Some(_), None) => std::cmp::Ordering::Greater,
                     (None, Some(_)) => std::cmp::Ordering::Less,
-                    (None, None) => std::cmp::Ordering::Equal,
+                    // Without bounds, explore depth-first.
+                    (None, None) => self.node.depth().cmp(&other.node.depth()),
                 }
             }
         }
@@ -8,6 +8,13 @@ use solverforge_core::score::Score;
 use super::Acceptor;
 use crate::heuristic::r#move::MoveTabuSignature;
 
+// Auto-mode rain-speed adjustment: grow while the water keeps moving the same
+// way, shrink when it reverses.
+const AUTO_RAIN_SPEED_GROWTH: f64 = 1.25;
+const AUTO_RAIN_SPEED_SHRINK: f64 = 0.5;
+const AUTO_MIN_RAIN_SPEED: f64 = 1.0e-6;
+const AUTO_MAX_RAIN_SPEED: f64 = 0.1;
+
 /// Great Deluge acceptor - accepts moves above a rising water level.
 ///
 /// The water level starts at the initial solution's score and rises over time.
@@ -37,20 +44,48 @@ use crate::heuristic::r#move::MoveTabuSignature;
 ///
 /// // Rain speed of 0.001 means water level rises by 0.1% of |initial| per step
 /// let acceptor = GreatDelugeAcceptor::<MySolution>::new(0.001);
+///
+/// // Auto mode tunes the rain speed so ~20% of non-improving moves pass
+/// let tuned = GreatDelugeAcceptor::<MySolution>::auto(0.001, 0.2);
 /// ```
+///
+/// # Auto mode
+///
+/// In auto mode the water level follows the fraction of non-improving moves
+/// that passed it each step: above the target acceptance rate the water rises
+/// by `rain_speed * |initial_score|`, below it the water recedes by the same
+/// amount. The rain speed grows while the water keeps moving in one direction
+/// and shrinks when it reverses, so the level settles where the target rate
+/// is met.
 pub struct GreatDelugeAcceptor<S: PlanningSolution> {
     // Rain speed - ratio of |initial_score| to add per step.
     rain_speed: f64,
+    // Rain speed restored at phase start in auto mode.
+    initial_rain_speed: f64,
     // Current water level.
     water_level: Option<S::Score>,
     // Absolute value of initial score, used to compute increment.
     initial_abs_score: Option<S::Score>,
+    // Acceptance-rate feedback when auto mode is enabled.
+    auto_tuning: Option<AutoTuning>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AutoTuning {
+    target_acceptance_rate: f64,
+    considered: u64,
+    accepted: u64,
+    rising: Option<bool>,
 }
 
 impl<S: PlanningSolution> Debug for GreatDelugeAcceptor<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GreatDelugeAcceptor")
             .field("rain_speed", &self.rain_speed)
+            .field(
+                "target_acceptance_rate",
+                &self.auto_tuning.map(|tuning| tuning.target_acceptance_rate),
+            )
             .finish()
     }
 }
@@ -59,8 +94,10 @@ impl<S: PlanningSolution> Clone for GreatDelugeAcceptor<S> {
     fn clone(&self) -> Self {
         Self {
             rain_speed: self.rain_speed,
+            initial_rain_speed: self.initial_rain_speed,
             water_level: self.water_level,
             initial_abs_score: self.initial_abs_score,
+            auto_tuning: self.auto_tuning,
         }
     }
 }
@@ -74,10 +111,42 @@ impl<S: PlanningSolution> GreatDelugeAcceptor<S> {
     pub fn new(rain_speed: f64) -> Self {
         Self {
             rain_speed,
+            initial_rain_speed: rain_speed,
             water_level: None,
             initial_abs_score: None,
+            auto_tuning: None,
+        }
+    }
+
+    /// Creates a Great Deluge acceptor that tunes its rain speed online.
+    ///
+    /// # Arguments
+    /// * `initial_rain_speed` - Rain speed for the first step of each phase.
+    /// * `target_acceptance_rate` - Fraction of non-improving moves to accept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_acceptance_rate` is not in (0, 1).
+    pub fn auto(initial_rain_speed: f64, target_acceptance_rate: f64) -> Self {
+        assert!(
+            target_acceptance_rate > 0.0 && target_acceptance_rate < 1.0,
+            "great_deluge target_acceptance_rate must be in (0, 1)"
+        );
+        Self {
+            auto_tuning: Some(AutoTuning {
+                target_acceptance_rate,
+                considered: 0,
+                accepted: 0,
+                rising: None,
+            }),
+            ..Self::new(initial_rain_speed)
         }
     }
+
+    /// Returns the current rain speed.
+    pub fn rain_speed(&self) -> f64 {
+        self.rain_speed
+    }
 }
 
 impl<S: PlanningSolution> Default for GreatDelugeAcceptor<S> {
@@ -99,15 +168,31 @@ impl<S: PlanningSolution> Acceptor<S> for GreatDelugeAcceptor<S> {
         }
 
         // Accept if at or above water level
-        match &self.water_level {
+        let accepted = match &self.water_level {
             Some(water_level) => move_score >= water_level,
             None => true, // No water level yet, accept
+        };
+        if let Some(tuning) = &mut self.auto_tuning {
+            tuning.considered += 1;
+            tuning.accepted += u64::from(accepted);
         }
+        accepted
     }
 
     fn phase_started(&mut self, initial_score: &S::Score) {
         self.water_level = Some(*initial_score);
         self.initial_abs_score = Some(initial_score.abs());
+        if let Some(tuning) = &mut self.auto_tuning {
+            self.rain_speed = self.initial_rain_speed;
+            tuning.rising = None;
+        }
+    }
+
+    fn step_started(&mut self) {
+        if let Some(tuning) = &mut self.auto_tuning {
+            tuning.considered = 0;
+            tuning.accepted = 0;
+        }
     }
 
     fn step_ended(
@@ -115,11 +200,38 @@ impl<S: PlanningSolution> Acceptor<S> for GreatDelugeAcceptor<S> {
         _step_score: &S::Score,
         _accepted_move_signature: Option<&MoveTabuSignature>,
     ) {
-        // Raise water level by rain_speed * |initial_score|
-        if let (Some(water), Some(abs_score)) = (&self.water_level, &self.initial_abs_score) {
-            let increment = abs_score.multiply(self.rain_speed);
-            self.water_level = Some(*water + increment);
+        let (Some(water), Some(abs_score)) = (self.water_level, self.initial_abs_score) else {
+            return;
+        };
+        let Some(tuning) = &mut self.auto_tuning else {
+            // Raise water level by rain_speed * |initial_score|
+            self.water_level = Some(water + abs_score.multiply(self.rain_speed));
+            return;
+        };
+        if tuning.considered == 0 {
+            return;
+        }
+        let acceptance_rate = tuning.accepted as f64 / tuning.considered as f64;
+        if acceptance_rate == tuning.target_acceptance_rate {
+            return;
+        }
+        let rising = acceptance_rate > tuning.target_acceptance_rate;
+        if let Some(was_rising) = tuning.rising {
+            let factor = if was_rising == rising {
+                AUTO_RAIN_SPEED_GROWTH
+            } else {
+                AUTO_RAIN_SPEED_SHRINK
+            };
+            self.rain_speed =
+                (self.rain_speed * factor).clamp(AUTO_MIN_RAIN_SPEED, AUTO_MAX_RAIN_SPEED);
         }
+        tuning.rising = Some(rising);
+        let increment = abs_score.multiply(self.rain_speed);
+        self.water_level = Some(if rising {
+            water + increment
+        } else {
+            water - increment
+        });
     }
 
     fn phase_ended(&mut self) {
@@ -71,3 +71,54 @@ fn test_phase_reset() {
     assert!(acceptor.is_accepted(&SoftScore::of(-50), &SoftScore::of(-50), None));
     assert!(!acceptor.is_accepted(&SoftScore::of(-50), &SoftScore::of(-51), None));
 }
+
+// Observed acceptance over `steps` steps of a synthetic stream of moves that
+// worsen the score by a pseudo-random amount in 0..1000.
+fn run_auto_stream(acceptor: &mut GreatDelugeAcceptor<TestSolution>, steps: usize) -> f64 {
+    let current = SoftScore::of(-100_000);
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut considered = 0u32;
+    let mut accepted = 0u32;
+    for _ in 0..steps {
+        acceptor.step_started();
+        for _ in 0..50 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let worsening = ((state >> 33) % 1000) as i64;
+            let candidate = SoftScore::of(-100_000 - worsening);
+            considered += 1;
+            accepted += u32::from(acceptor.is_accepted(&current, &candidate, None));
+        }
+        acceptor.step_ended(&current, None);
+    }
+    f64::from(accepted) / f64::from(considered)
+}
+
+#[test]
+fn test_auto_mode_tracks_target_acceptance_rate() {
+    for target in [0.2, 0.5] {
+        let mut acceptor = GreatDelugeAcceptor::<TestSolution>::auto(0.001, target);
+        acceptor.phase_started(&SoftScore::of(-100_000));
+
+        run_auto_stream(&mut acceptor, 200);
+        let observed = run_auto_stream(&mut acceptor, 400);
+
+        assert!(
+            (observed - target).abs() < 0.05,
+            "observed acceptance {observed} should track target {target}"
+        );
+    }
+}
+
+#[test]
+fn test_auto_mode_restores_rain_speed_on_phase_start() {
+    let mut acceptor = GreatDelugeAcceptor::<TestSolution>::auto(0.001, 0.2);
+    acceptor.phase_started(&SoftScore::of(-100_000));
+    run_auto_stream(&mut acceptor, 20);
+    assert_ne!(acceptor.rain_speed(), 0.001);
+    acceptor.phase_ended();
+
+    acceptor.phase_started(&SoftScore::of(-100_000));
+    assert_eq!(acceptor.rain_speed(), 0.001);
+}
@@ -92,6 +92,11 @@ Improving or equal moves are accepted unconditionally. Worsening moves are
 classified by the first score level that differs, and the acceptance probability
 uses only that level's delta and temperature. Lower-priority levels never mask
 higher-priority regressions.
+
+By default temperatures decay geometrically by `decay_rate` per step. With an
+ending temperature, a phase that runs under a time or step limit instead cools
+linearly from the starting temperatures to the ending temperature as that
+budget is spent; without such a limit the per-step decay still applies.
 */
 #[derive(Debug, Clone)]
 pub struct SimulatedAnnealingAcceptor {
@@ -99,6 +104,8 @@ pub struct SimulatedAnnealingAcceptor {
     starting_temperatures: Vec<f64>,
     current_temperatures: Vec<f64>,
     decay_rate: f64,
+    ending_temperature: Option<f64>,
+    progress: Option<f64>,
     hill_climbing_temperature: f64,
     hard_regression_policy: HardRegressionPolicy,
     calibration_state: Option<CalibrationState>,
@@ -230,6 +237,8 @@ impl SimulatedAnnealingAcceptor {
             starting_temperatures: Vec::new(),
             current_temperatures: Vec::new(),
             decay_rate,
+            ending_temperature: None,
+            progress: None,
             hill_climbing_temperature,
             hard_regression_policy,
             calibration_state: None,
@@ -238,6 +247,31 @@ impl SimulatedAnnealingAcceptor {
         }
     }
 
+    /// Cools linearly to `ending_temperature` as the termination budget is spent.
+    ///
+    /// Solver-wide limits count from solve start, so a phase that begins late
+    /// in the budget starts part of the way down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ending_temperature` is negative or not finite.
+    pub fn with_ending_temperature(mut self, ending_temperature: f64) -> Self {
+        assert_temperature(ending_temperature, "simulated_annealing ending_temperature");
+        self.ending_temperature = Some(ending_temperature);
+        self
+    }
+
+    /// Returns the current temperature of the highest-priority score level.
+    ///
+    /// `None` before the first phase starts and while auto-calibration is
+    /// still sampling deltas.
+    pub fn current_temperature(&self) -> Option<f64> {
+        if self.calibration_state.is_some() {
+            return None;
+        }
+        self.current_temperatures.first().copied()
+    }
+
     #[cfg(test)]
     pub(crate) fn current_temperature_for_level(&self, level: usize) -> f64 {
         self.current_temperatures[level]
@@ -249,6 +283,17 @@ impl SimulatedAnnealingAcceptor {
         self.current_temperatures = temperatures;
     }
 
+    fn apply_progress_schedule(&mut self, ending_temperature: f64, progress: f64) {
+        for (current, &starting) in self
+            .current_temperatures
+            .iter_mut()
+            .zip(&self.starting_temperatures)
+        {
+            let scheduled = starting + (ending_temperature - starting) * progress;
+            *current = scheduled.max(self.hill_climbing_temperature);
+        }
+    }
+
     fn finalize_calibration_if_ready(&mut self) {
         let Some(state) = self.calibration_state.take() else {
             return;
@@ -377,6 +422,7 @@ where
     fn phase_started(&mut self, _initial_score: &S::Score) {
         self.level_count = S::Score::levels_count();
         self.calibration_state = None;
+        self.progress = None;
         match self.temperature_seed.clone() {
             TemperatureSeed::Single(temperature) => {
                 let temperatures = vec![temperature; self.level_count];
@@ -413,12 +459,23 @@ where
         }
     }
 
+    fn progress_updated(&mut self, progress: f64) {
+        let Some(ending_temperature) = self.ending_temperature else {
+            return;
+        };
+        let progress = progress.clamp(0.0, 1.0);
+        self.progress = Some(progress);
+        if self.calibration_state.is_none() {
+            self.apply_progress_schedule(ending_temperature, progress);
+        }
+    }
+
     fn step_ended(
         &mut self,
         _step_score: &S::Score,
         _accepted_move_signature: Option<&MoveTabuSignature>,
     ) {
-        if self.calibration_state.is_some() {
+        if self.calibration_state.is_some() || self.progress.is_some() {
             return;
         }
         for temperature in &mut self.current_temperatures {
@@ -428,6 +485,10 @@ where
             }
         }
     }
+
+    fn temperature(&self) -> Option<f64> {
+        self.current_temperature()
+    }
 }
 
 #[cfg(test)]
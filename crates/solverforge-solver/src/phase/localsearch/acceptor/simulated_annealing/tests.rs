@@ -271,3 +271,59 @@ fn seeded_auto_calibration_starts_with_same_temperatures() {
         second.current_temperature_for_level(1)
     );
 }
+
+#[test]
+fn current_temperature_is_hidden_until_calibration_completes() {
+    let mut fixed = SimulatedAnnealingAcceptor::with_seed(100.0, 0.5, 7);
+    assert_eq!(fixed.current_temperature(), None);
+    Acceptor::<SimpleSol>::phase_started(&mut fixed, &SoftScore::of(0));
+    assert_eq!(fixed.current_temperature(), Some(100.0));
+    Acceptor::<SimpleSol>::step_ended(&mut fixed, &SoftScore::of(0), None);
+    assert_eq!(Acceptor::<SimpleSol>::temperature(&fixed), Some(50.0));
+
+    let mut calibrated = SimulatedAnnealingAcceptor::auto_calibrate_with_seed(0.5, 7);
+    Acceptor::<SimpleSol>::phase_started(&mut calibrated, &SoftScore::of(0));
+    assert_eq!(calibrated.current_temperature(), None);
+}
+
+#[test]
+fn ending_temperature_lowers_acceptance_as_phase_progresses() {
+    let last = SoftScore::of(-10);
+    let worse = SoftScore::of(-12);
+    let acceptance_counts: Vec<usize> = (0..=10)
+        .map(|tenth| {
+            let mut acceptor =
+                SimulatedAnnealingAcceptor::with_seed(10.0, 1.0, 42).with_ending_temperature(0.0);
+            Acceptor::<SimpleSol>::phase_started(&mut acceptor, &SoftScore::of(0));
+            Acceptor::<SimpleSol>::progress_updated(&mut acceptor, f64::from(tenth) / 10.0);
+            (0..1000)
+                .filter(|_| Acceptor::<SimpleSol>::is_accepted(&mut acceptor, &last, &worse, None))
+                .count()
+        })
+        .collect();
+
+    assert!(acceptance_counts[0] > 700);
+    assert!(acceptance_counts
+        .windows(2)
+        .all(|window| window[1] <= window[0]));
+    assert_eq!(acceptance_counts[10], 0);
+}
+
+#[test]
+fn ending_temperature_follows_progress_instead_of_step_decay() {
+    let mut acceptor =
+        SimulatedAnnealingAcceptor::with_seed(100.0, 0.5, 42).with_ending_temperature(20.0);
+    Acceptor::<SimpleSol>::phase_started(&mut acceptor, &SoftScore::of(0));
+
+    Acceptor::<SimpleSol>::progress_updated(&mut acceptor, 0.25);
+    Acceptor::<SimpleSol>::step_ended(&mut acceptor, &SoftScore::of(0), None);
+    assert_eq!(acceptor.current_temperature(), Some(80.0));
+
+    Acceptor::<SimpleSol>::progress_updated(&mut acceptor, 1.0);
+    assert_eq!(acceptor.current_temperature(), Some(20.0));
+
+    // Without a termination budget the per-step decay still applies.
+    Acceptor::<SimpleSol>::phase_started(&mut acceptor, &SoftScore::of(0));
+    Acceptor::<SimpleSol>::step_ended(&mut acceptor, &SoftScore::of(0), None);
+    assert_eq!(acceptor.current_temperature(), Some(50.0));
+}
@@ -116,6 +116,20 @@ fn late_acceptance_uses_late_score_threshold() {
     assert!(!acceptor.is_accepted(&SoftScore::of(-10), &SoftScore::of(-15), None));
 }
 
+#[test]
+fn late_acceptance_restarted_phase_uses_fresh_history() {
+    let mut acceptor = LateAcceptanceAcceptor::<DummySolution>::new(3);
+    acceptor.phase_started(&SoftScore::of(-100));
+    acceptor.step_ended(&SoftScore::of(-90), None);
+    assert!(acceptor.is_accepted(&SoftScore::of(-90), &SoftScore::of(-95), None));
+    acceptor.phase_ended();
+
+    // A stale -100 late score would accept this regression.
+    acceptor.phase_started(&SoftScore::of(-10));
+    assert!(!acceptor.is_accepted(&SoftScore::of(-10), &SoftScore::of(-50), None));
+    assert!(acceptor.is_accepted(&SoftScore::of(-10), &SoftScore::of(-10), None));
+}
+
 #[test]
 fn tabu_search_blocks_recent_entities_and_allows_aspiration() {
     let mut acceptor =
@@ -30,6 +30,10 @@ pub trait Acceptor<S: PlanningSolution>: Send + Debug {
     // Called when a phase ends.
     fn phase_ended(&mut self) {}
 
+    // Called before a step with the fraction of the termination budget spent,
+    // when the phase runs under a time or step limit.
+    fn progress_updated(&mut self, _progress: f64) {}
+
     // Called when a step starts.
     fn step_started(&mut self) {}
 
@@ -40,4 +44,9 @@ pub trait Acceptor<S: PlanningSolution>: Send + Debug {
         _accepted_move_signature: Option<&MoveTabuSignature>,
     ) {
     }
+
+    // Returns the current annealing temperature for step telemetry, if any.
+    fn temperature(&self) -> Option<f64> {
+        None
+    }
 }
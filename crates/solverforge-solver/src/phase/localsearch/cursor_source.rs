@@ -45,6 +45,20 @@ where
         score_director: &D,
         context: MoveStreamContext,
     ) -> Self::Cursor<'a>;
+
+    /// Drops state derived from the previous working solution.
+    ///
+    /// Local search calls this at phase start and whenever it re-seeds its
+    /// acceptor after problem changes or a restart; VND calls it at phase
+    /// start. Sources that keep nothing between cursors use the default no-op.
+    fn phase_started(&mut self) {}
+
+    /// Drops state derived from a working solution that a committed move has
+    /// since changed.
+    ///
+    /// Local search calls this after each step that commits a move; VND calls
+    /// it on every neighborhood after each committed move.
+    fn solution_changed(&mut self) {}
 }
 
 /// Type-level adapter for ordinary selectors and their one existing cursor
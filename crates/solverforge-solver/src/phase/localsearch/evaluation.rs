@@ -1,5 +1,6 @@
 use std::time::Instant;
 
+use solverforge_config::EnvironmentMode;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_core::score::Score;
 use solverforge_scoring::Director;
@@ -49,10 +50,14 @@ where
         return CandidateEvaluation::NotDoable;
     }
 
+    let undo_assert = UndoAssert::capture(step_scope);
     let score_state = step_scope.score_director().snapshot_score_state();
     let undo = mov.do_move(step_scope.score_director_mut());
     let move_score = step_scope.score_director_mut().calculate_score();
     mov.undo_move(step_scope.score_director_mut(), undo);
+    if let Some(undo_assert) = undo_assert {
+        undo_assert.verify(step_scope, mov.telemetry_label());
+    }
     step_scope
         .score_director_mut()
         .restore_score_state(score_state);
@@ -114,6 +119,83 @@ where
     CandidateEvaluation::Scored(move_score)
 }
 
+/* Pre-move state for the undo self-checks.
+
+`capture` holds the environment-mode gates. `FastAssert` and `FullAssert`
+checksum the planning variables; a model whose descriptor cannot cover every
+variable yields no checksum and skips that half. `FullAssert` also
+recomputes the score from scratch (directors without `fresh_score()` skip
+the score half). `verify` runs after the undo and panics when either the
+planning state or the incremental score differs.
+*/
+struct UndoAssert<Sc> {
+    state_checksum: Option<u64>,
+    fresh_score: Option<Sc>,
+}
+
+impl<Sc: Score> UndoAssert<Sc> {
+    fn capture<S, D, ProgressCb>(
+        step_scope: &StepScope<'_, '_, '_, S, D, ProgressCb>,
+    ) -> Option<Self>
+    where
+        S: PlanningSolution<Score = Sc>,
+        D: Director<S>,
+        ProgressCb: ProgressCallback<S>,
+    {
+        let environment_mode = step_scope.phase_scope().solver_scope().environment_mode();
+        let full_assert = match environment_mode {
+            EnvironmentMode::FullAssert => true,
+            EnvironmentMode::FastAssert => false,
+            EnvironmentMode::NonReproducible | EnvironmentMode::Reproducible => return None,
+        };
+        let score_director = step_scope.score_director();
+        Some(Self {
+            state_checksum: planning_state_checksum(score_director),
+            fresh_score: if full_assert {
+                score_director.fresh_score()
+            } else {
+                None
+            },
+        })
+    }
+
+    fn verify<S, D, ProgressCb>(
+        self,
+        step_scope: &mut StepScope<'_, '_, '_, S, D, ProgressCb>,
+        move_label: &str,
+    ) where
+        S: PlanningSolution<Score = Sc>,
+        D: Director<S>,
+        ProgressCb: ProgressCallback<S>,
+    {
+        if let Some(pre_move_checksum) = self.state_checksum {
+            assert_eq!(
+                Some(pre_move_checksum),
+                planning_state_checksum(step_scope.score_director()),
+                "undo of {move_label} move did not restore the planning state"
+            );
+        }
+        if let Some(pre_move_score) = self.fresh_score {
+            let restored_score = step_scope.score_director_mut().calculate_score();
+            assert!(
+                restored_score == pre_move_score,
+                "undo of {move_label} move did not restore the score: restored score {restored_score:?} != pre-move score {pre_move_score:?} (diff {:?}); a change is likely missing its before/after_variable_changed notification",
+                restored_score - pre_move_score
+            );
+        }
+    }
+}
+
+fn planning_state_checksum<S, D>(score_director: &D) -> Option<u64>
+where
+    S: PlanningSolution,
+    D: Director<S>,
+{
+    score_director
+        .solution_descriptor()
+        .state_checksum(score_director.working_solution())
+}
+
 #[inline]
 pub(crate) fn record_evaluated_move<S, D, ProgressCb>(
     step_scope: &mut StepScope<'_, '_, '_, S, D, ProgressCb>,
@@ -9,6 +9,7 @@ use std::marker::PhantomData;
 
 use crate::heuristic::r#move::Move;
 use crate::heuristic::selector::move_selector::CandidateId;
+use solverforge_config::PickEarlyType;
 use solverforge_core::domain::PlanningSolution;
 
 /// Trait for collecting and selecting moves in local search.
@@ -164,6 +165,10 @@ pub use improving::{FirstBestScoreImprovingForager, FirstLastStepScoreImprovingF
 /// neighborhood scan. `AcceptedCountForager(1)` therefore behaves like first
 /// accepted selection, while larger limits select the best candidate among the
 /// first `N` accepted moves.
+///
+/// With a [`PickEarlyType`] other than `Never`, the step also ends on the
+/// first accepted move that improves the configured reference score, and that
+/// move is selected.
 pub struct AcceptedCountForager<S>
 where
     S: PlanningSolution,
@@ -171,6 +176,10 @@ where
     // Number of accepted moves to collect before ending the step.
     accepted_count_limit: usize,
     accepted_count: usize,
+    pick_early_type: PickEarlyType,
+    // Score an accepted move must beat to end the step early.
+    pick_early_score: Option<S::Score>,
+    picked_early: bool,
     best_move: BestCandidate<S>,
     _phantom: PhantomData<fn() -> S>,
 }
@@ -195,10 +204,19 @@ where
         Self {
             accepted_count_limit,
             accepted_count: 0,
+            pick_early_type: PickEarlyType::Never,
+            pick_early_score: None,
+            picked_early: false,
             best_move: BestCandidate::new(random_ties),
             _phantom: PhantomData,
         }
     }
+
+    /// Ends the step on the first accepted move improving the chosen score.
+    pub fn with_pick_early_type(mut self, pick_early_type: PickEarlyType) -> Self {
+        self.pick_early_type = pick_early_type;
+        self
+    }
 }
 
 impl<S> Clone for AcceptedCountForager<S>
@@ -209,6 +227,9 @@ where
         Self {
             accepted_count_limit: self.accepted_count_limit,
             accepted_count: 0,
+            pick_early_type: self.pick_early_type,
+            pick_early_score: None,
+            picked_early: false,
             best_move: BestCandidate::new(self.best_move.random_ties()),
             _phantom: PhantomData,
         }
@@ -223,6 +244,7 @@ where
         f.debug_struct("AcceptedCountForager")
             .field("accepted_count_limit", &self.accepted_count_limit)
             .field("accepted_count", &self.accepted_count)
+            .field("pick_early_type", &self.pick_early_type)
             .finish()
     }
 }
@@ -232,21 +254,34 @@ where
     S: PlanningSolution,
     M: Move<S>,
 {
-    fn step_started(&mut self, _best_score: S::Score, _last_step_score: S::Score, step_seed: u64) {
+    fn step_started(&mut self, best_score: S::Score, last_step_score: S::Score, step_seed: u64) {
         self.accepted_count = 0;
+        self.pick_early_score = match self.pick_early_type {
+            PickEarlyType::Never => None,
+            PickEarlyType::FirstBestScoreImproving => Some(best_score),
+            PickEarlyType::FirstLastStepScoreImproving => Some(last_step_score),
+        };
+        self.picked_early = false;
         self.best_move.reset(step_seed);
     }
 
     fn add_move_index(&mut self, index: CandidateId, score: S::Score) -> ForagerDecision {
-        if self.accepted_count >= self.accepted_count_limit {
+        if self.picked_early || self.accepted_count >= self.accepted_count_limit {
             return ForagerDecision::Release;
         }
         self.accepted_count += 1;
+        if self
+            .pick_early_score
+            .is_some_and(|pick_early_score| score > pick_early_score)
+        {
+            self.picked_early = true;
+            return self.best_move.replace(index, score);
+        }
         self.best_move.consider(index, score)
     }
 
     fn is_quit_early(&self) -> bool {
-        self.accepted_count >= self.accepted_count_limit
+        self.picked_early || self.accepted_count >= self.accepted_count_limit
     }
 
     fn accepted_count_limit(&self) -> Option<usize> {
@@ -585,3 +585,87 @@ fn first_last_step_improving_falls_back_to_accepted_count_limit() {
     assert_eq!(index.index(), 1);
     assert_eq!(score, SoftScore::of(-12));
 }
+
+#[test]
+fn accepted_count_forager_picks_early_on_first_best_score_improvement() {
+    let mut forager = AcceptedCountForager::<DummySolution>::new(10, false)
+        .with_pick_early_type(PickEarlyType::FirstBestScoreImproving);
+    <AcceptedCountForager<DummySolution> as LocalSearchForager<DummySolution, TestMove>>::step_started(
+        &mut forager,
+        SoftScore::of(-10),
+        SoftScore::of(-12),
+        0,
+    );
+
+    for (index, score) in [(0, -15), (1, -11)] {
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::add_move_index(&mut forager, CandidateId::new(index), SoftScore::of(score));
+        assert!(
+            !<AcceptedCountForager<DummySolution> as LocalSearchForager<
+                DummySolution,
+                TestMove,
+            >>::is_quit_early(&forager)
+        );
+    }
+
+    assert_eq!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::add_move_index(&mut forager, CandidateId::new(2), SoftScore::of(-9)),
+        ForagerDecision::Replace(CandidateId::new(1))
+    );
+    assert!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::is_quit_early(&forager)
+    );
+    assert_eq!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::add_move_index(&mut forager, CandidateId::new(3), SoftScore::of(0)),
+        ForagerDecision::Release
+    );
+    assert_eq!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::pick_move_index(&mut forager),
+        Some((CandidateId::new(2), SoftScore::of(-9)))
+    );
+}
+
+#[test]
+fn accepted_count_forager_picks_early_on_first_last_step_improvement() {
+    let mut forager = AcceptedCountForager::<DummySolution>::new(10, false)
+        .with_pick_early_type(PickEarlyType::FirstLastStepScoreImproving);
+    <AcceptedCountForager<DummySolution> as LocalSearchForager<DummySolution, TestMove>>::step_started(
+        &mut forager,
+        SoftScore::of(-10),
+        SoftScore::of(-12),
+        0,
+    );
+
+    <AcceptedCountForager<DummySolution> as LocalSearchForager<DummySolution, TestMove>>::add_move_index(
+        &mut forager,
+        CandidateId::new(0),
+        SoftScore::of(-11),
+    );
+    assert!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::is_quit_early(&forager)
+    );
+    assert_eq!(
+        <AcceptedCountForager<DummySolution> as LocalSearchForager<
+            DummySolution,
+            TestMove,
+        >>::pick_move_index(&mut forager),
+        Some((CandidateId::new(0), SoftScore::of(-11)))
+    );
+}
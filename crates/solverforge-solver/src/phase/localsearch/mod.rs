@@ -9,6 +9,7 @@ mod cursor_source;
 mod evaluation;
 mod forager;
 mod phase;
+mod restart;
 pub(crate) mod vnd;
 
 pub(crate) use acceptor::TabuSearchPolicy;
@@ -26,3 +27,4 @@ pub use forager::{
 };
 pub(crate) use phase::solve_local_search_with_resources;
 pub use phase::LocalSearchPhase;
+pub use restart::RestartStrategy;
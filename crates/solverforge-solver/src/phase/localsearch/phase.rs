@@ -9,12 +9,15 @@ use solverforge_scoring::Director;
 use tracing::info;
 
 use crate::heuristic::r#move::Move;
+use crate::heuristic::selector::decorator::CachingMoveSelector;
 use crate::heuristic::selector::move_selector::{CandidateId, MoveCandidateRef};
+use crate::phase::control::has_no_planning_entities;
+use crate::phase::localsearch::restart::{perturb, RestartProgress};
 use crate::phase::localsearch::{
-    Acceptor, LocalSearchForager, MoveCursorSource, SelectorCursorSource,
+    Acceptor, LocalSearchForager, MoveCursorSource, RestartStrategy, SelectorCursorSource,
 };
 use crate::phase::Phase;
-use crate::scope::{PhaseScope, ProgressCallback, SolverScope};
+use crate::scope::{max_progress, CloneStrategy, PhaseScope, ProgressCallback, SolverScope};
 use crate::stats::{format_duration, whole_units_per_second, CandidateTracePullToken};
 
 mod candidates;
@@ -134,6 +137,8 @@ where
     acceptor: A,
     forager: Fo,
     step_limit: Option<u64>,
+    restart: Option<RestartStrategy>,
+    clone_strategy: CloneStrategy,
     _phantom: PhantomData<fn() -> (S, M)>,
 }
 
@@ -184,9 +189,26 @@ where
             acceptor,
             forager,
             step_limit,
+            restart: None,
+            clone_strategy: CloneStrategy::default(),
             _phantom: PhantomData,
         }
     }
+
+    /// Perturbs and restarts the search when it stops applying moves.
+    pub fn with_restart(mut self, restart: RestartStrategy) -> Self {
+        self.restart = Some(restart);
+        self
+    }
+
+    /// Sets when improved working solutions are cloned into the best solution.
+    ///
+    /// [`CloneStrategy::Deferred`] suits high-churn searches on large
+    /// solutions; the phase still ends with the same best solution.
+    pub fn with_clone_strategy(mut self, clone_strategy: CloneStrategy) -> Self {
+        self.clone_strategy = clone_strategy;
+        self
+    }
 }
 
 impl<S, M, MS, A, Fo> LocalSearchPhase<S, M, SelectorCursorSource<MS>, A, Fo>
@@ -212,6 +234,34 @@ where
     }
 }
 
+impl<S, M, MS, A, Fo> LocalSearchPhase<S, M, CachingMoveSelector<S, M, MS>, A, Fo>
+where
+    S: PlanningSolution,
+    M: Move<S> + 'static,
+    MS: crate::heuristic::selector::MoveSelector<S, M>,
+    A: Acceptor<S>,
+    Fo: LocalSearchForager<S, M>,
+{
+    /// Builds a local-search phase that reuses its selector's moves until a
+    /// step commits one.
+    ///
+    /// See [`CachingMoveSelector`] for when the snapshot is reused.
+    pub fn new_cached(
+        move_selector: MS,
+        acceptor: A,
+        forager: Fo,
+        step_limit: Option<u64>,
+    ) -> Self {
+        Self::with_cursor_source(
+            CachingMoveSelector::new(move_selector),
+            (),
+            acceptor,
+            forager,
+            step_limit,
+        )
+    }
+}
+
 impl<S, M, Source, A, Fo> Debug for LocalSearchPhase<S, M, Source, A, Fo>
 where
     S: PlanningSolution,
@@ -226,6 +276,8 @@ where
             .field("acceptor", &self.acceptor)
             .field("forager", &self.forager)
             .field("step_limit", &self.step_limit)
+            .field("restart", &self.restart)
+            .field("clone_strategy", &self.clone_strategy)
             .finish()
     }
 }
@@ -240,6 +292,7 @@ pub(crate) fn solve_local_search_with_resources<S, D, BestCb, M, Source, A, Fo>(
     acceptor: &mut A,
     forager: &mut Fo,
     step_limit: Option<u64>,
+    restart: Option<RestartStrategy>,
     solver_scope: &mut SolverScope<S, D, BestCb>,
 ) where
     S: PlanningSolution,
@@ -260,31 +313,77 @@ pub(crate) fn solve_local_search_with_resources<S, D, BestCb, M, Source, A, Fo>(
         phase_index = phase_index,
         score = %last_step_score,
     );
+    move_source.phase_started();
     acceptor.phase_started(&last_step_score);
 
     let start_time = Instant::now();
-    loop {
-        if phase_scope.solver_scope_mut().should_terminate() {
-            break;
-        }
-        if step_limit.is_some_and(|limit| phase_scope.step_count() >= limit) {
-            break;
-        }
+    let mut restart_progress = RestartProgress::default();
+    /* A solution without planning entities can never produce a move, so
+    the phase ends without steps instead of spinning until termination.
+    */
+    if !has_no_planning_entities(phase_scope.score_director()) {
+        loop {
+            if let Some(score) = phase_scope
+                .solver_scope_mut()
+                .apply_pending_problem_changes()
+            {
+                last_step_score = score;
+                move_source.phase_started();
+                acceptor.phase_ended();
+                acceptor.phase_started(&last_step_score);
+            }
+            if phase_scope.solver_scope_mut().should_terminate() {
+                break;
+            }
+            if step_limit.is_some_and(|limit| phase_scope.step_count() >= limit) {
+                break;
+            }
+            if let Some(progress) = phase_progress(&phase_scope, step_limit) {
+                acceptor.progress_updated(progress);
+            }
 
-        match execute_step(
-            move_source,
-            resources,
-            acceptor,
-            forager,
-            &mut phase_scope,
-            &mut last_step_score,
-        ) {
-            StepOutcome::Continue | StepOutcome::Restart => continue,
-            StepOutcome::Terminate => break,
+            match execute_step(
+                move_source,
+                resources,
+                acceptor,
+                forager,
+                &mut phase_scope,
+                &mut last_step_score,
+            ) {
+                StepOutcome::Applied => {
+                    move_source.solution_changed();
+                    restart_progress.record_applied();
+                }
+                StepOutcome::Idle => {
+                    let Some(strategy) = restart else {
+                        continue;
+                    };
+                    if !restart_progress.record_idle(&strategy) {
+                        continue;
+                    }
+                    if let Some(score) = perturb(
+                        move_source,
+                        resources,
+                        strategy.perturbation_size(),
+                        &mut phase_scope,
+                    ) {
+                        last_step_score = score;
+                    }
+                    move_source.phase_started();
+                    acceptor.phase_ended();
+                    acceptor.phase_started(&last_step_score);
+                }
+                StepOutcome::Restart => continue,
+                StepOutcome::Terminate => break,
+            }
         }
     }
 
+    phase_scope
+        .solver_scope_mut()
+        .capture_pending_best_solution();
     acceptor.phase_ended();
+    phase_scope.report_progress();
 
     let duration = start_time.elapsed();
     let steps = phase_scope.step_count();
@@ -310,6 +409,7 @@ pub(crate) fn solve_local_search_with_resources<S, D, BestCb, M, Source, A, Fo>(
         moves_score_improving = stats.moves_score_improving(),
         moves_applied_improving = stats.moves_applied_improving(),
         score_calculations = stats.score_calculations,
+        best_score_improvements = stats.best_score_improvements,
         generation_time = %format_duration(stats.generation_time()),
         evaluation_time = %format_duration(stats.evaluation_time()),
         moves_speed = speed,
@@ -319,6 +419,26 @@ pub(crate) fn solve_local_search_with_resources<S, D, BestCb, M, Source, A, Fo>(
     );
 }
 
+// Termination progress measured from solve start, counting the phase's own
+// step limit as a budget too.
+fn phase_progress<S, D, BestCb>(
+    phase_scope: &PhaseScope<'_, '_, S, D, BestCb>,
+    step_limit: Option<u64>,
+) -> Option<f64>
+where
+    S: PlanningSolution,
+    D: Director<S>,
+    BestCb: ProgressCallback<S>,
+{
+    let step_progress = step_limit
+        .filter(|&limit| limit > 0)
+        .map(|limit| (phase_scope.step_count() as f64 / limit as f64).min(1.0));
+    max_progress(
+        phase_scope.solver_scope().termination_progress(),
+        step_progress,
+    )
+}
+
 impl<S, D, BestCb, M, Source, A, Fo> Phase<S, D, BestCb> for LocalSearchPhase<S, M, Source, A, Fo>
 where
     S: PlanningSolution,
@@ -336,16 +456,21 @@ where
             acceptor,
             forager,
             step_limit,
+            restart,
+            clone_strategy,
             ..
         } = self;
-        solve_local_search_with_resources(
-            move_source,
-            resources,
-            acceptor,
-            forager,
-            *step_limit,
-            solver_scope,
-        );
+        solver_scope.with_clone_strategy(*clone_strategy, |solver_scope| {
+            solve_local_search_with_resources(
+                move_source,
+                resources,
+                acceptor,
+                forager,
+                *step_limit,
+                *restart,
+                solver_scope,
+            );
+        });
     }
 
     fn phase_type_name(&self) -> &'static str {
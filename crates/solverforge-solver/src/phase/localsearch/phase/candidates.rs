@@ -239,6 +239,7 @@ where
             move_index = candidate_id.index(),
             score = %move_score,
             accepted = accepted,
+            temperature = acceptor.temperature(),
         );
 
         if accepted {
@@ -21,7 +21,10 @@ use super::candidates::{evaluate_candidates, CandidateLoopState};
 use super::take_trace_token;
 
 pub(super) enum StepOutcome {
-    Continue,
+    // The step completed and committed the forager's pick.
+    Applied,
+    // The step completed without an accepted move to commit.
+    Idle,
     Restart,
     Terminate,
 }
@@ -119,7 +122,9 @@ where
 
     // The online forager retains only the selected candidate.
     let mut accepted_move_signature = None;
+    let mut outcome = StepOutcome::Idle;
     if let Some((selected_index, selected_score)) = forager.pick_move_index() {
+        outcome = StepOutcome::Applied;
         let selected_trace_token = take_trace_token(&mut accepted_trace_tokens, selected_index);
         if let Some(token) = selected_trace_token {
             step_scope
@@ -132,11 +137,16 @@ where
             .candidate(selected_index)
             .expect("selected candidate id must remain borrowable until commit");
         let selected_move_label = selected_move.telemetry_label();
+        let selected_move_type = selected_move.type_name();
         if requires_move_signatures {
             accepted_move_signature =
                 Some(selected_move.tabu_signature(step_scope.score_director()));
         }
         let previous_score = *last_step_score;
+        step_scope
+            .phase_scope_mut()
+            .solver_scope_mut()
+            .discard_superseded_best_solution(selected_score);
         step_scope.apply_committed_change(|score_director| {
             cursor.apply_owned_candidate(selected_index, score_director);
         });
@@ -161,6 +171,11 @@ where
         step_scope
             .phase_scope_mut()
             .record_move_kind_applied(selected_move_label, score_improvement);
+        if selected_score > previous_score {
+            step_scope
+                .phase_scope_mut()
+                .record_move_type_improvement(selected_move_type);
+        }
         if step_scope.phase_scope().can_record_applied_move_trace() {
             let score_before = previous_score.to_scalar();
             let score_after = selected_score.to_scalar();
@@ -221,7 +236,7 @@ where
     acceptor.step_ended(last_step_score, accepted_move_signature.as_ref());
 
     step_scope.complete();
-    StepOutcome::Continue
+    outcome
 }
 
 fn record_ignored_trace_tokens<S, D, BestCb>(
@@ -2,3 +2,10 @@ include!("tests/common.rs");
 include!("tests/selectors.rs");
 include!("tests/streaming.rs");
 include!("tests/foraging.rs");
+include!("tests/empty.rs");
+include!("tests/annealing.rs");
+include!("tests/undo_assert.rs");
+include!("tests/tabu.rs");
+include!("tests/restart.rs");
+include!("tests/problem_change.rs");
+include!("tests/pinning.rs");
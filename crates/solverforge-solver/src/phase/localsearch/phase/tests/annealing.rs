@@ -0,0 +1,113 @@
+use crate::phase::localsearch::SimulatedAnnealingAcceptor;
+
+#[derive(Clone, Default)]
+struct StepTemperatureSubscriber {
+    temperatures: Arc<std::sync::Mutex<Vec<(u64, f64)>>>,
+}
+
+#[derive(Default)]
+struct StepTemperature {
+    is_step: bool,
+    step: Option<u64>,
+    temperature: Option<f64>,
+}
+
+impl tracing::field::Visit for StepTemperature {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "event" {
+            self.is_step = format!("{value:?}").trim_matches('"') == "step";
+        }
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "event" {
+            self.is_step = value == "step";
+        }
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        if field.name() == "step" {
+            self.step = Some(value);
+        }
+    }
+
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        if field.name() == "temperature" {
+            self.temperature = Some(value);
+        }
+    }
+}
+
+impl tracing::Subscriber for StepTemperatureSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        tracing::span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut captured = StepTemperature::default();
+        event.record(&mut captured);
+        if let (true, Some(step), Some(temperature)) =
+            (captured.is_step, captured.step, captured.temperature)
+        {
+            self.temperatures.lock().unwrap().push((step, temperature));
+        }
+    }
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+fn logged_annealing_temperatures(seed: u64) -> Vec<(u64, f64)> {
+    let subscriber = StepTemperatureSubscriber::default();
+    let temperatures = Arc::clone(&subscriber.temperatures);
+    let mut solver_scope = SolverScope::new(create_nqueens_director(&[0, 0, 0, 0]));
+    solver_scope.start_solving();
+
+    let move_selector = create_move_selector((0..4).collect());
+    let acceptor = SimulatedAnnealingAcceptor::with_seed(10.0, 0.9, seed);
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, NQueensMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(20));
+
+    tracing::subscriber::with_default(subscriber, || phase.solve(&mut solver_scope));
+
+    let logged = temperatures.lock().unwrap().clone();
+    logged
+}
+
+#[test]
+fn simulated_annealing_logs_a_decreasing_step_temperature() {
+    let logged = logged_annealing_temperatures(7);
+
+    assert!(!logged.is_empty());
+    for pair in logged.windows(2) {
+        let ((previous_step, previous), (step, temperature)) = (pair[0], pair[1]);
+        if step == previous_step {
+            assert_eq!(temperature, previous);
+        } else {
+            assert!(temperature < previous);
+        }
+    }
+    let (first_step, first) = logged[0];
+    let (last_step, last) = logged[logged.len() - 1];
+    assert!(last_step > first_step);
+    assert!(last < first);
+    assert_eq!(first, 10.0);
+}
+
+#[test]
+fn simulated_annealing_step_temperatures_are_deterministic_per_seed() {
+    assert_eq!(
+        logged_annealing_temperatures(11),
+        logged_annealing_temperatures(11)
+    );
+}
@@ -236,6 +236,179 @@ fn local_search_can_improve_by_unassigning_optional_variable() {
     assert_eq!(solver_scope.working_solution().tasks[0].worker, None);
 }
 
+#[test]
+fn hill_climbing_reports_phase_statistics() {
+    type OptionalMove = crate::heuristic::r#move::ChangeMove<OptionalTaskSolution, i64>;
+
+    let phase_telemetry = Arc::new(std::sync::Mutex::new(None));
+    let observed = Arc::clone(&phase_telemetry);
+    let director = create_optional_director(OptionalTaskSolution {
+        tasks: vec![OptionalTask { worker: Some(5) }, OptionalTask { worker: Some(3) }],
+        score: None,
+    });
+    let mut solver_scope = SolverScope::new(director).with_progress_callback(
+        move |progress: crate::scope::SolverProgressRef<'_, OptionalTaskSolution>| {
+            if let Some(phase) = &progress.telemetry.phase {
+                *observed.lock().unwrap() = Some(phase.clone());
+            }
+        },
+    );
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_optional_worker,
+        set_optional_worker,
+        0,
+        0,
+        "worker",
+        vec![5, 3, 1],
+    )
+    .with_allows_unassigned(true);
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, OptionalMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(5));
+
+    phase.solve(&mut solver_scope);
+
+    let phase = phase_telemetry
+        .lock()
+        .unwrap()
+        .take()
+        .expect("local search reports its phase statistics at phase end");
+    assert_eq!(phase.phase_type, "Local Search");
+    assert_eq!(phase.step_count, 5);
+    assert!(phase.moves_evaluated >= phase.step_count);
+    assert!(phase.moves_accepted > 0);
+    assert!(phase.best_score_improvements > 0);
+    assert!(phase.best_score_improvements <= phase.step_count);
+    assert!(phase.acceptance_rate() > 0.0 && phase.acceptance_rate() <= 1.0);
+}
+
+#[test]
+fn step_callback_fires_once_per_completed_step() {
+    type OptionalMove = crate::heuristic::r#move::ChangeMove<OptionalTaskSolution, i64>;
+
+    let steps = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let observed = Arc::clone(&steps);
+    let director = create_optional_director(OptionalTaskSolution {
+        tasks: vec![OptionalTask { worker: Some(5) }, OptionalTask { worker: Some(3) }],
+        score: None,
+    });
+    let mut solver_scope = SolverScope::new(director).on_step(move |step: &crate::scope::StepEvent<'_, OptionalTaskSolution>| {
+        observed.lock().unwrap().push((
+            step.phase_type,
+            step.step_index,
+            step.step_score.copied(),
+            step.elapsed,
+        ));
+    });
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_optional_worker,
+        set_optional_worker,
+        0,
+        0,
+        "worker",
+        vec![5, 3, 1],
+    )
+    .with_allows_unassigned(true);
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, OptionalMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(4));
+
+    phase.solve(&mut solver_scope);
+
+    let steps = steps.lock().unwrap();
+    assert_eq!(steps.len() as u64, solver_scope.total_step_count());
+    assert_eq!(steps.len(), 4);
+    for (expected_index, (phase_type, step_index, step_score, _)) in steps.iter().enumerate() {
+        assert_eq!(*phase_type, "Local Search");
+        assert_eq!(*step_index, expected_index as u64);
+        assert!(step_score.is_some());
+    }
+    assert!(steps.windows(2).all(|pair| pair[0].3 <= pair[1].3));
+    assert!(steps[0].2 > Some(SoftScore::of(-8)));
+}
+
+fn solve_optional_tasks_with_clone_strategy(
+    clone_strategy: crate::scope::CloneStrategy,
+) -> (Vec<Option<i64>>, SoftScore, usize) {
+    type OptionalMove = crate::heuristic::r#move::ChangeMove<OptionalTaskSolution, i64>;
+
+    let best_solution_events = Arc::new(AtomicUsize::new(0));
+    let observed = Arc::clone(&best_solution_events);
+    let director = create_optional_director(OptionalTaskSolution {
+        tasks: vec![
+            OptionalTask { worker: Some(5) },
+            OptionalTask { worker: Some(3) },
+            OptionalTask { worker: Some(5) },
+        ],
+        score: None,
+    });
+    let mut solver_scope = SolverScope::new(director).with_progress_callback(
+        move |progress: crate::scope::SolverProgressRef<'_, OptionalTaskSolution>| {
+            if progress.kind == crate::scope::SolverProgressKind::BestSolution {
+                observed.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+    );
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_optional_worker,
+        set_optional_worker,
+        0,
+        0,
+        "worker",
+        vec![5, 3, 1],
+    )
+    .with_allows_unassigned(true);
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, OptionalMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(8))
+            .with_clone_strategy(clone_strategy);
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(
+        solver_scope.clone_strategy(),
+        crate::scope::CloneStrategy::EveryImprovement,
+        "the phase restores the scope's clone strategy"
+    );
+    let best_score = *solver_scope.best_score().expect("best score");
+    let best = solver_scope.take_best_solution().expect("best solution");
+    assert_eq!(best.score, Some(best_score));
+    let workers = best.tasks.iter().map(|task| task.worker).collect();
+    (workers, best_score, best_solution_events.load(Ordering::SeqCst))
+}
+
+#[test]
+fn deferred_clone_strategy_keeps_the_same_final_best() {
+    let (every_workers, every_score, every_clones) = solve_optional_tasks_with_clone_strategy(
+        crate::scope::CloneStrategy::EveryImprovement,
+    );
+    let (deferred_workers, deferred_score, deferred_clones) =
+        solve_optional_tasks_with_clone_strategy(crate::scope::CloneStrategy::Deferred);
+
+    assert_eq!(every_score, SoftScore::of(0));
+    assert_eq!(deferred_score, every_score);
+    assert_eq!(deferred_workers, every_workers);
+    assert!(
+        deferred_clones < every_clones,
+        "deferred captured {deferred_clones} bests, every improvement captured {every_clones}"
+    );
+}
+
 #[test]
 fn test_local_search_step_limit() {
     let director = create_nqueens_director(&[0, 0, 0, 0]);
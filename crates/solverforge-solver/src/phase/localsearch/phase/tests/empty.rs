@@ -0,0 +1,141 @@
+use crate::phase::localsearch::vnd::solve_vnd_with_resources;
+
+#[test]
+fn local_search_on_empty_solution_ends_without_steps() {
+    let director = create_nqueens_director(&[]);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+    let initial_score = solver_scope.calculate_score();
+
+    let move_selector = create_move_selector((0..4).collect());
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, NQueensMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, None);
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.stats().step_count, 0);
+    assert_eq!(solver_scope.stats().moves_generated, 0);
+    assert!(solver_scope.working_solution().queens.is_empty());
+    assert_eq!(solver_scope.current_score().copied(), Some(initial_score));
+}
+
+#[test]
+fn vnd_on_empty_solution_ends_without_steps() {
+    let director = create_nqueens_director(&[]);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+    let initial_score = solver_scope.calculate_score();
+
+    let mut neighborhoods = vec![
+        SelectorCursorSource::new(create_move_selector((0..4).collect())),
+        SelectorCursorSource::new(create_move_selector((0..4).collect())),
+    ];
+    let mut resources = ();
+    solve_vnd_with_resources::<_, _, _, NQueensMove, _>(
+        &mut neighborhoods,
+        &mut resources,
+        None,
+        &mut solver_scope,
+    );
+
+    assert_eq!(solver_scope.stats().step_count, 0);
+    assert!(solver_scope.working_solution().queens.is_empty());
+    assert_eq!(solver_scope.current_score().copied(), Some(initial_score));
+}
+
+#[derive(Clone, Default)]
+struct PhaseEndSubscriber {
+    phase_ends: Arc<std::sync::Mutex<Vec<(String, u64)>>>,
+}
+
+#[derive(Default)]
+struct PhaseEnd {
+    is_phase_end: bool,
+    phase: Option<String>,
+    steps: Option<u64>,
+}
+
+impl tracing::field::Visit for PhaseEnd {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.record_str(field, format!("{value:?}").trim_matches('"'));
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        match field.name() {
+            "event" => self.is_phase_end = value == "phase_end",
+            "phase" => self.phase = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        if field.name() == "steps" {
+            self.steps = Some(value);
+        }
+    }
+}
+
+impl tracing::Subscriber for PhaseEndSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        tracing::span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut captured = PhaseEnd::default();
+        event.record(&mut captured);
+        if let (true, Some(phase), Some(steps)) =
+            (captured.is_phase_end, captured.phase, captured.steps)
+        {
+            self.phase_ends.lock().unwrap().push((phase, steps));
+        }
+    }
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+#[test]
+fn empty_solution_traces_phase_end_with_zero_steps() {
+    let subscriber = PhaseEndSubscriber::default();
+    let phase_ends = Arc::clone(&subscriber.phase_ends);
+    let mut solver_scope = SolverScope::new(create_nqueens_director(&[]));
+    solver_scope.start_solving();
+
+    let mut phase: LocalSearchPhase<_, NQueensMove, _, _, _> = LocalSearchPhase::new(
+        create_move_selector((0..4).collect()),
+        HillClimbingAcceptor::new(),
+        AcceptedCountForager::new(1, false),
+        None,
+    );
+    let mut neighborhoods = vec![SelectorCursorSource::new(create_move_selector(
+        (0..4).collect(),
+    ))];
+    tracing::subscriber::with_default(subscriber, || {
+        phase.solve(&mut solver_scope);
+        solve_vnd_with_resources::<_, _, _, NQueensMove, _>(
+            &mut neighborhoods,
+            &mut (),
+            None,
+            &mut solver_scope,
+        );
+    });
+
+    assert_eq!(
+        *phase_ends.lock().unwrap(),
+        vec![
+            ("Local Search".to_string(), 0),
+            ("Variable Neighborhood Descent".to_string(), 0),
+        ]
+    );
+}
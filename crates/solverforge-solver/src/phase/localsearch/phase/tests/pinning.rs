@@ -0,0 +1,190 @@
+/* Four queens where queen 1 is pinned to row 1. The director records every
+entity a move touches, including trial moves that are undone, so the test
+proves the pinned queen never entered a move.
+*/
+
+#[derive(Clone, Debug)]
+struct PinnableQueen {
+    column: i64,
+    row: Option<i64>,
+    pinned: bool,
+}
+
+impl solverforge_core::domain::PlanningEntity for PinnableQueen {
+    fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PinnableQueens {
+    queens: Vec<PinnableQueen>,
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for PinnableQueens {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+struct PinnableQueensDirector {
+    working_solution: PinnableQueens,
+    descriptor: SolutionDescriptor,
+    changed_entities: Arc<std::sync::Mutex<Vec<usize>>>,
+}
+
+impl Director<PinnableQueens> for PinnableQueensDirector {
+    fn working_solution(&self) -> &PinnableQueens {
+        &self.working_solution
+    }
+
+    fn working_solution_mut(&mut self) -> &mut PinnableQueens {
+        &mut self.working_solution
+    }
+
+    fn calculate_score(&mut self) -> SoftScore {
+        let queens = &self.working_solution.queens;
+        let mut conflicts = 0;
+        for (i, left) in queens.iter().enumerate() {
+            for right in &queens[i + 1..] {
+                if let (Some(left_row), Some(right_row)) = (left.row, right.row) {
+                    let column_gap = (right.column - left.column).abs();
+                    if left_row == right_row || (left_row - right_row).abs() == column_gap {
+                        conflicts += 1;
+                    }
+                }
+            }
+        }
+        let score = SoftScore::of(-conflicts);
+        self.working_solution.set_score(Some(score));
+        score
+    }
+
+    fn solution_descriptor(&self) -> &SolutionDescriptor {
+        &self.descriptor
+    }
+
+    fn clone_working_solution(&self) -> PinnableQueens {
+        self.working_solution.clone()
+    }
+
+    fn before_variable_changed(&mut self, _descriptor_index: usize, _entity_index: usize) {}
+
+    fn after_variable_changed(&mut self, _descriptor_index: usize, entity_index: usize) {
+        self.changed_entities.lock().unwrap().push(entity_index);
+    }
+
+    fn entity_count(&self, descriptor_index: usize) -> Option<usize> {
+        (descriptor_index == 0).then_some(self.working_solution.queens.len())
+    }
+
+    fn total_entity_count(&self) -> Option<usize> {
+        Some(self.working_solution.queens.len())
+    }
+
+    fn constraint_metadata(&self) -> Vec<solverforge_scoring::ConstraintMetadata<'_>> {
+        Vec::new()
+    }
+}
+
+fn get_pinnable_queen_row(solution: &PinnableQueens, idx: usize, _: usize) -> Option<i64> {
+    solution.queens[idx].row
+}
+
+fn set_pinnable_queen_row(solution: &mut PinnableQueens, idx: usize, _: usize, row: Option<i64>) {
+    solution.queens[idx].row = row;
+}
+
+fn create_pinnable_queens_director(
+    rows: &[i64],
+    pinned_index: usize,
+    changed_entities: Arc<std::sync::Mutex<Vec<usize>>>,
+) -> PinnableQueensDirector {
+    let queens = rows
+        .iter()
+        .enumerate()
+        .map(|(column, &row)| PinnableQueen {
+            column: column as i64,
+            row: Some(row),
+            pinned: column == pinned_index,
+        })
+        .collect();
+    let descriptor = SolutionDescriptor::new("PinnableQueens", TypeId::of::<PinnableQueens>())
+        .with_entity(
+            EntityDescriptor::new("PinnableQueen", TypeId::of::<PinnableQueen>(), "queens")
+                .with_extractor(Box::new(EntityCollectionExtractor::new(
+                    "PinnableQueen",
+                    "queens",
+                    |s: &PinnableQueens| &s.queens,
+                    |s: &mut PinnableQueens| &mut s.queens,
+                )))
+                .with_pinning::<PinnableQueen>("pinned"),
+        );
+    PinnableQueensDirector {
+        working_solution: PinnableQueens {
+            queens,
+            score: None,
+        },
+        descriptor,
+        changed_entities,
+    }
+}
+
+#[test]
+fn local_search_never_moves_a_pinned_entity() {
+    type PinnableQueenMove = crate::heuristic::r#move::ChangeMove<PinnableQueens, i64>;
+
+    let changed_entities = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let director =
+        create_pinnable_queens_director(&[0, 1, 0, 0], 1, Arc::clone(&changed_entities));
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+    let start_score = solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_pinnable_queen_row,
+        set_pinnable_queen_row,
+        0,
+        0,
+        "row",
+        (0..4).collect(),
+    );
+    let entity_selector = crate::heuristic::selector::FromSolutionEntitySelector::new(0);
+    assert_eq!(
+        crate::heuristic::selector::EntitySelector::size(
+            &entity_selector,
+            solver_scope.score_director()
+        ),
+        3
+    );
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: BestScoreForager<_> = BestScoreForager::new(false);
+    let mut phase: LocalSearchPhase<_, PinnableQueenMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(20));
+
+    phase.solve(&mut solver_scope);
+
+    let changed_entities = changed_entities.lock().unwrap();
+    assert!(!changed_entities.is_empty());
+    assert!(!changed_entities.contains(&1));
+    let best_score = *solver_scope.best_score().expect("best score");
+    assert!(best_score > start_score);
+    let best = solver_scope.take_best_solution().expect("best solution");
+    assert_eq!(best.queens[1].row, Some(1));
+}
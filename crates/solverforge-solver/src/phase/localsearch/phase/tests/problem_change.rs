@@ -0,0 +1,101 @@
+/* Problem changes submitted through a `SolverHandle` while local search runs.
+The change arrives from the step callback, so it lands between two steps of
+the same phase.
+*/
+
+#[test]
+fn problem_change_adds_an_entity_mid_solve_and_search_optimizes_it() {
+    type OptionalMove = crate::heuristic::r#move::ChangeMove<OptionalTaskSolution, i64>;
+
+    let (handle, receiver) = crate::realtime::SolverHandle::<OptionalTaskSolution>::new();
+    handle.set_solving(true);
+    let director = create_optional_director(OptionalTaskSolution {
+        tasks: vec![OptionalTask { worker: Some(5) }, OptionalTask { worker: Some(3) }],
+        score: None,
+    });
+    let solver_scope = SolverScope::new(director).with_problem_change_receiver(receiver);
+    assert_eq!(solver_scope.score_director().total_entity_count(), Some(2));
+    let mut solver_scope = solver_scope.on_step(move |step: &crate::scope::StepEvent<'_, OptionalTaskSolution>| {
+        if step.step_index == 1 {
+            let change = crate::realtime::ClosureProblemChange::new(
+                "add_task",
+                |sd: &mut dyn Director<OptionalTaskSolution>| {
+                    sd.working_solution_mut()
+                        .tasks
+                        .push(OptionalTask { worker: Some(5) });
+                },
+            );
+            assert_eq!(
+                handle.add_problem_change(change),
+                crate::realtime::ProblemChangeResult::Queued
+            );
+        }
+    });
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_optional_worker,
+        set_optional_worker,
+        0,
+        0,
+        "worker",
+        vec![5, 3, 1],
+    )
+    .with_allows_unassigned(true);
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, OptionalMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(20));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.score_director().total_entity_count(), Some(3));
+    assert_eq!(solver_scope.best_score(), Some(&SoftScore::of(0)));
+    let best = solver_scope.take_best_solution().expect("best solution");
+    assert_eq!(best.tasks.len(), 3);
+    assert!(best.tasks.iter().all(|task| task.worker.is_none()));
+}
+
+#[test]
+fn submitted_change_is_applied_at_the_next_step_boundary() {
+    type OptionalMove = crate::heuristic::r#move::ChangeMove<OptionalTaskSolution, i64>;
+
+    let director = create_optional_director(OptionalTaskSolution {
+        tasks: vec![OptionalTask { worker: None }],
+        score: None,
+    });
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+    solver_scope.update_best_solution();
+    solver_scope.submit_change(Box::new(crate::realtime::ClosureProblemChange::new(
+        "add_task",
+        |sd: &mut dyn Director<OptionalTaskSolution>| {
+            sd.working_solution_mut()
+                .tasks
+                .push(OptionalTask { worker: Some(3) });
+        },
+    )));
+
+    let move_selector = ChangeMoveSelector::simple(
+        get_optional_worker,
+        set_optional_worker,
+        0,
+        0,
+        "worker",
+        vec![3, 1],
+    )
+    .with_allows_unassigned(true);
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, OptionalMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(5));
+
+    phase.solve(&mut solver_scope);
+
+    let best = solver_scope.take_best_solution().expect("best solution");
+    assert_eq!(best.tasks.len(), 2);
+    assert_eq!(best.score, Some(SoftScore::of(0)));
+}
@@ -0,0 +1,122 @@
+/* A line of four positions whose score encodes the position. Position 0 is
+a local optimum for +/-1 moves: its only neighbor is worse, and climbing out
+needs two moves before position 3 becomes reachable uphill.
+*/
+const LINE_SCORES: [i64; 4] = [-1, -3, -2, 0];
+
+fn line_position(score_director: &impl Director<TestSolution>) -> usize {
+    let score = score_director.working_solution().score.unwrap();
+    LINE_SCORES
+        .iter()
+        .position(|&line_score| SoftScore::of(line_score) == score)
+        .expect("working score must encode a line position")
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LineStepMove(isize);
+
+impl LineStepMove {
+    fn target(&self, score_director: &impl Director<TestSolution>) -> Option<usize> {
+        line_position(score_director)
+            .checked_add_signed(self.0)
+            .filter(|&position| position < LINE_SCORES.len())
+    }
+}
+
+impl Move<TestSolution> for LineStepMove {
+    type Undo = <ScoreFieldMove as Move<TestSolution>>::Undo;
+
+    fn is_doable<D: Director<TestSolution>>(&self, score_director: &D) -> bool {
+        self.target(score_director).is_some()
+    }
+
+    fn do_move<D: Director<TestSolution>>(&self, score_director: &mut D) -> Self::Undo {
+        let target = self.target(score_director).expect("line step must be doable");
+        ScoreFieldMove(LINE_SCORES[target]).do_move(score_director)
+    }
+
+    fn undo_move<D: Director<TestSolution>>(&self, score_director: &mut D, undo: Self::Undo) {
+        ScoreFieldMove(0).undo_move(score_director, undo);
+    }
+
+    fn descriptor_index(&self) -> usize {
+        0
+    }
+
+    fn entity_indices(&self) -> &[usize] {
+        &[0]
+    }
+
+    fn variable_name(&self) -> &str {
+        "position"
+    }
+
+    fn tabu_signature<D: Director<TestSolution>>(
+        &self,
+        _score_director: &D,
+    ) -> crate::heuristic::r#move::MoveTabuSignature {
+        let scope = crate::heuristic::r#move::metadata::MoveTabuScope::new(0, "position");
+        crate::heuristic::r#move::MoveTabuSignature::new(
+            scope,
+            smallvec::smallvec![self.0 as u64],
+            smallvec::smallvec![(-self.0) as u64],
+        )
+    }
+}
+
+#[derive(Debug)]
+struct LineStepSelector;
+
+impl MoveSelector<TestSolution, LineStepMove> for LineStepSelector {
+    type Cursor<'a>
+        = ArenaMoveCursor<TestSolution, LineStepMove>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<TestSolution>>(
+        &'a self,
+        _score_director: &D,
+    ) -> Self::Cursor<'a> {
+        ArenaMoveCursor::from_moves([LineStepMove(-1), LineStepMove(1)])
+    }
+
+    fn size<D: Director<TestSolution>>(&self, _score_director: &D) -> usize {
+        2
+    }
+}
+
+fn climb_line(restart: Option<crate::phase::localsearch::RestartStrategy>) -> SoftScore {
+    let director = ScoreFieldDirector {
+        working_solution: TestSolution::with_score(SoftScore::of(LINE_SCORES[0])),
+        descriptor: create_minimal_descriptor(),
+    };
+    let mut solver_scope = SolverScope::new(director).with_seed(7);
+    solver_scope.start_solving();
+
+    let forager: BestScoreForager<_> = BestScoreForager::new(false);
+    let mut phase: LocalSearchPhase<_, LineStepMove, _, _, _> =
+        LocalSearchPhase::new(LineStepSelector, HillClimbingAcceptor::new(), forager, Some(100));
+    if let Some(restart) = restart {
+        phase = phase.with_restart(restart);
+    }
+
+    phase.solve(&mut solver_scope);
+
+    // A run that never improves leaves no best solution behind.
+    solver_scope
+        .best_score()
+        .copied()
+        .or(solver_scope.working_solution().score)
+        .unwrap()
+}
+
+#[test]
+fn restart_escapes_local_optimum_that_stalls_hill_climbing() {
+    let stuck = climb_line(None);
+    let restarted = climb_line(Some(
+        crate::phase::localsearch::RestartStrategy::new(20).with_perturbation_size(2),
+    ));
+
+    assert_eq!(stuck, SoftScore::of(-1));
+    assert_eq!(restarted, SoftScore::of(0));
+}
@@ -409,3 +409,48 @@ impl MoveSelector<TestSolution, CancelOnDoableMove> for CancelOnDoableSelector {
         2
     }
 }
+
+use crate::heuristic::r#move::RuinMove;
+use crate::heuristic::selector::{RuinMoveSelector, RuinVariableAccess};
+
+fn solve_with_unseeded_ruin(seed: u64) -> (String, String) {
+    let director = create_nqueens_director(&[0, 1, 2, 3, 4, 5, 6, 7]);
+    let mut solver_scope = SolverScope::new(director).with_seed(seed);
+    solver_scope.start_solving();
+
+    let access = RuinVariableAccess::new(
+        |solution: &NQueensSolution| solution.queens.len(),
+        get_queen_row,
+        set_queen_row,
+        0,
+        "row",
+        0,
+    );
+    let move_selector =
+        RuinMoveSelector::<NQueensSolution, i64>::new(1, 2, access).with_moves_per_step(1);
+    // Ruin moves never improve this constant score, so accept equal steps.
+    let acceptor = crate::phase::localsearch::LateAcceptanceAcceptor::new(1);
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, RuinMove<NQueensSolution, i64>, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(3));
+
+    phase.solve(&mut solver_scope);
+
+    (
+        format!("{:?}", solver_scope.best_solution()),
+        format!("{:?}", solver_scope.working_solution()),
+    )
+}
+
+#[test]
+fn unseeded_ruin_selector_follows_the_solver_seed() {
+    let first = solve_with_unseeded_ruin(11);
+    let second = solve_with_unseeded_ruin(11);
+    assert_eq!(first, second);
+
+    let other = solve_with_unseeded_ruin(12);
+    assert_ne!(
+        first.1, other.1,
+        "ruin subsets must be drawn from the solver scope's seeded RNG"
+    );
+}
@@ -73,3 +73,130 @@ fn local_search_opens_selector_with_stream_context() {
     assert_eq!(step_index.load(Ordering::SeqCst), 0);
     assert_eq!(accepted_limit.load(Ordering::SeqCst), 2);
 }
+
+#[test]
+fn cached_selector_phase_applies_the_same_moves_as_the_plain_selector() {
+    let solve = |cached: bool| {
+        let director = ScoreFieldDirector::new();
+        let mut solver_scope = SolverScope::new(director);
+        solver_scope.start_solving();
+        let acceptor = HillClimbingAcceptor::new();
+        let forager: AcceptedCountForager<_> = AcceptedCountForager::new(3, false);
+        let move_selector = ScoreFieldSelector::new([1, 3, 2]);
+        if cached {
+            let mut phase: LocalSearchPhase<_, ScoreFieldMove, _, _, _> =
+                LocalSearchPhase::new_cached(move_selector, acceptor, forager, Some(2));
+            phase.solve(&mut solver_scope);
+        } else {
+            let mut phase: LocalSearchPhase<_, ScoreFieldMove, _, _, _> =
+                LocalSearchPhase::new(move_selector, acceptor, forager, Some(2));
+            phase.solve(&mut solver_scope);
+        }
+        (
+            solver_scope.stats().moves_applied,
+            solver_scope.working_solution().score,
+        )
+    };
+
+    assert_eq!(solve(true), solve(false));
+}
+
+#[derive(Debug)]
+struct OpenCountingSelector {
+    inner: ScoreFieldSelector,
+    opened: Arc<AtomicUsize>,
+}
+
+impl OpenCountingSelector {
+    fn new(scores: impl Into<Vec<i64>>, opened: &Arc<AtomicUsize>) -> Self {
+        Self {
+            inner: ScoreFieldSelector::new(scores),
+            opened: Arc::clone(opened),
+        }
+    }
+}
+
+impl MoveSelector<TestSolution, ScoreFieldMove> for OpenCountingSelector {
+    type Cursor<'a>
+        = ArenaMoveCursor<TestSolution, ScoreFieldMove>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<TestSolution>>(
+        &'a self,
+        score_director: &D,
+    ) -> Self::Cursor<'a> {
+        self.opened.fetch_add(1, Ordering::SeqCst);
+        self.inner.open_cursor(score_director)
+    }
+
+    fn size<D: Director<TestSolution>>(&self, score_director: &D) -> usize {
+        self.inner.size(score_director)
+    }
+}
+
+// Solves with hill climbing and returns (inner opens, steps, applied moves).
+fn count_selector_opens(cached: bool) -> (usize, u64, u64) {
+    let opened = Arc::new(AtomicUsize::new(0));
+    let move_selector = OpenCountingSelector::new([1, 3, 2], &opened);
+    let mut solver_scope = SolverScope::new(ScoreFieldDirector::new());
+    solver_scope.start_solving();
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    if cached {
+        let mut phase: LocalSearchPhase<_, ScoreFieldMove, _, _, _> =
+            LocalSearchPhase::new_cached(move_selector, acceptor, forager, Some(8));
+        phase.solve(&mut solver_scope);
+    } else {
+        let mut phase: LocalSearchPhase<_, ScoreFieldMove, _, _, _> =
+            LocalSearchPhase::new(move_selector, acceptor, forager, Some(8));
+        phase.solve(&mut solver_scope);
+    }
+    (
+        opened.load(Ordering::SeqCst),
+        solver_scope.stats().step_count,
+        solver_scope.stats().moves_applied,
+    )
+}
+
+#[test]
+fn cached_selector_reopens_only_after_committed_steps() {
+    let (plain_opens, plain_steps, plain_applied) = count_selector_opens(false);
+    let (cached_opens, cached_steps, cached_applied) = count_selector_opens(true);
+
+    assert_eq!(plain_steps, 8);
+    assert_eq!(plain_opens, 8);
+    assert_eq!((cached_steps, cached_applied), (plain_steps, plain_applied));
+    assert!(cached_applied < cached_steps);
+    // One snapshot per committed step, plus one shared by every idle step.
+    assert_eq!(cached_opens as u64, cached_applied + 1);
+}
+
+#[test]
+fn vnd_reopens_cached_neighborhoods_after_another_neighborhood_commits() {
+    use crate::heuristic::selector::decorator::CachingMoveSelector;
+    use crate::phase::localsearch::vnd::solve_vnd_with_resources;
+
+    let first_opens = Arc::new(AtomicUsize::new(0));
+    let second_opens = Arc::new(AtomicUsize::new(0));
+    let mut neighborhoods = [
+        CachingMoveSelector::new(OpenCountingSelector::new([0], &first_opens)),
+        CachingMoveSelector::new(OpenCountingSelector::new([1, 3, 2], &second_opens)),
+    ];
+    let mut solver_scope = SolverScope::new(ScoreFieldDirector::new());
+    solver_scope.start_solving();
+
+    solve_vnd_with_resources::<_, _, _, ScoreFieldMove, _>(
+        &mut neighborhoods,
+        &mut (),
+        None,
+        &mut solver_scope,
+    );
+
+    assert_eq!(solver_scope.stats().moves_applied, 1);
+    assert_eq!(solver_scope.working_solution().score, Some(SoftScore::of(3)));
+    // The first neighborhood never commits, but the second one's commit
+    // forces it to regenerate instead of replaying a stale snapshot.
+    assert_eq!(first_opens.load(Ordering::SeqCst), 2);
+    assert_eq!(second_opens.load(Ordering::SeqCst), 2);
+}
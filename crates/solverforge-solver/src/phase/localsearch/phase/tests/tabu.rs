@@ -0,0 +1,112 @@
+// Moves the score field to a target value; its undo identity is the value it
+// replaces, so committing A -> B makes the return move B -> A the undo.
+#[derive(Clone, Copy, Debug)]
+struct ScoreTargetMove(i64);
+
+impl Move<TestSolution> for ScoreTargetMove {
+    type Undo = <ScoreFieldMove as Move<TestSolution>>::Undo;
+
+    fn is_doable<D: Director<TestSolution>>(&self, score_director: &D) -> bool {
+        score_director.working_solution().score != Some(SoftScore::of(self.0))
+    }
+
+    fn do_move<D: Director<TestSolution>>(&self, score_director: &mut D) -> Self::Undo {
+        ScoreFieldMove(self.0).do_move(score_director)
+    }
+
+    fn undo_move<D: Director<TestSolution>>(&self, score_director: &mut D, undo: Self::Undo) {
+        ScoreFieldMove(self.0).undo_move(score_director, undo);
+    }
+
+    fn descriptor_index(&self) -> usize {
+        0
+    }
+
+    fn entity_indices(&self) -> &[usize] {
+        &[0]
+    }
+
+    fn variable_name(&self) -> &str {
+        "score"
+    }
+
+    fn tabu_signature<D: Director<TestSolution>>(
+        &self,
+        score_director: &D,
+    ) -> crate::heuristic::r#move::MoveTabuSignature {
+        let scope = crate::heuristic::r#move::metadata::MoveTabuScope::new(0, "score");
+        let current = score_director
+            .working_solution()
+            .score
+            .map_or(0, |score| score.score());
+        crate::heuristic::r#move::MoveTabuSignature::new(
+            scope,
+            smallvec::smallvec![self.0 as u64],
+            smallvec::smallvec![current as u64],
+        )
+    }
+}
+
+#[derive(Debug)]
+struct ScoreTargetSelector {
+    targets: Vec<i64>,
+}
+
+impl MoveSelector<TestSolution, ScoreTargetMove> for ScoreTargetSelector {
+    type Cursor<'a>
+        = ArenaMoveCursor<TestSolution, ScoreTargetMove>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<TestSolution>>(
+        &'a self,
+        _score_director: &D,
+    ) -> Self::Cursor<'a> {
+        ArenaMoveCursor::from_moves(self.targets.iter().copied().map(ScoreTargetMove))
+    }
+
+    fn size<D: Director<TestSolution>>(&self, _score_director: &D) -> usize {
+        self.targets.len()
+    }
+}
+
+fn score_after_two_tabu_steps(policy: crate::phase::localsearch::TabuSearchPolicy) -> SoftScore {
+    let mut solver_scope = SolverScope::new(ScoreFieldDirector::new());
+    solver_scope.start_solving();
+
+    // From A (0) the best non-tabu neighbor is B (-1); from B it is A again
+    // unless the undo of A -> B is tabu, which leaves only C (-5).
+    let move_selector = ScoreTargetSelector {
+        targets: vec![0, -1, -5],
+    };
+    let acceptor = crate::phase::localsearch::TabuSearchAcceptor::new(policy);
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(usize::MAX, false);
+    let mut phase: LocalSearchPhase<_, ScoreTargetMove, _, _, _> =
+        LocalSearchPhase::new(move_selector, acceptor, forager, Some(2));
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.stats().moves_applied, 2);
+    solver_scope.working_solution().score.unwrap()
+}
+
+#[test]
+fn undo_move_tabu_breaks_two_cycle_in_local_search() {
+    let cycling = score_after_two_tabu_steps(crate::phase::localsearch::TabuSearchPolicy {
+        entity_tabu_size: None,
+        value_tabu_size: None,
+        move_tabu_size: Some(1),
+        undo_move_tabu_size: None,
+        aspiration_enabled: false,
+    });
+    let broken = score_after_two_tabu_steps(crate::phase::localsearch::TabuSearchPolicy {
+        entity_tabu_size: None,
+        value_tabu_size: None,
+        move_tabu_size: Some(1),
+        undo_move_tabu_size: Some(1),
+        aspiration_enabled: false,
+    });
+
+    assert_eq!(cycling, SoftScore::of(0));
+    assert_eq!(broken, SoftScore::of(-5));
+}
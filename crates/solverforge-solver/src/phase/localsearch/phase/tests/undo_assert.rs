@@ -0,0 +1,391 @@
+use solverforge_config::EnvironmentMode;
+
+#[derive(Clone, Debug)]
+struct UndoSlot {
+    value: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct UndoSlotSolution {
+    slots: Vec<UndoSlot>,
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for UndoSlotSolution {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+fn get_undo_slots(solution: &UndoSlotSolution) -> &Vec<UndoSlot> {
+    &solution.slots
+}
+
+fn get_undo_slots_mut(solution: &mut UndoSlotSolution) -> &mut Vec<UndoSlot> {
+    &mut solution.slots
+}
+
+fn get_undo_slot_value(entity: &dyn std::any::Any) -> Option<usize> {
+    entity.downcast_ref::<UndoSlot>().unwrap().value
+}
+
+fn set_undo_slot_value(entity: &mut dyn std::any::Any, value: Option<usize>) {
+    entity.downcast_mut::<UndoSlot>().unwrap().value = value;
+}
+
+fn undo_slot_descriptor() -> SolutionDescriptor {
+    let extractor = Box::new(EntityCollectionExtractor::new(
+        "UndoSlot",
+        "slots",
+        get_undo_slots,
+        get_undo_slots_mut,
+    ));
+    let entity = EntityDescriptor::new("UndoSlot", TypeId::of::<UndoSlot>(), "slots")
+        .with_extractor(extractor)
+        .with_variable(
+            solverforge_core::domain::VariableDescriptor::genuine("value")
+                .with_usize_accessors(get_undo_slot_value, set_undo_slot_value),
+        );
+    SolutionDescriptor::new("UndoSlotSolution", TypeId::of::<UndoSlotSolution>())
+        .with_entity(entity)
+}
+
+fn create_undo_slot_director() -> solverforge_scoring::ScoreDirector<UndoSlotSolution, ()> {
+    let solution = UndoSlotSolution {
+        slots: vec![UndoSlot { value: Some(0) }],
+        score: None,
+    };
+    solverforge_scoring::ScoreDirector::simple(solution, undo_slot_descriptor(), |s, _| {
+        s.slots.len()
+    })
+}
+
+// Assigns slot 0; when `restores` is false the undo forgets to put it back.
+#[derive(Debug)]
+struct UndoSlotMove {
+    restores: bool,
+}
+
+impl Move<UndoSlotSolution> for UndoSlotMove {
+    type Undo = Option<usize>;
+
+    fn is_doable<D: Director<UndoSlotSolution>>(&self, _score_director: &D) -> bool {
+        true
+    }
+
+    fn do_move<D: Director<UndoSlotSolution>>(&self, score_director: &mut D) -> Self::Undo {
+        score_director.before_variable_changed(0, 0);
+        let previous = score_director.working_solution().slots[0].value;
+        score_director.working_solution_mut().slots[0].value = Some(1);
+        score_director.after_variable_changed(0, 0);
+        previous
+    }
+
+    fn undo_move<D: Director<UndoSlotSolution>>(&self, score_director: &mut D, undo: Self::Undo) {
+        if self.restores {
+            score_director.before_variable_changed(0, 0);
+            score_director.working_solution_mut().slots[0].value = undo;
+            score_director.after_variable_changed(0, 0);
+        }
+    }
+
+    fn descriptor_index(&self) -> usize {
+        0
+    }
+
+    fn entity_indices(&self) -> &[usize] {
+        &[0]
+    }
+
+    fn variable_name(&self) -> &str {
+        "value"
+    }
+
+    fn tabu_signature<D: Director<UndoSlotSolution>>(
+        &self,
+        _score_director: &D,
+    ) -> crate::heuristic::r#move::MoveTabuSignature {
+        let scope = crate::heuristic::r#move::metadata::MoveTabuScope::new(0, "value");
+        let identity = crate::heuristic::r#move::metadata::hash_str("undo_slot_move");
+        crate::heuristic::r#move::MoveTabuSignature::new(
+            scope,
+            smallvec::smallvec![identity],
+            smallvec::smallvec![identity],
+        )
+    }
+}
+
+#[derive(Debug)]
+struct UndoSlotSelector {
+    restores: bool,
+}
+
+impl MoveSelector<UndoSlotSolution, UndoSlotMove> for UndoSlotSelector {
+    type Cursor<'a>
+        = ArenaMoveCursor<UndoSlotSolution, UndoSlotMove>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<UndoSlotSolution>>(
+        &'a self,
+        _score_director: &D,
+    ) -> Self::Cursor<'a> {
+        ArenaMoveCursor::from_moves(std::iter::once(UndoSlotMove {
+            restores: self.restores,
+        }))
+    }
+
+    fn size<D: Director<UndoSlotSolution>>(&self, _score_director: &D) -> usize {
+        1
+    }
+}
+
+fn solve_undo_slot(environment_mode: EnvironmentMode, restores: bool) {
+    let mut solver_scope = SolverScope::new(create_undo_slot_director())
+        .with_environment_mode(environment_mode);
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, UndoSlotMove, _, _, _> =
+        LocalSearchPhase::new(UndoSlotSelector { restores }, acceptor, forager, Some(1));
+
+    phase.solve(&mut solver_scope);
+}
+
+#[test]
+fn full_assert_accepts_a_move_whose_undo_restores_the_state() {
+    solve_undo_slot(EnvironmentMode::FullAssert, true);
+}
+
+#[test]
+#[should_panic(expected = "did not restore the planning state")]
+fn full_assert_detects_a_move_whose_undo_leaks_state() {
+    solve_undo_slot(EnvironmentMode::FullAssert, false);
+}
+
+#[test]
+#[should_panic(expected = "did not restore the planning state")]
+fn fast_assert_detects_a_move_whose_undo_leaks_state() {
+    solve_undo_slot(EnvironmentMode::FastAssert, false);
+}
+
+#[test]
+fn reproducible_mode_skips_the_undo_checksum() {
+    solve_undo_slot(EnvironmentMode::Reproducible, false);
+}
+
+/* Incremental director scoring the sum of slot values; it only updates its
+cached score for changes reported through the variable-change notifications.
+*/
+struct SummingSlotDirector {
+    working_solution: UndoSlotSolution,
+    descriptor: SolutionDescriptor,
+    cached: i64,
+}
+
+impl SummingSlotDirector {
+    fn new(slots: Vec<Option<usize>>) -> Self {
+        let working_solution = UndoSlotSolution {
+            slots: slots.into_iter().map(|value| UndoSlot { value }).collect(),
+            score: None,
+        };
+        let cached = Self::slot_sum(&working_solution);
+        Self {
+            working_solution,
+            descriptor: undo_slot_descriptor(),
+            cached,
+        }
+    }
+
+    fn slot_sum(solution: &UndoSlotSolution) -> i64 {
+        solution
+            .slots
+            .iter()
+            .map(|slot| slot.value.unwrap_or(0) as i64)
+            .sum()
+    }
+
+    fn slot_value(&self, entity_index: usize) -> i64 {
+        self.working_solution.slots[entity_index]
+            .value
+            .unwrap_or(0) as i64
+    }
+}
+
+impl Director<UndoSlotSolution> for SummingSlotDirector {
+    fn working_solution(&self) -> &UndoSlotSolution {
+        &self.working_solution
+    }
+
+    fn working_solution_mut(&mut self) -> &mut UndoSlotSolution {
+        &mut self.working_solution
+    }
+
+    fn calculate_score(&mut self) -> SoftScore {
+        let score = SoftScore::of(self.cached);
+        self.working_solution.set_score(Some(score));
+        score
+    }
+
+    fn fresh_score(&self) -> Option<SoftScore> {
+        Some(SoftScore::of(Self::slot_sum(&self.working_solution)))
+    }
+
+    fn solution_descriptor(&self) -> &SolutionDescriptor {
+        &self.descriptor
+    }
+
+    fn clone_working_solution(&self) -> UndoSlotSolution {
+        self.working_solution.clone()
+    }
+
+    fn before_variable_changed(&mut self, _descriptor_index: usize, entity_index: usize) {
+        self.cached -= self.slot_value(entity_index);
+    }
+
+    fn after_variable_changed(&mut self, _descriptor_index: usize, entity_index: usize) {
+        self.cached += self.slot_value(entity_index);
+    }
+
+    fn entity_count(&self, descriptor_index: usize) -> Option<usize> {
+        (descriptor_index == 0).then_some(self.working_solution.slots.len())
+    }
+
+    fn total_entity_count(&self) -> Option<usize> {
+        Some(self.working_solution.slots.len())
+    }
+
+    fn constraint_metadata(&self) -> Vec<solverforge_scoring::ConstraintMetadata<'_>> {
+        Vec::new()
+    }
+}
+
+/* Moves slots 0 and 1 to value 2. The undo always restores both values, but
+when `notifies_both` is false it forgets to report the change to slot 1.
+*/
+#[derive(Debug)]
+struct PairedSlotMove {
+    notifies_both: bool,
+}
+
+impl Move<UndoSlotSolution> for PairedSlotMove {
+    type Undo = [Option<usize>; 2];
+
+    fn is_doable<D: Director<UndoSlotSolution>>(&self, _score_director: &D) -> bool {
+        true
+    }
+
+    fn do_move<D: Director<UndoSlotSolution>>(&self, score_director: &mut D) -> Self::Undo {
+        let mut previous = [None; 2];
+        for (entity_index, slot) in previous.iter_mut().enumerate() {
+            score_director.before_variable_changed(0, entity_index);
+            *slot = score_director.working_solution().slots[entity_index].value;
+            score_director.working_solution_mut().slots[entity_index].value = Some(2);
+            score_director.after_variable_changed(0, entity_index);
+        }
+        previous
+    }
+
+    fn undo_move<D: Director<UndoSlotSolution>>(&self, score_director: &mut D, undo: Self::Undo) {
+        for (entity_index, value) in undo.into_iter().enumerate() {
+            let notifies = entity_index == 0 || self.notifies_both;
+            if notifies {
+                score_director.before_variable_changed(0, entity_index);
+            }
+            score_director.working_solution_mut().slots[entity_index].value = value;
+            if notifies {
+                score_director.after_variable_changed(0, entity_index);
+            }
+        }
+    }
+
+    fn descriptor_index(&self) -> usize {
+        0
+    }
+
+    fn entity_indices(&self) -> &[usize] {
+        &[0, 1]
+    }
+
+    fn variable_name(&self) -> &str {
+        "value"
+    }
+
+    fn tabu_signature<D: Director<UndoSlotSolution>>(
+        &self,
+        _score_director: &D,
+    ) -> crate::heuristic::r#move::MoveTabuSignature {
+        let scope = crate::heuristic::r#move::metadata::MoveTabuScope::new(0, "value");
+        let identity = crate::heuristic::r#move::metadata::hash_str("paired_slot_move");
+        crate::heuristic::r#move::MoveTabuSignature::new(
+            scope,
+            smallvec::smallvec![identity],
+            smallvec::smallvec![identity],
+        )
+    }
+}
+
+#[derive(Debug)]
+struct PairedSlotSelector {
+    notifies_both: bool,
+}
+
+impl MoveSelector<UndoSlotSolution, PairedSlotMove> for PairedSlotSelector {
+    type Cursor<'a>
+        = ArenaMoveCursor<UndoSlotSolution, PairedSlotMove>
+    where
+        Self: 'a;
+
+    fn open_cursor<'a, D: Director<UndoSlotSolution>>(
+        &'a self,
+        _score_director: &D,
+    ) -> Self::Cursor<'a> {
+        ArenaMoveCursor::from_moves(std::iter::once(PairedSlotMove {
+            notifies_both: self.notifies_both,
+        }))
+    }
+
+    fn size<D: Director<UndoSlotSolution>>(&self, _score_director: &D) -> usize {
+        1
+    }
+}
+
+fn solve_paired_slots(notifies_both: bool) {
+    let director = SummingSlotDirector::new(vec![Some(0), Some(1)]);
+    let mut solver_scope =
+        SolverScope::new(director).with_environment_mode(EnvironmentMode::FullAssert);
+    solver_scope.start_solving();
+    solver_scope.calculate_score();
+
+    let acceptor = HillClimbingAcceptor::new();
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1, false);
+    let mut phase: LocalSearchPhase<_, PairedSlotMove, _, _, _> = LocalSearchPhase::new(
+        PairedSlotSelector { notifies_both },
+        acceptor,
+        forager,
+        Some(1),
+    );
+
+    phase.solve(&mut solver_scope);
+}
+
+#[test]
+fn full_assert_accepts_a_move_whose_undo_restores_the_score() {
+    solve_paired_slots(true);
+}
+
+#[test]
+#[should_panic(
+    expected = "did not restore the score: restored score SoftScore(2) != pre-move score SoftScore(1)"
+)]
+fn full_assert_detects_an_undo_that_skips_a_change_notification() {
+    solve_paired_slots(false);
+}
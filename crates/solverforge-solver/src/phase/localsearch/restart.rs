@@ -0,0 +1,177 @@
+//! Bounded perturbation restarts for local search that stops applying moves.
+
+use rand::RngExt;
+use solverforge_core::domain::PlanningSolution;
+use solverforge_scoring::Director;
+
+use crate::heuristic::r#move::Move;
+use crate::heuristic::selector::move_selector::{
+    CandidateId, MoveStreamContext, ResourceMoveCursor,
+};
+use crate::phase::localsearch::MoveCursorSource;
+use crate::scope::{PhaseScope, ProgressCallback, StepScope};
+
+/// Restart policy for a local-search phase stuck in a local optimum.
+///
+/// After `stuck_step_count` consecutive steps without an applied move, the
+/// phase applies `perturbation_size` uniformly drawn doable moves from its own
+/// move source regardless of score, then restarts the acceptor from the
+/// perturbed score; simulated annealing reheats, tabu memories clear. The
+/// best solution is tracked as usual, so a perturbation never loses it. At
+/// most `max_restarts` restarts happen per phase run.
+///
+/// # Example
+///
+/// ```
+/// use solverforge_solver::RestartStrategy;
+///
+/// let restart = RestartStrategy::new(10)
+///     .with_stuck_step_count(3)
+///     .with_perturbation_size(2);
+/// assert_eq!(restart.max_restarts(), 10);
+/// assert_eq!(restart.stuck_step_count(), 3);
+/// assert_eq!(restart.perturbation_size(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartStrategy {
+    max_restarts: u64,
+    stuck_step_count: u64,
+    perturbation_size: usize,
+}
+
+impl RestartStrategy {
+    /// Restarts after one idle step with a single perturbation move.
+    pub fn new(max_restarts: u64) -> Self {
+        Self {
+            max_restarts,
+            stuck_step_count: 1,
+            perturbation_size: 1,
+        }
+    }
+
+    pub fn with_stuck_step_count(mut self, stuck_step_count: u64) -> Self {
+        assert!(stuck_step_count > 0, "stuck_step_count must be >= 1");
+        self.stuck_step_count = stuck_step_count;
+        self
+    }
+
+    pub fn with_perturbation_size(mut self, perturbation_size: usize) -> Self {
+        assert!(perturbation_size > 0, "perturbation_size must be >= 1");
+        self.perturbation_size = perturbation_size;
+        self
+    }
+
+    pub fn max_restarts(&self) -> u64 {
+        self.max_restarts
+    }
+
+    pub fn stuck_step_count(&self) -> u64 {
+        self.stuck_step_count
+    }
+
+    pub fn perturbation_size(&self) -> usize {
+        self.perturbation_size
+    }
+}
+
+// Per-run bookkeeping of idle steps and spent restarts.
+#[derive(Debug, Default)]
+pub(super) struct RestartProgress {
+    idle_steps: u64,
+    restarts: u64,
+}
+
+impl RestartProgress {
+    pub(super) fn record_applied(&mut self) {
+        self.idle_steps = 0;
+    }
+
+    // Records an idle step and reports whether the strategy now restarts.
+    pub(super) fn record_idle(&mut self, strategy: &RestartStrategy) -> bool {
+        self.idle_steps += 1;
+        if self.idle_steps < strategy.stuck_step_count || self.restarts >= strategy.max_restarts {
+            return false;
+        }
+        self.idle_steps = 0;
+        self.restarts += 1;
+        true
+    }
+}
+
+/* Applies up to `perturbation_size` random doable moves, one per step.
+
+Each perturbation move is drawn uniformly by reservoir sampling over one
+freshly seeded cursor, so losers are released as soon as they are displaced.
+Returns the score after the last applied move, or `None` when nothing was
+applied.
+*/
+pub(super) fn perturb<S, D, BestCb, M, Source>(
+    move_source: &mut Source,
+    resources: &mut Source::Resources,
+    perturbation_size: usize,
+    phase_scope: &mut PhaseScope<'_, '_, S, D, BestCb>,
+) -> Option<S::Score>
+where
+    S: PlanningSolution,
+    D: Director<S>,
+    BestCb: ProgressCallback<S>,
+    M: Move<S>,
+    Source: MoveCursorSource<S, M>,
+{
+    let mut perturbed_score = None;
+    for _ in 0..perturbation_size {
+        if phase_scope.solver_scope_mut().should_terminate() {
+            break;
+        }
+        let mut step_scope = StepScope::new(phase_scope);
+        let step_seed = step_scope
+            .phase_scope_mut()
+            .solver_scope_mut()
+            .rng()
+            .random::<u64>();
+        let stream_context = MoveStreamContext::new(step_scope.step_index(), step_seed, None);
+        let mut cursor =
+            move_source.open_cursor(resources, step_scope.score_director(), stream_context);
+
+        let mut chosen: Option<CandidateId> = None;
+        let mut doable_count = 0u64;
+        while let Some(candidate_id) = cursor.next_candidate_with_resources(resources) {
+            let doable = cursor
+                .candidate(candidate_id)
+                .expect("newly generated perturbation candidate must remain live")
+                .is_doable(step_scope.score_director());
+            if !doable {
+                assert!(cursor.release_candidate(candidate_id));
+                continue;
+            }
+            doable_count += 1;
+            let keep = step_scope
+                .phase_scope_mut()
+                .solver_scope_mut()
+                .rng()
+                .random_range(0..doable_count)
+                == 0;
+            if keep {
+                if let Some(replaced_id) = chosen.replace(candidate_id) {
+                    assert!(cursor.release_candidate(replaced_id));
+                }
+            } else {
+                assert!(cursor.release_candidate(candidate_id));
+            }
+        }
+
+        let Some(selected_id) = chosen else {
+            break;
+        };
+        step_scope.apply_committed_change(|score_director| {
+            cursor.apply_owned_candidate(selected_id, score_director);
+        });
+        step_scope.phase_scope_mut().record_move_applied();
+        let score = step_scope.calculate_score();
+        step_scope.set_step_score(score);
+        step_scope.phase_scope_mut().update_best_solution();
+        step_scope.complete();
+        perturbed_score = Some(score);
+    }
+    perturbed_score
+}
@@ -11,8 +11,8 @@ use crate::heuristic::selector::move_selector::{
     CandidateId, MoveStreamContext, ResourceMoveCursor,
 };
 use crate::phase::control::{
-    settle_search_interrupt, should_interrupt_after_step, should_interrupt_before_candidate,
-    should_interrupt_before_evaluation, StepInterrupt,
+    has_no_planning_entities, settle_search_interrupt, should_interrupt_after_step,
+    should_interrupt_before_candidate, should_interrupt_before_evaluation, StepInterrupt,
 };
 use crate::phase::localsearch::evaluation::{
     evaluate_candidate, record_evaluated_move, CandidateEvaluation,
@@ -54,8 +54,13 @@ pub(crate) fn solve_vnd_with_resources<S, D, ProgressCb, M, Source>(
         score = %current_score,
     );
     phase_scope.report_progress();
+    for neighborhood in neighborhoods.iter_mut() {
+        neighborhood.phase_started();
+    }
 
-    while k < neighborhoods.len() {
+    // An entity-free solution has nothing to descend over.
+    let entity_free = has_no_planning_entities(phase_scope.score_director());
+    while !entity_free && k < neighborhoods.len() {
         if phase_scope.solver_scope_mut().should_terminate() {
             break;
         }
@@ -125,6 +130,10 @@ pub(crate) fn solve_vnd_with_resources<S, D, ProgressCb, M, Source>(
                     step_scope.phase_scope_mut().record_move_accepted();
                     step_scope.phase_scope_mut().record_move_applied();
                 }
+                drop(cursor);
+                for neighborhood in neighborhoods.iter_mut() {
+                    neighborhood.solution_changed();
+                }
                 step_scope.set_step_score(selected_score);
                 current_score = selected_score;
                 step_scope.phase_scope_mut().update_best_solution();
@@ -1,14 +1,16 @@
 // Partitioned search phase configuration.
 
-use solverforge_config::MoveThreadCount;
+use solverforge_config::{MoveThreadCount, PartitionCountMode};
 
-use super::partitioner::ThreadCount;
+use super::partitioner::{PartitionCount, ThreadCount};
 
 // Configuration for partitioned search phase.
 #[derive(Debug, Clone)]
 pub struct PartitionedSearchConfig {
     // Thread count configuration.
     pub thread_count: ThreadCount,
+    // Number of partitions to request from the partitioner.
+    pub partition_count: PartitionCount,
     // Whether to log partition progress.
     pub log_progress: bool,
 }
@@ -17,6 +19,7 @@ impl Default for PartitionedSearchConfig {
     fn default() -> Self {
         Self {
             thread_count: ThreadCount::Auto,
+            partition_count: PartitionCount::FromPartitioner,
             log_progress: false,
         }
     }
@@ -30,6 +33,11 @@ impl PartitionedSearchConfig {
                 MoveThreadCount::None => ThreadCount::Specific(1),
                 MoveThreadCount::Count(count) => ThreadCount::Specific(count.max(1)),
             },
+            partition_count: match config.partition_count {
+                PartitionCountMode::Partitioner => PartitionCount::FromPartitioner,
+                PartitionCountMode::Auto => PartitionCount::Auto,
+                PartitionCountMode::Count(count) => PartitionCount::Specific(count.max(1)),
+            },
             log_progress: config.log_progress,
         }
     }
@@ -12,10 +12,11 @@ Partitioned search splits a large problem into independent sub-problems
 # Example
 
 ```
-use solverforge_solver::phase::partitioned::{PartitionedSearchConfig, ThreadCount};
+use solverforge_solver::phase::partitioned::{PartitionCount, PartitionedSearchConfig, ThreadCount};
 
 let config = PartitionedSearchConfig {
 thread_count: ThreadCount::Specific(4),
+partition_count: PartitionCount::Auto,
 log_progress: true,
 };
 ```
@@ -28,5 +29,7 @@ mod phase;
 
 pub use child_phases::ChildPhases;
 pub use config::PartitionedSearchConfig;
-pub use partitioner::{FunctionalPartitioner, SolutionPartitioner, ThreadCount};
+pub use partitioner::{
+    BalancedPartitioner, FunctionalPartitioner, PartitionCount, SolutionPartitioner, ThreadCount,
+};
 pub use phase::PartitionedSearchPhase;
@@ -5,6 +5,7 @@ solved independently (potentially in parallel), then merged back together.
 */
 
 use std::fmt::Debug;
+use std::ops::Range;
 
 use solverforge_core::domain::PlanningSolution;
 
@@ -64,6 +65,17 @@ pub trait SolutionPartitioner<S: PlanningSolution>: Send + Sync + Debug {
     fn recommended_partition_count(&self) -> Option<usize> {
         None
     }
+
+    /* Splits the solution into a requested number of partitions.
+
+    Called instead of `partition` when the phase resolves a partition
+    count from its configuration. Partitioners that cannot honor a
+    requested count fall back to their own split.
+    */
+    fn partition_into(&self, solution: &S, partition_count: usize) -> Vec<S> {
+        let _ = partition_count;
+        self.partition(solution)
+    }
 }
 
 /// A simple partitioner that creates a specified number of partitions.
@@ -135,6 +147,86 @@ where
     }
 }
 
+/// A partitioner that splits entities into evenly sized contiguous ranges.
+///
+/// Entity ranges differ in size by at most one. Without a requested count
+/// the partitioner produces one partition per available CPU core; the
+/// count is always clamped to the number of entities.
+pub struct BalancedPartitioner<S> {
+    entity_count: fn(&S) -> usize,
+    extract: fn(&S, Range<usize>) -> S,
+    merge: fn(&S, Vec<S>) -> S,
+    partition_count: Option<usize>,
+}
+
+impl<S> BalancedPartitioner<S> {
+    pub fn new(
+        entity_count: fn(&S) -> usize,
+        extract: fn(&S, Range<usize>) -> S,
+        merge: fn(&S, Vec<S>) -> S,
+    ) -> Self {
+        Self {
+            entity_count,
+            extract,
+            merge,
+            partition_count: None,
+        }
+    }
+
+    pub fn with_partition_count(mut self, count: usize) -> Self {
+        self.partition_count = Some(count.max(1));
+        self
+    }
+
+    /// Returns the balanced entity ranges for `entity_count` entities.
+    pub fn ranges(entity_count: usize, partition_count: usize) -> Vec<Range<usize>> {
+        let partition_count = partition_count.clamp(1, entity_count.max(1));
+        let base = entity_count / partition_count;
+        let remainder = entity_count % partition_count;
+        let mut start = 0;
+        (0..partition_count)
+            .map(|index| {
+                let len = base + usize::from(index < remainder);
+                let range = start..start + len;
+                start += len;
+                range
+            })
+            .collect()
+    }
+}
+
+impl<S> Debug for BalancedPartitioner<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BalancedPartitioner")
+            .field("partition_count", &self.partition_count)
+            .finish()
+    }
+}
+
+impl<S: PlanningSolution> SolutionPartitioner<S> for BalancedPartitioner<S> {
+    fn partition(&self, solution: &S) -> Vec<S> {
+        let count = self
+            .partition_count
+            .unwrap_or_else(|| ThreadCount::Unlimited.resolve(usize::MAX));
+        self.partition_into(solution, count)
+    }
+
+    fn partition_into(&self, solution: &S, partition_count: usize) -> Vec<S> {
+        Self::ranges((self.entity_count)(solution), partition_count)
+            .into_iter()
+            .map(|range| (self.extract)(solution, range))
+            .collect()
+    }
+
+    fn merge(&self, original: &S, partitions: Vec<S>) -> S {
+        (self.merge)(original, partitions)
+    }
+
+    fn recommended_partition_count(&self) -> Option<usize> {
+        self.partition_count
+    }
+}
+
 // Thread count configuration for partitioned search.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ThreadCount {
@@ -173,6 +265,41 @@ impl ThreadCount {
     }
 }
 
+// Partition count configuration for partitioned search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionCount {
+    // Let the partitioner decide how many partitions to create.
+    #[default]
+    FromPartitioner,
+    // One partition per worker thread the thread count resolves to.
+    Auto,
+    // Request a specific number of partitions.
+    Specific(usize),
+}
+
+impl PartitionCount {
+    /// Resolves the partition count requested from the partitioner.
+    ///
+    /// Returns `None` when the partitioner should choose its own split.
+    pub fn resolve(&self, thread_count: ThreadCount) -> Option<usize> {
+        let cpus = std::thread::available_parallelism()
+            .map(|p| p.get())
+            .unwrap_or(1);
+        self.resolve_with(thread_count, cpus)
+    }
+
+    fn resolve_with(&self, thread_count: ThreadCount, cpus: usize) -> Option<usize> {
+        match self {
+            PartitionCount::FromPartitioner => None,
+            PartitionCount::Auto => Some(match thread_count {
+                ThreadCount::Auto | ThreadCount::Unlimited => cpus.max(1),
+                ThreadCount::Specific(n) => n.max(1),
+            }),
+            PartitionCount::Specific(n) => Some((*n).max(1)),
+        }
+    }
+}
+
 impl std::fmt::Display for ThreadCount {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -97,3 +97,75 @@ fn test_partitioner_debug() {
     assert!(debug.contains("FunctionalPartitioner"));
     assert!(debug.contains("recommended_count"));
 }
+
+fn values_len(solution: &TestSolution) -> usize {
+    solution.values.len()
+}
+
+fn extract_values(solution: &TestSolution, range: std::ops::Range<usize>) -> TestSolution {
+    TestSolution {
+        values: solution.values[range].to_vec(),
+        score: None,
+    }
+}
+
+fn concat_values(_original: &TestSolution, partitions: Vec<TestSolution>) -> TestSolution {
+    TestSolution {
+        values: partitions.into_iter().flat_map(|p| p.values).collect(),
+        score: None,
+    }
+}
+
+#[test]
+fn test_partition_count_resolve() {
+    assert_eq!(
+        PartitionCount::FromPartitioner.resolve_with(ThreadCount::Auto, 4),
+        None
+    );
+    assert_eq!(
+        PartitionCount::Auto.resolve_with(ThreadCount::Auto, 4),
+        Some(4)
+    );
+    assert_eq!(
+        PartitionCount::Auto.resolve_with(ThreadCount::Unlimited, 4),
+        Some(4)
+    );
+    assert_eq!(
+        PartitionCount::Auto.resolve_with(ThreadCount::Specific(2), 4),
+        Some(2)
+    );
+    assert_eq!(
+        PartitionCount::Specific(0).resolve_with(ThreadCount::Auto, 4),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_auto_partition_count_balances_entities_across_cpus() {
+    let partitioner = BalancedPartitioner::new(values_len, extract_values, concat_values);
+    let solution = TestSolution {
+        values: (0..100).collect(),
+        score: None,
+    };
+
+    let count = PartitionCount::Auto
+        .resolve_with(ThreadCount::Auto, 4)
+        .unwrap();
+    let partitions = partitioner.partition_into(&solution, count);
+
+    let sizes: Vec<usize> = partitions.iter().map(|p| p.values.len()).collect();
+    assert_eq!(sizes, vec![25, 25, 25, 25]);
+
+    let merged = partitioner.merge(&solution, partitions);
+    assert_eq!(merged.values, solution.values);
+}
+
+#[test]
+fn test_balanced_ranges_spread_remainder_and_clamp_to_entities() {
+    let sizes: Vec<usize> = BalancedPartitioner::<TestSolution>::ranges(10, 4)
+        .into_iter()
+        .map(|range| range.len())
+        .collect();
+    assert_eq!(sizes, vec![3, 3, 2, 2]);
+    assert_eq!(BalancedPartitioner::<TestSolution>::ranges(3, 8).len(), 3);
+}
@@ -7,6 +7,7 @@ use rand::RngExt;
 use rayon::prelude::*;
 use rayon::ThreadPoolBuilder;
 use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::Score;
 use solverforge_scoring::Director;
 
 use crate::manager::SolverTerminalReason;
@@ -43,7 +44,17 @@ enum PartitionOutcome<S> {
 /// * `SDF` - The score director factory function type
 /// * `PF` - The phase factory function type
 /// * `CP` - The child phases type (tuple of phases)
-pub struct PartitionedSearchPhase<S, PD, Part, SDF, PF, CP>
+/// * `RF` - The merge-repair phase factory function type
+///
+/// # Merge Conflicts
+///
+/// Partitions that share value-range resources can each be optimal while
+/// their merge is not. After `with_merge_tolerance`, the phase compares the
+/// recomputed merged score with the sum of the partition scores and warns
+/// when the merge is worse by more than the tolerance. A factory installed
+/// with `with_merge_repair` then runs its phases on the merged solution in
+/// a child scope and keeps the repaired result.
+pub struct PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF = fn() -> CP>
 where
     S: PlanningSolution,
     PD: Director<S>,
@@ -51,6 +62,7 @@ where
     SDF: Fn(S) -> PD + Send + Sync,
     PF: Fn() -> CP + Send + Sync,
     CP: ChildPhases<S, PD>,
+    RF: Fn() -> CP + Send + Sync,
 {
     // The partitioner that splits and merges solutions.
     partitioner: Part,
@@ -64,6 +76,12 @@ where
     // Configuration for this phase.
     config: PartitionedSearchConfig,
 
+    // Allowed shortfall of the merged score below the partition score sum.
+    merge_tolerance: Option<S::Score>,
+
+    // Factory for phases that repair a conflicting merge.
+    merge_repair: Option<RF>,
+
     _marker: PhantomData<(fn() -> S, fn() -> PD, fn() -> CP)>,
 }
 
@@ -82,6 +100,8 @@ where
             score_director_factory,
             phase_factory,
             config: PartitionedSearchConfig::default(),
+            merge_tolerance: None,
+            merge_repair: None,
             _marker: PhantomData,
         }
     }
@@ -97,12 +117,53 @@ where
             score_director_factory,
             phase_factory,
             config,
+            merge_tolerance: None,
+            merge_repair: None,
             _marker: PhantomData,
         }
     }
 }
 
-impl<S, PD, Part, SDF, PF, CP> Debug for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP>
+impl<S, PD, Part, SDF, PF, CP, RF> PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF>
+where
+    S: PlanningSolution,
+    PD: Director<S>,
+    Part: SolutionPartitioner<S>,
+    SDF: Fn(S) -> PD + Send + Sync,
+    PF: Fn() -> CP + Send + Sync,
+    CP: ChildPhases<S, PD>,
+    RF: Fn() -> CP + Send + Sync,
+{
+    /// Enables the post-merge conflict check with the given tolerance.
+    pub fn with_merge_tolerance(mut self, tolerance: S::Score) -> Self {
+        self.merge_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Repairs conflicting merges with phases from `repair_factory`.
+    ///
+    /// Enables the conflict check with a zero tolerance unless
+    /// `with_merge_tolerance` already set one.
+    pub fn with_merge_repair<RF2>(
+        self,
+        repair_factory: RF2,
+    ) -> PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF2>
+    where
+        RF2: Fn() -> CP + Send + Sync,
+    {
+        PartitionedSearchPhase {
+            partitioner: self.partitioner,
+            score_director_factory: self.score_director_factory,
+            phase_factory: self.phase_factory,
+            config: self.config,
+            merge_tolerance: Some(self.merge_tolerance.unwrap_or_else(S::Score::zero)),
+            merge_repair: Some(repair_factory),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, PD, Part, SDF, PF, CP, RF> Debug for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF>
 where
     S: PlanningSolution,
     PD: Director<S>,
@@ -110,17 +171,20 @@ where
     SDF: Fn(S) -> PD + Send + Sync,
     PF: Fn() -> CP + Send + Sync,
     CP: ChildPhases<S, PD>,
+    RF: Fn() -> CP + Send + Sync,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PartitionedSearchPhase")
             .field("partitioner", &self.partitioner)
             .field("config", &self.config)
+            .field("merge_tolerance", &self.merge_tolerance)
+            .field("merge_repair", &self.merge_repair.is_some())
             .finish()
     }
 }
 
-impl<S, D, BestCb, PD, Part, SDF, PF, CP> Phase<S, D, BestCb>
-    for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP>
+impl<S, D, BestCb, PD, Part, SDF, PF, CP, RF> Phase<S, D, BestCb>
+    for PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF>
 where
     S: PlanningSolution + 'static,
     D: Director<S>,
@@ -130,6 +194,7 @@ where
     SDF: Fn(S) -> PD + Send + Sync,
     PF: Fn() -> CP + Send + Sync,
     CP: ChildPhases<S, PD> + Send,
+    RF: Fn() -> CP + Send + Sync,
 {
     fn solve(&mut self, solver_scope: &mut SolverScope<S, D, BestCb>) {
         'partitioning: loop {
@@ -138,7 +203,14 @@ where
             }
 
             let solution = solver_scope.score_director().working_solution().clone();
-            let partitions = self.partitioner.partition(&solution);
+            let partitions = match self
+                .config
+                .partition_count
+                .resolve(self.config.thread_count)
+            {
+                Some(count) => self.partitioner.partition_into(&solution, count),
+                None => self.partitioner.partition(&solution),
+            };
             let partition_count = partitions.len();
 
             if partition_count == 0 {
@@ -182,10 +254,43 @@ where
                 return;
             }
 
+            let partition_score = solved_partitions
+                .iter()
+                .map(PlanningSolution::score)
+                .try_fold(S::Score::zero(), |total, score| Some(total + score?));
             let merged = self.partitioner.merge(&solution, solved_partitions);
-            solver_scope.replace_working_solution_and_reinitialize(merged);
+            let merged_score = solver_scope.replace_working_solution_and_reinitialize(merged);
             solver_scope.update_best_solution();
 
+            if self.merge_conflicts(merged_score, partition_score) {
+                tracing::warn!(
+                    event = "partition_merge_conflict",
+                    merged_score = %format!("{:?}", merged_score),
+                    partition_score = %format!("{:?}", partition_score),
+                );
+                if let Some(repair_factory) = &self.merge_repair {
+                    let seed = solver_scope.rng().random();
+                    let phase_budget = solver_scope.child_phase_budget();
+                    let child_config = solver_scope.child_config(Some(&phase_budget));
+                    let merged = solver_scope.working_solution().clone();
+                    match self.solve_child(merged, child_config, seed, repair_factory()) {
+                        PartitionOutcome::Complete(repaired) => {
+                            solver_scope.replace_working_solution_and_reinitialize(repaired);
+                            solver_scope.update_best_solution();
+                        }
+                        PartitionOutcome::Pause => solver_scope.pause_if_requested(),
+                        PartitionOutcome::Cancelled => {
+                            solver_scope.mark_cancelled();
+                            return;
+                        }
+                        PartitionOutcome::Terminated => {
+                            solver_scope.mark_terminated_by_config();
+                            return;
+                        }
+                    }
+                }
+            }
+
             if self.config.log_progress {
                 if let Some(score) = solver_scope.best_score() {
                     tracing::info!(
@@ -205,7 +310,7 @@ where
     }
 }
 
-impl<S, PD, Part, SDF, PF, CP> PartitionedSearchPhase<S, PD, Part, SDF, PF, CP>
+impl<S, PD, Part, SDF, PF, CP, RF> PartitionedSearchPhase<S, PD, Part, SDF, PF, CP, RF>
 where
     S: PlanningSolution,
     PD: Director<S>,
@@ -213,16 +318,36 @@ where
     SDF: Fn(S) -> PD + Send + Sync,
     PF: Fn() -> CP + Send + Sync,
     CP: ChildPhases<S, PD>,
+    RF: Fn() -> CP + Send + Sync,
 {
+    // Whether the merged score falls short of the partition sum by more than the tolerance.
+    fn merge_conflicts(&self, merged_score: S::Score, partition_score: Option<S::Score>) -> bool {
+        match (self.merge_tolerance, partition_score) {
+            (Some(tolerance), Some(partition_score)) => merged_score < partition_score - tolerance,
+            _ => false,
+        }
+    }
+
     // Solves a single partition and returns the solved solution.
     fn solve_partition<'t>(
         &self,
         partition: S,
         child_config: SolverScopeChildConfig<'t, S>,
         seed: u64,
+    ) -> PartitionOutcome<S> {
+        self.solve_child(partition, child_config, seed, (self.phase_factory)())
+    }
+
+    // Runs `phases` on `solution` in a fresh child scope.
+    fn solve_child<'t>(
+        &self,
+        solution: S,
+        child_config: SolverScopeChildConfig<'t, S>,
+        seed: u64,
+        mut phases: CP,
     ) -> PartitionOutcome<S> {
         // Create score director for this partition
-        let director = (self.score_director_factory)(partition);
+        let director = (self.score_director_factory)(solution);
 
         // Create solver scope
         let mut solver_scope = child_config.build_scope(director, seed);
@@ -231,8 +356,6 @@ where
         }
         solver_scope.initialize_working_solution_as_best();
 
-        // Create and run child phases
-        let mut phases = (self.phase_factory)();
         phases.solve_all(&mut solver_scope);
 
         match solver_scope.pending_control() {
@@ -287,5 +410,4 @@ where
 }
 
 #[cfg(test)]
-#[path = "phase_tests.rs"]
 mod tests;
@@ -0,0 +1,18 @@
+use std::any::TypeId;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
+use solverforge_core::score::SoftScore;
+use solverforge_scoring::Director;
+
+use crate::manager::SolverTerminalReason;
+
+use super::super::partitioner::{FunctionalPartitioner, PartitionCount, ThreadCount};
+use super::*;
+
+include!("tests/support.rs");
+include!("tests/lifecycle.rs");
+include!("tests/limits.rs");
+include!("tests/merge_repair.rs");
@@ -0,0 +1,184 @@
+#[test]
+fn test_config_default() {
+    let config = PartitionedSearchConfig::default();
+    assert_eq!(config.thread_count, ThreadCount::Auto);
+    assert!(!config.log_progress);
+}
+
+#[test]
+fn partitioned_search_reinitializes_after_merge() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone()],
+        |_original, _partitions| PartitionedLifecycleSolution {
+            value: 7,
+            shadow: 0,
+            score: None,
+        },
+    );
+    let mut phase =
+        PartitionedSearchPhase::new(partitioner, PartitionedLifecycleDirector::new, || {
+            (SetValuePhase { value: 1 },)
+        });
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().value, 7);
+    assert_eq!(solver_scope.working_solution().shadow, 70);
+    assert_eq!(
+        solver_scope.current_score().copied(),
+        Some(SoftScore::of(70))
+    );
+    assert_eq!(solver_scope.best_score().copied(), Some(SoftScore::of(70)));
+}
+
+#[test]
+fn partitioned_search_bootstraps_child_scopes_before_mutation() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone()],
+        |_original, mut partitions| partitions.pop().unwrap(),
+    );
+    let mut phase =
+        PartitionedSearchPhase::new(partitioner, PartitionedLifecycleDirector::new, || {
+            (SetValuePhase { value: 5 },)
+        });
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(solver_scope.working_solution().value, 5);
+    assert_eq!(solver_scope.working_solution().shadow, 50);
+    assert_eq!(solver_scope.best_score().copied(), Some(SoftScore::of(50)));
+}
+
+#[test]
+fn partitioned_search_honors_specific_thread_count() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+
+    let observed_threads = Arc::new(AtomicUsize::new(0));
+    let observed_threads_for_phase = Arc::clone(&observed_threads);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone(), solution.clone()],
+        |_original, mut partitions| partitions.pop().unwrap(),
+    );
+    let config = PartitionedSearchConfig {
+        thread_count: ThreadCount::Specific(2),
+        partition_count: PartitionCount::FromPartitioner,
+        log_progress: false,
+    };
+    let mut phase = PartitionedSearchPhase::with_config(
+        partitioner,
+        PartitionedLifecycleDirector::new,
+        move || {
+            (ObservePoolPhase {
+                observed_threads: Arc::clone(&observed_threads_for_phase),
+            },)
+        },
+        config,
+    );
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(observed_threads.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn partitioned_search_does_not_merge_cancelled_children() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+
+    let merge_count = Arc::new(AtomicUsize::new(0));
+    let merge_count_for_partitioner = Arc::clone(&merge_count);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone()],
+        move |_original, _partitions| {
+            merge_count_for_partitioner.fetch_add(1, Ordering::SeqCst);
+            PartitionedLifecycleSolution {
+                value: 99,
+                shadow: 0,
+                score: None,
+            }
+        },
+    );
+    let mut phase =
+        PartitionedSearchPhase::new(partitioner, PartitionedLifecycleDirector::new, || {
+            (MarkCancelledPhase,)
+        });
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(merge_count.load(Ordering::SeqCst), 0);
+    assert_eq!(solver_scope.working_solution().value, 1);
+    assert_eq!(
+        solver_scope.terminal_reason(),
+        SolverTerminalReason::Cancelled
+    );
+}
+
+#[test]
+fn partitioned_search_does_not_merge_config_terminated_children() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+
+    let merge_count = Arc::new(AtomicUsize::new(0));
+    let merge_count_for_partitioner = Arc::clone(&merge_count);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone()],
+        move |_original, _partitions| {
+            merge_count_for_partitioner.fetch_add(1, Ordering::SeqCst);
+            PartitionedLifecycleSolution {
+                value: 99,
+                shadow: 0,
+                score: None,
+            }
+        },
+    );
+    let mut phase =
+        PartitionedSearchPhase::new(partitioner, PartitionedLifecycleDirector::new, || {
+            (MarkTerminatedPhase,)
+        });
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(merge_count.load(Ordering::SeqCst), 0);
+    assert_eq!(solver_scope.working_solution().value, 1);
+    assert_eq!(
+        solver_scope.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+}
@@ -0,0 +1,131 @@
+#[test]
+fn sequential_partitioned_search_shares_step_limit_across_children() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+    solver_scope.inphase_step_count_limit = Some(2);
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let attempts_for_phase = Arc::clone(&attempts);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone(), solution.clone()],
+        |_original, mut partitions| partitions.pop().unwrap(),
+    );
+    let config = PartitionedSearchConfig {
+        thread_count: ThreadCount::Specific(1),
+        partition_count: PartitionCount::FromPartitioner,
+        log_progress: false,
+    };
+    let mut phase = PartitionedSearchPhase::with_config(
+        partitioner,
+        PartitionedLifecycleDirector::new,
+        move || {
+            (CountStepsPhase {
+                attempts: Arc::clone(&attempts_for_phase),
+                max_steps: 3,
+            },)
+        },
+        config,
+    );
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        solver_scope.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+}
+
+#[test]
+fn sequential_partitioned_search_shares_move_limit_across_children() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+    solver_scope.inphase_move_count_limit = Some(2);
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let attempts_for_phase = Arc::clone(&attempts);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone(), solution.clone()],
+        |_original, mut partitions| partitions.pop().unwrap(),
+    );
+    let config = PartitionedSearchConfig {
+        thread_count: ThreadCount::Specific(1),
+        partition_count: PartitionCount::FromPartitioner,
+        log_progress: false,
+    };
+    let mut phase = PartitionedSearchPhase::with_config(
+        partitioner,
+        PartitionedLifecycleDirector::new,
+        move || {
+            (CountMovesPhase {
+                attempts: Arc::clone(&attempts_for_phase),
+                max_moves: 3,
+            },)
+        },
+        config,
+    );
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        solver_scope.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+}
+
+#[test]
+fn sequential_partitioned_search_shares_score_limit_across_children() {
+    let solution = PartitionedLifecycleSolution {
+        value: 1,
+        shadow: 10,
+        score: None,
+    };
+    let director = PartitionedLifecycleDirector::new(solution);
+    let mut solver_scope = SolverScope::new(director);
+    solver_scope.initialize_working_solution_as_best();
+    solver_scope.inphase_score_calc_count_limit = Some(3);
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let attempts_for_phase = Arc::clone(&attempts);
+    let partitioner = FunctionalPartitioner::new(
+        |solution: &PartitionedLifecycleSolution| vec![solution.clone(), solution.clone()],
+        |_original, mut partitions| partitions.pop().unwrap(),
+    );
+    let config = PartitionedSearchConfig {
+        thread_count: ThreadCount::Specific(1),
+        partition_count: PartitionCount::FromPartitioner,
+        log_progress: false,
+    };
+    let mut phase = PartitionedSearchPhase::with_config(
+        partitioner,
+        PartitionedLifecycleDirector::new,
+        move || {
+            (CountScoresPhase {
+                attempts: Arc::clone(&attempts_for_phase),
+                max_scores: 3,
+            },)
+        },
+        config,
+    );
+
+    phase.solve(&mut solver_scope);
+
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        solver_scope.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+}
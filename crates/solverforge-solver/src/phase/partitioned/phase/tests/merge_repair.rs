@@ -0,0 +1,211 @@
+// Tasks share one-task-per-vehicle capacity that partitions cannot see across.
+#[derive(Clone, Debug)]
+struct FleetSolution {
+    vehicle_count: usize,
+    tasks: Vec<Option<usize>>,
+    score: Option<SoftScore>,
+}
+
+impl FleetSolution {
+    fn unassigned(task_count: usize, vehicle_count: usize) -> Self {
+        Self {
+            vehicle_count,
+            tasks: vec![None; task_count],
+            score: None,
+        }
+    }
+
+    fn evaluate(&self) -> SoftScore {
+        let mut loads = vec![0i64; self.vehicle_count];
+        let mut penalty = 0;
+        for task in &self.tasks {
+            match task {
+                Some(vehicle) => loads[*vehicle] += 1,
+                None => penalty += 2,
+            }
+        }
+        penalty += loads.iter().map(|load| (load - 1).max(0)).sum::<i64>();
+        SoftScore::of(-penalty)
+    }
+}
+
+impl PlanningSolution for FleetSolution {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+#[derive(Clone, Debug)]
+struct FleetDirector {
+    solution: FleetSolution,
+    descriptor: SolutionDescriptor,
+}
+
+impl FleetDirector {
+    fn new(solution: FleetSolution) -> Self {
+        Self {
+            solution,
+            descriptor: SolutionDescriptor::new("FleetSolution", TypeId::of::<FleetSolution>()),
+        }
+    }
+}
+
+impl Director<FleetSolution> for FleetDirector {
+    fn working_solution(&self) -> &FleetSolution {
+        &self.solution
+    }
+
+    fn working_solution_mut(&mut self) -> &mut FleetSolution {
+        &mut self.solution
+    }
+
+    fn calculate_score(&mut self) -> SoftScore {
+        let score = self.solution.evaluate();
+        self.solution.set_score(Some(score));
+        score
+    }
+
+    fn solution_descriptor(&self) -> &SolutionDescriptor {
+        &self.descriptor
+    }
+
+    fn clone_working_solution(&self) -> FleetSolution {
+        self.solution.clone()
+    }
+
+    fn before_variable_changed(&mut self, _descriptor_index: usize, _entity_index: usize) {}
+
+    fn after_variable_changed(&mut self, _descriptor_index: usize, _entity_index: usize) {}
+
+    fn entity_count(&self, _descriptor_index: usize) -> Option<usize> {
+        Some(self.solution.tasks.len())
+    }
+
+    fn total_entity_count(&self) -> Option<usize> {
+        Some(self.solution.tasks.len())
+    }
+
+    fn constraint_metadata(&self) -> Vec<solverforge_scoring::ConstraintMetadata<'_>> {
+        Vec::new()
+    }
+}
+
+// Greedy partitions all grab vehicle 0; the repair spreads tasks across vehicles.
+#[derive(Debug)]
+struct AssignVehiclesPhase {
+    spread: bool,
+    runs: Arc<AtomicUsize>,
+}
+
+impl<D, BestCb> Phase<FleetSolution, D, BestCb> for AssignVehiclesPhase
+where
+    D: Director<FleetSolution>,
+    BestCb: ProgressCallback<FleetSolution>,
+{
+    fn solve(&mut self, solver_scope: &mut SolverScope<'_, FleetSolution, D, BestCb>) {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        let spread = self.spread;
+        solver_scope.mutate(|score_director| {
+            let solution = score_director.working_solution_mut();
+            let vehicle_count = solution.vehicle_count;
+            for (index, task) in solution.tasks.iter_mut().enumerate() {
+                *task = Some(if spread { index % vehicle_count } else { 0 });
+            }
+        });
+        solver_scope.calculate_score();
+        solver_scope.update_best_solution();
+    }
+
+    fn phase_type_name(&self) -> &'static str {
+        "AssignVehicles"
+    }
+}
+
+// One partition per task; every partition sees the whole fleet.
+fn fleet_partitioner() -> impl SolutionPartitioner<FleetSolution> {
+    FunctionalPartitioner::new(
+        |solution: &FleetSolution| {
+            solution
+                .tasks
+                .iter()
+                .map(|task| FleetSolution {
+                    vehicle_count: solution.vehicle_count,
+                    tasks: vec![*task],
+                    score: None,
+                })
+                .collect()
+        },
+        |original: &FleetSolution, partitions: Vec<FleetSolution>| FleetSolution {
+            vehicle_count: original.vehicle_count,
+            tasks: partitions
+                .into_iter()
+                .flat_map(|partition| partition.tasks)
+                .collect(),
+            score: None,
+        },
+    )
+}
+
+fn solve_fleet_with_repair(tolerance: Option<SoftScore>) -> (SoftScore, usize) {
+    let mut solver_scope = SolverScope::new(FleetDirector::new(FleetSolution::unassigned(2, 2)));
+    solver_scope.initialize_working_solution_as_best();
+    let repairs = Arc::new(AtomicUsize::new(0));
+    let repairs_for_phase = Arc::clone(&repairs);
+    let partition_runs = Arc::new(AtomicUsize::new(0));
+
+    let phase = PartitionedSearchPhase::with_config(
+        fleet_partitioner(),
+        FleetDirector::new,
+        move || {
+            (AssignVehiclesPhase {
+                spread: false,
+                runs: Arc::clone(&partition_runs),
+            },)
+        },
+        PartitionedSearchConfig {
+            thread_count: ThreadCount::Specific(1),
+            partition_count: PartitionCount::FromPartitioner,
+            log_progress: false,
+        },
+    );
+    let phase = match tolerance {
+        Some(tolerance) => phase.with_merge_tolerance(tolerance),
+        None => phase,
+    };
+    let mut phase = phase.with_merge_repair(move || {
+        (AssignVehiclesPhase {
+            spread: true,
+            runs: Arc::clone(&repairs_for_phase),
+        },)
+    });
+
+    phase.solve(&mut solver_scope);
+
+    let score = solver_scope
+        .best_score()
+        .copied()
+        .expect("partitioned search should publish a best score");
+    (score, repairs.load(Ordering::SeqCst))
+}
+
+#[test]
+fn partitioned_search_repairs_merge_that_overshares_a_resource() {
+    let (score, repairs) = solve_fleet_with_repair(None);
+
+    assert_eq!(repairs, 1);
+    assert_eq!(score, SoftScore::of(0));
+}
+
+#[test]
+fn partitioned_search_accepts_merge_shortfall_within_tolerance() {
+    let (score, repairs) = solve_fleet_with_repair(Some(SoftScore::of(1)));
+
+    assert_eq!(repairs, 0);
+    assert_eq!(score, SoftScore::of(-1));
+}
@@ -11,6 +11,14 @@ Real-time planning enables dynamic updates to the problem during solving:
 - Update entity properties (e.g., deadline changes)
 - Modify problem facts (e.g., new constraints)
 
+# Wiring
+
+Pass the [`ProblemChangeReceiver`] from [`SolverHandle::new`] to
+`SolverScope::with_problem_change_receiver`, or queue changes directly with
+`SolverScope::submit_change`. Local search drains pending changes before each
+step, reinitializes the score director, and restarts from the changed working
+solution, which also becomes the new best solution.
+
 # Example
 
 ```
@@ -4,15 +4,14 @@ use std::fmt;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
-use std::time::Duration;
 
 #[cfg(test)]
 use std::path::Path;
 
-use solverforge_config::{SolverConfig, TerminationConfig};
+use solverforge_config::SolverConfig;
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::score::{ParseableScore, Score};
-use solverforge_scoring::{ConstraintSet, Director, ScoreDirector};
+use solverforge_scoring::{ConstraintSet, ScoreDirector};
 use tracing::info;
 
 use crate::builder::{RuntimeExtensionRegistry, Search};
@@ -23,29 +22,15 @@ use crate::runtime::compiler::executor::{
 };
 use crate::runtime::compiler::{compile_runtime_graph, CompiledRuntimeExecutor, RuntimeGraphInput};
 use crate::runtime_build_error::{RuntimeBuildError, RuntimeBuildResult};
-use crate::scope::{ProgressCallback, SolverProgressKind, SolverProgressRef, SolverScope};
-use crate::solver::{NoTermination, Solver};
-use crate::stats::{
-    format_duration, whole_units_per_second, CandidateTraceExecutionPolicy,
-    QualifiedCandidateTraceRunProvenance,
-};
-use crate::termination::{
-    BestScoreTermination, OrTermination, StepCountTermination, Termination, TimeTermination,
-    UnimprovedStepCountTermination, UnimprovedTimeTermination,
-};
+use crate::scope::{ProgressCallback, SolverProgressKind, SolverProgressRef};
+use crate::solver::Solver;
+use crate::stats::{format_duration, whole_units_per_second, QualifiedCandidateTraceRunProvenance};
 
-/// Monomorphized termination enum for config-driven solver configurations.
-///
-/// Avoids repeated branching across termination overloads by capturing the
-/// selected termination variant upfront.
-pub enum AnyTermination<S: PlanningSolution, D: Director<S>> {
-    None(NoTermination),
-    Default(OrTermination<(TimeTermination,), S, D>),
-    WithBestScore(OrTermination<(TimeTermination, BestScoreTermination<S::Score>), S, D>),
-    WithStepCount(OrTermination<(TimeTermination, StepCountTermination), S, D>),
-    WithUnimprovedStep(OrTermination<(TimeTermination, UnimprovedStepCountTermination<S>), S, D>),
-    WithUnimprovedTime(OrTermination<(TimeTermination, UnimprovedTimeTermination<S>), S, D>),
-}
+mod termination;
+
+use termination::configured_execution_policy;
+pub(crate) use termination::parse_configured_termination;
+pub use termination::{build_termination, AnyTermination};
 
 #[derive(Clone)]
 pub struct ChannelProgressCallback<S: PlanningSolution> {
@@ -92,291 +77,6 @@ impl<S: PlanningSolution> ProgressCallback<S> for ChannelProgressCallback<S> {
     }
 }
 
-impl<S: PlanningSolution, D: Director<S>> fmt::Debug for AnyTermination<S, D> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::None(_) => write!(f, "AnyTermination::None"),
-            Self::Default(_) => write!(f, "AnyTermination::Default"),
-            Self::WithBestScore(_) => write!(f, "AnyTermination::WithBestScore"),
-            Self::WithStepCount(_) => write!(f, "AnyTermination::WithStepCount"),
-            Self::WithUnimprovedStep(_) => write!(f, "AnyTermination::WithUnimprovedStep"),
-            Self::WithUnimprovedTime(_) => write!(f, "AnyTermination::WithUnimprovedTime"),
-        }
-    }
-}
-
-impl<S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
-    Termination<S, D, ProgressCb> for AnyTermination<S, D>
-where
-    S::Score: Score,
-{
-    fn is_terminated(&self, solver_scope: &SolverScope<S, D, ProgressCb>) -> bool {
-        match self {
-            Self::None(t) => t.is_terminated(solver_scope),
-            Self::Default(t) => t.is_terminated(solver_scope),
-            Self::WithBestScore(t) => t.is_terminated(solver_scope),
-            Self::WithStepCount(t) => t.is_terminated(solver_scope),
-            Self::WithUnimprovedStep(t) => t.is_terminated(solver_scope),
-            Self::WithUnimprovedTime(t) => t.is_terminated(solver_scope),
-        }
-    }
-
-    fn install_inphase_limits(&self, solver_scope: &mut SolverScope<S, D, ProgressCb>) {
-        match self {
-            Self::None(t) => t.install_inphase_limits(solver_scope),
-            Self::Default(t) => t.install_inphase_limits(solver_scope),
-            Self::WithBestScore(t) => t.install_inphase_limits(solver_scope),
-            Self::WithStepCount(t) => t.install_inphase_limits(solver_scope),
-            Self::WithUnimprovedStep(t) => t.install_inphase_limits(solver_scope),
-            Self::WithUnimprovedTime(t) => t.install_inphase_limits(solver_scope),
-        }
-    }
-}
-
-/// Parsed solver termination policy shared by runtime phase assembly and the
-/// top-level termination builder.
-///
-/// `TerminationConfig` historically chooses the first configured score/work
-/// criterion in this order: best score, step count, unimproved steps,
-/// unimproved time. A configured time limit is paired with that criterion, or
-/// is the policy itself when no other criterion is present. Keeping that
-/// precedence here prevents phase assembly from treating an empty or
-/// unparsable configuration as a finite solver boundary.
-#[derive(Clone, Copy)]
-pub(crate) struct ConfiguredTermination<Sc> {
-    time_limit: Option<Duration>,
-    criterion: Option<ConfiguredTerminationCriterion<Sc>>,
-}
-
-#[derive(Clone, Copy)]
-enum ConfiguredTerminationCriterion<Sc> {
-    BestScore(Sc),
-    StepCount(u64),
-    UnimprovedStepCount(u64),
-    UnimprovedTime(Duration),
-}
-
-impl<Sc> ConfiguredTermination<Sc> {
-    pub(crate) fn has_effective_limit(&self) -> bool {
-        self.time_limit.is_some() || self.criterion.is_some()
-    }
-}
-
-pub(crate) fn parse_configured_termination<S>(
-    config: Option<&TerminationConfig>,
-) -> ConfiguredTermination<S::Score>
-where
-    S: PlanningSolution,
-    S::Score: ParseableScore,
-{
-    let time_limit = config.and_then(TerminationConfig::time_limit);
-    let criterion = config.and_then(|config| {
-        config
-            .best_score_limit
-            .as_deref()
-            .and_then(|score| S::Score::parse(score).ok())
-            .map(ConfiguredTerminationCriterion::BestScore)
-            .or_else(|| {
-                config
-                    .step_count_limit
-                    .map(ConfiguredTerminationCriterion::StepCount)
-            })
-            .or_else(|| {
-                config
-                    .unimproved_step_count_limit
-                    .map(ConfiguredTerminationCriterion::UnimprovedStepCount)
-            })
-            .or_else(|| {
-                config
-                    .unimproved_time_limit()
-                    .map(ConfiguredTerminationCriterion::UnimprovedTime)
-            })
-    });
-    ConfiguredTermination {
-        time_limit,
-        criterion,
-    }
-}
-
-/// Builds a termination from config, returning both the termination and the time limit.
-pub fn build_termination<S, C>(
-    config: &SolverConfig,
-    default_secs: u64,
-) -> (AnyTermination<S, ScoreDirector<S, C>>, Option<Duration>)
-where
-    S: PlanningSolution,
-    S::Score: Score + ParseableScore,
-    C: ConstraintSet<S, S::Score>,
-{
-    let ConfiguredTermination {
-        time_limit: configured_time_limit,
-        criterion,
-    } = parse_configured_termination::<S>(config.termination.as_ref());
-    let fallback_time_limit = Duration::from_secs(default_secs);
-
-    let (termination, effective_time_limit) = match criterion {
-        Some(ConfiguredTerminationCriterion::BestScore(target)) => {
-            let effective_time_limit = configured_time_limit.unwrap_or(fallback_time_limit);
-            let time = TimeTermination::new(effective_time_limit);
-            (
-                AnyTermination::WithBestScore(OrTermination::new((
-                    time,
-                    BestScoreTermination::new(target),
-                ))),
-                Some(effective_time_limit),
-            )
-        }
-        Some(ConfiguredTerminationCriterion::StepCount(step_limit)) => {
-            let effective_time_limit = configured_time_limit.unwrap_or(fallback_time_limit);
-            let time = TimeTermination::new(effective_time_limit);
-            (
-                AnyTermination::WithStepCount(OrTermination::new((
-                    time,
-                    StepCountTermination::new(step_limit),
-                ))),
-                Some(effective_time_limit),
-            )
-        }
-        Some(ConfiguredTerminationCriterion::UnimprovedStepCount(unimproved_step_limit)) => {
-            let effective_time_limit = configured_time_limit.unwrap_or(fallback_time_limit);
-            let time = TimeTermination::new(effective_time_limit);
-            (
-                AnyTermination::WithUnimprovedStep(OrTermination::new((
-                    time,
-                    UnimprovedStepCountTermination::<S>::new(unimproved_step_limit),
-                ))),
-                Some(effective_time_limit),
-            )
-        }
-        Some(ConfiguredTerminationCriterion::UnimprovedTime(unimproved_time)) => {
-            let effective_time_limit = configured_time_limit.unwrap_or(fallback_time_limit);
-            let time = TimeTermination::new(effective_time_limit);
-            (
-                AnyTermination::WithUnimprovedTime(OrTermination::new((
-                    time,
-                    UnimprovedTimeTermination::<S>::new(unimproved_time),
-                ))),
-                Some(effective_time_limit),
-            )
-        }
-        None => configured_time_limit.map_or_else(
-            || (AnyTermination::None(NoTermination), None),
-            |limit| {
-                let time = TimeTermination::new(limit);
-                (
-                    AnyTermination::Default(OrTermination::new((time,))),
-                    Some(limit),
-                )
-            },
-        ),
-    };
-
-    (termination, effective_time_limit)
-}
-
-/// Records the termination policy the configured runtime actually installed.
-///
-/// This deliberately derives its time guard from `build_termination`'s
-/// returned effective limit rather than from the input TOML.  In particular,
-/// a score/work criterion without an explicit time limit gets the configured
-/// entrypoint's fallback guard, and that injected guard is material to both
-/// bounded-work and fixed-budget comparisons.
-pub(crate) fn configured_execution_policy<S>(
-    config: &SolverConfig,
-    default_secs: u64,
-    effective_time_limit: Option<Duration>,
-) -> CandidateTraceExecutionPolicy
-where
-    S: PlanningSolution,
-    S::Score: ParseableScore + std::fmt::Display,
-{
-    let configured = parse_configured_termination::<S>(config.termination.as_ref());
-    let configured_time_limit = configured.time_limit;
-    let criterion = configured.criterion;
-    let fallback_time_limit = Duration::from_secs(default_secs);
-
-    let time_limit_source = match (configured_time_limit, effective_time_limit) {
-        (Some(_), Some(_)) => "configured",
-        (None, Some(_)) if criterion.is_some() => "configured_entrypoint_fallback",
-        (None, Some(_)) => "internal",
-        (_, None) => "not_installed",
-    };
-    let mut attributes = vec![
-        ("entrypoint".to_string(), "configured_runtime".to_string()),
-        (
-            "configured_time_limit_ns".to_string(),
-            configured_time_limit.map_or_else(|| "none".to_string(), duration_nanos),
-        ),
-        (
-            "configured_entrypoint_default_time_limit_ns".to_string(),
-            duration_nanos(fallback_time_limit),
-        ),
-        (
-            "effective_time_limit_ns".to_string(),
-            effective_time_limit.map_or_else(|| "none".to_string(), duration_nanos),
-        ),
-        (
-            "time_limit_source".to_string(),
-            time_limit_source.to_string(),
-        ),
-    ];
-
-    match criterion {
-        Some(ConfiguredTerminationCriterion::BestScore(target)) => {
-            attributes.push(("criterion".to_string(), "best_score".to_string()));
-            attributes.push(("criterion_target".to_string(), target.to_string()));
-            attributes.push((
-                "termination_composition".to_string(),
-                "time_or_best_score".to_string(),
-            ));
-        }
-        Some(ConfiguredTerminationCriterion::StepCount(limit)) => {
-            attributes.push(("criterion".to_string(), "step_count".to_string()));
-            attributes.push(("criterion_target".to_string(), limit.to_string()));
-            attributes.push((
-                "termination_composition".to_string(),
-                "time_or_step_count".to_string(),
-            ));
-        }
-        Some(ConfiguredTerminationCriterion::UnimprovedStepCount(limit)) => {
-            attributes.push(("criterion".to_string(), "unimproved_step_count".to_string()));
-            attributes.push(("criterion_target".to_string(), limit.to_string()));
-            attributes.push((
-                "termination_composition".to_string(),
-                "time_or_unimproved_step_count".to_string(),
-            ));
-        }
-        Some(ConfiguredTerminationCriterion::UnimprovedTime(limit)) => {
-            attributes.push(("criterion".to_string(), "unimproved_time".to_string()));
-            attributes.push(("criterion_target_ns".to_string(), duration_nanos(limit)));
-            attributes.push((
-                "termination_composition".to_string(),
-                "time_or_unimproved_time".to_string(),
-            ));
-        }
-        None if effective_time_limit.is_some() => {
-            attributes.push(("criterion".to_string(), "none".to_string()));
-            attributes.push((
-                "termination_composition".to_string(),
-                "time_only".to_string(),
-            ));
-        }
-        None => {
-            attributes.push(("criterion".to_string(), "none".to_string()));
-            attributes.push((
-                "termination_composition".to_string(),
-                "unbounded".to_string(),
-            ));
-        }
-    }
-
-    CandidateTraceExecutionPolicy::known("solverforge.execution_policy", attributes)
-}
-
-fn duration_nanos(duration: Duration) -> String {
-    duration.as_nanos().to_string()
-}
-
 pub fn log_solve_start(
     entity_count: usize,
     element_count: Option<usize>,
@@ -549,16 +249,23 @@ where
     BuildRunner: FnOnce(&SolverConfig, &SolutionDescriptor) -> RuntimeBuildResult<Runner>,
 {
     log_scale(&solution);
+    let constraint_match_enabled = config
+        .score_director
+        .as_ref()
+        .is_some_and(|director| director.constraint_match_enabled);
     let director = ScoreDirector::with_descriptor(
         solution,
         constraints,
         descriptor.clone(),
         entity_count_by_descriptor,
-    );
+    )
+    .with_constraint_match_enabled(constraint_match_enabled);
 
-    let (termination, time_limit) = build_termination::<S, C>(&config, default_time_limit_secs);
+    let configured = parse_configured_termination::<S>(config.termination.as_ref());
+    let time_limit = configured.effective_time_limit(default_time_limit_secs);
     let execution_policy =
-        configured_execution_policy::<S>(&config, default_time_limit_secs, time_limit);
+        configured_execution_policy(&config, &configured, default_time_limit_secs, time_limit);
+    let termination = configured.into_termination(default_time_limit_secs);
 
     let callback = ChannelProgressCallback::new(runtime);
 
@@ -0,0 +1,490 @@
+/* Termination policy parsed from `TerminationConfig` and the monomorphized
+termination the configured runtime installs from it. */
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use solverforge_config::{SolverConfig, TerminationConfig};
+use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::{ParseableScore, Score};
+use solverforge_scoring::{ConstraintSet, Director, ScoreDirector};
+
+use crate::scope::{ProgressCallback, SolverScope};
+use crate::solver::NoTermination;
+use crate::stats::CandidateTraceExecutionPolicy;
+use crate::termination::{
+    BestScoreFeasibleTermination, BestScoreTermination, DiminishedReturnsTermination,
+    MoveCountTermination, NestedTermination, OrTermination, ScoreCalculationCountTermination,
+    StepCountTermination, Termination, TimeTermination, UnimprovedStepCountTermination,
+    UnimprovedTimeTermination,
+};
+
+/// Monomorphized termination enum for config-driven solver configurations.
+///
+/// Avoids repeated branching across termination overloads by capturing the
+/// selected termination variant upfront.
+pub enum AnyTermination<S: PlanningSolution, D: Director<S>> {
+    None(NoTermination),
+    Default(OrTermination<(TimeTermination,), S, D>),
+    WithBestScore(OrTermination<(TimeTermination, BestScoreTermination<S::Score>), S, D>),
+    WithFeasibleThen(
+        OrTermination<
+            (
+                TimeTermination,
+                BestScoreFeasibleTermination<S, fn(&S::Score) -> bool>,
+            ),
+            S,
+            D,
+        >,
+    ),
+    WithStepCount(OrTermination<(TimeTermination, StepCountTermination), S, D>),
+    WithMoveCount(OrTermination<(TimeTermination, MoveCountTermination<S>), S, D>),
+    WithScoreCalculationCount(
+        OrTermination<(TimeTermination, ScoreCalculationCountTermination<S>), S, D>,
+    ),
+    WithUnimprovedStep(OrTermination<(TimeTermination, UnimprovedStepCountTermination<S>), S, D>),
+    WithUnimprovedTime(OrTermination<(TimeTermination, UnimprovedTimeTermination<S>), S, D>),
+    WithDiminishedReturns(OrTermination<(TimeTermination, DiminishedReturnsTermination<S>), S, D>),
+    WithNested(OrTermination<(TimeTermination, NestedTermination<S>), S, D>),
+}
+
+impl<S: PlanningSolution, D: Director<S>> fmt::Debug for AnyTermination<S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None(_) => write!(f, "AnyTermination::None"),
+            Self::Default(_) => write!(f, "AnyTermination::Default"),
+            Self::WithBestScore(_) => write!(f, "AnyTermination::WithBestScore"),
+            Self::WithFeasibleThen(_) => write!(f, "AnyTermination::WithFeasibleThen"),
+            Self::WithStepCount(_) => write!(f, "AnyTermination::WithStepCount"),
+            Self::WithMoveCount(_) => write!(f, "AnyTermination::WithMoveCount"),
+            Self::WithScoreCalculationCount(_) => {
+                write!(f, "AnyTermination::WithScoreCalculationCount")
+            }
+            Self::WithUnimprovedStep(_) => write!(f, "AnyTermination::WithUnimprovedStep"),
+            Self::WithUnimprovedTime(_) => write!(f, "AnyTermination::WithUnimprovedTime"),
+            Self::WithDiminishedReturns(_) => write!(f, "AnyTermination::WithDiminishedReturns"),
+            Self::WithNested(_) => write!(f, "AnyTermination::WithNested"),
+        }
+    }
+}
+
+impl<S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
+    Termination<S, D, ProgressCb> for AnyTermination<S, D>
+where
+    S::Score: Score,
+{
+    fn is_terminated(&self, solver_scope: &SolverScope<S, D, ProgressCb>) -> bool {
+        match self {
+            Self::None(t) => t.is_terminated(solver_scope),
+            Self::Default(t) => t.is_terminated(solver_scope),
+            Self::WithBestScore(t) => t.is_terminated(solver_scope),
+            Self::WithFeasibleThen(t) => t.is_terminated(solver_scope),
+            Self::WithStepCount(t) => t.is_terminated(solver_scope),
+            Self::WithMoveCount(t) => t.is_terminated(solver_scope),
+            Self::WithScoreCalculationCount(t) => t.is_terminated(solver_scope),
+            Self::WithUnimprovedStep(t) => t.is_terminated(solver_scope),
+            Self::WithUnimprovedTime(t) => t.is_terminated(solver_scope),
+            Self::WithDiminishedReturns(t) => t.is_terminated(solver_scope),
+            Self::WithNested(t) => t.is_terminated(solver_scope),
+        }
+    }
+
+    fn install_inphase_limits(&self, solver_scope: &mut SolverScope<S, D, ProgressCb>) {
+        match self {
+            Self::None(t) => t.install_inphase_limits(solver_scope),
+            Self::Default(t) => t.install_inphase_limits(solver_scope),
+            Self::WithBestScore(t) => t.install_inphase_limits(solver_scope),
+            Self::WithFeasibleThen(t) => t.install_inphase_limits(solver_scope),
+            Self::WithStepCount(t) => t.install_inphase_limits(solver_scope),
+            Self::WithMoveCount(t) => t.install_inphase_limits(solver_scope),
+            Self::WithScoreCalculationCount(t) => t.install_inphase_limits(solver_scope),
+            Self::WithUnimprovedStep(t) => t.install_inphase_limits(solver_scope),
+            Self::WithUnimprovedTime(t) => t.install_inphase_limits(solver_scope),
+            Self::WithDiminishedReturns(t) => t.install_inphase_limits(solver_scope),
+            Self::WithNested(t) => t.install_inphase_limits(solver_scope),
+        }
+    }
+}
+
+/// Parsed solver termination policy shared by runtime phase assembly and the
+/// top-level termination builder.
+///
+/// `TerminationConfig` historically chooses the first configured score/work
+/// criterion in this order: best score, feasible-then grace, step count,
+/// move count, score calculation count, unimproved steps, unimproved time,
+/// diminished returns. A block with `any_of`/`all_of` children is instead
+/// folded whole into a [`NestedTermination`], built once here. A configured
+/// time limit is paired with that criterion, or is the policy itself when no
+/// other criterion is present. A wall-clock deadline rides along on the same
+/// time guard. Keeping that precedence here prevents phase assembly from
+/// treating an empty or unparsable configuration as a finite solver boundary.
+pub(crate) struct ConfiguredTermination<S: PlanningSolution> {
+    time_limit: Option<Duration>,
+    deadline: Option<SystemTime>,
+    criterion: Option<ConfiguredTerminationCriterion<S>>,
+}
+
+enum ConfiguredTerminationCriterion<S: PlanningSolution> {
+    BestScore(S::Score),
+    FeasibleThen(Duration),
+    StepCount(u64),
+    MoveCount(u64),
+    ScoreCalculationCount(u64),
+    UnimprovedStepCount(u64),
+    UnimprovedTime(Duration),
+    DiminishedReturns { window: Duration, min_ratio: f64 },
+    Nested(NestedTermination<S>),
+}
+
+impl<S: PlanningSolution> ConfiguredTermination<S> {
+    pub(crate) fn has_effective_limit(&self) -> bool {
+        self.time_limit.is_some() || self.deadline.is_some() || self.criterion.is_some()
+    }
+
+    // The relative time guard paired with the criterion: the configured time
+    // limit, else the entrypoint fallback for a criterion, else `Duration::MAX`
+    // for a lone deadline. `None` installs no termination at all.
+    fn guard_limit(&self, default_secs: u64) -> Option<Duration> {
+        match (self.time_limit, &self.criterion) {
+            (Some(limit), _) => Some(limit),
+            (None, Some(_)) => Some(Duration::from_secs(default_secs)),
+            (None, None) => self.deadline.map(|_| Duration::MAX),
+        }
+    }
+
+    /// The solver time limit that honors whichever of the time guard and the
+    /// deadline fires first.
+    ///
+    /// A deadline that has already passed leaves the limit alone: construction
+    /// still runs to completion and the deadline stops solving at the next
+    /// phase boundary.
+    pub(super) fn effective_time_limit(&self, default_secs: u64) -> Option<Duration> {
+        let limit = self.guard_limit(default_secs)?;
+        let relative = (limit < Duration::MAX).then_some(limit);
+        match self
+            .deadline
+            .and_then(|deadline| deadline.duration_since(SystemTime::now()).ok())
+        {
+            Some(remaining) => Some(relative.map_or(remaining, |limit| limit.min(remaining))),
+            None => relative,
+        }
+    }
+
+    /// Builds the termination for this policy, consuming the parsed criterion.
+    pub(super) fn into_termination<D: Director<S>>(self, default_secs: u64) -> AnyTermination<S, D>
+    where
+        S::Score: Score,
+    {
+        let Some(limit) = self.guard_limit(default_secs) else {
+            return AnyTermination::None(NoTermination);
+        };
+        let time = time_guard(limit, self.deadline);
+        match self.criterion {
+            Some(ConfiguredTerminationCriterion::BestScore(target)) => {
+                AnyTermination::WithBestScore(OrTermination::new((
+                    time,
+                    BestScoreTermination::new(target),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::FeasibleThen(grace_period)) => {
+                AnyTermination::WithFeasibleThen(OrTermination::new((
+                    time,
+                    BestScoreFeasibleTermination::score_is_feasible_with_grace_period(grace_period),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::StepCount(step_limit)) => {
+                AnyTermination::WithStepCount(OrTermination::new((
+                    time,
+                    StepCountTermination::new(step_limit),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::MoveCount(move_limit)) => {
+                AnyTermination::WithMoveCount(OrTermination::new((
+                    time,
+                    MoveCountTermination::<S>::new(move_limit),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::ScoreCalculationCount(calculation_limit)) => {
+                AnyTermination::WithScoreCalculationCount(OrTermination::new((
+                    time,
+                    ScoreCalculationCountTermination::<S>::new(calculation_limit),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::UnimprovedStepCount(unimproved_step_limit)) => {
+                AnyTermination::WithUnimprovedStep(OrTermination::new((
+                    time,
+                    UnimprovedStepCountTermination::<S>::new(unimproved_step_limit),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::UnimprovedTime(unimproved_time)) => {
+                AnyTermination::WithUnimprovedTime(OrTermination::new((
+                    time,
+                    UnimprovedTimeTermination::<S>::new(unimproved_time),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::DiminishedReturns { window, min_ratio }) => {
+                AnyTermination::WithDiminishedReturns(OrTermination::new((
+                    time,
+                    DiminishedReturnsTermination::<S>::new(window, min_ratio),
+                )))
+            }
+            Some(ConfiguredTerminationCriterion::Nested(nested)) => {
+                AnyTermination::WithNested(OrTermination::new((time, nested)))
+            }
+            None => AnyTermination::Default(OrTermination::new((time,))),
+        }
+    }
+}
+
+pub(crate) fn parse_configured_termination<S>(
+    config: Option<&TerminationConfig>,
+) -> ConfiguredTermination<S>
+where
+    S: PlanningSolution,
+    S::Score: ParseableScore,
+{
+    let time_limit = config.and_then(TerminationConfig::time_limit);
+    let deadline = config.and_then(TerminationConfig::deadline);
+    let criterion = config.and_then(|config| {
+        if config.is_nested() {
+            return NestedTermination::<S>::from_config(config)
+                .map(ConfiguredTerminationCriterion::Nested);
+        }
+        config
+            .best_score_limit
+            .as_deref()
+            .and_then(|score| S::Score::parse(score).ok())
+            .map(ConfiguredTerminationCriterion::BestScore)
+            .or_else(|| {
+                config
+                    .feasible_then_limit()
+                    .map(ConfiguredTerminationCriterion::FeasibleThen)
+            })
+            .or_else(|| {
+                config
+                    .step_count_limit
+                    .map(ConfiguredTerminationCriterion::StepCount)
+            })
+            .or_else(|| {
+                config
+                    .move_count_limit
+                    .map(ConfiguredTerminationCriterion::MoveCount)
+            })
+            .or_else(|| {
+                config
+                    .score_calculation_count_limit
+                    .map(ConfiguredTerminationCriterion::ScoreCalculationCount)
+            })
+            .or_else(|| {
+                config
+                    .unimproved_step_count_limit
+                    .map(ConfiguredTerminationCriterion::UnimprovedStepCount)
+            })
+            .or_else(|| {
+                config
+                    .unimproved_time_limit()
+                    .map(ConfiguredTerminationCriterion::UnimprovedTime)
+            })
+            .or_else(|| {
+                config.diminished_returns().map(|(window, min_ratio)| {
+                    ConfiguredTerminationCriterion::DiminishedReturns { window, min_ratio }
+                })
+            })
+    });
+    ConfiguredTermination {
+        time_limit,
+        deadline,
+        criterion,
+    }
+}
+
+// Pairs a relative time guard with the configured deadline; `Duration::MAX`
+// means no relative limit.
+fn time_guard(limit: Duration, deadline: Option<SystemTime>) -> TimeTermination {
+    let termination = TimeTermination::new(limit);
+    match deadline {
+        Some(deadline) => termination.with_deadline(deadline),
+        None => termination,
+    }
+}
+
+/// Builds a termination from config, returning both the termination and the time limit.
+pub fn build_termination<S, C>(
+    config: &SolverConfig,
+    default_secs: u64,
+) -> (AnyTermination<S, ScoreDirector<S, C>>, Option<Duration>)
+where
+    S: PlanningSolution,
+    S::Score: Score + ParseableScore,
+    C: ConstraintSet<S, S::Score>,
+{
+    let configured = parse_configured_termination::<S>(config.termination.as_ref());
+    let time_limit = configured.effective_time_limit(default_secs);
+    (configured.into_termination(default_secs), time_limit)
+}
+
+/// Records the termination policy the configured runtime actually installed.
+///
+/// This deliberately derives its time guard from the effective limit the
+/// runtime installs rather than from the input TOML.  In particular,
+/// a score/work criterion without an explicit time limit gets the configured
+/// entrypoint's fallback guard, and that injected guard is material to both
+/// bounded-work and fixed-budget comparisons.
+pub(super) fn configured_execution_policy<S>(
+    config: &SolverConfig,
+    configured: &ConfiguredTermination<S>,
+    default_secs: u64,
+    effective_time_limit: Option<Duration>,
+) -> CandidateTraceExecutionPolicy
+where
+    S: PlanningSolution,
+    S::Score: ParseableScore + std::fmt::Display,
+{
+    let configured_time_limit = configured.time_limit;
+    let configured_deadline = configured.deadline;
+    let criterion = configured.criterion.as_ref();
+    let fallback_time_limit = Duration::from_secs(default_secs);
+
+    let time_limit_source = match (configured_time_limit, effective_time_limit) {
+        (Some(_), Some(_)) => "configured",
+        (None, Some(_)) if configured_deadline.is_some() && criterion.is_none() => "deadline",
+        (None, Some(_)) if criterion.is_some() => "configured_entrypoint_fallback",
+        (None, Some(_)) => "internal",
+        (None, None) if configured_deadline.is_some() => "deadline",
+        (_, None) => "not_installed",
+    };
+    let mut attributes = vec![
+        ("entrypoint".to_string(), "configured_runtime".to_string()),
+        (
+            "configured_time_limit_ns".to_string(),
+            configured_time_limit.map_or_else(|| "none".to_string(), duration_nanos),
+        ),
+        (
+            "configured_entrypoint_default_time_limit_ns".to_string(),
+            duration_nanos(fallback_time_limit),
+        ),
+        (
+            "effective_time_limit_ns".to_string(),
+            effective_time_limit.map_or_else(|| "none".to_string(), duration_nanos),
+        ),
+        (
+            "time_limit_source".to_string(),
+            time_limit_source.to_string(),
+        ),
+    ];
+
+    if let Some(millis) = config
+        .termination
+        .as_ref()
+        .and_then(|termination| termination.unix_deadline_millis)
+    {
+        attributes.push((
+            "configured_deadline_unix_ms".to_string(),
+            millis.to_string(),
+        ));
+    }
+
+    match criterion {
+        Some(ConfiguredTerminationCriterion::BestScore(target)) => {
+            attributes.push(("criterion".to_string(), "best_score".to_string()));
+            attributes.push(("criterion_target".to_string(), target.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_best_score".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::FeasibleThen(grace_period)) => {
+            attributes.push(("criterion".to_string(), "feasible_then".to_string()));
+            attributes.push((
+                "criterion_target_ns".to_string(),
+                duration_nanos(*grace_period),
+            ));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_feasible_then".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::StepCount(limit)) => {
+            attributes.push(("criterion".to_string(), "step_count".to_string()));
+            attributes.push(("criterion_target".to_string(), limit.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_step_count".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::MoveCount(limit)) => {
+            attributes.push(("criterion".to_string(), "move_count".to_string()));
+            attributes.push(("criterion_target".to_string(), limit.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_move_count".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::ScoreCalculationCount(limit)) => {
+            attributes.push((
+                "criterion".to_string(),
+                "score_calculation_count".to_string(),
+            ));
+            attributes.push(("criterion_target".to_string(), limit.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_score_calculation_count".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::UnimprovedStepCount(limit)) => {
+            attributes.push(("criterion".to_string(), "unimproved_step_count".to_string()));
+            attributes.push(("criterion_target".to_string(), limit.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_unimproved_step_count".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::UnimprovedTime(limit)) => {
+            attributes.push(("criterion".to_string(), "unimproved_time".to_string()));
+            attributes.push(("criterion_target_ns".to_string(), duration_nanos(*limit)));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_unimproved_time".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::DiminishedReturns { window, min_ratio }) => {
+            attributes.push(("criterion".to_string(), "diminished_returns".to_string()));
+            attributes.push(("criterion_target_ns".to_string(), duration_nanos(*window)));
+            attributes.push(("criterion_min_ratio".to_string(), min_ratio.to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_diminished_returns".to_string(),
+            ));
+        }
+        Some(ConfiguredTerminationCriterion::Nested(_)) => {
+            attributes.push(("criterion".to_string(), "nested".to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_or_nested".to_string(),
+            ));
+        }
+        None if effective_time_limit.is_some() || configured_deadline.is_some() => {
+            attributes.push(("criterion".to_string(), "none".to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "time_only".to_string(),
+            ));
+        }
+        None => {
+            attributes.push(("criterion".to_string(), "none".to_string()));
+            attributes.push((
+                "termination_composition".to_string(),
+                "unbounded".to_string(),
+            ));
+        }
+    }
+
+    CandidateTraceExecutionPolicy::known("solverforge.execution_policy", attributes)
+}
+
+fn duration_nanos(duration: Duration) -> String {
+    duration.as_nanos().to_string()
+}
+
+#[cfg(test)]
+mod tests;
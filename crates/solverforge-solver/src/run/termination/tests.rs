@@ -0,0 +1,454 @@
+use super::*;
+use crate::builder::{RuntimeModel, ScalarVariableSlot, SearchContext, ValueSource, VariableSlot};
+use crate::manager::{SolverRuntime, SolverTerminalReason};
+use crate::run::try_run_solver_with_config_and_search;
+use crate::solver::Solver;
+use crate::test_utils::{create_scope_with_score, TestSolution};
+use crate::DefaultCrossEntityDistanceMeter;
+use solverforge_core::domain::{
+    EntityClassId, EntityCollectionExtractor, EntityDescriptor, SolutionDescriptor, ValueRangeType,
+    VariableDescriptor, VariableId,
+};
+use solverforge_core::score::SoftScore;
+use std::any::TypeId;
+use std::time::UNIX_EPOCH;
+
+#[test]
+fn build_termination_preserves_missing_time_limit_as_unlimited() {
+    let config = SolverConfig::default();
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::None(_)));
+    assert_eq!(time_limit, None);
+}
+
+#[test]
+fn build_termination_treats_empty_and_invalid_only_configs_as_unlimited() {
+    for termination_config in [
+        solverforge_config::TerminationConfig::default(),
+        solverforge_config::TerminationConfig {
+            best_score_limit: Some("not-a-score".to_string()),
+            ..solverforge_config::TerminationConfig::default()
+        },
+    ] {
+        let config = SolverConfig {
+            termination: Some(termination_config),
+            ..SolverConfig::default()
+        };
+        let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+        assert!(matches!(termination, AnyTermination::None(_)));
+        assert_eq!(time_limit, None);
+    }
+}
+
+#[test]
+fn build_termination_caps_time_limit_at_deadline() {
+    let deadline = SystemTime::now() + Duration::from_secs(10);
+    let deadline_millis = deadline.duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            seconds_spent_limit: Some(60),
+            unix_deadline_millis: Some(deadline_millis),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::Default(_)));
+    assert!(time_limit.is_some_and(|limit| limit <= Duration::from_secs(10)));
+}
+
+#[test]
+fn build_termination_keeps_the_time_limit_for_a_passed_deadline() {
+    let passed_deadline = |seconds_spent_limit| SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            seconds_spent_limit,
+            unix_deadline_millis: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) =
+        build_termination::<TestSolution, ()>(&passed_deadline(None), 180);
+    assert!(matches!(termination, AnyTermination::Default(_)));
+    assert_eq!(time_limit, None);
+
+    let (_, time_limit) = build_termination::<TestSolution, ()>(&passed_deadline(Some(60)), 180);
+    assert_eq!(time_limit, Some(Duration::from_secs(60)));
+}
+
+#[test]
+fn build_termination_returns_fallback_time_for_best_score_limit() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            best_score_limit: Some("0".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::WithBestScore(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(180)));
+}
+
+#[test]
+fn build_termination_returns_fallback_time_for_step_limit() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            step_count_limit: Some(10),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::WithStepCount(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(180)));
+}
+
+#[test]
+fn build_termination_returns_fallback_time_for_move_and_calculation_count_limits() {
+    let move_config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            move_count_limit: Some(10),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let calculation_config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            score_calculation_count_limit: Some(10),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (moves, move_time_limit) = build_termination::<TestSolution, ()>(&move_config, 180);
+    let (calculations, calculation_time_limit) =
+        build_termination::<TestSolution, ()>(&calculation_config, 180);
+
+    assert!(matches!(moves, AnyTermination::WithMoveCount(_)));
+    assert!(matches!(
+        calculations,
+        AnyTermination::WithScoreCalculationCount(_)
+    ));
+    assert_eq!(move_time_limit, Some(Duration::from_secs(180)));
+    assert_eq!(calculation_time_limit, Some(Duration::from_secs(180)));
+}
+
+#[test]
+fn config_score_calculation_count_limit_stops_local_search_mid_step() {
+    use crate::heuristic::selector::ChangeMoveSelector;
+    use crate::phase::localsearch::{AcceptedCountForager, HillClimbingAcceptor, LocalSearchPhase};
+    use crate::test_utils::{create_nqueens_director, get_queen_row, set_queen_row};
+
+    const LIMIT: u64 = 7;
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            score_calculation_count_limit: Some(LIMIT),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let (termination, time_limit) = build_termination(&config, 180);
+    let move_selector =
+        ChangeMoveSelector::simple(get_queen_row, set_queen_row, 0, 0, "row", vec![0, 1, 2, 3]);
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1_000, false);
+    let phase = LocalSearchPhase::new(move_selector, HillClimbingAcceptor::new(), forager, None);
+
+    let mut solver = Solver::new((phase,))
+        .with_config(config)
+        .with_termination(termination);
+    if let Some(time_limit) = time_limit {
+        solver = solver.with_time_limit(time_limit);
+    }
+
+    let result = solver.solve(create_nqueens_director(&[0, 1, 2, 3]));
+
+    assert_eq!(
+        result.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+    let calculations = result.stats().score_calculations;
+    assert!(
+        (LIMIT..=LIMIT + 2).contains(&calculations),
+        "expected roughly {LIMIT} score calculations, got {calculations}"
+    );
+}
+
+fn solve_nqueens_with_feasible_then(
+    feasible_then_seconds: u64,
+) -> (SolverTerminalReason, SoftScore, Duration) {
+    use crate::heuristic::selector::ChangeMoveSelector;
+    use crate::phase::localsearch::{AcceptedCountForager, HillClimbingAcceptor, LocalSearchPhase};
+    use crate::test_utils::{create_nqueens_director, get_queen_row, set_queen_row};
+
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            feasible_then_seconds: Some(feasible_then_seconds),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let (termination, time_limit) = build_termination(&config, 180);
+    assert!(matches!(termination, AnyTermination::WithFeasibleThen(_)));
+    let move_selector =
+        ChangeMoveSelector::simple(get_queen_row, set_queen_row, 0, 0, "row", vec![0, 1, 2, 3]);
+    let forager: AcceptedCountForager<_> = AcceptedCountForager::new(1_000, false);
+    let phase = LocalSearchPhase::new(move_selector, HillClimbingAcceptor::new(), forager, None);
+
+    let mut solver = Solver::new((phase,))
+        .with_config(config)
+        .with_termination(termination);
+    if let Some(time_limit) = time_limit {
+        solver = solver.with_time_limit(time_limit);
+    }
+
+    // One queen shares a row; moving it to row 2 leaves no conflicts.
+    let started = std::time::Instant::now();
+    let result = solver.solve(create_nqueens_director(&[1, 3, 0, 0]));
+    (
+        result.terminal_reason(),
+        *result.best_score(),
+        started.elapsed(),
+    )
+}
+
+#[test]
+fn config_feasible_then_stops_nqueens_at_zero_conflicts() {
+    let (reason, best_score, elapsed) = solve_nqueens_with_feasible_then(0);
+
+    assert_eq!(reason, SolverTerminalReason::TerminatedByConfig);
+    assert_eq!(best_score, SoftScore::of(0));
+    assert!(elapsed < Duration::from_secs(1), "ran for {elapsed:?}");
+}
+
+#[test]
+fn config_feasible_then_keeps_solving_nqueens_for_grace_period() {
+    let (reason, best_score, elapsed) = solve_nqueens_with_feasible_then(1);
+
+    assert_eq!(reason, SolverTerminalReason::TerminatedByConfig);
+    assert_eq!(best_score, SoftScore::of(0));
+    assert!(elapsed >= Duration::from_secs(1), "ran for {elapsed:?}");
+    assert!(elapsed < Duration::from_secs(60), "ran for {elapsed:?}");
+}
+
+#[test]
+fn build_termination_returns_fallback_time_for_unimproved_step_limit() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            unimproved_step_count_limit: Some(10),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::WithUnimprovedStep(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(180)));
+}
+
+#[test]
+fn build_termination_returns_fallback_time_for_unimproved_time_limit() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            unimproved_seconds_spent_limit: Some(10),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::WithUnimprovedTime(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(180)));
+}
+
+#[test]
+fn build_termination_stops_on_diminished_returns_only_when_flat() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            diminished_returns_window_seconds: Some(1),
+            diminished_returns_min_improvement_ratio: Some(10.0),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (flat, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+    let (improving, _) = build_termination::<TestSolution, ()>(&config, 180);
+    assert!(matches!(flat, AnyTermination::WithDiminishedReturns(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(180)));
+
+    let mut flat_scope = create_scope_with_score(SoftScore::of(-100));
+    let mut improving_scope = create_scope_with_score(SoftScore::of(-100));
+    assert!(!flat.is_terminated(&flat_scope));
+    assert!(!improving.is_terminated(&improving_scope));
+
+    flat_scope.advance_elapsed(Duration::from_millis(1100));
+    improving_scope.advance_elapsed(Duration::from_millis(1100));
+    improving_scope.set_best_solution(TestSolution::with_score(SoftScore::of(0)), SoftScore::of(0));
+
+    assert!(flat.is_terminated(&flat_scope));
+    assert!(!improving.is_terminated(&improving_scope));
+}
+
+#[test]
+fn build_termination_explicit_time_overrides_fallback() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            step_count_limit: Some(10),
+            seconds_spent_limit: Some(5),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+
+    assert!(matches!(termination, AnyTermination::WithStepCount(_)));
+    assert_eq!(time_limit, Some(Duration::from_secs(5)));
+}
+
+#[derive(Clone, Debug)]
+struct TaskPlan {
+    workers: Vec<Option<usize>>,
+    candidates: Vec<usize>,
+    score: Option<SoftScore>,
+}
+
+impl PlanningSolution for TaskPlan {
+    type Score = SoftScore;
+
+    fn score(&self) -> Option<Self::Score> {
+        self.score
+    }
+
+    fn set_score(&mut self, score: Option<Self::Score>) {
+        self.score = score;
+    }
+}
+
+fn task_plan_descriptor() -> SolutionDescriptor {
+    SolutionDescriptor::new("TaskPlan", TypeId::of::<TaskPlan>()).with_entity(
+        EntityDescriptor::new("Task", TypeId::of::<Option<usize>>(), "tasks")
+            .with_logical_id(EntityClassId(0))
+            .with_extractor(Box::new(EntityCollectionExtractor::new(
+                "Task",
+                "tasks",
+                |plan: &TaskPlan| &plan.workers,
+                |plan: &mut TaskPlan| &mut plan.workers,
+            )))
+            .with_variable(
+                VariableDescriptor::genuine("worker")
+                    .with_logical_id(VariableId(0))
+                    .with_value_range_type(ValueRangeType::EntityDependent)
+                    .with_usize_accessors(task_worker, set_task_worker),
+            ),
+    )
+}
+
+fn task_worker(entity: &dyn std::any::Any) -> Option<usize> {
+    *entity
+        .downcast_ref::<Option<usize>>()
+        .expect("Task entity must be an optional worker")
+}
+
+fn set_task_worker(entity: &mut dyn std::any::Any, value: Option<usize>) {
+    *entity
+        .downcast_mut::<Option<usize>>()
+        .expect("Task entity must be an optional worker") = value;
+}
+
+fn task_count(plan: &TaskPlan) -> usize {
+    plan.workers.len()
+}
+
+fn task_plan_entity_count(plan: &TaskPlan, _descriptor_index: usize) -> usize {
+    plan.workers.len()
+}
+
+fn worker_of(plan: &TaskPlan, entity: usize, _variable_index: usize) -> Option<usize> {
+    plan.workers[entity]
+}
+
+fn assign_worker(plan: &mut TaskPlan, entity: usize, _variable_index: usize, value: Option<usize>) {
+    plan.workers[entity] = value;
+}
+
+fn worker_candidates(plan: &TaskPlan, _entity: usize, _variable_index: usize) -> &[usize] {
+    &plan.candidates
+}
+
+fn noop_task_plan_log_scale(_plan: &TaskPlan) {}
+
+#[test]
+fn run_with_a_passed_deadline_completes_construction_then_stops() {
+    let config = SolverConfig {
+        termination: Some(solverforge_config::TerminationConfig {
+            unix_deadline_millis: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let plan = TaskPlan {
+        workers: vec![None; 4],
+        candidates: vec![0, 1],
+        score: None,
+    };
+
+    let started = std::time::Instant::now();
+    let solved = try_run_solver_with_config_and_search(
+        plan,
+        (),
+        task_plan_descriptor(),
+        task_plan_entity_count,
+        SolverRuntime::detached(),
+        config,
+        30,
+        noop_task_plan_log_scale,
+        None,
+        |config, descriptor| {
+            let slot = ScalarVariableSlot::new(
+                0,
+                0,
+                "Task",
+                task_count,
+                "worker",
+                worker_of,
+                assign_worker,
+                ValueSource::EntitySlice {
+                    values_for_entity: worker_candidates,
+                },
+                false,
+            );
+            let model = RuntimeModel::<
+                TaskPlan,
+                usize,
+                DefaultCrossEntityDistanceMeter,
+                DefaultCrossEntityDistanceMeter,
+            >::new(vec![VariableSlot::Scalar(slot)]);
+            Ok(SearchContext::try_new(descriptor, model, config.random_seed)?.defaults())
+        },
+    )
+    .unwrap_or_else(|error| panic!("deadline-bounded run failed: {error}"));
+
+    assert!(
+        solved.workers.iter().all(Option::is_some),
+        "construction must assign every task: {:?}",
+        solved.workers
+    );
+    assert!(
+        started.elapsed() < Duration::from_secs(10),
+        "solving continued past the phase boundary for {:?}",
+        started.elapsed()
+    );
+}
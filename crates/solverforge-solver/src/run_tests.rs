@@ -220,51 +220,6 @@ construction_heuristic_type = "first_fit"
     fs::remove_dir_all(parent).expect("temp directory should be removed");
 }
 
-#[test]
-fn build_termination_preserves_missing_time_limit_as_unlimited() {
-    let config = SolverConfig::default();
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-    assert!(matches!(termination, AnyTermination::None(_)));
-    assert_eq!(time_limit, None);
-}
-
-#[test]
-fn build_termination_treats_empty_and_invalid_only_configs_as_unlimited() {
-    for termination_config in [
-        solverforge_config::TerminationConfig::default(),
-        solverforge_config::TerminationConfig {
-            best_score_limit: Some("not-a-score".to_string()),
-            ..solverforge_config::TerminationConfig::default()
-        },
-    ] {
-        let config = SolverConfig {
-            termination: Some(termination_config),
-            ..SolverConfig::default()
-        };
-        let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-        assert!(matches!(termination, AnyTermination::None(_)));
-        assert_eq!(time_limit, None);
-    }
-}
-
-#[test]
-fn build_termination_returns_fallback_time_for_best_score_limit() {
-    let config = SolverConfig {
-        termination: Some(solverforge_config::TerminationConfig {
-            best_score_limit: Some("0".to_string()),
-            ..Default::default()
-        }),
-        ..Default::default()
-    };
-
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-    assert!(matches!(termination, AnyTermination::WithBestScore(_)));
-    assert_eq!(time_limit, Some(Duration::from_secs(180)));
-}
-
 #[test]
 fn config_best_score_limit_stops_active_phase_loop() {
     let config = SolverConfig {
@@ -304,68 +259,52 @@ fn config_best_score_limit_stops_active_phase_loop() {
 }
 
 #[test]
-fn build_termination_returns_fallback_time_for_step_limit() {
-    let config = SolverConfig {
-        termination: Some(solverforge_config::TerminationConfig {
-            step_count_limit: Some(10),
-            ..Default::default()
-        }),
-        ..Default::default()
-    };
-
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-    assert!(matches!(termination, AnyTermination::WithStepCount(_)));
-    assert_eq!(time_limit, Some(Duration::from_secs(180)));
-}
-
-#[test]
-fn build_termination_returns_fallback_time_for_unimproved_step_limit() {
+fn config_any_of_time_or_best_score_stops_active_phase_loop() {
     let config = SolverConfig {
         termination: Some(solverforge_config::TerminationConfig {
-            unimproved_step_count_limit: Some(10),
+            any_of: vec![
+                solverforge_config::TerminationConfig {
+                    seconds_spent_limit: Some(60),
+                    ..Default::default()
+                },
+                solverforge_config::TerminationConfig {
+                    best_score_limit: Some("2".to_string()),
+                    ..Default::default()
+                },
+            ],
             ..Default::default()
         }),
         ..Default::default()
     };
-
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-    assert!(matches!(termination, AnyTermination::WithUnimprovedStep(_)));
-    assert_eq!(time_limit, Some(Duration::from_secs(180)));
-}
-
-#[test]
-fn build_termination_returns_fallback_time_for_unimproved_time_limit() {
-    let config = SolverConfig {
-        termination: Some(solverforge_config::TerminationConfig {
-            unimproved_seconds_spent_limit: Some(10),
-            ..Default::default()
-        }),
-        ..Default::default()
-    };
-
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
-
-    assert!(matches!(termination, AnyTermination::WithUnimprovedTime(_)));
+    let (termination, time_limit) =
+        build_termination::<TestSolution, ScoreFromSolutionConstraints>(&config, 180);
+    assert!(matches!(termination, AnyTermination::WithNested(_)));
     assert_eq!(time_limit, Some(Duration::from_secs(180)));
-}
 
-#[test]
-fn build_termination_explicit_time_overrides_fallback() {
-    let config = SolverConfig {
-        termination: Some(solverforge_config::TerminationConfig {
-            step_count_limit: Some(10),
-            seconds_spent_limit: Some(5),
-            ..Default::default()
-        }),
-        ..Default::default()
-    };
+    let descriptor = SolutionDescriptor::new("TestSolution", TypeId::of::<TestSolution>());
+    let director = ScoreDirector::with_descriptor(
+        TestSolution {
+            score: Some(SoftScore::of(0)),
+        },
+        ScoreFromSolutionConstraints,
+        descriptor,
+        |_, _| 1,
+    );
+    let mut solver = Solver::new((IncrementScorePhase { max_score: 5 },))
+        .with_config(config)
+        .with_termination(termination);
+    if let Some(time_limit) = time_limit {
+        solver = solver.with_time_limit(time_limit);
+    }
 
-    let (termination, time_limit) = build_termination::<TestSolution, ()>(&config, 180);
+    let result = solver.solve(director);
 
-    assert!(matches!(termination, AnyTermination::WithStepCount(_)));
-    assert_eq!(time_limit, Some(Duration::from_secs(5)));
+    assert_eq!(
+        result.terminal_reason(),
+        SolverTerminalReason::TerminatedByConfig
+    );
+    assert_eq!(*result.best_score(), SoftScore::of(2));
+    assert_eq!(result.step_count(), 2);
 }
 
 #[test]
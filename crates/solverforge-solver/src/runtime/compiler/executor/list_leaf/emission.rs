@@ -187,6 +187,7 @@ where
         source_end: usize,
         destination_entity: usize,
         destination_position: usize,
+        reversed: bool,
     ) -> Self::Move {
         self.move_from_recipe(RuntimeListRecipe::SublistChange {
             access: self.move_access.clone(),
@@ -196,7 +197,8 @@ where
                 source_end,
                 destination_entity,
                 destination_position,
-            ),
+            )
+            .with_reversed(reversed),
         })
     }
 }
@@ -341,6 +343,7 @@ where
             source_end,
             entity,
             destination,
+            false,
         )
     }
 
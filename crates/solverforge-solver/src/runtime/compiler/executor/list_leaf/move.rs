@@ -19,7 +19,7 @@ use crate::heuristic::r#move::list_kernel::{
     sublist_swap_undo_move, swap_candidate_trace_identity, swap_do_move, swap_is_doable,
     swap_tabu_signature, ChangeValueTransfer, ListMoveAccess, RuinValueTransfer,
 };
-use crate::heuristic::r#move::{Move, MoveTabuSignature};
+use crate::heuristic::r#move::{Move, MoveTabuSignature, RecreateStrategy};
 use crate::heuristic::selector::nearby_list_change::CrossEntityDistanceMeter;
 use crate::stats::CandidateTraceIdentity;
 
@@ -196,6 +196,7 @@ where
                 sources,
                 *skip_empty_destinations,
                 RuinValueTransfer::MoveIntoInsert,
+                RecreateStrategy::Greedy,
                 score_director,
             )),
             RuntimeListRecipe::MultiSwap {
@@ -241,6 +241,7 @@ where
         }
         CompiledSelectorNode::Cartesian {
             require_hard_improvement,
+            max_moves,
             left,
             right,
         } => Ok(SelectorComposition::Cartesian(
@@ -248,7 +249,8 @@ where
                 lower_node(solver_config, left)?,
                 lower_node(solver_config, right)?,
             )
-            .with_require_hard_improvement(*require_hard_improvement),
+            .with_require_hard_improvement(*require_hard_improvement)
+            .with_max_moves(*max_moves),
         )),
     }
 }
@@ -2,6 +2,7 @@
 
 use std::fmt::{self, Debug};
 
+use smallvec::SmallVec;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_core::score::Score;
 use solverforge_scoring::Director;
@@ -180,6 +181,26 @@ where
         }
     }
 
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Scalar(mov) => mov.type_name(),
+            Self::List(mov) => mov.type_name(),
+            Self::Grouped(mov) => mov.type_name(),
+            Self::Provider(mov) => mov.type_name(),
+            Self::Sequential(mov) => mov.type_name(),
+        }
+    }
+
+    fn affected_entities(&self) -> SmallVec<[usize; 2]> {
+        match self {
+            Self::Scalar(mov) => mov.affected_entities(),
+            Self::List(mov) => mov.affected_entities(),
+            Self::Grouped(mov) => mov.affected_entities(),
+            Self::Provider(mov) => mov.affected_entities(),
+            Self::Sequential(mov) => mov.affected_entities(),
+        }
+    }
+
     fn requires_hard_improvement(&self) -> bool {
         match self {
             Self::Scalar(mov) => mov.requires_hard_improvement(),
@@ -5,8 +5,8 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use solverforge_config::{
-    ConstructionHeuristicConfig, ConstructionHeuristicType, CustomPhaseConfig, PhaseConfig,
-    SolverConfig, TerminationConfig,
+    ConstructionHeuristicConfig, ConstructionHeuristicType, CustomPhaseConfig, LocalSearchConfig,
+    PhaseConfig, SolverConfig, TerminationConfig,
 };
 use solverforge_core::domain::{
     EntityClassId, EntityDescriptor, PlanningSolution, SolutionDescriptor, VariableDescriptor,
@@ -905,3 +905,89 @@ fn eager_extension_preparation_precedes_lazy_source_binding() {
     ));
     assert_eq!(EXTENSION_BUILD_CALLS.load(Ordering::SeqCst), 1);
 }
+
+static GATHER_STEP_COUNT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Moves every element onto the first route, a constant assignment.
+#[derive(Debug)]
+struct GatherPhase;
+
+impl CustomSearchPhase<Plan> for GatherPhase {
+    fn solve<D, ProgressCb>(&mut self, solver_scope: &mut SolverScope<'_, Plan, D, ProgressCb>)
+    where
+        D: solverforge_scoring::Director<Plan>,
+        ProgressCb: ProgressCallback<Plan>,
+    {
+        assert_eq!(
+            solver_scope
+                .working_solution()
+                .routes
+                .iter()
+                .flatten()
+                .count(),
+            3,
+            "construction must finish before the custom phase"
+        );
+        GATHER_STEP_COUNT.store(solver_scope.total_step_count() as usize, Ordering::SeqCst);
+        solver_scope.mutate(|score_director| {
+            for entity in 0..2 {
+                score_director.before_variable_changed(0, entity);
+            }
+            let routes = &mut score_director.working_solution_mut().routes;
+            let gathered = routes.iter_mut().flat_map(std::mem::take).collect();
+            routes[0] = gathered;
+            for entity in 0..2 {
+                score_director.after_variable_changed(0, entity);
+            }
+        });
+        solver_scope.calculate_score();
+        solver_scope.update_best_solution();
+    }
+}
+
+#[test]
+fn registered_custom_phase_runs_between_construction_and_local_search() {
+    let _guard = TEST_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    GATHER_STEP_COUNT.store(usize::MAX, Ordering::SeqCst);
+    let config = SolverConfig {
+        phases: vec![
+            construction(ConstructionHeuristicType::ListRoundRobin),
+            PhaseConfig::Custom(CustomPhaseConfig {
+                name: "gather".to_string(),
+            }),
+            PhaseConfig::LocalSearch(LocalSearchConfig {
+                termination: Some(TerminationConfig {
+                    step_count_limit: Some(2),
+                    ..TerminationConfig::default()
+                }),
+                ..LocalSearchConfig::default()
+            }),
+        ],
+        random_seed: Some(7),
+        ..SolverConfig::default()
+    };
+    let context = SearchContext::new(descriptor(), model(), config.random_seed);
+    let (context, extensions) = context
+        .defaults()
+        .phase("gather", |_| GatherPhase)
+        .into_runtime_parts();
+    let graph = compile_runtime_graph(&config, RuntimeGraphInput::new(context, extensions))
+        .expect("the configured custom phase is registered");
+    let executor = CompiledRuntimeExecutor::new(graph);
+    let runner =
+        CompiledRuntimePhaseRunner::try_new(&executor).expect("custom runtime runner must prepare");
+    let director = ScoreDirector::simple(
+        plan(vec![1, 2, 3], vec![Vec::new(), Vec::new()]),
+        descriptor(),
+        |plan, _| entity_count(plan),
+    );
+
+    let result = Solver::new((runner,)).with_config(config).solve(director);
+
+    let gather_steps = GATHER_STEP_COUNT.load(Ordering::SeqCst);
+    assert_ne!(gather_steps, usize::MAX, "the custom phase must run");
+    assert_eq!(result.stats.step_count, gather_steps as u64 + 2);
+    assert_eq!(result.solution.routes.iter().flatten().count(), 3);
+}
@@ -17,7 +17,7 @@ use crate::phase::localsearch::vnd::solve_vnd_with_resources;
 use crate::phase::localsearch::{
     solve_local_search_with_resources, AcceptedCountForager, BestScoreForager,
     DiversifiedLateAcceptanceAcceptor, FirstLastStepScoreImprovingForager, LateAcceptanceAcceptor,
-    SimulatedAnnealingAcceptor,
+    RestartStrategy, SimulatedAnnealingAcceptor,
 };
 use crate::runtime::compiler::{
     DefaultLocalSearchAcceptorPolicy, DefaultLocalSearchComponents, DefaultLocalSearchForagerPolicy,
@@ -45,6 +45,7 @@ where
         acceptor: Option<AcceptorConfig>,
         forager: Option<ForagerConfig>,
         score_tie_break: ScoreTieBreak,
+        restart: Option<RestartStrategy>,
         random_seed: Option<u64>,
     },
     VariableNeighborhoodDescent {
@@ -77,6 +78,7 @@ where
                 acceptor,
                 forager,
                 score_tie_break,
+                restart,
                 random_seed,
             } => {
                 let (mut acceptor, mut forager) = build_acceptor_and_forager::<S>(
@@ -94,6 +96,7 @@ where
                         &mut acceptor,
                         &mut forager,
                         None,
+                        *restart,
                         solver_scope,
                     );
                 });
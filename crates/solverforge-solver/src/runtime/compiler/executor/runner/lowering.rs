@@ -10,6 +10,7 @@ use solverforge_config::SolverConfig;
 use solverforge_core::domain::PlanningSolution;
 use solverforge_core::score::{ParseableScore, Score};
 
+use crate::builder::{AcceptorBuilder, RestartBuilder};
 use crate::heuristic::selector::nearby_list_change::CrossEntityDistanceMeter;
 use crate::runtime::compiler::{
     CompiledAcceptorForagerSelector, CompiledLocalSearch, CompiledRuntimeExtension,
@@ -84,6 +85,7 @@ where
                             acceptor: None,
                             forager: None,
                             score_tie_break: solverforge_config::ScoreTieBreak::Random,
+                            restart: None,
                             random_seed: solver_config.random_seed,
                         }),
                         Some(plan.candidate_trace_plan()),
@@ -157,6 +159,20 @@ where
                 defaults.local_search_components,
                 omitted_selector_children,
             );
+            if let Some(acceptor) = config.acceptor.as_ref() {
+                AcceptorBuilder::validate(acceptor).map_err(|message| {
+                    RuntimeBuildError::Preparation {
+                        phase_index,
+                        message,
+                    }
+                })?;
+            }
+            let restart = RestartBuilder::build(config.restart.as_ref()).map_err(|message| {
+                RuntimeBuildError::Preparation {
+                    phase_index,
+                    message,
+                }
+            })?;
             Ok((
                 RuntimeLocalSearch::AcceptorForager {
                     phase_termination: config.termination.clone(),
@@ -165,6 +181,7 @@ where
                     acceptor: config.acceptor.clone(),
                     forager: config.forager.clone(),
                     score_tie_break: config.score_tie_break,
+                    restart,
                     random_seed: solver_config.random_seed,
                 },
                 declaration,
@@ -200,6 +200,7 @@ where
             require_hard_improvement,
             left,
             right,
+            ..
         } => CandidateTracePhasePlan::known(
             "solverforge.runtime.selector.cartesian",
             [(
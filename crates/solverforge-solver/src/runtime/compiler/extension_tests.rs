@@ -3,7 +3,8 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 
 use solverforge_config::{
-    CustomPhaseConfig, MoveThreadCount, PartitionedSearchConfig, PhaseConfig, SolverConfig,
+    CustomPhaseConfig, MoveThreadCount, PartitionCountMode, PartitionedSearchConfig, PhaseConfig,
+    SolverConfig,
 };
 use solverforge_core::domain::{PlanningSolution, SolutionDescriptor};
 use solverforge_core::score::SoftScore;
@@ -90,6 +91,7 @@ fn partitioned(name: Option<&str>) -> PhaseConfig {
     PhaseConfig::PartitionedSearch(PartitionedSearchConfig {
         partitioner: name.map(str::to_string),
         thread_count: MoveThreadCount::Count(3),
+        partition_count: PartitionCountMode::Partitioner,
         log_progress: true,
         child_phases: vec![custom("child_extension")],
         termination: None,
@@ -264,6 +264,7 @@ pub(crate) enum CompiledSelectorNode<S, V, DM, IDM> {
     },
     Cartesian {
         require_hard_improvement: bool,
+        max_moves: Option<usize>,
         left: Box<Self>,
         right: Box<Self>,
     },
@@ -386,6 +387,10 @@ pub(crate) enum CompiledRuntimePhase<S, V, DM, IDM> {
 /// Concrete extension builders are deliberately deferred to per-solve graph
 /// execution so compilation has no callback, allocation, or phase side effect.
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "extension declarations keep their frozen configuration value-owned"
+)]
 pub(crate) enum CompiledRuntimeExtension {
     Custom {
         name: String,
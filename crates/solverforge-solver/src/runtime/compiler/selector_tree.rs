@@ -440,6 +440,7 @@ where
             )?;
             Ok(CompiledSelectorNode::Cartesian {
                 require_hard_improvement: cartesian.require_hard_improvement,
+                max_moves: cartesian.max_moves,
                 left: Box::new(left),
                 right: Box::new(right),
             })
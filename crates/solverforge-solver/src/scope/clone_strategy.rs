@@ -0,0 +1,19 @@
+// Best-solution capture policy.
+
+/// When the solver scope clones the working solution into the best solution.
+///
+/// Both strategies end with the same best solution; they differ only in how
+/// many intermediate clones a high-churn search pays for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CloneStrategy {
+    /// Clones the working solution on every best-score improvement.
+    #[default]
+    EveryImprovement,
+    /// Records the improved score and defers the clone until the working
+    /// solution is about to diverge from that best, or the phase ends.
+    ///
+    /// An improvement that is immediately superseded by a better committed
+    /// step is never cloned. Best-solution events for deferred captures are
+    /// published when the snapshot is taken.
+    Deferred,
+}
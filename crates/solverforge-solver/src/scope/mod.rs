@@ -6,15 +6,17 @@ Scopes maintain state at different levels of the solving process:
 - [`StepScope`]: Per-step state within a phase
 */
 
+mod clone_strategy;
 mod phase;
 mod solver;
 mod step;
 
+pub use clone_strategy::CloneStrategy;
 pub use phase::PhaseScope;
-pub(crate) use solver::{PendingControl, SolverScopeChildConfig};
-pub use solver::{ProgressCallback, SolverProgressKind, SolverProgressRef, SolverScope};
+pub(crate) use solver::{max_progress, PendingControl, SolverScopeChildConfig};
+pub use solver::{OnStep, ProgressCallback, SolverProgressKind, SolverProgressRef, SolverScope};
 pub(crate) use step::StepControlPolicy;
-pub use step::StepScope;
+pub use step::{StepEvent, StepScope};
 
 #[cfg(test)]
 mod tests;
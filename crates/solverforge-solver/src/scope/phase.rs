@@ -42,6 +42,12 @@ pub struct PhaseScope<'t, 'a, S: PlanningSolution, D: Director<S>, BestCb = ()>
     stats: PhaseStats,
 }
 
+impl<S: PlanningSolution, D: Director<S>, BestCb> Drop for PhaseScope<'_, '_, S, D, BestCb> {
+    fn drop(&mut self) {
+        self.solver_scope.record_phase_statistics(&self.stats);
+    }
+}
+
 impl<'t, 'a, S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>>
     PhaseScope<'t, 'a, S, D, BestCb>
 {
@@ -110,6 +116,16 @@ impl<'t, 'a, S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>>
         self.step_count
     }
 
+    pub(crate) fn notify_step_completed(&mut self, step_index: u64, step_score: Option<&S::Score>) {
+        let phase_type = self.stats.phase_type;
+        self.solver_scope.notify_step_completed(
+            self.phase_index,
+            phase_type,
+            step_index,
+            step_score,
+        );
+    }
+
     pub fn solver_scope(&self) -> &SolverScope<'t, S, D, BestCb> {
         self.solver_scope
     }
@@ -139,9 +155,14 @@ impl<'t, 'a, S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>>
         self.solver_scope.calculate_score()
     }
 
-    /// Updates best solution.
+    /// Updates best solution, counting the phase's best-score improvements.
     pub fn update_best_solution(&mut self) {
-        self.solver_scope.update_best_solution()
+        let previous_best = self.solver_scope.best_score().copied();
+        self.solver_scope.update_best_solution();
+        let best = self.solver_scope.best_score().copied();
+        if previous_best.is_some_and(|previous| best.is_some_and(|best| best > previous)) {
+            self.stats.record_best_score_improvement();
+        }
     }
 
     /// Publishes the current working solution when it ties the current best score.
@@ -368,6 +389,12 @@ impl<'t, 'a, S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>>
             .record_move_kind_applied(move_label, score_improvement);
     }
 
+    pub fn record_move_type_improvement(&mut self, move_type: &'static str) {
+        self.solver_scope
+            .stats_mut()
+            .record_move_type_improvement(move_type);
+    }
+
     pub fn record_move_kind_not_doable(&mut self, move_label: &'static str) {
         self.stats.record_move_kind_not_doable(move_label);
         self.solver_scope
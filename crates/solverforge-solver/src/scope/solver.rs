@@ -1,5 +1,6 @@
 // Solver-level scope.
 
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
@@ -8,14 +9,16 @@ use rand::SeedableRng;
 
 use solverforge_config::{EnvironmentMode, TerminationConfig};
 use solverforge_core::domain::PlanningSolution;
-use solverforge_core::score::ParseableScore;
+use solverforge_core::score::{ParseableScore, Score};
 use solverforge_scoring::Director;
 
+use super::{CloneStrategy, StepEvent};
 use crate::heuristic::r#move::Move;
 use crate::manager::{SolverLifecycleState, SolverRuntime, SolverTerminalReason};
 use crate::phase::construction::{
     ConstructionFrontier, ConstructionGroupSlotId, ConstructionListElementId, ConstructionSlotId,
 };
+use crate::realtime::{BoxedProblemChange, ProblemChangeReceiver};
 use crate::stats::{
     CandidatePullTelemetry, CandidateTraceConstructionTarget, CandidateTraceDisposition,
     CandidateTraceHeader, CandidateTracePullToken, CandidateTraceRecordDecision,
@@ -25,3 +28,6 @@ use crate::stats::{
 include!("solver/progress.rs");
 include!("solver/scope_core.rs");
 include!("solver/scope_progress.rs");
+include!("solver/best_capture.rs");
+include!("solver/problem_changes.rs");
+include!("solver/inphase_limits.rs");
@@ -0,0 +1,57 @@
+// Best-solution capture under a `CloneStrategy`: immediate clones, deferred
+// captures, and the pending-capture bookkeeping that goes with them.
+
+impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
+    SolverScope<'t, S, D, ProgressCb>
+{
+    /// Runs one phase under `strategy`, then takes any pending best-solution
+    /// capture and restores the previous strategy.
+    pub(crate) fn with_clone_strategy<T>(
+        &mut self,
+        strategy: CloneStrategy,
+        work: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let previous = std::mem::replace(&mut self.clone_strategy, strategy);
+        let result = work(self);
+        self.capture_pending_best_solution();
+        self.clone_strategy = previous;
+        result
+    }
+
+    pub fn clone_strategy(&self) -> CloneStrategy {
+        self.clone_strategy
+    }
+
+    // Clones and publishes an improved best solution, or marks the capture
+    // pending under `CloneStrategy::Deferred`.
+    fn capture_improved_best_solution(&mut self) {
+        match self.clone_strategy {
+            CloneStrategy::EveryImprovement => {
+                self.best_solution = Some(self.score_director.clone_working_solution());
+                self.report_best_solution();
+            }
+            CloneStrategy::Deferred => self.best_solution_pending = true,
+        }
+    }
+
+    /// Clones the working solution into the best solution if a deferred
+    /// capture is pending, then publishes it.
+    pub(crate) fn capture_pending_best_solution(&mut self) {
+        if !self.best_solution_pending {
+            return;
+        }
+        self.best_solution_pending = false;
+        self.best_solution = Some(self.score_director.clone_working_solution());
+        self.report_best_solution();
+    }
+
+    /// Drops a pending capture that the next committed step will beat.
+    ///
+    /// `next_score` must be the score the working solution has once that
+    /// step is applied; `update_best_solution` then records the new best.
+    pub(crate) fn discard_superseded_best_solution(&mut self, next_score: S::Score) {
+        if self.best_solution_pending && self.best_score.is_some_and(|best| next_score > best) {
+            self.best_solution_pending = false;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Solver-wide limits that phases check in-phase: time limits, installed
+// best-score, feasibility-grace and count limits, and the passed deadline.
+
+impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
+    SolverScope<'t, S, D, ProgressCb>
+{
+    pub fn set_time_limit(&mut self, limit: Duration) {
+        self.time_limit = Some(limit);
+    }
+
+    // Tightens the solver time limit without ever relaxing an earlier one.
+    pub(crate) fn tighten_time_limit(&mut self, limit: Duration) {
+        self.time_limit = Some(self.time_limit.map_or(limit, |current| current.min(limit)));
+    }
+
+    pub(crate) fn install_inphase_best_score_limit(&mut self, target_score: S::Score) {
+        let target_score = match self.inphase_best_score_limit {
+            Some(existing) => existing.min(target_score),
+            None => target_score,
+        };
+        self.inphase_best_score_limit = Some(target_score);
+    }
+
+    // Stops phases once the best score has been feasible for `grace`.
+    pub(crate) fn install_inphase_feasible_grace(&mut self, grace: Duration) {
+        let grace = match self.inphase_feasible_grace {
+            Some(existing) => existing.min(grace),
+            None => grace,
+        };
+        self.inphase_feasible_grace = Some(grace);
+    }
+
+    // Records a deadline that had passed before solving started. Construction
+    // ignores it; every other phase stops once a phase has begun.
+    pub(crate) fn install_inphase_passed_deadline(&mut self) {
+        self.inphase_deadline_passed = true;
+    }
+
+    fn time_limit_reached(&self) -> bool {
+        if self
+            .time_deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return true;
+        }
+        self.time_limit
+            .zip(self.elapsed())
+            .is_some_and(|(limit, elapsed)| elapsed >= limit)
+    }
+
+    fn phase_budget_reached(&self) -> bool {
+        self.phase_budget
+            .is_some_and(|phase_budget| phase_budget.limit_reached())
+    }
+
+    fn inphase_best_score_limit_reached(&self) -> bool {
+        self.inphase_best_score_limit
+            .zip(self.best_score)
+            .is_some_and(|(target, best)| best >= target)
+    }
+
+    fn inphase_feasible_grace_reached(&self) -> bool {
+        self.inphase_feasible_grace
+            .zip(self.first_feasible_elapsed)
+            .is_some_and(|(grace, since)| {
+                self.elapsed()
+                    .is_some_and(|elapsed| elapsed.saturating_sub(since) >= grace)
+            })
+    }
+
+    fn record_first_feasible(&mut self, best_score: S::Score) {
+        if self.first_feasible_elapsed.is_none() && best_score.is_feasible() {
+            self.first_feasible_elapsed = Some(self.elapsed().unwrap_or_default());
+        }
+    }
+
+    fn inphase_step_count_limit_reached(&self) -> bool {
+        self.inphase_step_count_limit
+            .is_some_and(|limit| self.total_step_count >= limit)
+    }
+
+    fn inphase_move_count_limit_reached(&self) -> bool {
+        self.inphase_move_count_limit
+            .is_some_and(|limit| self.stats.moves_evaluated >= limit)
+    }
+
+    fn inphase_score_calc_count_limit_reached(&self) -> bool {
+        self.inphase_score_calc_count_limit
+            .is_some_and(|limit| self.stats.score_calculations >= limit)
+    }
+}
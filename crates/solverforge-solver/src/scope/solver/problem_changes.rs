@@ -0,0 +1,39 @@
+// Realtime problem changes: queueing submitted changes and draining them at
+// phase step boundaries.
+
+impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
+    SolverScope<'t, S, D, ProgressCb>
+{
+    /// Queues a problem change for the next step boundary of a phase that
+    /// drains changes.
+    pub fn submit_change(&mut self, change: BoxedProblemChange<S>) {
+        self.problem_changes.push_back(change);
+    }
+
+    /// Applies every queued and received problem change, in submission order.
+    ///
+    /// The score director is reinitialized once afterwards, and the changed
+    /// working solution becomes the new best solution because earlier bests
+    /// describe the old problem. Returns the new working score, or `None`
+    /// when no change was pending.
+    pub(crate) fn apply_pending_problem_changes(&mut self) -> Option<S::Score> {
+        if let Some(receiver) = &self.problem_change_receiver {
+            self.problem_changes.extend(receiver.drain_pending());
+        }
+        if self.problem_changes.is_empty() {
+            return None;
+        }
+        let changes = std::mem::take(&mut self.problem_changes);
+        self.committed_mutation(|score_director| {
+            for change in &changes {
+                change.apply(score_director);
+            }
+            score_director.reset();
+        });
+        let score = self.calculate_score();
+        let solution = self.score_director.clone_working_solution();
+        self.set_best_solution(solution, score);
+        self.report_best_solution();
+        Some(score)
+    }
+}
@@ -17,8 +17,13 @@ pub struct SolverProgressRef<'a, S: PlanningSolution> {
 pub trait ProgressCallback<S: PlanningSolution>: Send + Sync {
     #[doc(hidden)]
     const PUBLISHES_PROGRESS: bool = true;
+    #[doc(hidden)]
+    const OBSERVES_STEPS: bool = false;
 
     fn invoke(&self, progress: SolverProgressRef<'_, S>);
+
+    // Called once per completed step; only `OnStep` observes steps.
+    fn step_completed(&mut self, _step: &StepEvent<'_, S>) {}
 }
 
 impl<S: PlanningSolution> ProgressCallback<S> for () {
@@ -36,3 +41,32 @@ where
         self(progress);
     }
 }
+
+/// Progress callback that also hands every completed step to `on_step`.
+///
+/// Built by [`SolverScope::on_step`]; progress is forwarded to the wrapped
+/// callback unchanged.
+#[derive(Debug, Clone)]
+pub struct OnStep<ProgressCb, F> {
+    progress: ProgressCb,
+    on_step: F,
+}
+
+impl<S, ProgressCb, F> ProgressCallback<S> for OnStep<ProgressCb, F>
+where
+    S: PlanningSolution,
+    ProgressCb: ProgressCallback<S>,
+    F: for<'a> FnMut(&StepEvent<'a, S>) + Send + Sync,
+{
+    const PUBLISHES_PROGRESS: bool = ProgressCb::PUBLISHES_PROGRESS;
+    const OBSERVES_STEPS: bool = true;
+
+    fn invoke(&self, progress: SolverProgressRef<'_, S>) {
+        self.progress.invoke(progress);
+    }
+
+    fn step_completed(&mut self, step: &StepEvent<'_, S>) {
+        self.progress.step_completed(step);
+        (self.on_step)(step);
+    }
+}
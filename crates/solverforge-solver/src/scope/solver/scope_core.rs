@@ -1,4 +1,6 @@
 const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+// Steps between incremental-vs-fresh score checks under `EnvironmentMode::FastAssert`.
+pub(crate) const FAST_ASSERT_STEP_INTERVAL: u64 = 16;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ProgressTick {
@@ -77,6 +79,8 @@ impl ProgressPulse {
 pub struct SolverScope<'t, S: PlanningSolution, D: Director<S>, ProgressCb = ()> {
     score_director: D,
     best_solution: Option<S>,
+    best_solution_pending: bool,
+    clone_strategy: CloneStrategy,
     current_score: Option<S::Score>,
     best_score: Option<S::Score>,
     rng: StdRng,
@@ -85,6 +89,8 @@ pub struct SolverScope<'t, S: PlanningSolution, D: Director<S>, ProgressCb = ()>
     total_step_count: u64,
     terminate: Option<&'t AtomicBool>,
     runtime: Option<SolverRuntime<S>>,
+    problem_changes: VecDeque<BoxedProblemChange<S>>,
+    problem_change_receiver: Option<ProblemChangeReceiver<S>>,
     publication: Publication,
     best_solution_publication_enabled: bool,
     yielded_to_parent: bool,
@@ -96,6 +102,7 @@ pub struct SolverScope<'t, S: PlanningSolution, D: Director<S>, ProgressCb = ()>
     progress_pulse: Option<ProgressPulse>,
     terminal_reason: Option<SolverTerminalReason>,
     last_best_elapsed: Option<Duration>,
+    first_feasible_elapsed: Option<Duration>,
     best_solution_revision: Option<u64>,
     solution_revision: u64,
     construction_frontier: ConstructionFrontier,
@@ -104,6 +111,8 @@ pub struct SolverScope<'t, S: PlanningSolution, D: Director<S>, ProgressCb = ()>
     pub inphase_move_count_limit: Option<u64>,
     pub inphase_score_calc_count_limit: Option<u64>,
     inphase_best_score_limit: Option<S::Score>,
+    inphase_feasible_grace: Option<Duration>,
+    inphase_deadline_passed: bool,
     phase_termination: Option<ScopedPhaseTermination<S>>,
 }
 
@@ -211,7 +220,13 @@ where
             .best_score_limit
             .as_deref()
             .and_then(|score| S::Score::parse(score).ok());
-        let time_limit = config.time_limit();
+        let time_limit = match (
+            config.time_limit(),
+            config.deadline_remaining(std::time::SystemTime::now()),
+        ) {
+            (Some(limit), Some(remaining)) => Some(limit.min(remaining)),
+            (limit, remaining) => limit.or(remaining),
+        };
         let step_count_limit = config.step_count_limit;
         let unimproved_step_count_limit = config.unimproved_step_count_limit;
         let unimproved_time_limit = config.unimproved_time_limit();
@@ -264,6 +279,18 @@ where
             })
     }
 
+    fn progress(&self, total_step_count: u64, elapsed: Duration) -> Option<f64> {
+        let phase_steps = total_step_count.saturating_sub(self.start_step_count);
+        let phase_elapsed = elapsed.saturating_sub(self.start_elapsed);
+        let time_progress = self
+            .time_limit
+            .map(|limit| duration_fraction(phase_elapsed, limit));
+        let step_progress = self
+            .step_count_limit
+            .map(|limit| count_fraction(phase_steps, limit));
+        max_progress(time_progress, step_progress)
+    }
+
     fn record_improvement(&mut self, total_step_count: u64, elapsed: Duration) {
         self.last_improvement_step_count = total_step_count;
         self.last_improvement_elapsed = elapsed;
@@ -291,6 +318,27 @@ where
     }
 }
 
+fn duration_fraction(used: Duration, limit: Duration) -> f64 {
+    if limit.is_zero() {
+        return 1.0;
+    }
+    (used.as_secs_f64() / limit.as_secs_f64()).min(1.0)
+}
+
+fn count_fraction(used: u64, limit: u64) -> f64 {
+    if limit == 0 {
+        return 1.0;
+    }
+    (used as f64 / limit as f64).min(1.0)
+}
+
+pub(crate) fn max_progress(left: Option<f64>, right: Option<f64>) -> Option<f64> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(left.max(right)),
+        (progress, None) | (None, progress) => progress,
+    }
+}
+
 fn remaining_limit(limit: Option<u64>, used: u64) -> Option<u64> {
     limit.map(|limit| limit.saturating_sub(used))
 }
@@ -352,6 +400,8 @@ impl<'t, S: PlanningSolution, D: Director<S>> SolverScope<'t, S, D, ()> {
         Self {
             score_director,
             best_solution: None,
+            best_solution_pending: false,
+            clone_strategy: CloneStrategy::default(),
             current_score: None,
             best_score: None,
             rng: StdRng::from_rng(&mut rand::rng()),
@@ -360,6 +410,8 @@ impl<'t, S: PlanningSolution, D: Director<S>> SolverScope<'t, S, D, ()> {
             total_step_count: 0,
             terminate: None,
             runtime: None,
+            problem_changes: VecDeque::new(),
+            problem_change_receiver: None,
             publication: Publication::Enabled,
             best_solution_publication_enabled: true,
             yielded_to_parent: false,
@@ -371,6 +423,7 @@ impl<'t, S: PlanningSolution, D: Director<S>> SolverScope<'t, S, D, ()> {
             progress_pulse: None,
             terminal_reason: None,
             last_best_elapsed: None,
+            first_feasible_elapsed: None,
             best_solution_revision: None,
             solution_revision: 1,
             construction_frontier,
@@ -379,6 +432,8 @@ impl<'t, S: PlanningSolution, D: Director<S>> SolverScope<'t, S, D, ()> {
             inphase_move_count_limit: None,
             inphase_score_calc_count_limit: None,
             inphase_best_score_limit: None,
+            inphase_feasible_grace: None,
+            inphase_deadline_passed: false,
             phase_termination: None,
         }
     }
@@ -397,6 +452,8 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         Self {
             score_director,
             best_solution: None,
+            best_solution_pending: false,
+            clone_strategy: CloneStrategy::default(),
             current_score: None,
             best_score: None,
             rng: StdRng::from_rng(&mut rand::rng()),
@@ -405,6 +462,8 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             total_step_count: 0,
             terminate,
             runtime,
+            problem_changes: VecDeque::new(),
+            problem_change_receiver: None,
             publication: Publication::Enabled,
             best_solution_publication_enabled: true,
             yielded_to_parent: false,
@@ -416,6 +475,7 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             progress_pulse: None,
             terminal_reason: None,
             last_best_elapsed: None,
+            first_feasible_elapsed: None,
             best_solution_revision: None,
             solution_revision: 1,
             construction_frontier,
@@ -424,6 +484,8 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             inphase_move_count_limit: None,
             inphase_score_calc_count_limit: None,
             inphase_best_score_limit: None,
+            inphase_feasible_grace: None,
+            inphase_deadline_passed: false,
             phase_termination: None,
         }
     }
@@ -438,6 +500,14 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self
     }
 
+    /// Drains problem changes submitted through the receiver's [`crate::realtime::SolverHandle`].
+    ///
+    /// The handle's early-termination request also stops this solve.
+    pub fn with_problem_change_receiver(mut self, receiver: ProblemChangeReceiver<S>) -> Self {
+        self.problem_change_receiver = Some(receiver);
+        self
+    }
+
     pub(crate) fn without_publication(mut self) -> Self {
         self.publication = Publication::Disabled;
         self
@@ -460,6 +530,10 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self
     }
 
+    pub fn environment_mode(&self) -> EnvironmentMode {
+        self.environment_mode
+    }
+
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.rng = StdRng::seed_from_u64(seed);
         self
@@ -519,6 +593,32 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         })
     }
 
+    /// Fraction of the most-consumed time or step termination budget, in `[0, 1]`.
+    ///
+    /// Covers the solver time limit or deadline and the in-phase step limit,
+    /// both measured from solve start, and the phase's own time and step
+    /// limits. Returns `None` when none of them is set.
+    pub(crate) fn termination_progress(&self) -> Option<f64> {
+        let elapsed = self.elapsed().unwrap_or_default();
+        let deadline_limit = self
+            .time_deadline
+            .zip(self.start_time)
+            .map(|(deadline, start)| deadline.saturating_duration_since(start));
+        let time_progress = match (self.time_limit, deadline_limit) {
+            (Some(limit), Some(deadline)) => Some(limit.min(deadline)),
+            (limit, deadline) => limit.or(deadline),
+        }
+        .map(|limit| duration_fraction(elapsed, limit));
+        let step_progress = self
+            .inphase_step_count_limit
+            .map(|limit| count_fraction(self.total_step_count, limit));
+        let phase_progress = self
+            .phase_termination
+            .as_ref()
+            .and_then(|termination| termination.progress(self.total_step_count, elapsed));
+        max_progress(max_progress(time_progress, step_progress), phase_progress)
+    }
+
     pub(crate) fn phase_termination_requires_score_observation(&self) -> bool {
         self.phase_termination
             .as_ref()
@@ -549,10 +649,34 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
     pub fn with_progress_callback<F: ProgressCallback<S>>(
         self,
         callback: F,
+    ) -> SolverScope<'t, S, D, F> {
+        self.map_progress_callback(|_| callback)
+    }
+
+    /// Registers a callback invoked once per completed step of every phase.
+    ///
+    /// The callback becomes part of the scope's progress-callback type, so it
+    /// is dispatched statically; progress keeps going to the current callback.
+    /// Without a registration, step completion skips the event entirely.
+    pub fn on_step<F>(self, callback: F) -> SolverScope<'t, S, D, OnStep<ProgressCb, F>>
+    where
+        F: for<'a> FnMut(&StepEvent<'a, S>) + Send + Sync,
+    {
+        self.map_progress_callback(|progress| OnStep {
+            progress,
+            on_step: callback,
+        })
+    }
+
+    fn map_progress_callback<F: ProgressCallback<S>>(
+        self,
+        map: impl FnOnce(ProgressCb) -> F,
     ) -> SolverScope<'t, S, D, F> {
         SolverScope {
             score_director: self.score_director,
             best_solution: self.best_solution,
+            best_solution_pending: self.best_solution_pending,
+            clone_strategy: self.clone_strategy,
             current_score: self.current_score,
             best_score: self.best_score,
             rng: self.rng,
@@ -561,6 +685,8 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             total_step_count: self.total_step_count,
             terminate: self.terminate,
             runtime: self.runtime,
+            problem_changes: self.problem_changes,
+            problem_change_receiver: self.problem_change_receiver,
             publication: self.publication,
             best_solution_publication_enabled: self.best_solution_publication_enabled,
             yielded_to_parent: self.yielded_to_parent,
@@ -568,10 +694,11 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             stats: self.stats,
             time_limit: self.time_limit,
             time_deadline: self.time_deadline,
-            progress_callback: callback,
+            progress_callback: map(self.progress_callback),
             progress_pulse: self.progress_pulse,
             terminal_reason: self.terminal_reason,
             last_best_elapsed: self.last_best_elapsed,
+            first_feasible_elapsed: self.first_feasible_elapsed,
             best_solution_revision: self.best_solution_revision,
             solution_revision: self.solution_revision,
             construction_frontier: self.construction_frontier,
@@ -580,6 +707,8 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             inphase_move_count_limit: self.inphase_move_count_limit,
             inphase_score_calc_count_limit: self.inphase_score_calc_count_limit,
             inphase_best_score_limit: self.inphase_best_score_limit,
+            inphase_feasible_grace: self.inphase_feasible_grace,
+            inphase_deadline_passed: self.inphase_deadline_passed,
             phase_termination: self.phase_termination,
         }
     }
@@ -590,6 +719,7 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self.total_step_count = 0;
         self.terminal_reason = None;
         self.last_best_elapsed = None;
+        self.first_feasible_elapsed = None;
         self.yielded_to_parent = false;
         self.best_solution_revision = None;
         self.solution_revision = 1;
@@ -606,6 +736,12 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         }
     }
 
+    /// Solver elapsed time at which the best score first became feasible
+    /// under [`Score::is_feasible`](solverforge_core::score::Score::is_feasible).
+    pub fn first_feasible_elapsed(&self) -> Option<Duration> {
+        self.first_feasible_elapsed
+    }
+
     pub fn time_since_last_improvement(&self) -> Option<Duration> {
         let elapsed = self.elapsed()?;
         let last_best_elapsed = self.last_best_elapsed?;
@@ -639,17 +775,31 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         score
     }
 
+    /* Cross-checks the incremental score against a from-scratch recompute.
+    FullAssert checks every calculation; FastAssert only checks during every
+    `FAST_ASSERT_STEP_INTERVAL`th step.
+    */
     pub(crate) fn assert_score_consistent(&self, context: &str, score: S::Score) {
-        if self.environment_mode != EnvironmentMode::FullAssert {
+        let due = match self.environment_mode {
+            EnvironmentMode::FullAssert => true,
+            EnvironmentMode::FastAssert => {
+                self.total_step_count.is_multiple_of(FAST_ASSERT_STEP_INTERVAL)
+            }
+            EnvironmentMode::NonReproducible | EnvironmentMode::Reproducible => false,
+        };
+        if !due {
             return;
         }
         let Some(fresh_score) = self.score_director.fresh_score() else {
             return;
         };
-        assert_eq!(
-            score, fresh_score,
-            "score director drift after {context}: cached score {score:?} != fresh score {fresh_score:?}"
-        );
+        if score != fresh_score {
+            panic!(
+                "score director drift after {context} at step {}: cached score {score:?} != fresh score {fresh_score:?} (diff {:?})",
+                self.total_step_count,
+                score - fresh_score
+            );
+        }
     }
 
     pub fn initialize_working_solution_as_best(&mut self) -> S::Score {
@@ -663,6 +813,7 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
     }
 
     pub fn replace_working_solution_and_reinitialize(&mut self, solution: S) -> S::Score {
+        self.capture_pending_best_solution();
         *self.score_director.working_solution_mut() = solution;
         self.score_director.reset();
         self.current_score = None;
@@ -672,6 +823,10 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self.calculate_score()
     }
 
+    /// Last captured best solution.
+    ///
+    /// Under [`CloneStrategy::Deferred`] this can lag [`Self::best_score`]
+    /// until the pending capture is taken.
     pub fn best_solution(&self) -> Option<&S> {
         self.best_solution.as_ref()
     }
@@ -719,6 +874,13 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self.solution_revision
     }
 
+    // Moves the solve start earlier, so `elapsed()` reads `by` later without
+    // waiting on the wall clock.
+    #[cfg(test)]
+    pub(crate) fn advance_elapsed(&mut self, by: Duration) {
+        self.start_time = self.start_time.map(|start| start - by);
+    }
+
     pub(crate) fn apply_committed_move<M>(&mut self, mov: &M)
     where
         M: Move<S>,
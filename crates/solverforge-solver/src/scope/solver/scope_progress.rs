@@ -97,11 +97,12 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         };
 
         if is_better {
-            self.best_solution = Some(self.score_director.clone_working_solution());
             self.best_score = Some(current_score);
             self.last_best_elapsed = self.elapsed();
+            self.record_first_feasible(current_score);
+            self.record_score_improvement(current_score);
             self.best_solution_revision = Some(self.solution_revision);
-            self.report_best_solution();
+            self.capture_improved_best_solution();
         }
     }
 
@@ -128,10 +129,44 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         }
         self.current_score = Some(score);
         self.best_solution = Some(solution);
+        self.best_solution_pending = false;
         self.best_score = Some(score);
         self.last_best_elapsed = self.elapsed();
+        self.record_first_feasible(score);
         self.best_solution_revision = Some(self.solution_revision);
         self.observe_phase_score(score, self.total_step_count);
+        self.record_score_improvement(score);
+    }
+
+    pub(crate) fn notify_step_completed(
+        &mut self,
+        phase_index: usize,
+        phase_type: &'static str,
+        step_index: u64,
+        step_score: Option<&S::Score>,
+    ) {
+        if !ProgressCb::OBSERVES_STEPS {
+            return;
+        }
+        let elapsed = self.elapsed().unwrap_or_default();
+        self.progress_callback.step_completed(&StepEvent {
+            phase_index,
+            phase_type,
+            step_index,
+            step_score,
+            elapsed,
+        });
+    }
+
+    /// Enables opt-in per-phase and best-score history for this solve.
+    pub fn enable_statistics(&mut self) {
+        self.stats.enable_statistics();
+    }
+
+    fn record_score_improvement(&mut self, score: S::Score) {
+        let elapsed = self.elapsed().unwrap_or_default();
+        self.stats
+            .record_score_improvement(elapsed, self.total_step_count, || score.to_string());
     }
 
     pub(crate) fn publish_current_solution_as_best(&mut self) {
@@ -159,17 +194,19 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self.total_step_count
     }
 
-    pub fn take_best_solution(self) -> Option<S> {
+    pub fn take_best_solution(mut self) -> Option<S> {
+        self.capture_pending_best_solution();
         self.best_solution
     }
 
-    pub fn take_best_or_working_solution(self) -> S {
+    pub fn take_best_or_working_solution(mut self) -> S {
+        self.capture_pending_best_solution();
         self.best_solution
             .unwrap_or_else(|| self.score_director.clone_working_solution())
     }
 
     pub fn take_solution_and_stats(
-        self,
+        mut self,
     ) -> (
         S,
         Option<S::Score>,
@@ -177,6 +214,7 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         SolverStats,
         SolverTerminalReason,
     ) {
+        self.capture_pending_best_solution();
         let terminal_reason = self.terminal_reason();
         let solution = self
             .best_solution
@@ -200,6 +238,10 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             || self
                 .runtime
                 .is_some_and(|runtime| runtime.is_cancel_requested())
+            || self
+                .problem_change_receiver
+                .as_ref()
+                .is_some_and(ProblemChangeReceiver::is_terminate_early_requested)
     }
 
     pub(crate) fn pending_control(&self) -> PendingControl {
@@ -224,6 +266,9 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         if self.inphase_best_score_limit_reached() {
             return PendingControl::ConfigTerminationRequested;
         }
+        if self.inphase_feasible_grace_reached() {
+            return PendingControl::ConfigTerminationRequested;
+        }
         if self.inphase_step_count_limit_reached()
             || self.inphase_move_count_limit_reached()
             || self.inphase_score_calc_count_limit_reached()
@@ -237,11 +282,13 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         self.yielded_to_parent
             || self.terminate.is_some()
             || self.runtime.is_some()
+            || self.problem_change_receiver.is_some()
             || self.time_limit.is_some()
             || self.time_deadline.is_some()
             || self.phase_budget.is_some()
             || self.phase_termination.is_some()
             || self.inphase_best_score_limit.is_some()
+            || self.inphase_feasible_grace.is_some()
             || self.inphase_step_count_limit.is_some()
             || self.inphase_move_count_limit.is_some()
             || self.inphase_score_calc_count_limit.is_some()
@@ -254,13 +301,15 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             || self.phase_budget_reached()
             || self.phase_termination_reached()
             || self.inphase_best_score_limit_reached()
+            || self.inphase_feasible_grace_reached()
             || self.inphase_step_count_limit_reached()
             || self.inphase_move_count_limit_reached()
             || self.inphase_score_calc_count_limit_reached()
     }
 
-    pub fn set_time_limit(&mut self, limit: Duration) {
-        self.time_limit = Some(limit);
+    // Whether any phase has begun reporting progress in this solve.
+    pub(crate) fn has_started_phase(&self) -> bool {
+        self.progress_pulse.is_some()
     }
 
     pub fn pause_if_requested(&mut self) {
@@ -308,6 +357,10 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             self.mark_terminated_by_config();
             return true;
         }
+        if self.inphase_feasible_grace_reached() {
+            self.mark_terminated_by_config();
+            return true;
+        }
         if self.inphase_step_count_limit_reached()
             || self.inphase_move_count_limit_reached()
             || self.inphase_score_calc_count_limit_reached()
@@ -342,6 +395,14 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             self.mark_terminated_by_config();
             return true;
         }
+        if self.inphase_feasible_grace_reached() {
+            self.mark_terminated_by_config();
+            return true;
+        }
+        if self.inphase_deadline_passed && self.has_started_phase() {
+            self.mark_terminated_by_config();
+            return true;
+        }
         if self.inphase_step_count_limit_reached() {
             self.mark_terminated_by_config();
             return true;
@@ -367,14 +428,6 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
             .get_or_insert(SolverTerminalReason::TerminatedByConfig);
     }
 
-    pub(crate) fn install_inphase_best_score_limit(&mut self, target_score: S::Score) {
-        let target_score = match self.inphase_best_score_limit {
-            Some(existing) => existing.min(target_score),
-            None => target_score,
-        };
-        self.inphase_best_score_limit = Some(target_score);
-    }
-
     pub fn stats(&self) -> &SolverStats {
         &self.stats
     }
@@ -549,44 +602,6 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
         }
     }
 
-    fn time_limit_reached(&self) -> bool {
-        if self
-            .time_deadline
-            .is_some_and(|deadline| Instant::now() >= deadline)
-        {
-            return true;
-        }
-        self.time_limit
-            .zip(self.elapsed())
-            .is_some_and(|(limit, elapsed)| elapsed >= limit)
-    }
-
-    fn phase_budget_reached(&self) -> bool {
-        self.phase_budget
-            .is_some_and(|phase_budget| phase_budget.limit_reached())
-    }
-
-    fn inphase_best_score_limit_reached(&self) -> bool {
-        self.inphase_best_score_limit
-            .zip(self.best_score)
-            .is_some_and(|(target, best)| best >= target)
-    }
-
-    fn inphase_step_count_limit_reached(&self) -> bool {
-        self.inphase_step_count_limit
-            .is_some_and(|limit| self.total_step_count >= limit)
-    }
-
-    fn inphase_move_count_limit_reached(&self) -> bool {
-        self.inphase_move_count_limit
-            .is_some_and(|limit| self.stats.moves_evaluated >= limit)
-    }
-
-    fn inphase_score_calc_count_limit_reached(&self) -> bool {
-        self.inphase_score_calc_count_limit
-            .is_some_and(|limit| self.stats.score_calculations >= limit)
-    }
-
     fn advance_solution_revision(&mut self) {
         self.solution_revision = self.solution_revision.wrapping_add(1);
         if self.solution_revision == 0 {
@@ -599,9 +614,16 @@ impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb: ProgressCallback<S>>
     where
         F: FnOnce(&mut D) -> T,
     {
+        self.capture_pending_best_solution();
         self.current_score = None;
         let output = mutate(&mut self.score_director);
         self.advance_solution_revision();
         output
     }
 }
+
+impl<'t, S: PlanningSolution, D: Director<S>, ProgressCb> SolverScope<'t, S, D, ProgressCb> {
+    pub(crate) fn record_phase_statistics(&mut self, phase: &crate::stats::PhaseStats) {
+        self.stats.record_phase_statistics(phase);
+    }
+}
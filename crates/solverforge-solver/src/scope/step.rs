@@ -1,5 +1,7 @@
 // Step-level scope.
 
+use std::time::Duration;
+
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
 
@@ -31,6 +33,21 @@ impl StepControlPolicy {
     }
 }
 
+/// Completed-step payload passed to a [`SolverScope::on_step`] callback.
+///
+/// The programmatic counterpart of the console's per-step trace events.
+#[derive(Debug, Clone, Copy)]
+pub struct StepEvent<'a, S: PlanningSolution> {
+    pub phase_index: usize,
+    pub phase_type: &'static str,
+    // Index of the completed step within its phase (0-based).
+    pub step_index: u64,
+    // Score after the step, when the phase set one.
+    pub step_score: Option<&'a S::Score>,
+    // Solver-active elapsed time; pauses are excluded.
+    pub elapsed: Duration,
+}
+
 /// Scope for a single step within a phase.
 ///
 /// # Type Parameters
@@ -113,6 +130,8 @@ impl<'t, 'a, 'b, S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S
     /// Marks this step as complete and increments counters.
     pub fn complete(&mut self) {
         self.phase_scope.increment_step_count();
+        self.phase_scope
+            .notify_step_completed(self.step_index, self.step_score.as_ref());
         self.phase_scope.solver_scope_mut().pause_if_requested();
         self.phase_scope.report_progress_if_due();
     }
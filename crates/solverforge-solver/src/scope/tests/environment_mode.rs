@@ -0,0 +1,161 @@
+// Row-sum penalty whose retract forgets to give the old contribution back.
+#[derive(Debug)]
+struct LeakyRetractConstraints;
+
+impl LeakyRetractConstraints {
+    fn row_penalty(solution: &NQueensSolution, entity_index: usize) -> SoftScore {
+        SoftScore::of(-solution.queens[entity_index].row.unwrap_or(0))
+    }
+}
+
+impl ConstraintSet<NQueensSolution, SoftScore> for LeakyRetractConstraints {
+    fn evaluate_all(&self, solution: &NQueensSolution) -> SoftScore {
+        (0..solution.queens.len())
+            .map(|entity_index| Self::row_penalty(solution, entity_index))
+            .fold(SoftScore::ZERO, |total, penalty| total + penalty)
+    }
+
+    fn constraint_count(&self) -> usize {
+        1
+    }
+
+    fn constraint_metadata_entries(&self) -> Vec<ConstraintMetadata<'_>> {
+        Vec::new()
+    }
+
+    fn evaluate_each<'a>(
+        &'a self,
+        _solution: &NQueensSolution,
+    ) -> Vec<ConstraintResult<'a, SoftScore>> {
+        Vec::new()
+    }
+
+    fn evaluate_detailed<'a>(
+        &'a self,
+        _solution: &NQueensSolution,
+    ) -> Vec<ConstraintAnalysis<'a, SoftScore>> {
+        Vec::new()
+    }
+
+    fn initialize_all(&mut self, solution: &NQueensSolution) -> SoftScore {
+        self.evaluate_all(solution)
+    }
+
+    fn on_insert_all(
+        &mut self,
+        solution: &NQueensSolution,
+        entity_index: usize,
+        _descriptor_index: usize,
+    ) -> SoftScore {
+        Self::row_penalty(solution, entity_index)
+    }
+
+    fn on_retract_all(
+        &mut self,
+        _solution: &NQueensSolution,
+        _entity_index: usize,
+        _descriptor_index: usize,
+    ) -> SoftScore {
+        SoftScore::ZERO
+    }
+
+    fn reset_all(&mut self) {}
+}
+
+fn leaky_scope(
+    environment_mode: EnvironmentMode,
+) -> SolverScope<'static, NQueensSolution, ScoreDirector<NQueensSolution, LeakyRetractConstraints>>
+{
+    let director = ScoreDirector::with_descriptor(
+        NQueensSolution::with_rows(&[0, 1, 2, 3]),
+        LeakyRetractConstraints,
+        create_nqueens_descriptor(),
+        |s, _| s.queens.len(),
+    );
+    let mut scope = SolverScope::new(director).with_environment_mode(environment_mode);
+    scope.calculate_score();
+    scope
+}
+
+fn move_queen(
+    scope: &mut SolverScope<
+        'static,
+        NQueensSolution,
+        ScoreDirector<NQueensSolution, LeakyRetractConstraints>,
+    >,
+    entity_index: usize,
+    row: i64,
+) -> SoftScore {
+    scope.mutate(|director| {
+        director.before_variable_changed(0, entity_index);
+        director.working_solution_mut().queens[entity_index].row = Some(row);
+        director.after_variable_changed(0, entity_index);
+    });
+    scope.calculate_score()
+}
+
+#[test]
+#[should_panic(expected = "score director drift after calculate_score at step 0")]
+fn full_assert_catches_wrong_retract_delta() {
+    let mut scope = leaky_scope(EnvironmentMode::FullAssert);
+
+    move_queen(&mut scope, 3, 0);
+}
+
+#[test]
+fn full_assert_reports_the_score_diff() {
+    let mut scope = leaky_scope(EnvironmentMode::FullAssert);
+
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        move_queen(&mut scope, 3, 0);
+    }))
+    .expect_err("drift should panic");
+    let message = panic
+        .downcast_ref::<String>()
+        .expect("panic message should be formatted");
+
+    let expected = format!(
+        "cached score {:?} != fresh score {:?} (diff {:?})",
+        SoftScore::of(-6),
+        SoftScore::of(-3),
+        SoftScore::of(-3)
+    );
+    assert!(message.contains(&expected), "{message}");
+}
+
+#[test]
+fn fast_assert_only_checks_every_interval_steps() {
+    let mut scope = leaky_scope(EnvironmentMode::FastAssert);
+    scope.increment_step_count();
+
+    // Off-interval steps tolerate drift.
+    assert_eq!(move_queen(&mut scope, 3, 0), SoftScore::of(-6));
+
+    while !scope
+        .total_step_count()
+        .is_multiple_of(FAST_ASSERT_STEP_INTERVAL)
+    {
+        scope.increment_step_count();
+    }
+    let drift = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        scope.calculate_score();
+    }));
+
+    assert!(drift.is_err());
+}
+
+#[test]
+fn non_assert_modes_skip_the_fresh_score_check() {
+    for environment_mode in [
+        EnvironmentMode::NonReproducible,
+        EnvironmentMode::Reproducible,
+    ] {
+        let mut scope = leaky_scope(environment_mode);
+
+        assert_eq!(move_queen(&mut scope, 3, 0), SoftScore::of(-6));
+        assert_eq!(
+            scope.score_director().fresh_score(),
+            Some(SoftScore::of(-3))
+        );
+    }
+}
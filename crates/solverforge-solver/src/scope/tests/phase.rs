@@ -0,0 +1,79 @@
+#[test]
+fn test_phase_scope() {
+    let director = create_simple_nqueens_director(2);
+    let mut solver_scope = SolverScope::new(director);
+
+    {
+        let mut phase_scope = PhaseScope::new(&mut solver_scope, 0);
+        assert_eq!(phase_scope.phase_index(), 0);
+        assert_eq!(phase_scope.step_count(), 0);
+
+        phase_scope.increment_step_count();
+        assert_eq!(phase_scope.step_count(), 1);
+    }
+
+    assert_eq!(solver_scope.total_step_count(), 1);
+}
+
+#[test]
+fn phase_progress_elapsed_uses_the_pause_aware_solver_clock() {
+    let publications = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let captured = std::sync::Arc::clone(&publications);
+    let callback = move |progress: SolverProgressRef<'_, TestSolution>| {
+        captured.lock().unwrap().push(progress.telemetry);
+    };
+    let mut solver_scope =
+        SolverScope::new_with_callback(create_minimal_director(), callback, None, None);
+    solver_scope.start_solving();
+
+    let mut phase_scope = PhaseScope::with_phase_type(&mut solver_scope, 0, "PauseAwarePhase");
+    phase_scope.solver_scope_mut().pause_timers();
+    let frozen_elapsed = phase_scope.elapsed();
+    let raw_before = phase_scope.stats().elapsed();
+    for value in 0..100_000 {
+        std::hint::black_box(value);
+    }
+    let raw_after = phase_scope.stats().elapsed();
+
+    assert_eq!(phase_scope.elapsed(), frozen_elapsed);
+    assert!(raw_after > raw_before);
+    phase_scope.report_progress();
+    drop(phase_scope);
+
+    let telemetry = publications
+        .lock()
+        .unwrap()
+        .pop()
+        .expect("phase progress is published");
+    let phase = telemetry.phase.expect("phase telemetry is attached");
+    assert_eq!(phase.phase_type, "PauseAwarePhase");
+    assert_eq!(phase.elapsed, frozen_elapsed);
+    assert!(phase.elapsed < raw_after);
+}
+
+#[test]
+fn phase_progress_reports_first_real_work_then_resumes_the_time_cadence() {
+    let publications = Arc::new(AtomicUsize::new(0));
+    let captured = Arc::clone(&publications);
+    let callback = move |progress: SolverProgressRef<'_, TestSolution>| {
+        if progress.kind == SolverProgressKind::Progress {
+            captured.fetch_add(1, Ordering::SeqCst);
+        }
+    };
+    let mut solver_scope =
+        SolverScope::new_with_callback(create_minimal_director(), callback, None, None);
+    solver_scope.start_solving();
+
+    let mut phase_scope = PhaseScope::with_phase_type(&mut solver_scope, 0, "ImmediatePhase");
+    assert!(!phase_scope.report_progress_if_due());
+
+    phase_scope.record_generated_move(std::time::Duration::ZERO);
+    phase_scope.record_evaluated_move(std::time::Duration::ZERO);
+    assert!(phase_scope.report_progress_if_due());
+    assert_eq!(publications.load(Ordering::SeqCst), 1);
+
+    phase_scope.record_generated_move(std::time::Duration::ZERO);
+    phase_scope.record_evaluated_move(std::time::Duration::ZERO);
+    assert!(!phase_scope.report_progress_if_due());
+    assert_eq!(publications.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,21 @@
+#[test]
+fn test_step_scope() {
+    let director = create_simple_nqueens_director(2);
+    let mut solver_scope = SolverScope::new(director);
+
+    {
+        let mut phase_scope = PhaseScope::new(&mut solver_scope, 0);
+
+        {
+            let mut step_scope = StepScope::new(&mut phase_scope);
+            assert_eq!(step_scope.step_index(), 0);
+
+            step_scope.set_step_score(SoftScore::of(-5));
+            assert_eq!(step_scope.step_score(), Some(&SoftScore::of(-5)));
+
+            step_scope.complete();
+        }
+
+        assert_eq!(phase_scope.step_count(), 1);
+    }
+}
@@ -102,6 +102,8 @@ pub struct Solver<'t, P, T, S: PlanningSolution, D, ProgressCb = ()> {
     candidate_trace_execution_policy: Option<CandidateTraceExecutionPolicy>,
     /// Explicit fail-closed benchmark attestation for qualified comparisons.
     candidate_trace_qualified_run_provenance: Option<QualifiedCandidateTraceRunProvenance>,
+    // Whether to collect per-phase and best-score history into `SolverStats`.
+    collect_statistics: bool,
     time_limit: Option<Duration>,
     // Callback invoked when the solver should publish progress.
     progress_callback: ProgressCb,
@@ -132,6 +134,7 @@ where
             config: None,
             candidate_trace_execution_policy: None,
             candidate_trace_qualified_run_provenance: None,
+            collect_statistics: false,
             time_limit: None,
             progress_callback: (),
             _phantom: PhantomData,
@@ -147,6 +150,7 @@ where
             config: self.config,
             candidate_trace_execution_policy: self.candidate_trace_execution_policy,
             candidate_trace_qualified_run_provenance: self.candidate_trace_qualified_run_provenance,
+            collect_statistics: self.collect_statistics,
             time_limit: self.time_limit,
             progress_callback: self.progress_callback,
             _phantom: PhantomData,
@@ -170,6 +174,7 @@ where
             config: self.config,
             candidate_trace_execution_policy: self.candidate_trace_execution_policy,
             candidate_trace_qualified_run_provenance: self.candidate_trace_qualified_run_provenance,
+            collect_statistics: self.collect_statistics,
             time_limit: self.time_limit,
             progress_callback: self.progress_callback,
             _phantom: PhantomData,
@@ -186,6 +191,13 @@ where
         self
     }
 
+    /// Collects per-phase and best-score history, exposed through
+    /// `SolveResult::stats().statistics()`.
+    pub fn with_statistics(mut self) -> Self {
+        self.collect_statistics = true;
+        self
+    }
+
     /// Supplies the canonical policy resolved by a first-party entrypoint.
     ///
     /// This remains crate-private so external users cannot accidentally claim
@@ -222,6 +234,7 @@ where
             config: self.config,
             candidate_trace_execution_policy: self.candidate_trace_execution_policy,
             candidate_trace_qualified_run_provenance: self.candidate_trace_qualified_run_provenance,
+            collect_statistics: self.collect_statistics,
             time_limit: self.time_limit,
             progress_callback: callback,
             _phantom: PhantomData,
@@ -331,6 +344,7 @@ macro_rules! impl_solver {
                     config,
                     candidate_trace_execution_policy,
                     candidate_trace_qualified_run_provenance,
+                    collect_statistics,
                     time_limit,
                     progress_callback,
                     ..
@@ -342,6 +356,9 @@ macro_rules! impl_solver {
                     terminate,
                     runtime,
                 );
+                if collect_statistics {
+                    solver_scope.enable_statistics();
+                }
                 if let Some(trace_config) = config.as_ref().and_then(|config| config.candidate_trace) {
                     let phase_children = vec![$(phases.$idx.candidate_trace_plan(),)+];
                     let resolved_phase_plan = CandidateTracePhasePlan::known(
@@ -493,6 +510,7 @@ macro_rules! impl_solver_with_director {
                     config: self.config,
                     candidate_trace_execution_policy: self.candidate_trace_execution_policy,
                     candidate_trace_qualified_run_provenance: self.candidate_trace_qualified_run_provenance,
+                    collect_statistics: self.collect_statistics,
                     time_limit: self.time_limit,
                     progress_callback: self.progress_callback,
                     _phantom: PhantomData,
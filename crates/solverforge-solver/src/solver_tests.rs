@@ -1,13 +1,14 @@
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Barrier, Mutex};
+use std::time::Duration;
 
 use solverforge_config::{CandidateTraceConfig, SolverConfig};
 
 use super::Solver;
 use crate::manager::SolverTerminalReason;
 use crate::phase::{Phase, PhaseSequence};
-use crate::scope::{ProgressCallback, SolverScope};
-use crate::stats::CandidateTracePhasePlan;
+use crate::scope::{PhaseScope, ProgressCallback, SolverScope};
+use crate::stats::{CandidateTracePhasePlan, SolverStatistics, SOLVER_STATISTICS_CSV_COLUMNS};
 use crate::termination::StepCountTermination;
 use crate::test_utils::{create_minimal_director, TestDirector, TestSolution};
 
@@ -291,3 +292,93 @@ fn cancellation_accepted_during_terminal_hook_is_settled_before_result_extractio
         SolverTerminalReason::Cancelled
     );
 }
+
+#[derive(Debug)]
+struct CountingPhase {
+    phase_type: &'static str,
+    steps: u64,
+}
+
+impl<ProgressCb> Phase<TestSolution, TestDirector, ProgressCb> for CountingPhase
+where
+    ProgressCb: ProgressCallback<TestSolution>,
+{
+    fn solve(
+        &mut self,
+        solver_scope: &mut SolverScope<'_, TestSolution, TestDirector, ProgressCb>,
+    ) {
+        let mut phase_scope = PhaseScope::with_phase_type(solver_scope, 0, self.phase_type);
+        for _ in 0..self.steps {
+            phase_scope.record_evaluated_move(Duration::ZERO);
+            phase_scope.record_evaluated_move(Duration::ZERO);
+            phase_scope.record_move_accepted();
+            phase_scope.increment_step_count();
+        }
+        phase_scope.update_best_solution();
+    }
+
+    fn phase_type_name(&self) -> &'static str {
+        self.phase_type
+    }
+}
+
+#[test]
+fn statistics_are_collected_only_when_enabled() {
+    let result = Solver::new((CountingPhase {
+        phase_type: "Construction",
+        steps: 1,
+    },))
+    .solve(create_minimal_director());
+
+    assert!(result.stats().statistics().is_none());
+}
+
+#[test]
+fn collected_statistics_dump_one_csv_row_per_phase_and_round_trip_json() {
+    let result = Solver::new((
+        CountingPhase {
+            phase_type: "Construction",
+            steps: 2,
+        },
+        CountingPhase {
+            phase_type: "Local Search",
+            steps: 3,
+        },
+    ))
+    .with_statistics()
+    .solve(create_minimal_director());
+    let statistics = result
+        .stats()
+        .statistics()
+        .expect("statistics collection was enabled");
+
+    let mut csv = Vec::new();
+    statistics.to_csv(&mut csv).expect("CSV writes to memory");
+    let csv = String::from_utf8(csv).expect("CSV is UTF-8");
+    let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0], SOLVER_STATISTICS_CSV_COLUMNS);
+    let column = |name: &str| {
+        SOLVER_STATISTICS_CSV_COLUMNS
+            .iter()
+            .position(|column| *column == name)
+            .expect("known column")
+    };
+    assert_eq!(rows[1][column("phase_type")], "Construction");
+    assert_eq!(rows[1][column("step_count")], "2");
+    assert_eq!(rows[2][column("phase_type")], "Local Search");
+    assert_eq!(rows[2][column("step_count")], "3");
+    assert_eq!(rows[2][column("moves_evaluated")], "6");
+    assert_eq!(rows[2][column("acceptance_rate")], "0.5");
+    assert!(rows
+        .iter()
+        .all(|row| row.len() == SOLVER_STATISTICS_CSV_COLUMNS.len()));
+
+    let mut json = Vec::new();
+    statistics
+        .to_json(&mut json)
+        .expect("JSON writes to memory");
+    let parsed: SolverStatistics = serde_json::from_slice(&json).expect("JSON parses back");
+    assert_eq!(&parsed, statistics);
+    assert!(!parsed.score_improvements.is_empty());
+}
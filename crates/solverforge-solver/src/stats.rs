@@ -2,6 +2,7 @@ mod candidate_trace;
 mod candidate_trace_qualified;
 mod phase;
 mod solver;
+mod statistics;
 mod telemetry;
 
 pub use candidate_trace::{
@@ -20,6 +21,10 @@ pub use candidate_trace_qualified::{
 };
 pub use phase::PhaseStats;
 pub use solver::SolverStats;
+pub use statistics::{
+    PhaseStatistics, ScoreImprovement, SolverStatistics, StatisticsCollector,
+    SOLVER_STATISTICS_CSV_COLUMNS,
+};
 pub(crate) use telemetry::{format_duration, whole_units_per_second};
 pub use telemetry::{
     AppliedMoveTelemetry, MoveTelemetry, PhaseTelemetry, SelectorTelemetry, SolverTelemetry,
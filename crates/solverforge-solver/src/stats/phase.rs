@@ -42,6 +42,8 @@ pub struct PhaseStats {
     pub construction_slots_kept: u64,
     pub construction_slots_no_doable: u64,
     pub scalar_assignment_required_remaining: u64,
+    // Number of times this phase improved the best score.
+    pub best_score_improvements: u64,
     generation_time: Duration,
     evaluation_time: Duration,
     selector_stats: Vec<SelectorTelemetry>,
@@ -78,6 +80,7 @@ impl PhaseStats {
             construction_slots_kept: 0,
             construction_slots_no_doable: 0,
             scalar_assignment_required_remaining: 0,
+            best_score_improvements: 0,
             generation_time: Duration::default(),
             evaluation_time: Duration::default(),
             selector_stats: Vec::new(),
@@ -107,6 +110,7 @@ impl PhaseStats {
             moves_score_improving: self.moves_score_improving(),
             moves_applied_improving: self.moves_applied_improving(),
             score_calculations: self.score_calculations,
+            best_score_improvements: self.best_score_improvements,
             generation_time: self.generation_time,
             evaluation_time: self.evaluation_time,
         }
@@ -253,6 +257,11 @@ impl PhaseStats {
         self.construction_slots_no_doable += 1;
     }
 
+    /// Records that this phase published a strictly better best score.
+    pub fn record_best_score_improvement(&mut self) {
+        self.best_score_improvements += 1;
+    }
+
     pub fn record_scalar_assignment_required_remaining(&mut self, count: u64) {
         self.scalar_assignment_required_remaining = count;
     }
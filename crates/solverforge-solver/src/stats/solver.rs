@@ -5,7 +5,8 @@ use std::time::{Duration, Instant};
 use super::{
     AppliedMoveTelemetry, CandidatePullTelemetry, CandidateTraceDisposition,
     CandidateTracePhasePlan, CandidateTracePullToken, CandidateTraceRecordDecision,
-    CandidateTraceTelemetry, MoveTelemetry, SelectorTelemetry, SolverTelemetry, Throughput,
+    CandidateTraceTelemetry, MoveTelemetry, PhaseStats, SelectorTelemetry, SolverStatistics,
+    SolverTelemetry, StatisticsCollector, Throughput,
 };
 
 const APPLIED_MOVE_TRACE_LIMIT: usize = 8;
@@ -50,6 +51,7 @@ pub struct SolverStats {
     move_stats: BTreeMap<&'static str, MoveTelemetry>,
     applied_move_trace: Vec<AppliedMoveTelemetry>,
     candidate_trace: Option<CandidateTraceTelemetry>,
+    statistics: Option<StatisticsCollector>,
 }
 
 impl SolverStats {
@@ -400,6 +402,41 @@ impl SolverStats {
         }
     }
 
+    /// Enables opt-in per-phase and best-score history collection.
+    pub fn enable_statistics(&mut self) {
+        self.statistics.get_or_insert_with(StatisticsCollector::new);
+    }
+
+    /// Returns the collected history, or `None` when collection is disabled.
+    pub fn statistics(&self) -> Option<&SolverStatistics> {
+        self.statistics
+            .as_ref()
+            .map(StatisticsCollector::statistics)
+    }
+
+    pub(crate) fn record_phase_statistics(&mut self, phase: &PhaseStats) {
+        if let Some(statistics) = self.statistics.as_mut() {
+            statistics.record_phase(phase);
+        }
+    }
+
+    pub(crate) fn record_move_type_improvement(&mut self, move_type: &'static str) {
+        if let Some(statistics) = self.statistics.as_mut() {
+            statistics.record_move_type_improvement(move_type);
+        }
+    }
+
+    pub(crate) fn record_score_improvement(
+        &mut self,
+        elapsed: Duration,
+        step_count: u64,
+        score: impl FnOnce() -> String,
+    ) {
+        if let Some(statistics) = self.statistics.as_mut() {
+            statistics.record_score_improvement(elapsed, step_count, score());
+        }
+    }
+
     pub(crate) fn enable_candidate_trace(&mut self, candidate_trace: CandidateTraceTelemetry) {
         assert!(
             self.candidate_trace.is_none(),
@@ -0,0 +1,175 @@
+//! Opt-in per-phase and best-score history for post-hoc analysis.
+//!
+//! Nothing is collected unless a solve enables a [`StatisticsCollector`]; the
+//! counters in [`super::SolverStats`] remain the always-on aggregate view.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::PhaseStats;
+
+/// Column order written by [`SolverStatistics::to_csv`].
+pub const SOLVER_STATISTICS_CSV_COLUMNS: [&str; 11] = [
+    "phase_index",
+    "phase_type",
+    "elapsed_secs",
+    "step_count",
+    "moves_generated",
+    "moves_evaluated",
+    "moves_accepted",
+    "moves_applied",
+    "acceptance_rate",
+    "best_score_improvements",
+    "score_calculations",
+];
+
+/// Final counters of one completed phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseStatistics {
+    pub phase_index: usize,
+    pub phase_type: String,
+    pub elapsed: Duration,
+    pub step_count: u64,
+    pub moves_generated: u64,
+    pub moves_evaluated: u64,
+    pub moves_accepted: u64,
+    pub moves_applied: u64,
+    pub best_score_improvements: u64,
+    pub score_calculations: u64,
+}
+
+impl PhaseStatistics {
+    fn from_phase(phase: &PhaseStats) -> Self {
+        Self {
+            phase_index: phase.phase_index,
+            phase_type: phase.phase_type.to_string(),
+            elapsed: phase.elapsed(),
+            step_count: phase.step_count,
+            moves_generated: phase.moves_generated,
+            moves_evaluated: phase.moves_evaluated,
+            moves_accepted: phase.moves_accepted,
+            moves_applied: phase.moves_applied,
+            best_score_improvements: phase.best_score_improvements,
+            score_calculations: phase.score_calculations,
+        }
+    }
+
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.moves_evaluated == 0 {
+            0.0
+        } else {
+            self.moves_accepted as f64 / self.moves_evaluated as f64
+        }
+    }
+}
+
+/// One published best score, in publication order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreImprovement {
+    // Solver-active elapsed time when the best score was published.
+    pub elapsed: Duration,
+    // Total solver step count when the best score was published.
+    pub step_count: u64,
+    // Display form of the published best score.
+    pub score: String,
+}
+
+/// Collected per-phase and best-score history of one solve.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolverStatistics {
+    pub phases: Vec<PhaseStatistics>,
+    pub score_improvements: Vec<ScoreImprovement>,
+    // Applied local-search steps that improved the step score, by `Move::type_name`.
+    #[serde(default)]
+    pub move_type_improvements: BTreeMap<String, u64>,
+}
+
+impl SolverStatistics {
+    /// Writes a header row and one row per completed phase.
+    ///
+    /// Columns follow [`SOLVER_STATISTICS_CSV_COLUMNS`].
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", SOLVER_STATISTICS_CSV_COLUMNS.join(","))?;
+        for phase in &self.phases {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                phase.phase_index,
+                csv_field(&phase.phase_type),
+                phase.elapsed.as_secs_f64(),
+                phase.step_count,
+                phase.moves_generated,
+                phase.moves_evaluated,
+                phase.moves_accepted,
+                phase.moves_applied,
+                phase.acceptance_rate(),
+                phase.best_score_improvements,
+                phase.score_calculations,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the full history, phases and score improvements, as JSON.
+    pub fn to_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        serde_json::to_writer_pretty(writer, self).map_err(io::Error::from)
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Recorder enabled per solve; owns the [`SolverStatistics`] it builds.
+#[derive(Debug, Clone, Default)]
+pub struct StatisticsCollector {
+    statistics: SolverStatistics,
+}
+
+impl StatisticsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record_phase(&mut self, phase: &PhaseStats) {
+        self.statistics
+            .phases
+            .push(PhaseStatistics::from_phase(phase));
+    }
+
+    pub(crate) fn record_move_type_improvement(&mut self, move_type: &str) {
+        *self
+            .statistics
+            .move_type_improvements
+            .entry(move_type.to_string())
+            .or_default() += 1;
+    }
+
+    pub(crate) fn record_score_improvement(
+        &mut self,
+        elapsed: Duration,
+        step_count: u64,
+        score: String,
+    ) {
+        self.statistics.score_improvements.push(ScoreImprovement {
+            elapsed,
+            step_count,
+            score,
+        });
+    }
+
+    pub fn statistics(&self) -> &SolverStatistics {
+        &self.statistics
+    }
+
+    pub fn into_statistics(self) -> SolverStatistics {
+        self.statistics
+    }
+}
@@ -92,10 +92,22 @@ pub struct PhaseTelemetry {
     pub moves_score_improving: u64,
     pub moves_applied_improving: u64,
     pub score_calculations: u64,
+    pub best_score_improvements: u64,
     pub generation_time: Duration,
     pub evaluation_time: Duration,
 }
 
+impl PhaseTelemetry {
+    /// Fraction of evaluated moves the acceptor accepted during this phase.
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.moves_evaluated == 0 {
+            0.0
+        } else {
+            self.moves_accepted as f64 / self.moves_evaluated as f64
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct AppliedMoveTelemetry {
     pub step_index: u64,
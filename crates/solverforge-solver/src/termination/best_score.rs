@@ -1,6 +1,7 @@
 // Score-based termination conditions.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use solverforge_core::domain::PlanningSolution;
 use solverforge_core::score::Score;
@@ -61,16 +62,52 @@ where
 /// by a user-provided function. For HardSoftScore, this typically means
 /// hard score >= 0 (no hard constraint violations).
 ///
+/// With a grace period, solving continues for that long after the first
+/// feasible best score, so soft constraints keep improving before the solver
+/// stops. The grace is measured from
+/// [`SolverScope::first_feasible_elapsed`], which records the first best score
+/// passing [`Score::is_feasible`], so only
+/// [`score_is_feasible_with_grace_period`](Self::score_is_feasible_with_grace_period)
+/// offers one; custom checks always stop as soon as they pass.
+///
 /// # Zero-Erasure Design
 ///
 /// The feasibility check function `F` is stored as a concrete generic type
 /// parameter, eliminating virtual dispatch overhead when checking termination.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use solverforge_core::domain::PlanningSolution;
+/// use solverforge_core::score::HardSoftScore;
+/// use solverforge_solver::termination::BestScoreFeasibleTermination;
+///
+/// #[derive(Clone)]
+/// struct MySolution;
+/// impl PlanningSolution for MySolution {
+///     type Score = HardSoftScore;
+///     fn score(&self) -> Option<Self::Score> { None }
+///     fn set_score(&mut self, _: Option<Self::Score>) {}
+/// }
+///
+/// // Keep optimizing soft constraints for 30 seconds once feasible.
+/// let term = BestScoreFeasibleTermination::<MySolution, _>::score_is_feasible_with_grace_period(
+///     Duration::from_secs(30),
+/// );
+/// assert_eq!(term.grace_period(), Duration::from_secs(30));
+/// ```
 pub struct BestScoreFeasibleTermination<S, F>
 where
     S: PlanningSolution,
     F: Fn(&S::Score) -> bool + Send + Sync,
 {
     feasibility_check: F,
+    grace_period: Duration,
+    // Whether `feasibility_check` is `Score::is_feasible`, which the solver
+    // scope can also evaluate inside a phase's step loop.
+    checks_score_feasibility: bool,
     _phantom: std::marker::PhantomData<fn() -> S>,
 }
 
@@ -80,7 +117,9 @@ where
     F: Fn(&S::Score) -> bool + Send + Sync,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("BestScoreFeasibleTermination").finish()
+        f.debug_struct("BestScoreFeasibleTermination")
+            .field("grace_period", &self.grace_period)
+            .finish()
     }
 }
 
@@ -92,9 +131,15 @@ where
     pub fn new(feasibility_check: F) -> Self {
         Self {
             feasibility_check,
+            grace_period: Duration::ZERO,
+            checks_score_feasibility: false,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
 }
 
 impl<S: PlanningSolution> BestScoreFeasibleTermination<S, fn(&S::Score) -> bool> {
@@ -104,6 +149,24 @@ impl<S: PlanningSolution> BestScoreFeasibleTermination<S, fn(&S::Score) -> bool>
     pub fn score_at_least_zero() -> Self {
         Self::new(|score| *score >= S::Score::zero())
     }
+
+    /// Creates a termination that checks [`Score::is_feasible`].
+    ///
+    /// Unlike custom checks, this one also stops a running phase at its next
+    /// step boundary instead of waiting for the phase to end.
+    pub fn score_is_feasible() -> Self {
+        let mut termination = Self::new(|score| score.is_feasible());
+        termination.checks_score_feasibility = true;
+        termination
+    }
+
+    /// Like [`score_is_feasible`](Self::score_is_feasible), but keeps solving
+    /// for `grace_period` after the first feasible best score.
+    pub fn score_is_feasible_with_grace_period(grace_period: Duration) -> Self {
+        let mut termination = Self::score_is_feasible();
+        termination.grace_period = grace_period;
+        termination
+    }
 }
 
 impl<S, D, BestCb, F> Termination<S, D, BestCb> for BestScoreFeasibleTermination<S, F>
@@ -114,9 +177,24 @@ where
     F: Fn(&S::Score) -> bool + Send + Sync,
 {
     fn is_terminated(&self, solver_scope: &SolverScope<S, D, BestCb>) -> bool {
-        solver_scope
+        let feasible = solver_scope
             .best_score()
-            .map(|score| (self.feasibility_check)(score))
-            .unwrap_or(false)
+            .is_some_and(|score| (self.feasibility_check)(score));
+        if !feasible {
+            return false;
+        }
+        if self.grace_period.is_zero() {
+            return true;
+        }
+        solver_scope
+            .first_feasible_elapsed()
+            .zip(solver_scope.elapsed())
+            .is_some_and(|(since, now)| now.saturating_sub(since) >= self.grace_period)
+    }
+
+    fn install_inphase_limits(&self, solver_scope: &mut SolverScope<S, D, BestCb>) {
+        if self.checks_score_feasibility {
+            solver_scope.install_inphase_feasible_grace(self.grace_period);
+        }
     }
 }
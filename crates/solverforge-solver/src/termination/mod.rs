@@ -4,6 +4,7 @@ mod best_score;
 mod composite;
 mod diminished_returns;
 mod move_count;
+mod nested;
 mod score_calculation_count;
 mod step_count;
 mod time;
@@ -14,6 +15,7 @@ pub use best_score::{BestScoreFeasibleTermination, BestScoreTermination};
 pub use composite::{AndTermination, OrTermination};
 pub use diminished_returns::DiminishedReturnsTermination;
 pub use move_count::MoveCountTermination;
+pub use nested::NestedTermination;
 pub use score_calculation_count::ScoreCalculationCountTermination;
 pub use step_count::StepCountTermination;
 pub use time::TimeTermination;
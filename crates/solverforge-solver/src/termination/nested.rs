@@ -0,0 +1,230 @@
+/* Runtime-sized termination tree built from nested termination config.
+
+`OrTermination` and `AndTermination` fold compile-time tuples. Configured
+`any_of`/`all_of` blocks have a shape known only after parsing, so they are
+folded into this enum instead.
+*/
+
+use std::fmt::Debug;
+
+use solverforge_config::TerminationConfig;
+use solverforge_core::domain::PlanningSolution;
+use solverforge_core::score::ParseableScore;
+use solverforge_scoring::Director;
+
+use super::{
+    BestScoreFeasibleTermination, BestScoreTermination, DiminishedReturnsTermination,
+    MoveCountTermination, ScoreCalculationCountTermination, StepCountTermination, Termination,
+    TimeTermination, UnimprovedStepCountTermination, UnimprovedTimeTermination,
+};
+use crate::scope::ProgressCallback;
+use crate::scope::SolverScope;
+
+/// Termination tree folded from a [`TerminationConfig`] with nested blocks.
+///
+/// Every limit set on a node is an alternative: the node stops solving when
+/// any of its own limits, any `any_of` child, or all of its `all_of` children
+/// are reached. Children are always evaluated in full so stateful limits such
+/// as unimproved-step counters observe every check.
+///
+/// Only limits reachable through `any_of` paths are installed as in-phase
+/// limits; an `all_of` child cannot stop a phase on its own.
+///
+/// # Example
+///
+/// ```
+/// use solverforge_config::TerminationConfig;
+/// use solverforge_core::domain::PlanningSolution;
+/// use solverforge_core::score::SoftScore;
+/// use solverforge_solver::termination::NestedTermination;
+///
+/// #[derive(Clone)]
+/// struct MySolution;
+/// impl PlanningSolution for MySolution {
+///     type Score = SoftScore;
+///     fn score(&self) -> Option<Self::Score> { None }
+///     fn set_score(&mut self, _: Option<Self::Score>) {}
+/// }
+///
+/// // Stop after 60 seconds or at a zero score, but never before 100 steps.
+/// let config = TerminationConfig {
+///     all_of: vec![
+///         TerminationConfig {
+///             any_of: vec![
+///                 TerminationConfig {
+///                     seconds_spent_limit: Some(60),
+///                     ..Default::default()
+///                 },
+///                 TerminationConfig {
+///                     best_score_limit: Some("0".to_string()),
+///                     ..Default::default()
+///                 },
+///             ],
+///             ..Default::default()
+///         },
+///         TerminationConfig {
+///             step_count_limit: Some(100),
+///             ..Default::default()
+///         },
+///     ],
+///     ..Default::default()
+/// };
+/// let term = NestedTermination::<MySolution>::from_config(&config);
+/// assert!(term.is_some());
+/// ```
+pub enum NestedTermination<S: PlanningSolution> {
+    Time(TimeTermination),
+    BestScore(BestScoreTermination<S::Score>),
+    FeasibleThen(BestScoreFeasibleTermination<S, fn(&S::Score) -> bool>),
+    StepCount(StepCountTermination),
+    MoveCount(MoveCountTermination<S>),
+    ScoreCalculationCount(ScoreCalculationCountTermination<S>),
+    UnimprovedStepCount(UnimprovedStepCountTermination<S>),
+    UnimprovedTime(UnimprovedTimeTermination<S>),
+    DiminishedReturns(DiminishedReturnsTermination<S>),
+    AnyOf(Vec<NestedTermination<S>>),
+    AllOf(Vec<NestedTermination<S>>),
+}
+
+impl<S> NestedTermination<S>
+where
+    S: PlanningSolution,
+    S::Score: ParseableScore,
+{
+    /// Folds a termination config into a tree, or `None` if it sets no limit.
+    ///
+    /// Unparsable best-score limits are skipped, as in flat configs.
+    pub fn from_config(config: &TerminationConfig) -> Option<Self> {
+        let mut alternatives = Vec::new();
+        if let Some(limit) = config.time_limit() {
+            alternatives.push(Self::Time(TimeTermination::new(limit)));
+        }
+        if let Some(deadline) = config.deadline() {
+            alternatives.push(Self::Time(TimeTermination::until(deadline)));
+        }
+        if let Some(target) = config
+            .best_score_limit
+            .as_deref()
+            .and_then(|score| S::Score::parse(score).ok())
+        {
+            alternatives.push(Self::BestScore(BestScoreTermination::new(target)));
+        }
+        if let Some(grace_period) = config.feasible_then_limit() {
+            alternatives.push(Self::FeasibleThen(
+                BestScoreFeasibleTermination::score_is_feasible_with_grace_period(grace_period),
+            ));
+        }
+        if let Some(limit) = config.step_count_limit {
+            alternatives.push(Self::StepCount(StepCountTermination::new(limit)));
+        }
+        if let Some(limit) = config.move_count_limit {
+            alternatives.push(Self::MoveCount(MoveCountTermination::new(limit)));
+        }
+        if let Some(limit) = config.score_calculation_count_limit {
+            alternatives.push(Self::ScoreCalculationCount(
+                ScoreCalculationCountTermination::new(limit),
+            ));
+        }
+        if let Some(limit) = config.unimproved_step_count_limit {
+            alternatives.push(Self::UnimprovedStepCount(
+                UnimprovedStepCountTermination::new(limit),
+            ));
+        }
+        if let Some(limit) = config.unimproved_time_limit() {
+            alternatives.push(Self::UnimprovedTime(UnimprovedTimeTermination::new(limit)));
+        }
+        if let Some((window, min_ratio)) = config.diminished_returns() {
+            alternatives.push(Self::DiminishedReturns(DiminishedReturnsTermination::new(
+                window, min_ratio,
+            )));
+        }
+        alternatives.extend(config.any_of.iter().filter_map(Self::from_config));
+        let all_of = config
+            .all_of
+            .iter()
+            .filter_map(Self::from_config)
+            .collect::<Vec<_>>();
+        if !all_of.is_empty() {
+            alternatives.push(Self::fold(all_of, Self::AllOf));
+        }
+        (!alternatives.is_empty()).then(|| Self::fold(alternatives, Self::AnyOf))
+    }
+
+    fn fold(mut children: Vec<Self>, group: fn(Vec<Self>) -> Self) -> Self {
+        if children.len() == 1 {
+            children.pop().expect("checked one child")
+        } else {
+            group(children)
+        }
+    }
+}
+
+impl<S: PlanningSolution> Debug for NestedTermination<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Time(t) => t.fmt(f),
+            Self::BestScore(t) => t.fmt(f),
+            Self::FeasibleThen(t) => t.fmt(f),
+            Self::StepCount(t) => t.fmt(f),
+            Self::MoveCount(t) => t.fmt(f),
+            Self::ScoreCalculationCount(t) => t.fmt(f),
+            Self::UnimprovedStepCount(t) => t.fmt(f),
+            Self::UnimprovedTime(t) => t.fmt(f),
+            Self::DiminishedReturns(t) => t.fmt(f),
+            Self::AnyOf(children) => f.debug_tuple("AnyOf").field(children).finish(),
+            Self::AllOf(children) => f.debug_tuple("AllOf").field(children).finish(),
+        }
+    }
+}
+
+impl<S, D, BestCb> Termination<S, D, BestCb> for NestedTermination<S>
+where
+    S: PlanningSolution,
+    D: Director<S>,
+    BestCb: ProgressCallback<S>,
+{
+    fn is_terminated(&self, solver_scope: &SolverScope<S, D, BestCb>) -> bool {
+        match self {
+            Self::Time(t) => t.is_terminated(solver_scope),
+            Self::BestScore(t) => t.is_terminated(solver_scope),
+            Self::FeasibleThen(t) => t.is_terminated(solver_scope),
+            Self::StepCount(t) => t.is_terminated(solver_scope),
+            Self::MoveCount(t) => t.is_terminated(solver_scope),
+            Self::ScoreCalculationCount(t) => t.is_terminated(solver_scope),
+            Self::UnimprovedStepCount(t) => t.is_terminated(solver_scope),
+            Self::UnimprovedTime(t) => t.is_terminated(solver_scope),
+            Self::DiminishedReturns(t) => t.is_terminated(solver_scope),
+            Self::AnyOf(children) => children
+                .iter()
+                .fold(false, |any, child| child.is_terminated(solver_scope) | any),
+            Self::AllOf(children) => children
+                .iter()
+                .fold(true, |all, child| child.is_terminated(solver_scope) & all),
+        }
+    }
+
+    fn install_inphase_limits(&self, solver_scope: &mut SolverScope<S, D, BestCb>) {
+        match self {
+            Self::Time(t) => {
+                solver_scope.tighten_time_limit(t.limit());
+                Termination::<S, D, BestCb>::install_inphase_limits(t, solver_scope);
+            }
+            Self::BestScore(t) => t.install_inphase_limits(solver_scope),
+            Self::FeasibleThen(t) => t.install_inphase_limits(solver_scope),
+            Self::StepCount(t) => {
+                Termination::<S, D, BestCb>::install_inphase_limits(t, solver_scope)
+            }
+            Self::MoveCount(t) => t.install_inphase_limits(solver_scope),
+            Self::ScoreCalculationCount(t) => t.install_inphase_limits(solver_scope),
+            Self::UnimprovedStepCount(t) => t.install_inphase_limits(solver_scope),
+            Self::UnimprovedTime(t) => t.install_inphase_limits(solver_scope),
+            Self::DiminishedReturns(t) => t.install_inphase_limits(solver_scope),
+            Self::AnyOf(children) => {
+                for child in children {
+                    child.install_inphase_limits(solver_scope);
+                }
+            }
+            Self::AllOf(_) => {}
+        }
+    }
+}
@@ -80,6 +80,37 @@ fn test_best_score_feasible_termination_custom() {
     assert!(term.is_terminated(&scope));
 }
 
+#[test]
+fn test_best_score_feasible_termination_waits_out_grace_period() {
+    let mut scope = create_scope_with_score(SoftScore::of(-1));
+    let term = BestScoreFeasibleTermination::<TestSolution, _>::score_is_feasible_with_grace_period(
+        std::time::Duration::from_secs(60),
+    );
+
+    assert!(!term.is_terminated(&scope));
+    scope.set_best_solution(TestSolution::with_score(SoftScore::of(0)), SoftScore::of(0));
+    assert!(!term.is_terminated(&scope));
+
+    scope.advance_elapsed(std::time::Duration::from_secs(59));
+    assert!(!term.is_terminated(&scope));
+    scope.advance_elapsed(std::time::Duration::from_secs(1));
+    assert!(term.is_terminated(&scope));
+}
+
+#[test]
+fn test_best_score_feasible_termination_custom_check_ignores_score_feasibility() {
+    // The custom check passes on a score `Score::is_feasible` rejects, so the
+    // termination must not wait on the solver's first-feasible clock.
+    let scope = create_scope_with_score(SoftScore::of(-5));
+    let term = BestScoreFeasibleTermination::<TestSolution, _>::new(|score: &SoftScore| {
+        *score >= SoftScore::of(-10)
+    });
+
+    assert_eq!(scope.first_feasible_elapsed(), None);
+    assert_eq!(term.grace_period(), std::time::Duration::ZERO);
+    assert!(term.is_terminated(&scope));
+}
+
 #[test]
 fn test_unimproved_step_count_termination() {
     let mut scope = create_scope_with_score(SoftScore::of(-10));
@@ -216,6 +247,28 @@ fn test_unimproved_time_termination_initial_score() {
     assert!(!term.is_terminated(&scope));
 }
 
+#[test]
+fn test_time_termination_honors_a_passed_deadline_after_the_first_phase() {
+    let mut scope = create_scope();
+    scope.start_solving();
+    let term = TimeTermination::until(std::time::SystemTime::UNIX_EPOCH);
+
+    assert!(!term.is_terminated(&scope));
+    drop(crate::scope::PhaseScope::new(&mut scope, 0));
+    assert!(term.is_terminated(&scope));
+}
+
+#[test]
+fn test_time_termination_limit_fires_before_a_later_deadline() {
+    let mut scope = create_scope();
+    scope.start_solving();
+    let deadline = std::time::SystemTime::now() + std::time::Duration::from_secs(3600);
+    let term = TimeTermination::new(std::time::Duration::ZERO).with_deadline(deadline);
+
+    assert_eq!(term.deadline(), Some(deadline));
+    assert!(term.is_terminated(&scope));
+}
+
 // Diminished returns termination tests
 
 use std::thread::sleep;
@@ -284,3 +337,84 @@ fn test_diminished_no_score_does_not_terminate() {
     sleep(Duration::from_millis(20));
     assert!(!termination.is_terminated(&scope));
 }
+
+// Nested config termination tests
+
+use solverforge_config::TerminationConfig;
+
+fn best_score_limit(score: &str) -> TerminationConfig {
+    TerminationConfig {
+        best_score_limit: Some(score.to_string()),
+        ..Default::default()
+    }
+}
+
+fn step_count_limit(limit: u64) -> TerminationConfig {
+    TerminationConfig {
+        step_count_limit: Some(limit),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_nested_any_of_stops_when_one_child_is_reached() {
+    let config = TerminationConfig {
+        any_of: vec![
+            TerminationConfig {
+                seconds_spent_limit: Some(60),
+                ..Default::default()
+            },
+            best_score_limit("0"),
+        ],
+        ..Default::default()
+    };
+    let termination = NestedTermination::<TestSolution>::from_config(&config).unwrap();
+
+    assert!(!termination.is_terminated(&create_scope_with_score(SoftScore::of(-1))));
+    assert!(termination.is_terminated(&create_scope_with_score(SoftScore::of(0))));
+}
+
+#[test]
+fn test_nested_all_of_waits_for_every_child() {
+    let config = TerminationConfig {
+        all_of: vec![best_score_limit("0"), step_count_limit(2)],
+        ..Default::default()
+    };
+    let termination = NestedTermination::<TestSolution>::from_config(&config).unwrap();
+
+    let mut scope = create_scope_with_score(SoftScore::of(0));
+    assert!(!termination.is_terminated(&scope));
+    scope.increment_step_count();
+    scope.increment_step_count();
+    assert!(termination.is_terminated(&scope));
+
+    let mut scope = create_scope_with_score(SoftScore::of(-1));
+    scope.increment_step_count();
+    scope.increment_step_count();
+    assert!(!termination.is_terminated(&scope));
+}
+
+#[test]
+fn test_nested_all_of_installs_no_inphase_limits() {
+    let config = TerminationConfig {
+        all_of: vec![best_score_limit("0"), step_count_limit(2)],
+        ..Default::default()
+    };
+    let termination = NestedTermination::<TestSolution>::from_config(&config).unwrap();
+
+    let mut scope = create_scope_with_score(SoftScore::of(0));
+    termination.install_inphase_limits(&mut scope);
+    scope.increment_step_count();
+    scope.increment_step_count();
+    scope.increment_step_count();
+    assert!(!scope.should_terminate());
+}
+
+#[test]
+fn test_nested_config_without_limits_builds_nothing() {
+    let config = TerminationConfig {
+        any_of: vec![TerminationConfig::default()],
+        ..Default::default()
+    };
+    assert!(NestedTermination::<TestSolution>::from_config(&config).is_none());
+}
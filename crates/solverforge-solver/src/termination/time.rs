@@ -1,6 +1,6 @@
 // Time-based termination.
 
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use solverforge_core::domain::PlanningSolution;
 use solverforge_scoring::Director;
@@ -9,27 +9,38 @@ use super::Termination;
 use crate::scope::ProgressCallback;
 use crate::scope::SolverScope;
 
-/* Terminates after a time limit.
+/* Terminates after a time limit or at a wall-clock deadline, whichever
+comes first.
+
+A deadline that has already passed when solving starts does not stop the
+first phase, so construction still initializes the solution; solving then
+terminates at the next phase boundary.
 
 # Example
 
 ```
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use solverforge_solver::termination::TimeTermination;
 
 let term = TimeTermination::new(Duration::from_secs(30));
 let term = TimeTermination::seconds(30);
 let term = TimeTermination::millis(500);
+let term = TimeTermination::seconds(30)
+    .with_deadline(SystemTime::now() + Duration::from_secs(10));
 ```
 */
 #[derive(Debug, Clone)]
 pub struct TimeTermination {
     limit: Duration,
+    deadline: Option<SystemTime>,
 }
 
 impl TimeTermination {
     pub fn new(limit: Duration) -> Self {
-        Self { limit }
+        Self {
+            limit,
+            deadline: None,
+        }
     }
 
     pub fn millis(ms: u64) -> Self {
@@ -39,6 +50,29 @@ impl TimeTermination {
     pub fn seconds(secs: u64) -> Self {
         Self::new(Duration::from_secs(secs))
     }
+
+    // Terminates only at the given wall-clock deadline.
+    pub fn until(deadline: SystemTime) -> Self {
+        Self::new(Duration::MAX).with_deadline(deadline)
+    }
+
+    pub fn with_deadline(mut self, deadline: SystemTime) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn limit(&self) -> Duration {
+        self.limit
+    }
+
+    pub fn deadline(&self) -> Option<SystemTime> {
+        self.deadline
+    }
+
+    fn deadline_passed(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| SystemTime::now() >= deadline)
+    }
 }
 
 impl<S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>> Termination<S, D, BestCb>
@@ -46,5 +80,20 @@ impl<S: PlanningSolution, D: Director<S>, BestCb: ProgressCallback<S>> Terminati
 {
     fn is_terminated(&self, solver_scope: &SolverScope<S, D, BestCb>) -> bool {
         solver_scope.elapsed().is_some_and(|e| e >= self.limit)
+            || (self.deadline_passed() && solver_scope.has_started_phase())
+    }
+
+    fn install_inphase_limits(&self, solver_scope: &mut SolverScope<S, D, BestCb>) {
+        // A deadline still ahead becomes an in-phase time limit; one already
+        // behind stops solving once construction has finished.
+        let Some(deadline) = self.deadline else {
+            return;
+        };
+        let Ok(remaining) = deadline.duration_since(SystemTime::now()) else {
+            solver_scope.install_inphase_passed_deadline();
+            return;
+        };
+        let elapsed = solver_scope.elapsed().unwrap_or_default();
+        solver_scope.tighten_time_limit(elapsed.saturating_add(remaining));
     }
 }
@@ -1,6 +1,7 @@
 pub use solverforge_cvrp::{
-    depot_for_entity, get_route, replace_route, route_distance, route_feasible, route_hooks,
-    savings_depot_for_entity, savings_distance, savings_feasible, savings_hooks,
-    savings_metric_class, MatrixDistanceMeter, MatrixIntraDistanceMeter, ProblemData, VrpSolution,
-    UNREACHABLE,
+    compute_arrival_times, depot_for_entity, get_route, replace_route, route_distance,
+    route_feasible, route_hooks, route_time_feasible, savings_depot_for_entity, savings_distance,
+    savings_feasible, savings_hooks, savings_metric_class, time_window, MatrixDistanceMeter,
+    MatrixIntraDistanceMeter, ProblemData, Stop, StopTiming, TimeWindow, TimeWindowViolation,
+    VrpSolution, UNREACHABLE,
 };
@@ -1,6 +1,8 @@
 // Integration tests for derive macros.
 
-use solverforge::__internal::{PlanningId, PlanningSolution as PlanningSolutionTrait};
+use solverforge::__internal::{
+    PlanningId, PlanningSolution as PlanningSolutionTrait, SolvableSolution,
+};
 use solverforge::prelude::*;
 use solverforge::stream::CollectionExtract;
 
@@ -8,17 +10,26 @@ use solverforge::stream::CollectionExtract;
 mod aliased_route_plan_domain;
 #[path = "derive_macros/duplicate_names/mod.rs"]
 mod duplicate_names;
+#[path = "derive_macros/fleet_plan/mod.rs"]
+mod fleet_plan_domain;
+#[path = "derive_macros/linked_route_plan/mod.rs"]
+mod linked_route_plan_domain;
 #[path = "derive_macros/route_plan/mod.rs"]
 mod route_plan_domain;
 #[path = "derive_macros/schedule/mod.rs"]
 mod schedule_domain;
 #[path = "derive_macros/shadow_plan/mod.rs"]
 mod shadow_plan_domain;
+#[path = "derive_macros/timed_route_plan/mod.rs"]
+mod timed_route_plan_domain;
 
 use aliased_route_plan_domain::{AliasedRoutePlan, Route as AliasedRoute, Visit as AliasedVisit};
+use fleet_plan_domain::{FleetPlan, FleetVehicle, FleetVisit};
+use linked_route_plan_domain::{LinkedRoute, LinkedRoutePlan, LinkedVisit};
 use route_plan_domain::{Route, RoutePlan, Visit};
 use schedule_domain::{Employee, Schedule, Shift};
 use shadow_plan_domain::{MultiOwnerShadowPlan, RoutedVisit, ShadowRoute, ShadowShift, ShiftVisit};
+use timed_route_plan_domain::{TimedRoute, TimedRoutePlan, TimedVisit};
 
 #[test]
 fn test_problem_fact_derives_correctly() {
@@ -33,6 +44,41 @@ fn test_problem_fact_derives_correctly() {
     );
 }
 
+#[test]
+fn test_problem_fact_resolves_by_lookup_key() {
+    let schedule = Schedule {
+        employees: vec![
+            Employee {
+                id: 1,
+                name: "Alice".to_string(),
+            },
+            Employee {
+                id: 2,
+                name: "Bob".to_string(),
+            },
+        ],
+        shifts: Vec::new(),
+        score: None,
+    };
+    assert_eq!(schedule.employees[1].lookup_key(), "Bob");
+
+    let descriptor = Schedule::descriptor();
+    let employee_descriptor = descriptor
+        .problem_fact_descriptors
+        .iter()
+        .find(|fact| fact.type_name == "Employee")
+        .expect("Employee descriptor should be present");
+    assert_eq!(employee_descriptor.lookup_key_field, Some("name"));
+
+    let index = employee_descriptor
+        .lookup_key_index::<String>(&schedule)
+        .expect("names should index by lookup key");
+    assert_eq!(index.len(), 2);
+    assert_eq!(schedule.employees[index["Bob"]].id, 2);
+    assert_eq!(index.get("Carol"), None);
+    assert_eq!(employee_descriptor.lookup_key_index::<i64>(&schedule), None);
+}
+
 #[test]
 fn test_planning_entity_derives_correctly() {
     let shift = Shift {
@@ -105,6 +151,15 @@ fn test_field_only_list_solution_preserves_list_descriptor_metadata() {
         visits_var.variable_type,
         solverforge_core::domain::VariableType::List
     );
+
+    let route = Route {
+        id: 1,
+        visits: vec![2, 0],
+    };
+    let list_getter = visits_var
+        .list_getter
+        .expect("list variables expose an element getter");
+    assert_eq!(list_getter(&route), &[2, 0]);
 }
 
 #[test]
@@ -208,6 +263,139 @@ fn test_multi_owner_list_shadows_are_descriptor_scoped_and_positioned() {
     assert_eq!(plan.routed_visits[1].next, None);
 }
 
+#[test]
+fn test_cascading_listeners_update_transitive_shadows_in_list_order() {
+    let mut plan = TimedRoutePlan {
+        routes: vec![TimedRoute {
+            id: 1,
+            visits: vec![0, 1, 2],
+        }],
+        visits: [10, 20, 30]
+            .into_iter()
+            .enumerate()
+            .map(|(id, service)| TimedVisit {
+                id,
+                service,
+                previous: None,
+                arrival: -1,
+                departure: -1,
+            })
+            .collect(),
+        score: None,
+    };
+
+    <TimedRoutePlan as PlanningSolutionTrait>::update_entity_shadows(&mut plan, 0, 0);
+    let times = |plan: &TimedRoutePlan| -> Vec<(i64, i64)> {
+        plan.visits
+            .iter()
+            .map(|visit| (visit.arrival, visit.departure))
+            .collect()
+    };
+    assert_eq!(times(&plan), vec![(0, 10), (15, 35), (40, 70)]);
+
+    plan.routes[0].visits = vec![2, 0, 1];
+    <TimedRoutePlan as PlanningSolutionTrait>::update_entity_shadows(&mut plan, 0, 0);
+
+    assert_eq!(plan.visits[0].previous, Some(2));
+    assert_eq!(times(&plan), vec![(35, 45), (50, 70), (0, 30)]);
+}
+
+#[test]
+fn test_inverse_relation_follows_scalar_reassignment() {
+    let plan = FleetPlan {
+        vehicles: (0..2)
+            .map(|id| FleetVehicle {
+                id,
+                visits: Vec::new(),
+            })
+            .collect(),
+        visits: [Some(0), Some(0), Some(1), None]
+            .into_iter()
+            .enumerate()
+            .map(|(id, vehicle)| FleetVisit { id, vehicle })
+            .collect(),
+        score: None,
+    };
+    let mut director = ScoreDirector::simple(
+        plan,
+        <FleetPlan as SolvableSolution>::descriptor(),
+        <FleetPlan as SolvableSolution>::entity_count,
+    );
+    director.calculate_score();
+    let inverse = |director: &ScoreDirector<FleetPlan, ()>| -> Vec<Vec<usize>> {
+        director
+            .working_solution()
+            .vehicles
+            .iter()
+            .map(|vehicle| vehicle.visits.clone())
+            .collect()
+    };
+    assert_eq!(inverse(&director), vec![vec![0, 1], vec![2]]);
+
+    director.before_variable_changed(1, 0);
+    director.working_solution_mut().visits[0].vehicle = Some(1);
+    director.after_variable_changed(1, 0);
+    assert_eq!(inverse(&director), vec![vec![1], vec![0, 2]]);
+
+    director.before_variable_changed(1, 2);
+    director.working_solution_mut().visits[2].vehicle = None;
+    director.after_variable_changed(1, 2);
+    assert_eq!(inverse(&director), vec![vec![1], vec![0]]);
+}
+
+#[test]
+fn test_list_change_windows_relink_only_boundary_elements() {
+    let mut plan = LinkedRoutePlan {
+        routes: vec![
+            LinkedRoute {
+                id: 0,
+                visits: vec![0, 1, 2, 3, 4],
+            },
+            LinkedRoute {
+                id: 1,
+                visits: vec![5, 6],
+            },
+        ],
+        visits: (0..7)
+            .map(|id| LinkedVisit {
+                id,
+                route: None,
+                previous: None,
+                next: None,
+            })
+            .collect(),
+        score: None,
+    };
+    <LinkedRoutePlan as PlanningSolutionTrait>::update_all_shadows(&mut plan);
+    // Far from the edit: a boundary-only update must leave this untouched.
+    plan.visits[4].previous = Some(99);
+
+    // Move visit 1 from route 0 position 1 to route 1 position 1.
+    plan.routes[0].visits.remove(1);
+    plan.routes[1].visits.insert(1, 1);
+    let (closed_gap, inserted) = (1..1, 1..2);
+    <LinkedRoutePlan as PlanningSolutionTrait>::update_list_shadows(
+        &mut plan,
+        0,
+        0,
+        std::slice::from_ref(&closed_gap),
+    );
+    <LinkedRoutePlan as PlanningSolutionTrait>::update_list_shadows(
+        &mut plan,
+        0,
+        1,
+        std::slice::from_ref(&inserted),
+    );
+
+    let links = |visit: &LinkedVisit| (visit.route, visit.previous, visit.next);
+    assert_eq!(links(&plan.visits[1]), (Some(1), Some(5), Some(6)));
+    assert_eq!(links(&plan.visits[0]), (Some(0), None, Some(2)));
+    assert_eq!(links(&plan.visits[2]), (Some(0), Some(0), Some(3)));
+    assert_eq!(links(&plan.visits[5]), (Some(1), None, Some(1)));
+    assert_eq!(links(&plan.visits[6]), (Some(1), Some(1), None));
+    assert_eq!(plan.visits[4].previous, Some(99));
+}
+
 #[test]
 fn test_multi_owner_list_sources_are_owner_field_scoped() {
     let plan = MultiOwnerShadowPlan {
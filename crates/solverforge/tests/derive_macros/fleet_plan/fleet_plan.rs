@@ -0,0 +1,15 @@
+use solverforge::prelude::*;
+
+use super::{FleetVehicle, FleetVisit};
+
+#[planning_solution]
+pub struct FleetPlan {
+    #[planning_entity_collection]
+    pub vehicles: Vec<FleetVehicle>,
+
+    #[planning_entity_collection]
+    pub visits: Vec<FleetVisit>,
+
+    #[planning_score]
+    pub score: Option<HardSoftScore>,
+}
@@ -0,0 +1,10 @@
+use solverforge::prelude::*;
+
+#[planning_entity]
+pub struct FleetVehicle {
+    #[planning_id]
+    pub id: usize,
+
+    #[inverse_relation_shadow_variable(source_variable_name = "vehicle")]
+    pub visits: Vec<usize>,
+}
@@ -0,0 +1,10 @@
+use solverforge::prelude::*;
+
+#[planning_entity]
+pub struct FleetVisit {
+    #[planning_id]
+    pub id: usize,
+
+    #[planning_variable(value_range_provider = "vehicles", allows_unassigned = true)]
+    pub vehicle: Option<usize>,
+}
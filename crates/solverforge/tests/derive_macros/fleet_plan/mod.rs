@@ -0,0 +1,11 @@
+solverforge::planning_model! {
+    root = "crates/solverforge/tests/derive_macros/fleet_plan";
+
+    mod fleet_plan;
+    mod fleet_vehicle;
+    mod fleet_visit;
+
+    pub use fleet_plan::FleetPlan;
+    pub use fleet_vehicle::FleetVehicle;
+    pub use fleet_visit::FleetVisit;
+}
@@ -0,0 +1,21 @@
+use solverforge::prelude::*;
+
+use super::{LinkedRoute, LinkedVisit};
+
+#[planning_solution]
+#[shadow_variable_updates(
+    list_owner = "routes",
+    inverse_field = "route",
+    previous_field = "previous",
+    next_field = "next"
+)]
+pub struct LinkedRoutePlan {
+    #[planning_entity_collection]
+    pub routes: Vec<LinkedRoute>,
+
+    #[problem_fact_collection]
+    pub visits: Vec<LinkedVisit>,
+
+    #[planning_score]
+    pub score: Option<HardSoftScore>,
+}
@@ -0,0 +1,10 @@
+use solverforge::prelude::*;
+
+#[problem_fact]
+pub struct LinkedVisit {
+    #[planning_id]
+    pub id: usize,
+    pub route: Option<usize>,
+    pub previous: Option<usize>,
+    pub next: Option<usize>,
+}
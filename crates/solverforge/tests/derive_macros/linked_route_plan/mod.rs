@@ -0,0 +1,11 @@
+solverforge::planning_model! {
+    root = "crates/solverforge/tests/derive_macros/linked_route_plan";
+
+    mod linked_route;
+    mod linked_route_plan;
+    mod linked_visit;
+
+    pub use linked_route::LinkedRoute;
+    pub use linked_route_plan::LinkedRoutePlan;
+    pub use linked_visit::LinkedVisit;
+}
@@ -4,5 +4,6 @@ use solverforge::prelude::*;
 pub struct Employee {
     #[planning_id]
     pub id: i64,
+    #[lookup_key]
     pub name: String,
 }
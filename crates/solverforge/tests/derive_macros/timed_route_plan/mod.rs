@@ -0,0 +1,11 @@
+solverforge::planning_model! {
+    root = "crates/solverforge/tests/derive_macros/timed_route_plan";
+
+    mod timed_route;
+    mod timed_route_plan;
+    mod timed_visit;
+
+    pub use timed_route::TimedRoute;
+    pub use timed_route_plan::TimedRoutePlan;
+    pub use timed_visit::TimedVisit;
+}
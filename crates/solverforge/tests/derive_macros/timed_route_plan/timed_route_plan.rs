@@ -0,0 +1,39 @@
+use solverforge::prelude::*;
+
+use super::{TimedRoute, TimedVisit};
+
+pub const TRAVEL_TIME: i64 = 5;
+
+#[planning_solution]
+#[shadow_variable_updates(
+    list_owner = "routes",
+    previous_field = "previous",
+    cascading_listener = "update_arrival",
+    cascading_listener = "update_departure"
+)]
+pub struct TimedRoutePlan {
+    #[planning_entity_collection]
+    pub routes: Vec<TimedRoute>,
+
+    #[problem_fact_collection]
+    pub visits: Vec<TimedVisit>,
+
+    #[planning_score]
+    pub score: Option<HardSoftScore>,
+}
+
+impl TimedRoutePlan {
+    // Arrival depends on the previous visit's departure.
+    pub fn update_arrival(&mut self, visit_idx: usize) {
+        self.visits[visit_idx].arrival = match self.visits[visit_idx].previous {
+            Some(previous) => self.visits[previous].departure + TRAVEL_TIME,
+            None => 0,
+        };
+    }
+
+    // Departure depends on this visit's arrival.
+    pub fn update_departure(&mut self, visit_idx: usize) {
+        let visit = &mut self.visits[visit_idx];
+        visit.departure = visit.arrival + visit.service;
+    }
+}
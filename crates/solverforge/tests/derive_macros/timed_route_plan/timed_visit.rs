@@ -0,0 +1,11 @@
+use solverforge::prelude::*;
+
+#[problem_fact]
+pub struct TimedVisit {
+    #[planning_id]
+    pub id: usize,
+    pub service: i64,
+    pub previous: Option<usize>,
+    pub arrival: i64,
+    pub departure: i64,
+}
@@ -0,0 +1,68 @@
+use solverforge::prelude::HardSoftScore;
+use solverforge::{SolverEvent, SolverManager, SolverTerminalReason};
+
+#[path = "nqueens_config_solve/domain/mod.rs"]
+mod domain;
+
+use domain::{Board, Queen, Row};
+
+fn empty_board(n: usize) -> Board {
+    Board {
+        rows: (0..n).map(|id| Row { id }).collect(),
+        queens: (0..n)
+            .map(|column| Queen {
+                id: column,
+                column,
+                row_idx: None,
+            })
+            .collect(),
+        score: None,
+    }
+}
+
+fn conflict_count(board: &Board) -> usize {
+    let mut conflicts = 0;
+    for (index, left) in board.queens.iter().enumerate() {
+        for right in &board.queens[index + 1..] {
+            let (Some(left_row), Some(right_row)) = (left.row_idx, right.row_idx) else {
+                continue;
+            };
+            if left_row == right_row
+                || left_row.abs_diff(right_row) == left.column.abs_diff(right.column)
+            {
+                conflicts += 1;
+            }
+        }
+    }
+    conflicts
+}
+
+#[test]
+fn config_driven_late_acceptance_solves_eight_queens() {
+    static MANAGER: SolverManager<Board> = SolverManager::new();
+
+    let (job_id, mut receiver) = MANAGER.solve(empty_board(8)).expect("job should start");
+    let mut completed_solution = None;
+
+    while let Some(event) = receiver.blocking_recv() {
+        match event {
+            SolverEvent::Completed { metadata, solution } => {
+                assert_eq!(
+                    metadata.terminal_reason,
+                    Some(SolverTerminalReason::TerminatedByConfig)
+                );
+                completed_solution = Some(solution);
+                break;
+            }
+            SolverEvent::Failed { error, .. } => panic!("solver failed: {error}"),
+            _ => {}
+        }
+    }
+
+    let solution = completed_solution.expect("expected a completed solution");
+    assert!(solution.queens.iter().all(|queen| queen.row_idx.is_some()));
+    assert_eq!(conflict_count(&solution), 0);
+    assert_eq!(solution.score, Some(HardSoftScore::of(0, 0)));
+
+    MANAGER.delete(job_id).expect("delete completed job");
+}
@@ -0,0 +1,7 @@
+use solverforge::prelude::*;
+
+#[problem_fact]
+pub struct Row {
+    #[planning_id]
+    pub id: usize,
+}
@@ -21,3 +21,31 @@ fn bound_generated_source_can_build_multiple_scalar_targets() {
 
     assert_eq!(group.targets().len(), 2);
 }
+
+#[test]
+fn entity_with_two_scalar_variables_exposes_both_descriptors() {
+    let descriptor = Plan::descriptor();
+    let shift = descriptor
+        .find_entity_descriptor("Shift")
+        .expect("Shift descriptor should be present");
+
+    let names: Vec<_> = shift.variables().iter().map(|v| v.name).collect();
+    assert_eq!(names, ["primary", "secondary"]);
+    assert!(shift.find_variable("secondary").is_some());
+}
+
+#[test]
+fn scalar_variables_link_to_their_own_value_range_providers() {
+    let descriptor = Plan::descriptor();
+    let shift = descriptor
+        .find_entity_descriptor("Shift")
+        .expect("Shift descriptor should be present");
+
+    let provider = |name: &str| {
+        shift
+            .find_variable(name)
+            .and_then(|variable| variable.value_range_provider)
+    };
+    assert_eq!(provider("primary"), Some("workers"));
+    assert_eq!(provider("secondary"), Some("rooms"));
+}
@@ -2,8 +2,12 @@ solverforge::planning_model! {
     root = "crates/solverforge/tests/scalar_target_source_reuse/domain";
 
     mod plan;
+    mod room;
     mod shift;
+    mod worker;
 
     pub use plan::Plan;
+    pub use room::Room;
     pub use shift::Shift;
+    pub use worker::Worker;
 }
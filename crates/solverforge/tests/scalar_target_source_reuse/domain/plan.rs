@@ -1,9 +1,15 @@
 use solverforge::prelude::*;
 
-use super::Shift;
+use super::{Room, Shift, Worker};
 
 #[planning_solution]
 pub struct Plan {
+    #[problem_fact_collection]
+    pub workers: Vec<Worker>,
+
+    #[problem_fact_collection]
+    pub rooms: Vec<Room>,
+
     #[planning_entity_collection]
     pub shifts: Vec<Shift>,
 
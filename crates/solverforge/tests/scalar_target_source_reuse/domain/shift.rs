@@ -5,9 +5,9 @@ pub struct Shift {
     #[planning_id]
     pub id: i64,
 
-    #[planning_variable]
+    #[planning_variable(value_range_provider = "workers")]
     pub primary: Option<usize>,
 
-    #[planning_variable]
+    #[planning_variable(value_range_provider = "rooms")]
     pub secondary: Option<usize>,
 }
@@ -0,0 +1,87 @@
+use solverforge::prelude::HardSoftScore;
+use solverforge::{Analyzable, ScoreAnalysis, SolverEvent, SolverManager, SolverTerminalReason};
+
+#[path = "score_analysis/domain/mod.rs"]
+mod domain;
+
+use domain::{Board, Queen, Row};
+
+fn board(rows: &[Option<usize>]) -> Board {
+    Board {
+        rows: (0..rows.len()).map(|id| Row { id }).collect(),
+        queens: rows
+            .iter()
+            .enumerate()
+            .map(|(column, &row_idx)| Queen {
+                id: column,
+                column,
+                row_idx,
+            })
+            .collect(),
+        score: None,
+    }
+}
+
+fn match_count(analysis: &ScoreAnalysis<HardSoftScore>, name: &str) -> usize {
+    analysis
+        .constraints
+        .iter()
+        .find(|constraint| constraint.name == name)
+        .unwrap_or_else(|| panic!("missing analysis for {name}"))
+        .match_count
+}
+
+#[test]
+fn nqueens_analysis_reports_conflict_match_counts() {
+    // Every pair shares row 0: six conflicts among four queens.
+    let analysis = board(&[Some(0), Some(0), Some(0), Some(0)]).analyze();
+
+    assert_eq!(analysis.score, HardSoftScore::of(-6, 0));
+    assert_eq!(match_count(&analysis, "Queen conflict"), 6);
+    assert_eq!(match_count(&analysis, "Unassigned queen"), 0);
+
+    // Columns 0 and 1 share a diagonal; column 3 is unassigned.
+    let analysis = board(&[Some(0), Some(1), Some(3), None]).analyze();
+
+    assert_eq!(analysis.score, HardSoftScore::of(-2, 0));
+    assert_eq!(match_count(&analysis, "Queen conflict"), 1);
+    assert_eq!(match_count(&analysis, "Unassigned queen"), 1);
+}
+
+#[test]
+fn completed_job_snapshot_explains_the_final_score() {
+    static MANAGER: SolverManager<Board> = SolverManager::new();
+
+    let (job_id, mut receiver) = MANAGER.solve(board(&[None; 4])).expect("job should start");
+    let mut completed_solution = None;
+
+    while let Some(event) = receiver.blocking_recv() {
+        match event {
+            SolverEvent::Completed { metadata, solution } => {
+                assert_eq!(
+                    metadata.terminal_reason,
+                    Some(SolverTerminalReason::Completed)
+                );
+                completed_solution = Some(solution);
+                break;
+            }
+            SolverEvent::Failed { error, .. } => panic!("solver failed: {error}"),
+            _ => {}
+        }
+    }
+
+    let solution = completed_solution.expect("expected a completed solution");
+    let snapshot = MANAGER
+        .analyze_snapshot(job_id, None)
+        .expect("completed job should keep its snapshot");
+
+    assert_eq!(Some(snapshot.analysis.score), solution.score);
+    let violations = match_count(&snapshot.analysis, "Queen conflict")
+        + match_count(&snapshot.analysis, "Unassigned queen");
+    assert_eq!(
+        snapshot.analysis.score,
+        HardSoftScore::of(-(violations as i64), 0)
+    );
+
+    MANAGER.delete(job_id).expect("delete completed job");
+}
@@ -0,0 +1,11 @@
+solverforge::planning_model! {
+    root = "crates/solverforge/tests/score_analysis/domain";
+
+    mod board;
+    mod queen;
+    mod row;
+
+    pub use board::Board;
+    pub use queen::Queen;
+    pub use row::Row;
+}